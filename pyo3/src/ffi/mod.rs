@@ -103,6 +103,12 @@
 //! |---------------------|-------------------|--------------|--------------|
 //! | `BooleanArray` | `Array::BooleanArray` | `b` | `pa.BooleanArray` |
 //!
+//! ### Fixed-size binary
+//!
+//! | MinArrow inner type | `Array` enum path | Arrow format | PyArrow type |
+//! |---------------------|-------------------|--------------|--------------|
+//! | `FixedSizeBinaryArray` | `Array::FixedSizeBinaryArray` | `w:<width>` | `pa.FixedSizeBinaryArray` |
+//!
 //! ### Text types
 //!
 //! | MinArrow inner type | `Array` enum path | Arrow format | PyArrow type |
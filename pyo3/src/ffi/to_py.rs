@@ -87,6 +87,7 @@ fn arrow_type_to_pyarrow<'py>(
         ArrowType::LargeString => pa.call_method0("large_utf8"),
         // Utf8View data is stored as regular Utf8 after import
         ArrowType::Utf8View => pa.call_method0("utf8"),
+        ArrowType::FixedSizeBinary(width) => pa.call_method1("binary", (*width,)),
 
         #[cfg(feature = "datetime")]
         ArrowType::Date32 => pa.call_method0("date32"),
@@ -127,10 +128,11 @@ fn arrow_type_to_pyarrow<'py>(
 
         ArrowType::Dictionary(key_type) => {
             let index_ty = match key_type {
-                #[cfg(all(feature = "extended_categorical", feature = "extended_numeric_types"))]
+                #[cfg(feature = "default_categorical_8")]
                 CategoricalIndexType::UInt8 => pa.call_method0("uint8")?,
-                #[cfg(all(feature = "extended_categorical", feature = "extended_numeric_types"))]
+                #[cfg(feature = "extended_categorical")]
                 CategoricalIndexType::UInt16 => pa.call_method0("uint16")?,
+                #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
                 CategoricalIndexType::UInt32 => pa.call_method0("uint32")?,
                 #[cfg(feature = "extended_categorical")]
                 CategoricalIndexType::UInt64 => pa.call_method0("uint64")?,
@@ -533,24 +535,10 @@ pub fn array_to_capsules<'py>(
     Ok((schema_capsule.unbind(), array_capsule.unbind()))
 }
 
-/// Exports a MinArrow Table as an ArrowArrayStream PyCapsule.
+/// Wraps an exported ArrowArrayStream in an `arrow_array_stream` PyCapsule.
 ///
-/// The stream yields one struct array (record batch) corresponding to the table.
-pub fn table_to_stream_capsule<'py>(table: &Table, py: Python<'py>) -> PyResult<PyObject> {
-    let fields: Vec<Field> = table.cols.iter().map(|fa| (*fa.field).clone()).collect();
-    let columns: Vec<(Arc<Array>, Schema)> = table
-        .cols
-        .iter()
-        .map(|fa| {
-            (
-                Arc::new(fa.array.clone()),
-                Schema::from(vec![(*fa.field).clone()]),
-            )
-        })
-        .collect();
-
-    let metadata = build_stream_metadata(table);
-    let stream = export_record_batch_stream_with_metadata(vec![columns], fields, metadata);
+/// The capsule destructor releases the stream if it is never consumed.
+fn stream_to_capsule(stream: Box<ArrowArrayStream>, py: Python<'_>) -> PyResult<PyObject> {
     let stream_ptr = Box::into_raw(stream);
 
     let name = c"arrow_array_stream";
@@ -561,7 +549,6 @@ pub fn table_to_stream_capsule<'py>(table: &Table, py: Python<'py>) -> PyResult<
             Some(arrow_stream_capsule_destructor),
         );
         if cap.is_null() {
-            // Clean up
             let s = &mut *stream_ptr;
             if let Some(release) = s.release {
                 release(stream_ptr);
@@ -577,6 +564,40 @@ pub fn table_to_stream_capsule<'py>(table: &Table, py: Python<'py>) -> PyResult<
     Ok(capsule.unbind())
 }
 
+/// Exports a MinArrow array as an ArrowArrayStream PyCapsule.
+///
+/// The stream yields the array as its single chunk. Dictionary-typed fields
+/// carry their value schema, so categorical arrays stream as Arrow dictionaries.
+pub fn array_to_stream_capsule<'py>(
+    array: Arc<Array>,
+    field: &Field,
+    py: Python<'py>,
+) -> PyResult<PyObject> {
+    let stream = export_array_stream(vec![array], field.clone());
+    stream_to_capsule(stream, py)
+}
+
+/// Exports a MinArrow Table as an ArrowArrayStream PyCapsule.
+///
+/// The stream yields one struct array (record batch) corresponding to the table.
+pub fn table_to_stream_capsule<'py>(table: &Table, py: Python<'py>) -> PyResult<PyObject> {
+    let fields: Vec<Field> = table.cols.iter().map(|fa| (*fa.field).clone()).collect();
+    let columns: Vec<(Arc<Array>, Schema)> = table
+        .cols
+        .iter()
+        .map(|fa| {
+            (
+                Arc::new(fa.array.clone()),
+                Schema::from(vec![(*fa.field).clone()]),
+            )
+        })
+        .collect();
+
+    let metadata = build_stream_metadata(table);
+    let stream = export_record_batch_stream_with_metadata(vec![columns], fields, metadata);
+    stream_to_capsule(stream, py)
+}
+
 /// Exports a MinArrow SuperTable as an ArrowArrayStream PyCapsule.
 ///
 /// The stream yields one struct array per batch in the SuperTable.
@@ -617,29 +638,7 @@ pub fn super_table_to_stream_capsule<'py>(
 
     let metadata = build_stream_metadata(&super_table.batches[0]);
     let stream = export_record_batch_stream_with_metadata(batches, fields, metadata);
-    let stream_ptr = Box::into_raw(stream);
-
-    let name = c"arrow_array_stream";
-    let capsule = unsafe {
-        let cap = pyo3::ffi::PyCapsule_New(
-            stream_ptr as *mut std::ffi::c_void,
-            name.as_ptr(),
-            Some(arrow_stream_capsule_destructor),
-        );
-        if cap.is_null() {
-            let s = &mut *stream_ptr;
-            if let Some(release) = s.release {
-                release(stream_ptr);
-            }
-            let _ = Box::from_raw(stream_ptr);
-            return Err(pyo3::exceptions::PyRuntimeError::new_err(
-                "Failed to create stream PyCapsule",
-            ));
-        }
-        Bound::from_owned_ptr(py, cap)
-    };
-
-    Ok(capsule.unbind())
+    stream_to_capsule(stream, py)
 }
 
 /// Exports a MinArrow SuperArray as an ArrowArrayStream PyCapsule.
@@ -660,27 +659,5 @@ pub fn super_array_to_stream_capsule<'py>(
     let array_chunks: Vec<Arc<Array>> = chunks.iter().map(|c| Arc::new(c.clone())).collect();
 
     let stream = export_array_stream(array_chunks, field);
-    let stream_ptr = Box::into_raw(stream);
-
-    let name = c"arrow_array_stream";
-    let capsule = unsafe {
-        let cap = pyo3::ffi::PyCapsule_New(
-            stream_ptr as *mut std::ffi::c_void,
-            name.as_ptr(),
-            Some(arrow_stream_capsule_destructor),
-        );
-        if cap.is_null() {
-            let s = &mut *stream_ptr;
-            if let Some(release) = s.release {
-                release(stream_ptr);
-            }
-            let _ = Box::from_raw(stream_ptr);
-            return Err(pyo3::exceptions::PyRuntimeError::new_err(
-                "Failed to create stream PyCapsule",
-            ));
-        }
-        Bound::from_owned_ptr(py, cap)
-    };
-
-    Ok(capsule.unbind())
+    stream_to_capsule(stream, py)
 }
@@ -27,40 +27,120 @@ use minarrow::ffi::arrow_c_ffi::{
     ArrowArray, ArrowArrayStream, ArrowSchema, import_from_c_owned,
     import_record_batch_stream_with_metadata,
 };
+#[cfg(feature = "datetime")]
+use minarrow::ffi::arrow_dtype::ArrowType;
 use minarrow::{Field, FieldArray, SuperArray, SuperTable};
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::error::{PyMinarrowError, PyMinarrowResult};
 use crate::ffi::to_py::TABLE_NAME_KEY;
 
+/// Schema metadata key under which pandas stores its JSON column description.
+const PANDAS_METADATA_KEY: &str = "pandas";
+
+/// Reads a schema-level metadata value from a PyArrow schema.
+/// Returns `None` if the key is absent or extraction fails.
+fn pyarrow_schema_metadata_value(schema: &Bound<PyAny>, key: &str) -> Option<String> {
+    schema.getattr("metadata").ok().and_then(|meta| {
+        if meta.is_none() {
+            return None;
+        }
+        // PyArrow schema.metadata is a dict with bytes keys
+        meta.call_method1("get", (key.as_bytes(),))
+            .ok()
+            .and_then(|val| {
+                if val.is_none() {
+                    return None;
+                }
+                // Value is bytes in PyArrow metadata
+                val.extract::<Vec<u8>>()
+                    .ok()
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+            })
+    })
+}
+
 /// Tries to extract the `minarrow:table_name` value from a PyArrow schema's metadata.
 /// Returns an empty string if the metadata key is absent or extraction fails.
 fn extract_table_name_from_pyarrow_schema(schema: &Bound<PyAny>) -> String {
-    schema
-        .getattr("metadata")
-        .ok()
-        .and_then(|meta| {
+    pyarrow_schema_metadata_value(schema, TABLE_NAME_KEY).unwrap_or_default()
+}
+
+/// Per-column entries parsed from the schema-level `pandas` metadata,
+/// as `(field_name, metadata)` pairs.
+type PandasColumnMetadata = Vec<(String, BTreeMap<String, String>)>;
+
+/// Parses the `columns[*].metadata` entries of a pandas metadata JSON document.
+///
+/// pandas records e.g. `ordered` for categoricals and `timezone` for tz-aware
+/// datetimes there. String values are kept as-is and other values are stored
+/// as their JSON text, so `true` stays `"true"`. Columns without metadata are
+/// skipped, and a document that fails to parse yields no entries.
+fn parse_pandas_column_metadata(py: Python<'_>, pandas_json: &str) -> PandasColumnMetadata {
+    let parse = || -> PyResult<PandasColumnMetadata> {
+        let json = py.import("json")?;
+        let doc = json.call_method1("loads", (pandas_json,))?;
+        let mut out = Vec::new();
+        for column in doc.get_item("columns")?.try_iter()? {
+            let column = column?;
+            let meta = column.get_item("metadata")?;
             if meta.is_none() {
-                return None;
+                continue;
             }
-            // PyArrow schema.metadata is a dict with bytes keys
-            let key = TABLE_NAME_KEY.as_bytes();
-            meta.call_method1("get", (key,))
-                .ok()
-                .and_then(|val| {
-                    if val.is_none() {
-                        return None;
-                    }
-                    // Value is bytes in PyArrow metadata
-                    val.extract::<Vec<u8>>()
-                        .ok()
-                        .and_then(|bytes| String::from_utf8(bytes).ok())
-                })
-        })
-        .unwrap_or_default()
+            let name_key = if column.get_item("field_name").is_ok() {
+                "field_name"
+            } else {
+                "name"
+            };
+            let Ok(name) = column.get_item(name_key)?.extract::<String>() else {
+                continue;
+            };
+            let mut entries = BTreeMap::new();
+            for item in meta.call_method0("items")?.try_iter()? {
+                let (key, value): (String, Bound<PyAny>) = item?.extract()?;
+                let value = match value.extract::<String>() {
+                    Ok(s) => s,
+                    Err(_) => json.call_method1("dumps", (value,))?.extract()?,
+                };
+                entries.insert(key, value);
+            }
+            out.push((name, entries));
+        }
+        Ok(out)
+    };
+    parse().unwrap_or_default()
+}
+
+/// Applies parsed pandas column metadata to the matching imported fields.
+///
+/// Each entry is copied into `Field.metadata` under a `pandas.` prefix.
+/// `ordered` also sets the dictionary-ordered flag, and `timezone` fills in
+/// a timestamp field that arrived without one.
+fn apply_pandas_metadata(cols: &mut [FieldArray], pandas: &PandasColumnMetadata) {
+    for (name, entries) in pandas {
+        let Some(fa) = cols.iter_mut().find(|fa| &fa.field.name == name) else {
+            continue;
+        };
+        let field = Arc::make_mut(&mut fa.field);
+        for (key, value) in entries {
+            field
+                .metadata
+                .insert(format!("{}.{}", PANDAS_METADATA_KEY, key), value.clone());
+        }
+        if entries.get("ordered").is_some_and(|v| v == "true") {
+            field.set_ordered_dictionary(true);
+        }
+        #[cfg(feature = "datetime")]
+        if let (Some(tz), ArrowType::Timestamp(_, field_tz @ None)) =
+            (entries.get("timezone"), &mut field.dtype)
+        {
+            *field_tz = Some(tz.clone());
+        }
+    }
 }
 
 /// Splits imported stream metadata into the table name and remaining entries.
@@ -92,6 +172,18 @@ fn extract_table_name(
         .unwrap_or_default()
 }
 
+/// Parses the pandas column metadata from imported stream metadata, if present.
+fn stream_pandas_metadata(
+    py: Python<'_>,
+    metadata: &Option<BTreeMap<String, String>>,
+) -> PandasColumnMetadata {
+    metadata
+        .as_ref()
+        .and_then(|m| m.get(PANDAS_METADATA_KEY))
+        .map(|json| parse_pandas_column_metadata(py, json))
+        .unwrap_or_default()
+}
+
 // PyCapsule helpers
 
 /// Attempts to import a single array via the `__arrow_c_array__` PyCapsule protocol.
@@ -348,6 +440,7 @@ pub fn record_batch_to_rust(obj: &Bound<PyAny>) -> PyMinarrowResult<minarrow::Ta
     // Try PyCapsule stream (RecordBatch may support __arrow_c_stream__)
     if let Some(result) = try_capsule_record_batch_stream(obj) {
         let (batches, metadata) = result?;
+        let pandas = stream_pandas_metadata(obj.py(), &metadata);
 
         #[cfg(feature = "table_metadata")]
         let (table_name, remaining_meta) = split_stream_metadata(metadata);
@@ -362,10 +455,11 @@ pub fn record_batch_to_rust(obj: &Bound<PyAny>) -> PyMinarrowResult<minarrow::Ta
         let tables: Vec<minarrow::Table> = batches
             .into_iter()
             .map(|columns| {
-                let cols: Vec<FieldArray> = columns
+                let mut cols: Vec<FieldArray> = columns
                     .into_iter()
                     .map(|(array, field)| FieldArray::new(field, (*array).clone()))
                     .collect();
+                apply_pandas_metadata(&mut cols, &pandas);
                 #[cfg(feature = "table_metadata")]
                 let table = if remaining_meta.is_empty() {
                     minarrow::Table::new(table_name.clone(), Some(cols))
@@ -428,15 +522,15 @@ fn record_batch_to_rust_legacy(obj: &Bound<PyAny>) -> PyMinarrowResult<minarrow:
 
         let mut field_array = array_to_rust(&column)?;
 
-        field_array.field = Arc::new(Field::new(
-            name,
-            field_array.field.dtype.clone(),
-            field_array.field.nullable,
-            None,
-        ));
+        // Keep the imported metadata and ordered flag, only taking the schema's name
+        Arc::make_mut(&mut field_array.field).name = name;
         cols.push(field_array);
     }
 
+    if let Some(json) = pyarrow_schema_metadata_value(&schema, PANDAS_METADATA_KEY) {
+        apply_pandas_metadata(&mut cols, &parse_pandas_column_metadata(obj.py(), &json));
+    }
+
     let table = minarrow::Table::new(table_name, Some(cols));
     Ok(table)
 }
@@ -455,6 +549,7 @@ pub fn table_to_rust(obj: &Bound<PyAny>) -> PyMinarrowResult<SuperTable> {
     // Try PyCapsule stream
     if let Some(result) = try_capsule_record_batch_stream(obj) {
         let (batches, metadata) = result?;
+        let pandas = stream_pandas_metadata(obj.py(), &metadata);
 
         #[cfg(feature = "table_metadata")]
         let (table_name, remaining_meta) = split_stream_metadata(metadata);
@@ -467,10 +562,11 @@ pub fn table_to_rust(obj: &Bound<PyAny>) -> PyMinarrowResult<SuperTable> {
 
         let mut tables = Vec::with_capacity(batches.len());
         for columns in batches {
-            let cols: Vec<FieldArray> = columns
+            let mut cols: Vec<FieldArray> = columns
                 .into_iter()
                 .map(|(array, field)| FieldArray::new(field, (*array).clone()))
                 .collect();
+            apply_pandas_metadata(&mut cols, &pandas);
             #[cfg(feature = "table_metadata")]
             let table = if remaining_meta.is_empty() {
                 minarrow::Table::new(table_name.clone(), Some(cols))
@@ -119,7 +119,9 @@ pub mod types;
 
 // Re-export the main types for ease of use
 pub use error::{PyMinarrowError, PyMinarrowResult};
-pub use types::{PyArray, PyChunkedArray, PyField, PyRecordBatch, PyTable};
+pub use types::{
+    PyArray, PyChunkedArray, PyField, PyRecordBatch, PyTable, RecordBatchHandle, TableHandle,
+};
 
 // Re-export minarrow types that users might need
 pub use minarrow::{Array, Field, FieldArray, MaskedArray, NumericArray, SuperArray, SuperTable, Table, TextArray};
@@ -154,14 +156,45 @@ fn array_info(arr: PyArray) -> PyResult<String> {
 /// Get information about a PyArrow RecordBatch after converting to MinArrow.
 #[pyfunction]
 fn batch_info(batch: PyRecordBatch) -> PyResult<String> {
-    let inner = batch.inner();
     Ok(format!(
         "MinArrow Table: rows={}, cols={}",
-        inner.n_rows(),
-        inner.n_cols()
+        batch.num_rows(),
+        batch.num_columns()
     ))
 }
 
+/// Get the imported MinArrow `Field` state for a RecordBatch column.
+///
+/// Returns `(ordered, metadata)`, reflecting both Arrow field metadata and
+/// any per-column pandas metadata applied on import.
+#[pyfunction]
+fn batch_field_info(
+    batch: PyRecordBatch,
+    column: &str,
+) -> PyResult<(bool, std::collections::BTreeMap<String, String>)> {
+    let table = batch.inner();
+    let fa = table
+        .cols
+        .iter()
+        .find(|fa| fa.field.name == column)
+        .ok_or_else(|| {
+            pyo3::exceptions::PyKeyError::new_err(format!("column '{}' not found", column))
+        })?;
+    Ok((fa.field.ordered, fa.field.metadata.clone()))
+}
+
+/// Convert a PyArrow RecordBatch to MinArrow and return it as an inspectable handle.
+#[pyfunction]
+fn batch_handle(batch: PyRecordBatch) -> RecordBatchHandle {
+    batch.into()
+}
+
+/// Convert a PyArrow Table to MinArrow and return it as an inspectable handle.
+#[pyfunction]
+fn table_handle(table: PyTable) -> TableHandle {
+    table.into()
+}
+
 /// Echo back a PyArrow Table after roundtrip through MinArrow.
 /// Used to test that conversion works correctly.
 #[pyfunction]
@@ -181,12 +214,11 @@ fn echo_chunked(arr: PyChunkedArray) -> PyResult<PyChunkedArray> {
 /// Get information about a PyArrow Table after converting to MinArrow.
 #[pyfunction]
 fn table_info(table: PyTable) -> PyResult<String> {
-    let inner = table.inner();
     Ok(format!(
         "MinArrow SuperTable: batches={}, rows={}, cols={}",
-        inner.batches.len(),
-        inner.n_rows,
-        inner.schema.len()
+        table.inner().n_batches(),
+        table.num_rows(),
+        table.num_columns()
     ))
 }
 
@@ -201,6 +233,31 @@ fn chunked_info(arr: PyChunkedArray) -> PyResult<String> {
     ))
 }
 
+/// Get the dictionary values of each chunk of a dictionary-typed ChunkedArray.
+///
+/// Each chunk keeps its own dictionary on import, so the result has one
+/// entry per chunk. Non-categorical chunks raise a `TypeError`.
+#[pyfunction]
+fn chunked_unique_values(arr: PyChunkedArray) -> PyResult<Vec<Vec<String>>> {
+    arr.inner()
+        .chunks()
+        .iter()
+        .map(|chunk| match chunk {
+            #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+            Array::TextArray(TextArray::Categorical32(c)) => Ok(c.unique_values.to_vec()),
+            #[cfg(feature = "default_categorical_8")]
+            Array::TextArray(TextArray::Categorical8(c)) => Ok(c.unique_values.to_vec()),
+            #[cfg(feature = "extended_categorical")]
+            Array::TextArray(TextArray::Categorical16(c)) => Ok(c.unique_values.to_vec()),
+            #[cfg(feature = "extended_categorical")]
+            Array::TextArray(TextArray::Categorical64(c)) => Ok(c.unique_values.to_vec()),
+            _ => Err(pyo3::exceptions::PyTypeError::new_err(
+                "chunked_unique_values: chunk is not dictionary-encoded",
+            )),
+        })
+        .collect()
+}
+
 /// Export a MinArrow array as a pair of Arrow PyCapsules (schema, array).
 ///
 /// The returned tuple follows the Arrow PyCapsule Interface and can be
@@ -216,16 +273,20 @@ fn export_array_capsule(py: Python, arr: PyArray) -> PyResult<ArrowArrayWrapper>
     })
 }
 
+/// Export a MinArrow array as an ArrowArrayStream PyCapsule.
+///
+/// The stream yields the array as a single chunk.
+#[pyfunction]
+fn export_array_stream_capsule(py: Python, arr: PyArray) -> PyResult<ArrowStream> {
+    arr.to_arrow_stream(py)
+}
+
 /// Export a MinArrow RecordBatch as an ArrowArrayStream PyCapsule.
 ///
 /// The stream yields one struct array representing the record batch.
 #[pyfunction]
 fn export_batch_stream_capsule(py: Python, batch: PyRecordBatch) -> PyResult<ArrowStream> {
-    let table = batch.inner();
-    let capsule = ffi::to_py::table_to_stream_capsule(table, py)?;
-    Ok(ArrowStream {
-        capsule: Some(capsule),
-    })
+    batch.to_arrow_stream(py)
 }
 
 /// Export a MinArrow Table as an ArrowArrayStream PyCapsule.
@@ -233,11 +294,7 @@ fn export_batch_stream_capsule(py: Python, batch: PyRecordBatch) -> PyResult<Arr
 /// The stream yields one struct array per batch in the table.
 #[pyfunction]
 fn export_table_stream_capsule(py: Python, table: PyTable) -> PyResult<ArrowStream> {
-    let super_table = table.inner();
-    let capsule = ffi::to_py::super_table_to_stream_capsule(super_table, py)?;
-    Ok(ArrowStream {
-        capsule: Some(capsule),
-    })
+    table.to_arrow_stream(py)
 }
 
 /// Export a MinArrow ChunkedArray as an ArrowArrayStream PyCapsule.
@@ -245,11 +302,7 @@ fn export_table_stream_capsule(py: Python, table: PyTable) -> PyResult<ArrowStre
 /// The stream yields one plain array per chunk.
 #[pyfunction]
 fn export_chunked_stream_capsule(py: Python, arr: PyChunkedArray) -> PyResult<ArrowStream> {
-    let super_array = arr.inner();
-    let capsule = ffi::to_py::super_array_to_stream_capsule(super_array, py)?;
-    Ok(ArrowStream {
-        capsule: Some(capsule),
-    })
+    arr.to_arrow_stream(py)
 }
 
 // PyCapsule protocol wrapper types
@@ -257,12 +310,22 @@ fn export_chunked_stream_capsule(py: Python, arr: PyChunkedArray) -> PyResult<Ar
 /// Python-visible wrapper implementing `__arrow_c_stream__`.
 ///
 /// Any Arrow-compatible Python library can consume this object directly,
-/// e.g. `pa.RecordBatchReader.from_stream(obj)` or `nanoarrow.ArrayStream(obj)`.
+/// e.g. `pa.RecordBatchReader.from_stream(obj)`, `pl.from_arrow(obj)` or
+/// `nanoarrow.ArrayStream(obj)`. Built by the wrapper types' `to_arrow_stream`.
 #[pyclass(name = "ArrowStream")]
-struct ArrowStream {
+pub struct ArrowStream {
     capsule: Option<PyObject>,
 }
 
+impl ArrowStream {
+    /// Wraps an `arrow_array_stream` PyCapsule.
+    pub fn new(capsule: PyObject) -> Self {
+        Self {
+            capsule: Some(capsule),
+        }
+    }
+}
+
 #[pymethods]
 impl ArrowStream {
     /// Arrow PyCapsule stream protocol.
@@ -353,10 +416,7 @@ fn generate_sample_batch(py: Python) -> PyResult<ArrowStream> {
         ]),
     );
 
-    let capsule = ffi::to_py::table_to_stream_capsule(&table, py)?;
-    Ok(ArrowStream {
-        capsule: Some(capsule),
-    })
+    PyRecordBatch::from(table).to_arrow_stream(py)
 }
 
 /// Generate a sample array with nulls in Rust and return as an ArrowArray.
@@ -405,11 +465,16 @@ fn minarrow_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(echo_chunked, m)?)?;
     m.add_function(wrap_pyfunction!(array_info, m)?)?;
     m.add_function(wrap_pyfunction!(batch_info, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_field_info, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_handle, m)?)?;
+    m.add_function(wrap_pyfunction!(table_handle, m)?)?;
     m.add_function(wrap_pyfunction!(table_info, m)?)?;
     m.add_function(wrap_pyfunction!(chunked_info, m)?)?;
+    m.add_function(wrap_pyfunction!(chunked_unique_values, m)?)?;
 
     // PyCapsule export functions
     m.add_function(wrap_pyfunction!(export_array_capsule, m)?)?;
+    m.add_function(wrap_pyfunction!(export_array_stream_capsule, m)?)?;
     m.add_function(wrap_pyfunction!(export_batch_stream_capsule, m)?)?;
     m.add_function(wrap_pyfunction!(export_table_stream_capsule, m)?)?;
     m.add_function(wrap_pyfunction!(export_chunked_stream_capsule, m)?)?;
@@ -417,6 +482,8 @@ fn minarrow_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // PyCapsule protocol wrapper types
     m.add_class::<ArrowStream>()?;
     m.add_class::<ArrowArrayWrapper>()?;
+    m.add_class::<RecordBatchHandle>()?;
+    m.add_class::<TableHandle>()?;
 
     // Data generators (return protocol-conforming objects)
     m.add_function(wrap_pyfunction!(generate_sample_batch, m)?)?;
@@ -21,6 +21,7 @@ use minarrow::{Array, Field, FieldArray, SuperArray, SuperTable, Table};
 use pyo3::prelude::*;
 use std::sync::Arc;
 
+use crate::ArrowStream;
 use crate::ffi::{to_py, to_rust};
 
 // PyArray - Wrapper around MinArrow's FieldArray
@@ -71,6 +72,15 @@ impl PyArray {
     pub fn into_inner(self) -> FieldArray {
         self.0
     }
+
+    /// Exports the array as an Arrow PyCapsule stream yielding a single chunk.
+    ///
+    /// The returned [`ArrowStream`] implements `__arrow_c_stream__`, so Python
+    /// consumers such as `pl.from_arrow` or `pa.chunked_array` accept it directly.
+    pub fn to_arrow_stream(&self, py: Python<'_>) -> PyResult<ArrowStream> {
+        let array = Arc::new(self.0.array.clone());
+        to_py::array_to_stream_capsule(array, &self.0.field, py).map(ArrowStream::new)
+    }
 }
 
 impl From<FieldArray> for PyArray {
@@ -167,6 +177,31 @@ impl PyRecordBatch {
     pub fn into_inner(self) -> Table {
         self.0
     }
+
+    /// Number of rows, as `pa.RecordBatch.num_rows`.
+    pub fn num_rows(&self) -> usize {
+        self.0.n_rows()
+    }
+
+    /// Number of columns, as `pa.RecordBatch.num_columns`.
+    pub fn num_columns(&self) -> usize {
+        self.0.n_cols()
+    }
+
+    /// Column names in order, as `pa.RecordBatch.column_names`.
+    pub fn column_names(&self) -> Vec<String> {
+        self.0.cols.iter().map(|fa| fa.field.name.clone()).collect()
+    }
+
+    /// One `name: type` line per column, in the style of `str(pa.Schema)`.
+    pub fn schema_str(&self) -> String {
+        schema_str(self.0.cols.iter().map(|fa| fa.field.as_ref()))
+    }
+
+    /// Exports the table as an Arrow PyCapsule stream yielding one record batch.
+    pub fn to_arrow_stream(&self, py: Python<'_>) -> PyResult<ArrowStream> {
+        to_py::table_to_stream_capsule(&self.0, py).map(ArrowStream::new)
+    }
 }
 
 impl From<Table> for PyRecordBatch {
@@ -204,6 +239,60 @@ impl<'py> IntoPyObject<'py> for PyRecordBatch {
     }
 }
 
+/// Python-visible handle to a MinArrow Table, for inspecting a batch before converting it.
+///
+/// Unlike `PyRecordBatch`, which converts to a PyArrow RecordBatch as soon as it is
+/// returned, this stays a MinArrow value on the Python side. It exposes PyArrow's
+/// `num_rows`, `num_columns` and `column_names` attributes, plus `schema_str()`, and
+/// converts through `__arrow_c_stream__`, e.g. `pa.RecordBatchReader.from_stream(handle)`.
+#[pyclass(name = "RecordBatchHandle", frozen)]
+#[derive(Debug, Clone)]
+pub struct RecordBatchHandle(pub PyRecordBatch);
+
+#[pymethods]
+impl RecordBatchHandle {
+    /// Number of rows, as `pa.RecordBatch.num_rows`.
+    #[getter]
+    pub fn num_rows(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    /// Number of columns, as `pa.RecordBatch.num_columns`.
+    #[getter]
+    pub fn num_columns(&self) -> usize {
+        self.0.num_columns()
+    }
+
+    /// Column names in order, as `pa.RecordBatch.column_names`.
+    #[getter]
+    pub fn column_names(&self) -> Vec<String> {
+        self.0.column_names()
+    }
+
+    /// One `name: type` line per column, in the style of `str(pa.Schema)`.
+    pub fn schema_str(&self) -> String {
+        self.0.schema_str()
+    }
+
+    /// Arrow PyCapsule stream protocol, yielding the batch.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__(
+        &self,
+        py: Python<'_>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        self.0
+            .to_arrow_stream(py)?
+            .__arrow_c_stream__(requested_schema)
+    }
+}
+
+impl From<PyRecordBatch> for RecordBatchHandle {
+    fn from(batch: PyRecordBatch) -> Self {
+        Self(batch)
+    }
+}
+
 // PyField - Wrapper around MinArrow's Field
 
 /// Transparent wrapper around MinArrow's Field.
@@ -248,6 +337,18 @@ impl AsRef<Field> for PyField {
     }
 }
 
+/// Formats fields as `name: type` lines, marking non-nullable fields `not null`
+/// as PyArrow does.
+fn schema_str<'a>(fields: impl Iterator<Item = &'a Field>) -> String {
+    fields
+        .map(|f| {
+            let null = if f.nullable { "" } else { " not null" };
+            format!("{}: {}{}", f.name, f.dtype, null)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // PyTable - Wrapper around MinArrow's SuperTable (PyArrow Table)
 
 /// Transparent wrapper around MinArrow's SuperTable.
@@ -286,6 +387,86 @@ impl PyTable {
     pub fn into_inner(self) -> SuperTable {
         self.0
     }
+
+    /// Number of rows across all batches, as `pa.Table.num_rows`.
+    pub fn num_rows(&self) -> usize {
+        self.0.n_rows()
+    }
+
+    /// Number of columns, as `pa.Table.num_columns`.
+    pub fn num_columns(&self) -> usize {
+        self.0.n_cols()
+    }
+
+    /// Column names in order, as `pa.Table.column_names`.
+    pub fn column_names(&self) -> Vec<String> {
+        self.0.schema().iter().map(|f| f.name.clone()).collect()
+    }
+
+    /// One `name: type` line per column, in the style of `str(pa.Schema)`.
+    pub fn schema_str(&self) -> String {
+        schema_str(self.0.schema().iter().map(|f| f.as_ref()))
+    }
+
+    /// Exports the table as an Arrow PyCapsule stream yielding each batch.
+    ///
+    /// Returns a `ValueError` when the table has no batches.
+    pub fn to_arrow_stream(&self, py: Python<'_>) -> PyResult<ArrowStream> {
+        to_py::super_table_to_stream_capsule(&self.0, py).map(ArrowStream::new)
+    }
+}
+
+/// Python-visible handle to a MinArrow SuperTable, for inspecting a table before converting it.
+///
+/// The `PyTable` counterpart of `RecordBatchHandle`, mirroring `pa.Table`'s
+/// `num_rows`, `num_columns` and `column_names`. Converts through `__arrow_c_stream__`,
+/// yielding each batch.
+#[pyclass(name = "TableHandle", frozen)]
+#[derive(Debug, Clone)]
+pub struct TableHandle(pub PyTable);
+
+#[pymethods]
+impl TableHandle {
+    /// Number of rows across all batches, as `pa.Table.num_rows`.
+    #[getter]
+    pub fn num_rows(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    /// Number of columns, as `pa.Table.num_columns`.
+    #[getter]
+    pub fn num_columns(&self) -> usize {
+        self.0.num_columns()
+    }
+
+    /// Column names in order, as `pa.Table.column_names`.
+    #[getter]
+    pub fn column_names(&self) -> Vec<String> {
+        self.0.column_names()
+    }
+
+    /// One `name: type` line per column, in the style of `str(pa.Schema)`.
+    pub fn schema_str(&self) -> String {
+        self.0.schema_str()
+    }
+
+    /// Arrow PyCapsule stream protocol, yielding each batch.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__(
+        &self,
+        py: Python<'_>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        self.0
+            .to_arrow_stream(py)?
+            .__arrow_c_stream__(requested_schema)
+    }
+}
+
+impl From<PyTable> for TableHandle {
+    fn from(table: PyTable) -> Self {
+        Self(table)
+    }
 }
 
 impl From<SuperTable> for PyTable {
@@ -361,6 +542,13 @@ impl PyChunkedArray {
     pub fn into_inner(self) -> SuperArray {
         self.0
     }
+
+    /// Exports the array as an Arrow PyCapsule stream yielding each chunk.
+    ///
+    /// Returns a `ValueError` when the array has no chunks.
+    pub fn to_arrow_stream(&self, py: Python<'_>) -> PyResult<ArrowStream> {
+        to_py::super_array_to_stream_capsule(&self.0, py).map(ArrowStream::new)
+    }
 }
 
 impl From<SuperArray> for PyChunkedArray {
@@ -135,6 +135,10 @@ fn main() -> PyResult<()> {
         println!("\n--- Categorical ---");
         run_test("categorical32 roundtrip", || test_categorical32_roundtrip(py), &mut passed, &mut failed);
 
+        // Fixed-size binary: Rust → Python → Rust
+        println!("\n--- Fixed-Size Binary ---");
+        run_test("fixed size binary roundtrip", || test_fixed_size_binary_roundtrip(py), &mut passed, &mut failed);
+
         // Python → Rust import tests
         println!("\n--- Python → Rust Import Tests ---");
         run_test("import i8 from pyarrow", || test_import_int8(py), &mut passed, &mut failed);
@@ -148,6 +152,7 @@ fn main() -> PyResult<()> {
         run_test("import boolean from pyarrow", || test_import_boolean(py), &mut passed, &mut failed);
         run_test("import string from pyarrow", || test_import_string(py), &mut passed, &mut failed);
         run_test("import nullable from pyarrow", || test_import_nullable(py), &mut passed, &mut failed);
+        run_test("import fixed size binary from pyarrow", || test_import_fixed_size_binary(py), &mut passed, &mut failed);
         run_test("import mixed table from pyarrow", || test_import_mixed_table(py), &mut passed, &mut failed);
 
         // Table name preservation tests
@@ -1258,6 +1263,47 @@ fn test_categorical32_roundtrip(py: Python<'_>) -> Result<(), String> {
     Ok(())
 }
 
+fn test_fixed_size_binary_roundtrip(py: Python<'_>) -> Result<(), String> {
+    use minarrow::FixedSizeBinaryArray;
+    let mut arr = FixedSizeBinaryArray::with_capacity(4, 3, true);
+    arr.push_value(b"abc");
+    arr.push_null();
+    arr.push_value(&[0, 1, 255]);
+    arr.push_null();
+    let original = Array::from_fixed_size_binary(arr);
+
+    let py_obj = PyArray::from(original.clone())
+        .into_pyobject(py)
+        .map_err(|e| format!("Failed to convert to PyArrow: {}", e))?;
+    let dtype = py_obj.getattr("type").map_err(|e| format!("{}", e))?.to_string();
+    if dtype != "fixed_size_binary[3]" {
+        return Err(format!("Expected fixed_size_binary[3], got {}", dtype));
+    }
+    let null_count: usize = py_obj
+        .getattr("null_count")
+        .and_then(|n| n.extract())
+        .map_err(|e| format!("{}", e))?;
+    if null_count != 2 {
+        return Err(format!("Expected 2 nulls in PyArrow, got {}", null_count));
+    }
+
+    let back: PyArray = py_obj
+        .extract()
+        .map_err(|e| format!("Failed to convert from PyArrow: {}", e))?;
+    match (&original, &back.into_inner().array) {
+        (Array::FixedSizeBinaryArray(a), Array::FixedSizeBinaryArray(b)) => {
+            if b.width() != 3 {
+                return Err(format!("Width mismatch: 3 vs {}", b.width()));
+            }
+            if a.iter_values_opt().ne(b.iter_values_opt()) {
+                return Err("Value or null mismatch after roundtrip".to_string());
+            }
+        }
+        _ => return Err("Expected FixedSizeBinaryArray after roundtrip".to_string()),
+    }
+    Ok(())
+}
+
 // ── Python → Rust import tests ────────────────────────────────────
 
 fn test_import_int8(py: Python<'_>) -> Result<(), String> {
@@ -1432,6 +1478,28 @@ fn test_import_nullable(py: Python<'_>) -> Result<(), String> {
     Ok(())
 }
 
+fn test_import_fixed_size_binary(py: Python<'_>) -> Result<(), String> {
+    let pa = py.import("pyarrow").map_err(|e| format!("{}", e))?;
+    let pa_fsb = pa.call_method1("binary", (2,)).map_err(|e| format!("{}", e))?;
+    let values: Vec<Option<&[u8]>> = vec![Some(&b"ab"[..]), None, Some(&b"\x00\xff"[..])];
+    let py_arr = pa.call_method1("array", (values, pa_fsb)).map_err(|e| format!("{}", e))?;
+    let result = to_rust::try_capsule_array(&py_arr)
+        .ok_or("__arrow_c_array__ not available")?
+        .map_err(|e| format!("{}", e))?;
+    match &result.array {
+        Array::FixedSizeBinaryArray(a) => {
+            assert_eq!(a.len(), 3);
+            assert_eq!(a.width(), 2);
+            assert_eq!(a.null_count(), 1);
+            assert_eq!(a.value(0), Some(&b"ab"[..]));
+            assert_eq!(a.value(1), None);
+            assert_eq!(a.value(2), Some(&[0u8, 255][..]));
+        }
+        _ => return Err(format!("Expected FixedSizeBinary, got {:?}", "unexpected")),
+    }
+    Ok(())
+}
+
 fn test_import_mixed_table(py: Python<'_>) -> Result<(), String> {
     let pa = py.import("pyarrow").map_err(|e| format!("{}", e))?;
     let dict = vec![
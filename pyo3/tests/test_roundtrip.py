@@ -238,6 +238,111 @@ print(f"  Output: {len(result)} elements, {result.num_chunks} chunks")
 assert chunked.to_pylist() == result.to_pylist(), "String ChunkedArray mismatch!"
 print("  ✓ PASSED")
 
+# Test 21: ChunkedArray with dictionary chunks
+print("\nTest 21: Dictionary ChunkedArray Roundtrip")
+print("-" * 40)
+dict_type = pa.dictionary(pa.int32(), pa.string())
+arr1 = pa.array(["cat", "dog", "cat"], type=dict_type)
+arr2 = pa.array(["bird", None, "fish", "bird"], type=dict_type)
+chunked = pa.chunked_array([arr1, arr2], type=dict_type)
+print(f"  Input:  {len(chunked)} elements, {chunked.num_chunks} chunks")
+uniques = ma.chunked_unique_values(chunked)
+print(f"  Unique: {uniques}")
+assert uniques == [c.dictionary.to_pylist() for c in chunked.chunks], "Dictionary values mismatch!"
+result = ma.echo_chunked(chunked)
+print(f"  Output: {len(result)} elements, {result.num_chunks} chunks")
+assert result.num_chunks == chunked.num_chunks, "Dictionary ChunkedArray chunk count mismatch!"
+assert chunked.to_pylist() == result.to_pylist(), "Dictionary ChunkedArray mismatch!"
+print("  ✓ PASSED")
+
+# Test 22: Array exported as an ArrowArrayStream
+print("\nTest 22: Array Stream Capsule Export")
+print("-" * 40)
+arr = pa.array([1, None, 3], type=pa.int64())
+result = pa.chunked_array(ma.export_array_stream_capsule(arr))
+print(f"  Output: {result.to_pylist()}, {result.num_chunks} chunks")
+assert result.num_chunks == 1, "Array stream should yield one chunk!"
+assert arr.to_pylist() == result.to_pylist(), "Array stream mismatch!"
+dict_arr = pa.array(["x", "y", "x"], type=pa.dictionary(pa.int32(), pa.string()))
+result = pa.chunked_array(ma.export_array_stream_capsule(dict_arr))
+assert pa.types.is_dictionary(result.type), f"Expected dictionary type, got {result.type}"
+assert dict_arr.to_pylist() == result.to_pylist(), "Dictionary array stream mismatch!"
+print("  ✓ PASSED")
+
+# Test 23: Table and ChunkedArray exported as ArrowArrayStreams
+print("\nTest 23: Table / ChunkedArray Stream Capsule Export")
+print("-" * 40)
+table = pa.Table.from_batches([
+    pa.RecordBatch.from_pydict({"id": pa.array([1, 2], type=pa.int64())}),
+    pa.RecordBatch.from_pydict({"id": pa.array([3], type=pa.int64())}),
+])
+reader = pa.RecordBatchReader.from_stream(ma.export_table_stream_capsule(table))
+result = reader.read_all()
+assert table.to_pydict() == result.to_pydict(), "Table stream mismatch!"
+chunked = pa.chunked_array([["a", "b"], ["c"]], type=pa.dictionary(pa.int32(), pa.string()))
+result = pa.chunked_array(ma.export_chunked_stream_capsule(chunked))
+assert result.num_chunks == chunked.num_chunks, "ChunkedArray stream chunk count mismatch!"
+assert chunked.to_pylist() == result.to_pylist(), "ChunkedArray stream mismatch!"
+print("  ✓ PASSED")
+
+# Test 24: pandas ordered categorical metadata on import
+print("\nTest 24: pandas Ordered Categorical Metadata")
+print("-" * 40)
+import pandas as pd
+df = pd.DataFrame({
+    "size": pd.Categorical(["s", "m", "l", "m"], categories=["s", "m", "l"], ordered=True),
+    "when": pd.to_datetime([0, 1, 2, 3], unit="s", utc=True),
+})
+batch = pa.RecordBatch.from_pandas(df, preserve_index=False)
+ordered, metadata = ma.batch_field_info(batch, "size")
+print(f"  size:   ordered={ordered}, metadata={metadata}")
+assert ordered, "Ordered flag should be imported!"
+assert metadata.get("pandas.ordered") == "true", "pandas ordered metadata missing!"
+assert metadata.get("pandas.num_categories") == "3", "pandas num_categories metadata missing!"
+_, metadata = ma.batch_field_info(batch, "when")
+print(f"  when:   metadata={metadata}")
+assert metadata.get("pandas.timezone") == "UTC", "pandas timezone metadata missing!"
+result = ma.echo_batch(batch)
+assert result.schema.field("size").type.ordered, "Ordered flag should survive export!"
+assert str(result.schema.field("when").type.tz) == "UTC", "Timezone should survive export!"
+print("  ✓ PASSED")
+
+# Test 25: shape and schema accessors
+print("\nTest 25: Shape and Schema Accessors")
+print("-" * 40)
+schema = pa.schema([pa.field("id", pa.int64(), nullable=False), pa.field("name", pa.string())])
+batch = pa.record_batch([pa.array([1, 2, 3]), pa.array(["a", "b", None])], schema=schema)
+handle = ma.batch_handle(batch)
+print(f"  Columns: {handle.column_names}")
+assert handle.column_names == batch.schema.names, "Column names mismatch!"
+assert (handle.num_rows, handle.num_columns) == (3, 2), "Batch shape mismatch!"
+assert ma.batch_info(batch) == "MinArrow Table: rows=3, cols=2", "Batch info mismatch!"
+assert pa.RecordBatchReader.from_stream(handle).read_all() == pa.Table.from_batches([batch]), "Batch stream mismatch!"
+table = pa.Table.from_batches([batch, batch])
+handle = ma.table_handle(table)
+lines = handle.schema_str().splitlines()
+print(f"  Schema: {lines}")
+assert len(lines) == 2, "Expected one schema line per column!"
+assert lines[0].startswith("id: ") and lines[0].endswith(" not null"), "id should be not null!"
+assert lines[1].startswith("name: ") and not lines[1].endswith(" not null"), "name is nullable!"
+assert (handle.num_rows, handle.num_columns) == (6, 2), "Table shape mismatch!"
+assert handle.column_names == table.column_names, "Table column names mismatch!"
+assert ma.table_info(table) == "MinArrow SuperTable: batches=2, rows=6, cols=2", "Table info mismatch!"
+print("  ✓ PASSED")
+
+# Test 26: FixedSizeBinary Array roundtrip with nulls
+print("\nTest 26: FixedSizeBinary Array Roundtrip")
+print("-" * 40)
+arr = pa.array([b"\x00\x01\x02\x03", None, b"abcd", None, b"\xff\xfe\xfd\xfc"], type=pa.binary(4))
+print(f"  Input:  {arr.to_pylist()}")
+print(f"  Info:   {ma.array_info(arr)}")
+result = ma.echo_array(arr)
+print(f"  Output: {result.to_pylist()}")
+assert result.type == pa.binary(4), "FixedSizeBinary type mismatch!"
+assert result.null_count == 2, "FixedSizeBinary null count mismatch!"
+assert arr.to_pylist() == result.to_pylist(), "FixedSizeBinary array mismatch!"
+print("  ✓ PASSED")
+
 print("\n" + "=" * 50)
 print("All tests PASSED!")
 print("=" * 50)
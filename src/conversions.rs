@@ -1271,7 +1271,7 @@ impl From<Scalar> for Array {
     fn from(scalar: Scalar) -> Self {
         use crate::Scalar::*;
         match scalar {
-            Null => Array::from_int32(IntegerArray::from_slice(&[0i32])),
+            Null(dtype) => Array::null_array(&dtype, 1),
             Boolean(v) => Array::from_bool(BooleanArray::from_slice(&[v])),
             #[cfg(feature = "extended_numeric_types")]
             Int8(v) => Array::from_int8(IntegerArray::from_slice(&[v])),
@@ -0,0 +1,706 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **CSV Module** - *Delimited Text Import and Export*
+//!
+//! Parses RFC 4180 style delimited text into a `Table`, and writes a `Table`
+//! back out. Use it through `Table::from_csv` and `Table::to_csv`.
+//!
+//! ## Parsing
+//! - Fields may be quoted with `"`, in which case they can hold delimiters,
+//!   line breaks and doubled `""` quotes.
+//! - Records end at `\n` or `\r\n`. Blank lines are skipped.
+//! - Every record must have as many fields as the header, or the first record
+//!   when there is no header.
+//!
+//! ## Type Inference
+//! Each column is typed from the first `infer_rows` data rows, trying in order
+//! `Int64`, `Float64`, `Boolean` (`true`/`false`, any case) and falling back to
+//! `String`. Numbers and booleans are matched after trimming surrounding spaces.
+//! An empty unquoted field is null in every column type, while a quoted empty
+//! field `""` is an empty string.
+//!
+//! ## Writing
+//! - One record per row, each ending in `\n`, after an optional header of field names.
+//! - Text is quoted, with `"` doubled, when it holds the delimiter, a quote or a line
+//!   break, or when it equals the null token, so it reads back as text rather than null.
+//! - Categoricals are written as their decoded values and fixed-size binary as hex.
+//! - Datetimes are RFC 3339 strings with the `datetime_ops` feature, or raw integers otherwise.
+
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+
+use crate::enums::error::MinarrowError;
+use crate::ffi::arrow_dtype::ArrowType;
+use crate::structs::field_array::FieldArray;
+use crate::{
+    Array, Bitmask, BooleanArray, Field, FloatArray, IntegerArray, NumericArray, StringArray,
+    Table, TextArray, Vec64,
+};
+#[cfg(feature = "datetime")]
+use crate::{TemporalArray, TimeUnit};
+
+/// Options for `Table::from_csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvReadOptions {
+    /// Field delimiter. Must be an ASCII byte other than `"`, `\r` or `\n`.
+    pub delimiter: u8,
+    /// Whether the first record holds the column names. Without a header,
+    /// columns are named `col_0`, `col_1`, and so on.
+    pub has_header: bool,
+    /// Number of data rows sampled to infer each column's type.
+    pub infer_rows: usize,
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: true,
+            infer_rows: 1000,
+        }
+    }
+}
+
+/// Options for `Table::to_csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvWriteOptions {
+    /// Field delimiter. Must be an ASCII byte other than `"`, `\r` or `\n`.
+    pub delimiter: u8,
+    /// Whether to write a header record of field names first.
+    pub has_header: bool,
+    /// Text written for null values.
+    pub null_token: String,
+    /// Fixed number of decimal places for floats. `None` writes the shortest
+    /// representation that round-trips.
+    pub float_precision: Option<usize>,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: true,
+            null_token: String::new(),
+            float_precision: None,
+        }
+    }
+}
+
+/// A single parsed field, remembering whether it was quoted so that `""`
+/// can be told apart from an empty, null field.
+struct CsvField {
+    value: String,
+    quoted: bool,
+}
+
+impl CsvField {
+    #[inline]
+    fn is_null(&self) -> bool {
+        !self.quoted && self.value.is_empty()
+    }
+}
+
+/// A parsed record and the line it starts on.
+struct Record {
+    line: usize,
+    fields: Vec<CsvField>,
+}
+
+/// Column types the reader can infer.
+#[derive(Clone, Copy)]
+enum CsvType {
+    Int,
+    Float,
+    Bool,
+    Str,
+}
+
+/// Reads a CSV document from `reader` into a table.
+pub(crate) fn read_table(
+    mut reader: impl Read,
+    opts: &CsvReadOptions,
+) -> Result<Table, MinarrowError> {
+    let d = check_delimiter(opts.delimiter)?;
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let mut records = parse_records(&input, d)?.into_iter();
+    let names: Vec<String> = if opts.has_header {
+        match records.next() {
+            Some(header) => header.fields.into_iter().map(|f| f.value).collect(),
+            None => return Ok(Table::new_empty()),
+        }
+    } else {
+        Vec::new()
+    };
+    let rows: Vec<Record> = records.collect();
+    let n_cols = if opts.has_header {
+        names.len()
+    } else {
+        match rows.first() {
+            Some(r) => r.fields.len(),
+            None => return Ok(Table::new_empty()),
+        }
+    };
+    if let Some(bad) = rows.iter().find(|r| r.fields.len() != n_cols) {
+        return Err(MinarrowError::ParseError {
+            line: bad.line,
+            message: format!("expected {} fields, found {}", n_cols, bad.fields.len()),
+        });
+    }
+
+    let mut table = Table::new_empty();
+    for col in 0..n_cols {
+        let name = match names.get(col) {
+            Some(n) => n.clone(),
+            None => format!("col_{}", col),
+        };
+        let sample = rows.iter().take(opts.infer_rows).map(|r| &r.fields[col]);
+        let (array, dtype) = match infer_type(sample) {
+            CsvType::Int => {
+                let (data, mask) =
+                    parse_column(&rows, col, &name, "Int64", |s| s.trim().parse::<i64>().ok())?;
+                (
+                    Array::from_int64(IntegerArray::new(data, mask)),
+                    ArrowType::Int64,
+                )
+            }
+            CsvType::Float => {
+                let (data, mask) = parse_column(&rows, col, &name, "Float64", |s| {
+                    s.trim().parse::<f64>().ok()
+                })?;
+                (
+                    Array::from_float64(FloatArray::new(data, mask)),
+                    ArrowType::Float64,
+                )
+            }
+            CsvType::Bool => (bool_column(&rows, col, &name)?, ArrowType::Boolean),
+            CsvType::Str => (string_column(&rows, col), ArrowType::String),
+        };
+        let nullable = array.null_count() > 0;
+        table.add_col(FieldArray::new(
+            Field::new(name, dtype, nullable, None),
+            array,
+        ));
+    }
+    Ok(table)
+}
+
+/// Rejects delimiters that would clash with quoting, line breaks or UTF-8 boundaries.
+fn check_delimiter(d: u8) -> Result<u8, MinarrowError> {
+    if !d.is_ascii() || matches!(d, b'"' | b'\r' | b'\n') {
+        return Err(MinarrowError::TypeError {
+            from: "u8",
+            to: "CSV delimiter",
+            message: Some(format!("invalid delimiter byte 0x{:02x}", d)),
+        });
+    }
+    Ok(d)
+}
+
+/// Splits `input` into records of fields.
+fn parse_records(input: &str, delim: u8) -> Result<Vec<Record>, MinarrowError> {
+    let bytes = input.as_bytes();
+    let mut records = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\n' => {
+                line += 1;
+                i += 1;
+                continue;
+            }
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                line += 1;
+                i += 2;
+                continue;
+            }
+            _ => {}
+        }
+        let start_line = line;
+        let mut fields = Vec::new();
+        loop {
+            let field = if bytes.get(i) == Some(&b'"') {
+                let mut value = String::new();
+                i += 1;
+                let mut seg = i;
+                loop {
+                    match bytes.get(i) {
+                        None => {
+                            return Err(MinarrowError::ParseError {
+                                line: start_line,
+                                message: "unterminated quoted field".to_string(),
+                            });
+                        }
+                        Some(b'"') => {
+                            value.push_str(&input[seg..i]);
+                            i += 1;
+                            if bytes.get(i) == Some(&b'"') {
+                                value.push('"');
+                                i += 1;
+                                seg = i;
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(b'\n') => {
+                            line += 1;
+                            i += 1;
+                        }
+                        Some(_) => i += 1,
+                    }
+                }
+                if !matches!(bytes.get(i), None | Some(b'\r' | b'\n')) && bytes[i] != delim {
+                    return Err(MinarrowError::ParseError {
+                        line,
+                        message: "unexpected character after closing quote".to_string(),
+                    });
+                }
+                CsvField {
+                    value,
+                    quoted: true,
+                }
+            } else {
+                let start = i;
+                while i < bytes.len() && !matches!(bytes[i], b'\r' | b'\n') && bytes[i] != delim {
+                    i += 1;
+                }
+                CsvField {
+                    value: input[start..i].to_string(),
+                    quoted: false,
+                }
+            };
+            fields.push(field);
+            match bytes.get(i) {
+                Some(&b) if b == delim => i += 1,
+                Some(b'\r') if bytes.get(i + 1) == Some(&b'\n') => {
+                    i += 2;
+                    line += 1;
+                    break;
+                }
+                Some(b'\r' | b'\n') => {
+                    i += 1;
+                    line += 1;
+                    break;
+                }
+                _ => break,
+            }
+        }
+        records.push(Record {
+            line: start_line,
+            fields,
+        });
+    }
+    Ok(records)
+}
+
+/// Picks the narrowest type every non-null sampled value parses as.
+fn infer_type<'a>(sample: impl Iterator<Item = &'a CsvField>) -> CsvType {
+    let (mut int, mut float, mut boolean, mut seen) = (true, true, true, false);
+    for f in sample.filter(|f| !f.is_null()) {
+        seen = true;
+        let s = f.value.trim();
+        int = int && s.parse::<i64>().is_ok();
+        float = float && s.parse::<f64>().is_ok();
+        boolean = boolean && parse_bool(s).is_some();
+        if !(int || float || boolean) {
+            break;
+        }
+    }
+    match (seen, int, float, boolean) {
+        (false, ..) => CsvType::Str,
+        (_, true, ..) => CsvType::Int,
+        (_, _, true, _) => CsvType::Float,
+        (_, _, _, true) => CsvType::Bool,
+        _ => CsvType::Str,
+    }
+}
+
+#[inline]
+fn parse_bool(s: &str) -> Option<bool> {
+    if s.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if s.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Returns the null mask for column `col`, or `None` when it holds no nulls.
+fn null_mask(rows: &[Record], col: usize) -> Option<Bitmask> {
+    let any_null = rows.iter().any(|r| r.fields[col].is_null());
+    any_null.then(|| {
+        let valid: Vec<bool> = rows.iter().map(|r| !r.fields[col].is_null()).collect();
+        Bitmask::from_bools(&valid)
+    })
+}
+
+/// Error for a value that does not parse as its column's inferred type.
+fn value_error(rec: &Record, col: usize, name: &str, ty: &str) -> MinarrowError {
+    MinarrowError::ParseError {
+        line: rec.line,
+        message: format!(
+            "column '{}': cannot parse '{}' as {}",
+            name, rec.fields[col].value, ty
+        ),
+    }
+}
+
+/// Parses column `col` into fixed-width values and their null mask.
+fn parse_column<T: Default>(
+    rows: &[Record],
+    col: usize,
+    name: &str,
+    ty: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<(Vec64<T>, Option<Bitmask>), MinarrowError> {
+    let mut data = Vec64::with_capacity(rows.len());
+    for rec in rows {
+        let f = &rec.fields[col];
+        if f.is_null() {
+            data.push(T::default());
+        } else {
+            data.push(parse(&f.value).ok_or_else(|| value_error(rec, col, name, ty))?);
+        }
+    }
+    Ok((data, null_mask(rows, col)))
+}
+
+/// Parses column `col` into a boolean array.
+fn bool_column(rows: &[Record], col: usize, name: &str) -> Result<Array, MinarrowError> {
+    let mut data = Bitmask::new_set_all(rows.len(), false);
+    for (i, rec) in rows.iter().enumerate() {
+        let f = &rec.fields[col];
+        if !f.is_null() {
+            let v =
+                parse_bool(f.value.trim()).ok_or_else(|| value_error(rec, col, name, "Boolean"))?;
+            data.set(i, v);
+        }
+    }
+    Ok(Array::BooleanArray(
+        BooleanArray::new(data, null_mask(rows, col)).into(),
+    ))
+}
+
+/// Collects column `col` into a string array.
+fn string_column(rows: &[Record], col: usize) -> Array {
+    let values: Vec64<&str> = rows.iter().map(|r| r.fields[col].value.as_str()).collect();
+    Array::from_string32(StringArray::from_vec64(values, null_mask(rows, col)))
+}
+
+/// Writes `table` as CSV to `writer`, one record at a time.
+pub(crate) fn write_table(
+    table: &Table,
+    mut writer: impl Write,
+    opts: &CsvWriteOptions,
+) -> Result<(), MinarrowError> {
+    let delim = check_delimiter(opts.delimiter)? as char;
+    let mut line = String::new();
+    if opts.has_header {
+        for (i, col) in table.cols.iter().enumerate() {
+            if i > 0 {
+                line.push(delim);
+            }
+            write_text(&mut line, &col.field.name, opts);
+        }
+        line.push('\n');
+        writer.write_all(line.as_bytes())?;
+    }
+    for row in 0..table.n_rows {
+        line.clear();
+        for (i, col) in table.cols.iter().enumerate() {
+            if i > 0 {
+                line.push(delim);
+            }
+            write_value(&mut line, &col.array, row, opts);
+        }
+        line.push('\n');
+        writer.write_all(line.as_bytes())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Appends the CSV encoding of `array[idx]`. Writing into a `String` cannot fail.
+fn write_value(out: &mut String, array: &Array, idx: usize, opts: &CsvWriteOptions) {
+    if array.null_mask().is_some_and(|m| !m.get(idx)) {
+        out.push_str(&opts.null_token);
+        return;
+    }
+    match array {
+        Array::NumericArray(inner) => match inner {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            NumericArray::Int32(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            NumericArray::Int64(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            NumericArray::UInt32(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            NumericArray::UInt64(a) => write!(out, "{}", a.data[idx]).unwrap(),
+            NumericArray::Float32(a) => write_float(out, a.data[idx], opts),
+            NumericArray::Float64(a) => write_float(out, a.data[idx], opts),
+            NumericArray::Null => out.push_str(&opts.null_token),
+        },
+        Array::TextArray(inner) => {
+            let s = match inner {
+                TextArray::String32(a) => a.get_str(idx),
+                #[cfg(feature = "large_string")]
+                TextArray::String64(a) => a.get_str(idx),
+                #[cfg(feature = "default_categorical_8")]
+                TextArray::Categorical8(a) => a.get_str(idx),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical16(a) => a.get_str(idx),
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                TextArray::Categorical32(a) => a.get_str(idx),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical64(a) => a.get_str(idx),
+                TextArray::Null => None,
+            };
+            match s {
+                Some(s) => write_text(out, s, opts),
+                None => out.push_str(&opts.null_token),
+            }
+        }
+        #[cfg(feature = "datetime")]
+        Array::TemporalArray(inner) => match inner {
+            TemporalArray::Datetime32(a) => write_datetime(out, a.data[idx] as i64, a.time_unit),
+            TemporalArray::Datetime64(a) => write_datetime(out, a.data[idx], a.time_unit),
+            TemporalArray::Null => out.push_str(&opts.null_token),
+        },
+        Array::BooleanArray(a) => out.push_str(if a.data.get(idx) { "true" } else { "false" }),
+        Array::FixedSizeBinaryArray(a) => {
+            for b in &a.data[idx * a.width..(idx + 1) * a.width] {
+                write!(out, "{:02x}", b).unwrap();
+            }
+        }
+        Array::Null => out.push_str(&opts.null_token),
+    }
+}
+
+/// Writes a float with the configured precision.
+#[inline]
+fn write_float<T: std::fmt::Display>(out: &mut String, v: T, opts: &CsvWriteOptions) {
+    match opts.float_precision {
+        Some(p) => write!(out, "{:.*}", p, v).unwrap(),
+        None => write!(out, "{}", v).unwrap(),
+    }
+}
+
+/// Writes a datetime as an RFC 3339 string, falling back to the raw
+/// integer when it falls outside the representable range.
+#[cfg(feature = "datetime")]
+fn write_datetime(out: &mut String, v: i64, unit: TimeUnit) {
+    #[cfg(feature = "datetime_ops")]
+    {
+        use time::format_description::well_known::Rfc3339;
+        let formatted = crate::DatetimeArray::<i64>::i64_to_datetime(v, unit)
+            .and_then(|dt| dt.format(&Rfc3339).ok());
+        if let Some(s) = formatted {
+            out.push_str(&s);
+            return;
+        }
+    }
+    #[cfg(not(feature = "datetime_ops"))]
+    let _ = unit;
+    write!(out, "{}", v).unwrap();
+}
+
+/// Writes `s`, quoting it per RFC 4180 when needed.
+fn write_text(out: &mut String, s: &str, opts: &CsvWriteOptions) {
+    let needs_quotes = s == opts.null_token
+        || s.bytes()
+            .any(|b| b == opts.delimiter || matches!(b, b'"' | b'\r' | b'\n'));
+    if !needs_quotes {
+        out.push_str(s);
+        return;
+    }
+    out.push('"');
+    for (i, part) in s.split('"').enumerate() {
+        if i > 0 {
+            out.push_str("\"\"");
+        }
+        out.push_str(part);
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MaskedArray, NumericArray, TextArray};
+
+    fn read(input: &str, opts: CsvReadOptions) -> Result<Table, MinarrowError> {
+        Table::from_csv(input.as_bytes(), opts)
+    }
+
+    #[test]
+    fn test_read_infers_types_and_nulls() {
+        let input =
+            "id,score,flag,name\r\n1,2.5,true,\"a, b\"\n2,,FALSE,\"\"\n,3,,\"say \"\"hi\"\"\"\n";
+        let t = read(input, CsvReadOptions::default()).unwrap();
+        assert_eq!(t.n_rows, 3);
+        assert_eq!(t.col_names(), vec!["id", "score", "flag", "name"]);
+        let dtypes: Vec<ArrowType> = t.cols.iter().map(|c| c.field.dtype.clone()).collect();
+        assert_eq!(
+            dtypes,
+            vec![
+                ArrowType::Int64,
+                ArrowType::Float64,
+                ArrowType::Boolean,
+                ArrowType::String
+            ]
+        );
+
+        match &t.cols[0].array {
+            Array::NumericArray(NumericArray::Int64(a)) => {
+                assert_eq!(a.get(0), Some(1));
+                assert_eq!(a.get(2), None);
+            }
+            other => panic!("expected Int64, got {:?}", other),
+        }
+        assert!(t.cols[0].field.nullable);
+        match &t.cols[1].array {
+            Array::NumericArray(NumericArray::Float64(a)) => {
+                assert_eq!(a.get(0), Some(2.5));
+                assert_eq!(a.get(1), None);
+                assert_eq!(a.get(2), Some(3.0));
+            }
+            other => panic!("expected Float64, got {:?}", other),
+        }
+        match &t.cols[2].array {
+            Array::BooleanArray(a) => {
+                assert_eq!(a.get(0), Some(true));
+                assert_eq!(a.get(1), Some(false));
+                assert_eq!(a.get(2), None);
+            }
+            other => panic!("expected Boolean, got {:?}", other),
+        }
+        match &t.cols[3].array {
+            Array::TextArray(TextArray::String32(a)) => {
+                assert_eq!(a.get_str(0), Some("a, b"));
+                assert_eq!(a.get_str(1), Some(""));
+                assert_eq!(a.get_str(2), Some("say \"hi\""));
+            }
+            other => panic!("expected String32, got {:?}", other),
+        }
+        assert!(!t.cols[3].field.nullable);
+    }
+
+    #[test]
+    fn test_read_without_header_and_custom_delimiter() {
+        let opts = CsvReadOptions {
+            delimiter: b';',
+            has_header: false,
+            ..Default::default()
+        };
+        let t = read("x;\"multi\nline\"\ny;z\n", opts).unwrap();
+        assert_eq!(t.col_names(), vec!["col_0", "col_1"]);
+        match &t.cols[1].array {
+            Array::TextArray(TextArray::String32(a)) => {
+                assert_eq!(a.get_str(0), Some("multi\nline"));
+                assert_eq!(a.get_str(1), Some("z"));
+            }
+            other => panic!("expected String32, got {:?}", other),
+        }
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_write_quotes_nulls_and_precision() {
+        let mut score = FloatArray::<f64>::from_slice(&[1.0, 2.345, 0.0]);
+        score.set_null(2);
+        let mut t = Table::new_empty();
+        t.add_col(crate::fa_i32!("id", 1, 2, 3));
+        t.add_col(FieldArray::from_arr("score", Array::from_float64(score)));
+        t.add_col(crate::fa_str32!("note", "a,b", "say \"hi\"", ""));
+        t.add_col(crate::fa_cat32!("cat", "x", "y", "x"));
+
+        let mut out = Vec::new();
+        t.to_csv(&mut out, CsvWriteOptions::default()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,score,note,cat\n1,1,\"a,b\",x\n2,2.345,\"say \"\"hi\"\"\",y\n3,,\"\",x\n"
+        );
+
+        let opts = CsvWriteOptions {
+            delimiter: b'|',
+            has_header: false,
+            null_token: "NA".to_string(),
+            float_precision: Some(2),
+        };
+        let mut out = Vec::new();
+        t.to_csv(&mut out, opts).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1|1.00|a,b|x\n2|2.35|\"say \"\"hi\"\"\"|y\n3|NA||x\n"
+        );
+    }
+
+    #[test]
+    fn test_write_read_round_trip() {
+        let mut t = Table::new_empty();
+        t.add_col(crate::fa_i64!("n", 5, -7));
+        t.add_col(crate::fa_str32!("s", "line\nbreak", ""));
+        let mut out = Vec::new();
+        t.to_csv(&mut out, CsvWriteOptions::default()).unwrap();
+
+        let back = Table::from_csv(out.as_slice(), CsvReadOptions::default()).unwrap();
+        assert_eq!(back.col_names(), vec!["n", "s"]);
+        match &back.cols[1].array {
+            Array::TextArray(TextArray::String32(a)) => {
+                assert_eq!(a.get_str(0), Some("line\nbreak"));
+                assert_eq!(a.get_str(1), Some(""));
+            }
+            other => panic!("expected String32, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_errors_carry_line_numbers() {
+        let err = read("a,b\n1,2\n3\n", CsvReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 3, .. }));
+
+        let opts = CsvReadOptions {
+            infer_rows: 1,
+            ..Default::default()
+        };
+        let err = read("a\n1\n2\nx\n", opts).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 4, .. }));
+
+        let err = read("a\n\"open\n", CsvReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 2, .. }));
+
+        let opts = CsvReadOptions {
+            delimiter: b'"',
+            ..Default::default()
+        };
+        assert!(matches!(
+            read("a", opts),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+}
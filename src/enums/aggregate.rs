@@ -0,0 +1,181 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Aggregate Module** - *Grouped Aggregation Functions*
+//!
+//! Defines the aggregation functions used by the table reshaping and
+//! grouping methods, along with the running state that accumulates them,
+//! and the interpolation rules for quantiles.
+
+use crate::{Array, Bitmask, FloatArray, IntegerArray, Vec64};
+
+/// # AggFn
+///
+/// Aggregation applied to the values that fall into one group or cell.
+///
+/// Null values are skipped. `Count` yields the number of non-null values,
+/// while the other functions yield null when a group has no non-null values.
+///
+/// `Min` and `Max` keep the value type. `Sum` widens to `Int64`, `UInt64` or
+/// `Float64`, matching `NumericArray::sum`, and is null where an integer total
+/// falls outside that type. `Mean` is `Float64` and `Count` is `UInt64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggFn {
+    Sum,
+    Min,
+    Max,
+    Count,
+    Mean,
+}
+
+/// # Interpolation
+///
+/// How a quantile that falls between two values is resolved.
+///
+/// For `n` sorted values, quantile `q` sits at position `q * (n - 1)`. When that
+/// position is fractional it lies between a lower and a higher value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Weighted average of the two neighbours, by the fractional position.
+    #[default]
+    Linear,
+    /// The lower neighbour.
+    Lower,
+    /// The higher neighbour.
+    Higher,
+    /// Whichever neighbour is closer, with ties going to the lower one.
+    Nearest,
+}
+
+/// Element type that can be aggregated per group.
+///
+/// Sums accumulate exactly in `Acc` and are then narrowed to the type that
+/// `NumericArray::sum` returns, while `Min` and `Max` keep the element type.
+pub(crate) trait AggValue: Copy + Default {
+    /// Output type of `Sum`: `i64`, `u64` or `f64` by the element's kind.
+    type Sum: AggValue;
+    /// Running total: `i128`, `u128` or `f64`, too wide for any group to overflow.
+    type Acc: Copy + Default;
+
+    fn add(acc: Self::Acc, v: Self) -> Self::Acc;
+    /// Narrows a total to `Sum`, or `None` if it does not fit.
+    fn finish_sum(acc: Self::Acc) -> Option<Self::Sum>;
+    fn acc_to_f64(acc: Self::Acc) -> f64;
+    fn lesser(self, other: Self) -> Self;
+    fn greater(self, other: Self) -> Self;
+    fn into_array(data: Vec64<Self>, null_mask: Option<Bitmask>) -> Array;
+}
+
+macro_rules! impl_agg_value {
+    ($t:ty, $sum:ty, $acc:ty, $ctor:ident, $wrapper:ident) => {
+        impl AggValue for $t {
+            type Sum = $sum;
+            type Acc = $acc;
+
+            #[inline]
+            fn add(acc: $acc, v: $t) -> $acc {
+                acc + v as $acc
+            }
+            #[inline]
+            fn finish_sum(acc: $acc) -> Option<$sum> {
+                <$sum>::try_from(acc).ok()
+            }
+            #[inline]
+            fn acc_to_f64(acc: $acc) -> f64 {
+                acc as f64
+            }
+            #[inline]
+            fn lesser(self, other: Self) -> Self {
+                self.min(other)
+            }
+            #[inline]
+            fn greater(self, other: Self) -> Self {
+                self.max(other)
+            }
+            fn into_array(data: Vec64<Self>, null_mask: Option<Bitmask>) -> Array {
+                Array::$ctor($wrapper::new(data, null_mask))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "extended_numeric_types")]
+impl_agg_value!(i8, i64, i128, from_int8, IntegerArray);
+#[cfg(feature = "extended_numeric_types")]
+impl_agg_value!(i16, i64, i128, from_int16, IntegerArray);
+impl_agg_value!(i32, i64, i128, from_int32, IntegerArray);
+impl_agg_value!(i64, i64, i128, from_int64, IntegerArray);
+#[cfg(feature = "extended_numeric_types")]
+impl_agg_value!(u8, u64, u128, from_uint8, IntegerArray);
+#[cfg(feature = "extended_numeric_types")]
+impl_agg_value!(u16, u64, u128, from_uint16, IntegerArray);
+impl_agg_value!(u32, u64, u128, from_uint32, IntegerArray);
+impl_agg_value!(u64, u64, u128, from_uint64, IntegerArray);
+impl_agg_value!(f32, f64, f64, from_float32, FloatArray);
+impl_agg_value!(f64, f64, f64, from_float64, FloatArray);
+
+/// Running accumulator for a single group, fed one non-null value at a time.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AggState<T: AggValue> {
+    count: usize,
+    sum: T::Acc,
+    min: T,
+    max: T,
+}
+
+impl<T: AggValue> AggState<T> {
+    /// Adds a non-null value to the accumulator.
+    #[inline]
+    pub(crate) fn update(&mut self, v: T) {
+        if self.count == 0 {
+            (self.min, self.max) = (v, v);
+        } else {
+            self.min = self.min.lesser(v);
+            self.max = self.max.greater(v);
+        }
+        self.sum = T::add(self.sum, v);
+        self.count += 1;
+    }
+
+    /// Number of values seen.
+    #[inline]
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Sum of the values seen, or `None` when there were none or the total
+    /// does not fit `T::Sum`.
+    #[inline]
+    pub(crate) fn sum(&self) -> Option<T::Sum> {
+        (self.count > 0).then_some(self.sum).and_then(T::finish_sum)
+    }
+
+    /// Smallest value seen, or `None` when there were none.
+    #[inline]
+    pub(crate) fn min(&self) -> Option<T> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Largest value seen, or `None` when there were none.
+    #[inline]
+    pub(crate) fn max(&self) -> Option<T> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Mean of the values seen, or `None` when there were none.
+    #[inline]
+    pub(crate) fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| T::acc_to_f64(self.sum) / self.count as f64)
+    }
+}
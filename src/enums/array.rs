@@ -26,8 +26,11 @@
 
 use std::any::TypeId;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::sync::Arc;
 
+use num_traits::NumCast;
+
 #[cfg(feature = "cast_arrow")]
 use crate::ffi::arrow_c_ffi::export_to_c;
 #[cfg(feature = "cast_arrow")]
@@ -45,14 +48,16 @@ use crate::DatetimeArray;
 use crate::SuperArray;
 #[cfg(feature = "datetime")]
 use crate::TemporalArray;
-use crate::enums::error::MinarrowError;
+use crate::enums::cast::CastOverflow;
+use crate::enums::error::{KernelError, MinarrowError};
 use crate::enums::shape_dim::ShapeDim;
 use crate::ffi::arrow_dtype::{ArrowType, CategoricalIndexType};
 use crate::traits::{concatenate::Concatenate, shape::Shape};
 use crate::utils::{float_to_text_array, int_to_text_array};
 use crate::{
-    Bitmask, BooleanArray, CategoricalArray, Field, FieldArray, FloatArray, IntegerArray,
-    MaskedArray, NumericArray, StringArray, TextArray, Vec64, match_array,
+    Bitmask, BooleanArray, CategoricalArray, Field, FieldArray, FixedSizeBinaryArray, FloatArray,
+    Integer, IntegerArray, MaskedArray, Numeric, NumericArray, StringArray, TextArray, Vec64,
+    match_array,
 };
 
 /// # Array
@@ -121,6 +126,7 @@ pub enum Array {
     #[cfg(feature = "datetime")]
     TemporalArray(TemporalArray),
     BooleanArray(Arc<BooleanArray<()>>),
+    FixedSizeBinaryArray(Arc<FixedSizeBinaryArray>),
     #[default]
     Null, // Default Marker for mem::take
 }
@@ -232,6 +238,11 @@ impl Array {
         Array::BooleanArray(Arc::new(arr))
     }
 
+    /// Creates an Array enum with a FixedSizeBinary array.
+    pub fn from_fixed_size_binary(arr: FixedSizeBinaryArray) -> Self {
+        Array::FixedSizeBinaryArray(Arc::new(arr))
+    }
+
     /// Wraps this Array in a FieldArray with the given name.
     ///
     /// Infers the Arrow type and nullability from the array itself.
@@ -792,10 +803,263 @@ impl Array {
                 TextArray::Null => NumericArray::Null,
             },
 
+            Array::FixedSizeBinaryArray(_) => NumericArray::Null,
             Array::Null => NumericArray::Null,
         }
     }
 
+    /// Converts a numeric array to the numeric `target` type, returning the
+    /// `NumericArray` directly.
+    ///
+    /// Intended for reconciling columns of mixed numeric widths. Every non-null
+    /// value must survive the conversion exactly, so widening always succeeds
+    /// while narrowing succeeds only when the actual values fit. Nulls are
+    /// carried over unchanged. When the type already matches, the inner `Arc`
+    /// is cloned rather than the data.
+    ///
+    /// # Errors
+    /// - `TypeError` if `self` is not numeric, or `target` is not a numeric type.
+    /// - `Overflow` if a value is out of range for `target`.
+    /// - `LossyCast` if a value would lose precision, e.g. `2.5` into an integer.
+    pub fn to_common_numeric(&self, target: ArrowType) -> Result<NumericArray, MinarrowError> {
+        let num = self.num_ref()?;
+        if self.arrow_type() == target {
+            return Ok(num.clone());
+        }
+        macro_rules! convert {
+            ($a:expr) => {
+                numeric_to($a.data.as_slice(), $a.null_mask.as_ref(), &target)
+            };
+        }
+        match num {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => convert!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => convert!(a),
+            NumericArray::Int32(a) => convert!(a),
+            NumericArray::Int64(a) => convert!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => convert!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => convert!(a),
+            NumericArray::UInt32(a) => convert!(a),
+            NumericArray::UInt64(a) => convert!(a),
+            NumericArray::Float32(a) => convert!(a),
+            NumericArray::Float64(a) => convert!(a),
+            NumericArray::Null => Err(MinarrowError::NullError { message: None }),
+        }
+    }
+
+    /// Concatenates two arrays, promoting mismatched numeric types to a common type first.
+    ///
+    /// Promotion follows this lattice:
+    /// - Identical types are concatenated as-is.
+    /// - Signed with signed, or unsigned with unsigned, widens to the larger width.
+    /// - Signed with unsigned picks the smallest signed type that holds both,
+    ///   e.g. `UInt32` + `Int32` gives `Int64`. `UInt64` with any signed type
+    ///   has no such type and gives `Float64`.
+    /// - Any float involved gives `Float32` when the other side is `Float32` or an
+    ///   integer of at most 16 bits, and `Float64` otherwise.
+    ///
+    /// Conversion goes through [`Array::to_common_numeric`], so a value that cannot
+    /// be represented exactly in the promoted type, such as an `Int64` above 2^53
+    /// promoted to `Float64`, is an error rather than a silent rounding.
+    ///
+    /// # Errors
+    /// - `IncompatibleTypeError` if only one side is numeric, or the categories
+    ///   otherwise differ, e.g. text vs boolean.
+    /// - Any conversion error from the promotion.
+    pub fn concat_promoting(self, other: Array) -> Result<Array, MinarrowError> {
+        let (lhs_type, rhs_type) = (self.arrow_type(), other.arrow_type());
+        if lhs_type == rhs_type {
+            return self.concat(other);
+        }
+        let both_numeric =
+            matches!(self, Array::NumericArray(_)) && matches!(other, Array::NumericArray(_));
+        if !both_numeric {
+            return self.concat(other);
+        }
+        let target = promote_numeric(&lhs_type, &rhs_type).ok_or_else(|| {
+            MinarrowError::IncompatibleTypeError {
+                from: "NumericArray",
+                to: "NumericArray",
+                message: Some(format!(
+                    "no common numeric type for {} and {}",
+                    lhs_type, rhs_type
+                )),
+            }
+        })?;
+        let lhs = self.to_common_numeric(target.clone())?;
+        let rhs = other.to_common_numeric(target)?;
+        Ok(Array::NumericArray(lhs.concat(rhs)?))
+    }
+
+    /// Casts to the `target` type, failing on any numeric value out of range.
+    ///
+    /// Shorthand for [`Array::cast_with`] with `CastOverflow::Error`.
+    pub fn cast(&self, target: ArrowType) -> Result<Array, MinarrowError> {
+        self.cast_with(target, CastOverflow::Error)
+    }
+
+    /// Casts to the `target` type, with `on_overflow` deciding what happens to
+    /// numeric values that do not fit.
+    ///
+    /// Supported conversions:
+    /// - Numeric to numeric. Floats into integers truncate toward zero, and
+    ///   wide integers into floats round to the nearest representable value.
+    ///   A value outside the target range, including `NaN` or an infinity into
+    ///   an integer type, is an overflow and follows `on_overflow`.
+    /// - Numeric to `String` or `LargeString`, using each value's `Display` form.
+    /// - Text to numeric. Values are trimmed and parsed as the target type,
+    ///   and anything that fails to parse becomes null regardless of `on_overflow`.
+    /// - Text to `String` or `LargeString`, including categoricals.
+    ///
+    /// Nulls are preserved in every case. Casting to the current type clones.
+    ///
+    /// Unlike [`Array::to_common_numeric`], which rejects any inexact value,
+    /// this is the loose conversion intended for ingesting untyped data.
+    ///
+    /// # Errors
+    /// - `TypeError` for any other combination of source and target.
+    /// - `Overflow` if `on_overflow` is `CastOverflow::Error` and a value does not fit.
+    pub fn cast_with(
+        &self,
+        target: ArrowType,
+        on_overflow: CastOverflow,
+    ) -> Result<Array, MinarrowError> {
+        if self.arrow_type() == target {
+            return Ok(self.clone());
+        }
+        let to_numeric = numeric_kind(&target).is_some();
+        let to_string = match target {
+            ArrowType::String => true,
+            #[cfg(feature = "large_string")]
+            ArrowType::LargeString => true,
+            _ => false,
+        };
+        match self {
+            Array::Null
+            | Array::NumericArray(NumericArray::Null)
+            | Array::TextArray(TextArray::Null) => Ok(Array::Null),
+            Array::NumericArray(num) if to_numeric => {
+                macro_rules! convert {
+                    ($a:expr) => {
+                        cast_numeric_to(
+                            $a.data.as_slice(),
+                            $a.null_mask.as_ref(),
+                            &target,
+                            on_overflow,
+                        )
+                    };
+                }
+                let out = match num {
+                    #[cfg(feature = "extended_numeric_types")]
+                    NumericArray::Int8(a) => convert!(a),
+                    #[cfg(feature = "extended_numeric_types")]
+                    NumericArray::Int16(a) => convert!(a),
+                    NumericArray::Int32(a) => convert!(a),
+                    NumericArray::Int64(a) => convert!(a),
+                    #[cfg(feature = "extended_numeric_types")]
+                    NumericArray::UInt8(a) => convert!(a),
+                    #[cfg(feature = "extended_numeric_types")]
+                    NumericArray::UInt16(a) => convert!(a),
+                    NumericArray::UInt32(a) => convert!(a),
+                    NumericArray::UInt64(a) => convert!(a),
+                    NumericArray::Float32(a) => convert!(a),
+                    NumericArray::Float64(a) => convert!(a),
+                    NumericArray::Null => unreachable!(),
+                }?;
+                Ok(Array::NumericArray(out))
+            }
+            Array::NumericArray(num) if to_string => {
+                Ok(string_array_as(num.clone().str()?, &target))
+            }
+            Array::TextArray(text) if to_numeric => {
+                Ok(Array::NumericArray(parse_text_to(text, &target)))
+            }
+            Array::TextArray(text) if to_string => Ok(match target {
+                #[cfg(feature = "large_string")]
+                ArrowType::LargeString => Array::from_string64(text.clone().str64()?),
+                _ => Array::from_string32(text.clone().str32()?),
+            }),
+            other => Err(MinarrowError::TypeError {
+                from: array_category_name(other),
+                to: "ArrowType",
+                message: Some(format!("cannot cast {} to {}", other.arrow_type(), target)),
+            }),
+        }
+    }
+
+    /// Uppercases every string using full Unicode case mapping.
+    ///
+    /// Works on both string and categorical text arrays, keeping the variant
+    /// and the null mask. See `kernels::string::to_uppercase_str`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-text arrays.
+    pub fn to_uppercase(&self) -> Result<Array, MinarrowError> {
+        self.transform_text(TextTransform::Upper)
+    }
+
+    /// Lowercases every string using full Unicode case mapping.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-text arrays.
+    pub fn to_lowercase(&self) -> Result<Array, MinarrowError> {
+        self.transform_text(TextTransform::Lower)
+    }
+
+    /// Strips leading and trailing Unicode whitespace from every string.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-text arrays.
+    pub fn trim(&self) -> Result<Array, MinarrowError> {
+        self.transform_text(TextTransform::Trim)
+    }
+
+    /// Strips leading Unicode whitespace from every string.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-text arrays.
+    pub fn trim_start(&self) -> Result<Array, MinarrowError> {
+        self.transform_text(TextTransform::TrimStart)
+    }
+
+    /// Strips trailing Unicode whitespace from every string.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-text arrays.
+    pub fn trim_end(&self) -> Result<Array, MinarrowError> {
+        self.transform_text(TextTransform::TrimEnd)
+    }
+
+    fn transform_text(&self, op: TextTransform) -> Result<Array, MinarrowError> {
+        let Array::TextArray(text) = self else {
+            return Err(MinarrowError::TypeError {
+                from: array_category_name(self),
+                to: "TextArray",
+                message: Some(format!("{} requires a text array", op.name())),
+            });
+        };
+        Ok(Array::TextArray(match text {
+            TextArray::String32(a) => TextArray::String32(Arc::new(op.apply_str(a)?)),
+            #[cfg(feature = "large_string")]
+            TextArray::String64(a) => TextArray::String64(Arc::new(op.apply_str(a)?)),
+            #[cfg(feature = "default_categorical_8")]
+            TextArray::Categorical8(a) => TextArray::Categorical8(Arc::new(op.apply_dict(a)?)),
+            #[cfg(feature = "extended_categorical")]
+            TextArray::Categorical16(a) => TextArray::Categorical16(Arc::new(op.apply_dict(a)?)),
+            #[cfg(any(
+                not(feature = "default_categorical_8"),
+                feature = "extended_categorical"
+            ))]
+            TextArray::Categorical32(a) => TextArray::Categorical32(Arc::new(op.apply_dict(a)?)),
+            #[cfg(feature = "extended_categorical")]
+            TextArray::Categorical64(a) => TextArray::Categorical64(Arc::new(op.apply_dict(a)?)),
+            TextArray::Null => TextArray::Null,
+        }))
+    }
+
     /// Returns an inner `TextArray`, consuming self.
     /// - If already a `TextArray`, consumes and returns the inner value with no clone.
     /// - Other types: casts *(to string)* and copies.
@@ -860,6 +1124,22 @@ impl Array {
                 _ => TextArray::Null,
             },
 
+            Array::FixedSizeBinaryArray(arr) => {
+                let mut strings: Vec64<String> = Vec64::with_capacity(arr.len());
+                for v in arr.iter_values_opt() {
+                    match v {
+                        Some(bytes) => {
+                            strings.push(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+                        }
+                        None => strings.push(String::new()),
+                    }
+                }
+                TextArray::String32(Arc::new(StringArray::<u32>::from_vec64_owned(
+                    strings,
+                    arr.null_mask.clone(),
+                )))
+            }
+
             Array::Null => TextArray::Null,
         }
     }
@@ -966,6 +1246,7 @@ impl Array {
                 }
                 _ => BooleanArray::default().into(),
             },
+            Array::FixedSizeBinaryArray(_) => BooleanArray::default().into(),
             Array::Null => BooleanArray::default().into(),
         }
     }
@@ -1071,6 +1352,7 @@ impl Array {
                 }
                 _ => TemporalArray::Null,
             },
+            Array::FixedSizeBinaryArray(_) => TemporalArray::Null,
             Array::Null => TemporalArray::Null,
         }
     }
@@ -1170,6 +1452,13 @@ impl Array {
             }
         }
 
+        // Fixed-size binary
+        if let Array::FixedSizeBinaryArray(inner) = self
+            && TypeId::of::<T>() == TypeId::of::<FixedSizeBinaryArray>()
+        {
+            return unsafe { &*(inner as *const Arc<FixedSizeBinaryArray> as *const Arc<T>) };
+        }
+
         panic!(
             "Type mismatch: attempted to access Array::{:?} as incompatible type",
             self.arrow_type()
@@ -1241,6 +1530,13 @@ impl Array {
             }
         }
 
+        // Fixed-size binary
+        if let Array::FixedSizeBinaryArray(inner) = self
+            && TypeId::of::<T>() == TypeId::of::<FixedSizeBinaryArray>()
+        {
+            return unsafe { &mut *(inner as *mut Arc<FixedSizeBinaryArray> as *mut Arc<T>) };
+        }
+
         panic!(
             "Type mismatch: attempted to mutably access Array::{:?} as incompatible type",
             self.arrow_type()
@@ -1472,6 +1768,11 @@ impl Array {
                 )
             }
 
+            Array::FixedSizeBinaryArray(arr) => {
+                cast_slice::<u8, T>(arr.data(), offset * arr.width, len * arr.width)
+                    .expect("cast failed")
+            }
+
             Array::Null => panic!("Null array has no data payload"),
         }
     }
@@ -1563,15 +1864,41 @@ impl Array {
                 _ => None,
             },
 
+            Array::FixedSizeBinaryArray(a) if TypeId::of::<T>() == TypeId::of::<u8>() => {
+                cast_slice::<u8, T>(&a.data, offset * a.width, len * a.width)
+            }
+
             _ => None,
         }
     }
 
+    /// Returns an owned, compacted copy of the window `offset..offset + len`.
+    ///
+    /// Unlike `ArrayV`, which keeps the parent buffer alive, the result owns
+    /// only the selected values. The null mask is rebuilt from bit zero and
+    /// string offsets are rebased, so no tail of the parent is retained.
+    /// Categoricals keep their full dictionary so codes stay comparable.
+    ///
+    /// # Errors
+    /// - `IndexError` if the window extends past the end of the array.
+    pub fn slice(&self, offset: usize, len: usize) -> Result<Self, MinarrowError> {
+        let total = self.len();
+        if offset.checked_add(len).is_none_or(|end| end > total) {
+            return Err(MinarrowError::IndexError(format!(
+                "Array::slice: window {}..{} out of bounds for length {}",
+                offset,
+                offset.saturating_add(len),
+                total
+            )));
+        }
+        Ok(self.slice_clone(offset, len))
+    }
+
     /// Returns a new `Array` of the same variant sliced to the given offset and length
     /// .
     /// Copies the data of the scoped range that's selected.
     ///  
-    /// If out-of-bounds, returns Self::Null.
+    /// Panics if out-of-bounds; see [`Array::slice`] for a checked version.
     /// All null mask, offsets, etc. are trimmed.
     #[inline]
     pub fn slice_clone(&self, offset: usize, len: usize) -> Self {
@@ -1626,10 +1953,289 @@ impl Array {
                 TemporalArray::Null => TemporalArray::Null,
             }),
             Array::BooleanArray(arr) => Self::BooleanArray(arr.slice_clone(offset, len)),
+            Array::FixedSizeBinaryArray(arr) => {
+                Self::FixedSizeBinaryArray(arr.slice_clone(offset, len))
+            }
             Array::Null => Self::Null,
         }
     }
 
+    /// Returns the first `n` elements, or the whole array if it is shorter.
+    pub fn head(&self, n: usize) -> Self {
+        self.slice_clone(0, n.min(self.len()))
+    }
+
+    /// Returns the last `n` elements, or the whole array if it is shorter.
+    pub fn tail(&self, n: usize) -> Self {
+        let len = n.min(self.len());
+        self.slice_clone(self.len() - len, len)
+    }
+
+    /// Shifts the elements by `by` slots, keeping the length.
+    ///
+    /// A positive `by` moves elements towards the end, i.e. a lag, and a negative
+    /// one towards the start, i.e. a lead. Elements shifted past either end are
+    /// dropped. The vacated slots are null, or `fill` when given, which must match
+    /// the array type as in [`Array::fill_null`]. Nulls in the source move with
+    /// their values. Gathers through [`Array::take`], so text, categorical and
+    /// datetime arrays keep their offsets, dictionaries and units.
+    ///
+    /// # Errors
+    /// - `TypeError` if `fill` does not match the array type.
+    #[cfg(feature = "scalar_type")]
+    pub fn shift(&self, by: i64, fill: Option<crate::Scalar>) -> Result<Array, MinarrowError> {
+        let len = self.len();
+        if len == 0 || by == 0 {
+            return Ok(self.clone());
+        }
+        let k = (by.unsigned_abs() as usize).min(len);
+        let source = |i: usize| {
+            if by > 0 {
+                i.checked_sub(k)
+            } else {
+                Some(i + k).filter(|&j| j < len)
+            }
+        };
+        // Vacated slots gather row 0 as a placeholder and are then masked out.
+        let gather: Vec<usize> = (0..len).map(|i| source(i).unwrap_or(0)).collect();
+        let kept = Bitmask::from_bools(&(0..len).map(|i| source(i).is_some()).collect::<Vec<_>>());
+
+        let mut out = self.take(&gather);
+        let moved = out.null_mask().cloned();
+        out.set_null_mask(kept.clone());
+        if let Some(value) = fill.filter(|v| !v.is_null()) {
+            out = out.fill_null(value)?;
+            if let Some(moved) = moved {
+                out.set_null_mask(moved.union(&kept.invert()));
+            }
+        } else if let Some(moved) = moved {
+            out.set_null_mask(moved.intersect(&kept));
+        }
+        Ok(out)
+    }
+
+    /// Gathers the elements at `indices` into a new owned `Array`.
+    ///
+    /// Output element `i` is `self[indices[i]]`, so the same index may repeat
+    /// and any ordering is allowed. Null masks follow the gathered rows,
+    /// categorical dictionaries are kept as-is and datetime units are preserved.
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    pub fn take(&self, indices: &[usize]) -> Self {
+        let len = self.len();
+        if let Some(&bad) = indices.iter().find(|&&i| i >= len) {
+            panic!(
+                "Array::take: index {} out of bounds for length {}",
+                bad, len
+            );
+        }
+
+        macro_rules! take_fixed {
+            ($arr:expr, $ctor:path) => {
+                Arc::new($ctor(
+                    take_values($arr.data.as_slice(), indices),
+                    take_null_mask($arr.null_mask.as_ref(), indices),
+                ))
+            };
+        }
+        macro_rules! take_categorical {
+            ($arr:expr) => {
+                Arc::new(CategoricalArray::new_unchecked(
+                    take_values($arr.data.as_slice(), indices),
+                    $arr.unique_values.clone(),
+                    take_null_mask($arr.null_mask.as_ref(), indices),
+                ))
+            };
+        }
+
+        match self {
+            Array::NumericArray(inner) => Array::NumericArray(match inner {
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int8(a) => NumericArray::Int8(take_fixed!(a, IntegerArray::new)),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int16(a) => NumericArray::Int16(take_fixed!(a, IntegerArray::new)),
+                NumericArray::Int32(a) => NumericArray::Int32(take_fixed!(a, IntegerArray::new)),
+                NumericArray::Int64(a) => NumericArray::Int64(take_fixed!(a, IntegerArray::new)),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt8(a) => NumericArray::UInt8(take_fixed!(a, IntegerArray::new)),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt16(a) => NumericArray::UInt16(take_fixed!(a, IntegerArray::new)),
+                NumericArray::UInt32(a) => NumericArray::UInt32(take_fixed!(a, IntegerArray::new)),
+                NumericArray::UInt64(a) => NumericArray::UInt64(take_fixed!(a, IntegerArray::new)),
+                NumericArray::Float32(a) => NumericArray::Float32(take_fixed!(a, FloatArray::new)),
+                NumericArray::Float64(a) => NumericArray::Float64(take_fixed!(a, FloatArray::new)),
+                NumericArray::Null => NumericArray::Null,
+            }),
+            Array::TextArray(inner) => Array::TextArray(match inner {
+                TextArray::String32(a) => TextArray::String32(Arc::new(take_strings(a, indices))),
+                #[cfg(feature = "large_string")]
+                TextArray::String64(a) => TextArray::String64(Arc::new(take_strings(a, indices))),
+                #[cfg(feature = "default_categorical_8")]
+                TextArray::Categorical8(a) => TextArray::Categorical8(take_categorical!(a)),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical16(a) => TextArray::Categorical16(take_categorical!(a)),
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                TextArray::Categorical32(a) => TextArray::Categorical32(take_categorical!(a)),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical64(a) => TextArray::Categorical64(take_categorical!(a)),
+                TextArray::Null => TextArray::Null,
+            }),
+            #[cfg(feature = "datetime")]
+            Array::TemporalArray(inner) => Array::TemporalArray(match inner {
+                TemporalArray::Datetime32(a) => {
+                    TemporalArray::Datetime32(Arc::new(DatetimeArray::new(
+                        take_values(a.data.as_slice(), indices),
+                        take_null_mask(a.null_mask.as_ref(), indices),
+                        Some(a.time_unit),
+                    )))
+                }
+                TemporalArray::Datetime64(a) => {
+                    TemporalArray::Datetime64(Arc::new(DatetimeArray::new(
+                        take_values(a.data.as_slice(), indices),
+                        take_null_mask(a.null_mask.as_ref(), indices),
+                        Some(a.time_unit),
+                    )))
+                }
+                TemporalArray::Null => TemporalArray::Null,
+            }),
+            Array::BooleanArray(a) => {
+                let mut data = Bitmask::new_set_all(indices.len(), false);
+                for (out, &idx) in indices.iter().enumerate() {
+                    if a.data.get(idx) {
+                        data.set(out, true);
+                    }
+                }
+                Array::BooleanArray(Arc::new(BooleanArray::new(
+                    data,
+                    take_null_mask(a.null_mask.as_ref(), indices),
+                )))
+            }
+            Array::FixedSizeBinaryArray(a) => {
+                let w = a.width;
+                let mut data = Vec64::with_capacity(indices.len() * w);
+                for &idx in indices {
+                    data.extend_from_slice(&a.data[idx * w..(idx + 1) * w]);
+                }
+                Array::FixedSizeBinaryArray(Arc::new(FixedSizeBinaryArray {
+                    data: data.into(),
+                    null_mask: take_null_mask(a.null_mask.as_ref(), indices),
+                    width: w,
+                    len: indices.len(),
+                }))
+            }
+            Array::Null => Array::Null,
+        }
+    }
+
+    /// Returns a copy with the element order flipped.
+    ///
+    /// Built on [`take`](Array::take), so null masks and string offsets are
+    /// rebuilt to match and categorical dictionaries are kept as-is.
+    pub fn reverse(&self) -> Self {
+        let indices: Vec<usize> = (0..self.len()).rev().collect();
+        self.take(&indices)
+    }
+
+    /// Run-length encodes the array, returning the first element of each run of
+    /// equal consecutive elements alongside the length of each run.
+    ///
+    /// Elements compare as in [`compare_at`](Array::compare_at), so floats match
+    /// by `total_cmp` and categorical values by their strings. Consecutive nulls
+    /// form a run of their own. [`decode_rle`](Array::decode_rle) reverses this.
+    pub fn rle(&self) -> (Self, IntegerArray<u64>) {
+        let starts = self.run_starts();
+        let mut lengths = Vec64::with_capacity(starts.len());
+        for (i, &start) in starts.iter().enumerate() {
+            let end = starts.get(i + 1).copied().unwrap_or(self.len());
+            lengths.push((end - start) as u64);
+        }
+        (self.take(&starts), IntegerArray::new(lengths, None))
+    }
+
+    /// Returns a copy with each run of equal consecutive elements collapsed to
+    /// its first element, i.e. the values half of [`rle`](Array::rle).
+    pub fn dedup_consecutive(&self) -> Self {
+        self.take(&self.run_starts())
+    }
+
+    /// Expands run-length encoded data, repeating `values[i]` `lengths[i]` times.
+    ///
+    /// The inverse of [`rle`](Array::rle). Zero-length runs are allowed and
+    /// contribute nothing.
+    ///
+    /// # Errors
+    /// - `ShapeError` if `values` and `lengths` differ in length.
+    /// - `NullError` if any run length is null.
+    /// - `Overflow` if the run lengths add up to more elements than can be allocated.
+    pub fn decode_rle(values: &Array, lengths: &IntegerArray<u64>) -> Result<Self, MinarrowError> {
+        if values.len() != lengths.len() {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "Array::decode_rle: {} values but {} run lengths",
+                    values.len(),
+                    lengths.len()
+                ),
+            });
+        }
+        if lengths.null_count() > 0 {
+            return Err(MinarrowError::NullError {
+                message: Some("Array::decode_rle: run lengths must not be null".to_string()),
+            });
+        }
+        let overflow = |value: String| MinarrowError::Overflow {
+            value,
+            target: "usize",
+        };
+        let total = lengths
+            .data
+            .iter()
+            .try_fold(0u64, |acc, &n| acc.checked_add(n))
+            .and_then(|t| usize::try_from(t).ok())
+            .ok_or_else(|| {
+                let exact: u128 = lengths.data.iter().map(|&n| n as u128).sum();
+                overflow(exact.to_string())
+            })?;
+        let mut indices = Vec::new();
+        indices
+            .try_reserve_exact(total)
+            .map_err(|_| overflow(total.to_string()))?;
+        for (i, &n) in lengths.data.iter().enumerate() {
+            indices.extend(std::iter::repeat_n(i, n as usize));
+        }
+        Ok(values.take(&indices))
+    }
+
+    /// Indices where a run of equal consecutive elements begins.
+    fn run_starts(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| i == 0 || self.compare_at(i - 1, i) != std::cmp::Ordering::Equal)
+            .collect()
+    }
+
+    /// Returns a new `Array` holding only the elements whose bit is set in `mask`.
+    ///
+    /// The validity mask of the result is compacted alongside the values.
+    ///
+    /// # Errors
+    /// - `ShapeError` if `mask.len()` differs from `self.len()`
+    pub fn filter(&self, mask: &Bitmask) -> Result<Self, MinarrowError> {
+        if mask.len() != self.len() {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "Array::filter: mask length {} does not match array length {}",
+                    mask.len(),
+                    self.len()
+                ),
+            });
+        }
+        let indices: Vec<usize> = mask.iter_set().collect();
+        Ok(self.take(&indices))
+    }
+
     /// Arrow physical type for this array.
     pub fn arrow_type(&self) -> ArrowType {
         match self {
@@ -1671,6 +2277,7 @@ impl Array {
                 TemporalArray::Null => ArrowType::Null,
             },
             Array::BooleanArray(_) => ArrowType::Boolean,
+            Array::FixedSizeBinaryArray(arr) => arr.arrow_type(),
             Array::Null => ArrowType::Null,
         }
     }
@@ -1707,6 +2314,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1733,6 +2341,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1759,6 +2368,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1772,10 +2382,17 @@ impl Array {
             Array::TextArray(_) => false,
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
 
+    /// Returns true if this is a fixed-size binary array.
+    #[inline]
+    pub fn is_fixed_size_binary_array(&self) -> bool {
+        matches!(self, Array::FixedSizeBinaryArray(_))
+    }
+
     /// Returns true if this is an integer array.
     #[inline]
     pub fn is_integer_array(&self) -> bool {
@@ -1801,6 +2418,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1830,6 +2448,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1859,6 +2478,7 @@ impl Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1876,6 +2496,7 @@ impl Array {
             Array::NumericArray(_) => false,
             Array::TextArray(_) => false,
             Array::BooleanArray(_) => false,
+            Array::FixedSizeBinaryArray(_) => false,
             Array::Null => false,
         }
     }
@@ -1921,6 +2542,7 @@ impl Array {
                 TemporalArray::Datetime64(arr) => arr.null_mask.as_ref(),
                 TemporalArray::Null => None,
             },
+            Array::FixedSizeBinaryArray(arr) => arr.null_mask.as_ref(),
             Array::Null => None,
         }
     }
@@ -1938,6 +2560,7 @@ impl Array {
             Array::TextArray(inner) => inner.has_nulls(),
             #[cfg(feature = "datetime")]
             Array::TemporalArray(inner) => inner.has_nulls(),
+            Array::FixedSizeBinaryArray(arr) => arr.has_nulls(),
             Array::Null => false,
         }
     }
@@ -1953,9 +2576,170 @@ impl Array {
         crate::traits::print::value_to_string(self, idx)
     }
 
+    /// Sum of the non-null values. See `NumericArray::sum`.
+    ///
+    /// `Array::Null` yields `Scalar::Null`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-numeric arrays.
+    #[cfg(feature = "scalar_type")]
+    pub fn sum(&self) -> Result<crate::Scalar, MinarrowError> {
+        self.reduce_numeric(NumericArray::sum)
+    }
+
+    /// Mean of the non-null values as `Float64`. See `NumericArray::mean`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-numeric arrays.
+    #[cfg(feature = "scalar_type")]
+    pub fn mean(&self) -> Result<crate::Scalar, MinarrowError> {
+        self.reduce_numeric(NumericArray::mean)
+    }
+
+    /// Smallest non-null value. See `NumericArray::min`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-numeric arrays.
+    #[cfg(feature = "scalar_type")]
+    pub fn min(&self) -> Result<crate::Scalar, MinarrowError> {
+        self.reduce_numeric(NumericArray::min)
+    }
+
+    /// Largest non-null value. See `NumericArray::max`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-numeric arrays.
+    #[cfg(feature = "scalar_type")]
+    pub fn max(&self) -> Result<crate::Scalar, MinarrowError> {
+        self.reduce_numeric(NumericArray::max)
+    }
+
+    #[cfg(feature = "scalar_type")]
+    fn reduce_numeric(
+        &self,
+        f: fn(&NumericArray) -> crate::Scalar,
+    ) -> Result<crate::Scalar, MinarrowError> {
+        match self {
+            Array::NumericArray(arr) => Ok(f(arr)),
+            Array::Null => Ok(crate::Scalar::Null(ArrowType::Null)),
+            other => Err(MinarrowError::TypeError {
+                from: array_category_name(other),
+                to: "NumericArray",
+                message: Some("reductions require a numeric array".to_string()),
+            }),
+        }
+    }
+
+    /// Whether every non-null value is `true`. See `BooleanArray::all`.
+    ///
+    /// `Array::Null` yields `None`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-boolean arrays.
+    pub fn all(&self) -> Result<Option<bool>, MinarrowError> {
+        Ok(self.boolean_for_reduce()?.and_then(|b| b.all()))
+    }
+
+    /// Whether any non-null value is `true`. See `BooleanArray::any`.
+    ///
+    /// `Array::Null` yields `None`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-boolean arrays.
+    pub fn any(&self) -> Result<Option<bool>, MinarrowError> {
+        Ok(self.boolean_for_reduce()?.and_then(|b| b.any()))
+    }
+
+    /// Number of non-null `true` values. See `BooleanArray::count_true`.
+    ///
+    /// # Errors
+    /// - `TypeError` for non-boolean arrays.
+    pub fn count_true(&self) -> Result<u64, MinarrowError> {
+        Ok(self.boolean_for_reduce()?.map_or(0, |b| b.count_true()))
+    }
+
+    /// The boolean array to reduce, or `None` for `Array::Null`.
+    fn boolean_for_reduce(&self) -> Result<Option<&BooleanArray<()>>, MinarrowError> {
+        match self {
+            Array::BooleanArray(arr) => Ok(Some(arr.as_ref())),
+            Array::Null => Ok(None),
+            other => Err(MinarrowError::TypeError {
+                from: array_category_name(other),
+                to: "BooleanArray",
+                message: Some("boolean reductions require a boolean array".to_string()),
+            }),
+        }
+    }
+
+    /// Returns a copy with every null replaced by `value`, clearing the null mask.
+    ///
+    /// The scalar variant must match the array's physical type, e.g. `Scalar::Int64`
+    /// for an `Int64` array. `Scalar::Null` leaves the array unchanged. For floats
+    /// only masked nulls are filled; stored `NaN`s are valid values, see `FloatArray::fill_nan`.
+    ///
+    /// # Errors
+    /// - `TypeError` if `value` does not match the array type, or the array is
+    ///   categorical, fixed-size binary or `Array::Null`
+    #[cfg(feature = "scalar_type")]
+    pub fn fill_null(&self, value: crate::Scalar) -> Result<Array, MinarrowError> {
+        use crate::Scalar;
+        macro_rules! fill {
+            ($a:expr, $v:expr, $ctor:ident) => {
+                Array::$ctor($a.fill_null(*$v))
+            };
+        }
+        let filled = match (self, &value) {
+            (_, Scalar::Null(_)) => self.clone(),
+            (Array::NumericArray(num), v) => match (num, v) {
+                #[cfg(feature = "extended_numeric_types")]
+                (NumericArray::Int8(a), Scalar::Int8(v)) => fill!(a, v, from_int8),
+                #[cfg(feature = "extended_numeric_types")]
+                (NumericArray::Int16(a), Scalar::Int16(v)) => fill!(a, v, from_int16),
+                (NumericArray::Int32(a), Scalar::Int32(v)) => fill!(a, v, from_int32),
+                (NumericArray::Int64(a), Scalar::Int64(v)) => fill!(a, v, from_int64),
+                #[cfg(feature = "extended_numeric_types")]
+                (NumericArray::UInt8(a), Scalar::UInt8(v)) => fill!(a, v, from_uint8),
+                #[cfg(feature = "extended_numeric_types")]
+                (NumericArray::UInt16(a), Scalar::UInt16(v)) => fill!(a, v, from_uint16),
+                (NumericArray::UInt32(a), Scalar::UInt32(v)) => fill!(a, v, from_uint32),
+                (NumericArray::UInt64(a), Scalar::UInt64(v)) => fill!(a, v, from_uint64),
+                (NumericArray::Float32(a), Scalar::Float32(v)) => fill!(a, v, from_float32),
+                (NumericArray::Float64(a), Scalar::Float64(v)) => fill!(a, v, from_float64),
+                _ => return Err(fill_type_error(self, &value)),
+            },
+            (Array::BooleanArray(a), Scalar::Boolean(v)) => {
+                let mut data = a.data.clone();
+                if let Some(mask) = a.null_mask.as_ref() {
+                    for i in mask.iter_cleared() {
+                        data.set(i, *v);
+                    }
+                }
+                Array::BooleanArray(Arc::new(BooleanArray::new(data, None)))
+            }
+            (Array::TextArray(TextArray::String32(a)), Scalar::String32(v)) => {
+                Array::from_string32(fill_null_str(a, v))
+            }
+            #[cfg(feature = "large_string")]
+            (Array::TextArray(TextArray::String64(a)), Scalar::String64(v)) => {
+                Array::from_string64(fill_null_str(a, v))
+            }
+            #[cfg(feature = "datetime")]
+            (Array::TemporalArray(TemporalArray::Datetime32(a)), Scalar::Datetime32(v)) => {
+                Array::from_datetime_i32(fill_null_datetime(a, *v))
+            }
+            #[cfg(feature = "datetime")]
+            (Array::TemporalArray(TemporalArray::Datetime64(a)), Scalar::Datetime64(v)) => {
+                Array::from_datetime_i64(fill_null_datetime(a, *v))
+            }
+            _ => return Err(fill_type_error(self, &value)),
+        };
+        Ok(filled)
+    }
+
     /// Extract the element at `idx` as a `Scalar`, or `None` if out of bounds.
     ///
-    /// Returns `Scalar::Null` for null elements.
+    /// Returns a `Scalar::Null` of the array's type for null elements. Fixed-size
+    /// binary values have no `Scalar` counterpart and also return `None`.
     #[cfg(feature = "scalar_type")]
     pub fn get_scalar(&self, idx: usize) -> Option<crate::Scalar> {
         use crate::Scalar;
@@ -1964,7 +2748,7 @@ impl Array {
         }
         let is_null = self.null_mask().is_some_and(|m| !m.get(idx));
         if is_null {
-            return Some(Scalar::Null);
+            return Some(Scalar::Null(self.arrow_type()));
         }
         match self {
             Array::NumericArray(num) => match num {
@@ -1982,7 +2766,7 @@ impl Array {
                 NumericArray::UInt64(a) => Some(Scalar::UInt64(a.data[idx])),
                 NumericArray::Float32(a) => Some(Scalar::Float32(a.data[idx])),
                 NumericArray::Float64(a) => Some(Scalar::Float64(a.data[idx])),
-                NumericArray::Null => Some(Scalar::Null),
+                NumericArray::Null => Some(Scalar::Null(ArrowType::Null)),
             },
             Array::TextArray(text) => match text {
                 TextArray::String32(a) => Some(Scalar::String32(a.get_str(idx)?.to_owned())),
@@ -1996,16 +2780,17 @@ impl Array {
                 TextArray::Categorical16(a) => Some(Scalar::String32(a.get_str(idx)?.to_owned())),
                 #[cfg(feature = "extended_categorical")]
                 TextArray::Categorical64(a) => Some(Scalar::String32(a.get_str(idx)?.to_owned())),
-                TextArray::Null => Some(Scalar::Null),
+                TextArray::Null => Some(Scalar::Null(ArrowType::Null)),
             },
             Array::BooleanArray(a) => Some(Scalar::Boolean(a.get(idx)?)),
             #[cfg(feature = "datetime")]
             Array::TemporalArray(temp) => match temp {
                 crate::TemporalArray::Datetime32(a) => Some(Scalar::Datetime32(a.data[idx])),
                 crate::TemporalArray::Datetime64(a) => Some(Scalar::Datetime64(a.data[idx])),
-                crate::TemporalArray::Null => Some(Scalar::Null),
+                crate::TemporalArray::Null => Some(Scalar::Null(ArrowType::Null)),
             },
-            Array::Null => Some(Scalar::Null),
+            Array::FixedSizeBinaryArray(_) => None,
+            Array::Null => Some(Scalar::Null(ArrowType::Null)),
         }
     }
 
@@ -2109,6 +2894,17 @@ impl Array {
                 arr.null_mask = Some(mask);
                 Array::from_string32(arr)
             }
+            ArrowType::FixedSizeBinary(width) => {
+                let width = *width as usize;
+                let mut arr = FixedSizeBinaryArray::new(
+                    Vec64::from_slice(&vec![0u8; n_rows * width]),
+                    width,
+                    None,
+                );
+                arr.len = n_rows;
+                arr.null_mask = Some(mask);
+                Array::from_fixed_size_binary(arr)
+            }
         }
     }
 
@@ -2124,7 +2920,7 @@ impl Array {
         }
 
         // Find the first non-null to determine type
-        let template = scalars.iter().find(|s| !matches!(s, Scalar::Null));
+        let template = scalars.iter().find(|s| !matches!(s, Scalar::Null(_)));
         let Some(template) = template else {
             return Array::Null;
         };
@@ -2136,7 +2932,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Float64(v) => data.push(*v),
-                        Scalar::Null => { data.push(0.0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0.0); mask.set(i, false); }
                         _ => data.push(s.f64()),
                     }
                 }
@@ -2149,7 +2945,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Float32(v) => data.push(*v),
-                        Scalar::Null => { data.push(0.0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0.0); mask.set(i, false); }
                         _ => data.push(s.f64() as f32),
                     }
                 }
@@ -2162,7 +2958,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Int32(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as i32),
                     }
                 }
@@ -2175,7 +2971,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Int64(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as i64),
                     }
                 }
@@ -2188,7 +2984,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::UInt32(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as u32),
                     }
                 }
@@ -2201,7 +2997,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::UInt64(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as u64),
                     }
                 }
@@ -2214,7 +3010,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Boolean(v) => data.push(*v),
-                        Scalar::Null => { data.push(false); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(false); mask.set(i, false); }
                         _ => data.push(false),
                     }
                 }
@@ -2226,12 +3022,12 @@ impl Array {
                     Scalar::String32(v) => v.clone(),
                     #[cfg(feature = "large_string")]
                     Scalar::String64(v) => v.clone(),
-                    Scalar::Null => String::new(),
+                    Scalar::Null(_) => String::new(),
                     _ => String::new(),
                 }).collect();
                 let mut mask = Bitmask::new_set_all(scalars.len(), true);
                 for (i, s) in scalars.iter().enumerate() {
-                    if matches!(s, Scalar::Null) { mask.set(i, false); }
+                    if matches!(s, Scalar::Null(_)) { mask.set(i, false); }
                 }
                 let refs: Vec<&str> = strs.iter().map(|s| s.as_str()).collect();
                 let mut arr = StringArray::<u32>::from_slice(&refs);
@@ -2243,12 +3039,12 @@ impl Array {
             Scalar::String64(_) => {
                 let strs: Vec<String> = scalars.iter().map(|s| match s {
                     Scalar::String64(v) | Scalar::String32(v) => v.clone(),
-                    Scalar::Null => String::new(),
+                    Scalar::Null(_) => String::new(),
                     _ => String::new(),
                 }).collect();
                 let mut mask = Bitmask::new_set_all(scalars.len(), true);
                 for (i, s) in scalars.iter().enumerate() {
-                    if matches!(s, Scalar::Null) { mask.set(i, false); }
+                    if matches!(s, Scalar::Null(_)) { mask.set(i, false); }
                 }
                 let refs: Vec<&str> = strs.iter().map(|s| s.as_str()).collect();
                 let mut arr = StringArray::<u64>::from_slice(&refs);
@@ -2263,7 +3059,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Datetime32(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(0),
                     }
                 }
@@ -2279,7 +3075,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Datetime64(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(0),
                     }
                 }
@@ -2297,7 +3093,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Int8(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as i8),
                     }
                 }
@@ -2311,7 +3107,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::Int16(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as i16),
                     }
                 }
@@ -2325,7 +3121,7 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::UInt8(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as u8),
                     }
                 }
@@ -2339,14 +3135,14 @@ impl Array {
                 for (i, s) in scalars.iter().enumerate() {
                     match s {
                         Scalar::UInt16(v) => data.push(*v),
-                        Scalar::Null => { data.push(0); mask.set(i, false); }
+                        Scalar::Null(_) => { data.push(0); mask.set(i, false); }
                         _ => data.push(s.f64() as u16),
                     }
                 }
                 let has_nulls = mask.count_zeros() > 0;
                 Array::from_uint16(IntegerArray::new(crate::Buffer::from_vec64(data), if has_nulls { Some(mask) } else { None }))
             }
-            Scalar::Null => Array::Null,
+            Scalar::Null(_) => Array::Null,
         }
     }
 
@@ -2407,6 +3203,7 @@ impl Array {
                 TemporalArray::Datetime64(a) => a.data[i].cmp(&a.data[j]),
                 TemporalArray::Null => Ordering::Equal,
             },
+            Array::FixedSizeBinaryArray(a) => a.value(i).cmp(&a.value(j)),
             Array::Null => Ordering::Equal,
         }
     }
@@ -2467,10 +3264,41 @@ impl Array {
                 TemporalArray::Datetime64(a) => a.data[idx].hash(state),
                 TemporalArray::Null => 0xDEAD_BEEF_u64.hash(state),
             },
+            Array::FixedSizeBinaryArray(a) => a.value(idx).hash(state),
             Array::Null => 0xDEAD_BEEF_u64.hash(state),
         }
     }
 
+    /// Hash every element into a `u64`, seeding each hasher with `seed`.
+    ///
+    /// Each slot is `hash_element_at` run through a fresh hasher, so nulls
+    /// share one hash and string and categorical columns holding the same
+    /// text hash alike. Equal seeds give equal hashes for equal values across
+    /// arrays, which is what join and group-by keys need. For multi-column
+    /// keys, combine the per-column results.
+    ///
+    /// Uses AHash with `fast_hash`, otherwise the standard library hasher.
+    /// Both are deterministic for a given seed, but differ from each other.
+    #[cfg(feature = "hash")]
+    pub fn hash_all(&self, seed: u64) -> Vec64<u64> {
+        use std::hash::{BuildHasher, Hasher};
+
+        #[cfg(feature = "fast_hash")]
+        let build = ahash::RandomState::with_seeds(seed, 0, 0, 0);
+        #[cfg(not(feature = "fast_hash"))]
+        let build = std::hash::BuildHasherDefault::<std::hash::DefaultHasher>::default();
+
+        (0..self.len())
+            .map(|idx| {
+                let mut state = build.build_hasher();
+                #[cfg(not(feature = "fast_hash"))]
+                state.write_u64(seed);
+                self.hash_element_at(idx, &mut state);
+                state.finish()
+            })
+            .collect()
+    }
+
     /// Set null mask on Array by matching on variants
     pub fn set_null_mask(&mut self, mask: Bitmask) {
         match self {
@@ -2552,14 +3380,56 @@ impl Array {
             Array::BooleanArray(arr) => {
                 Arc::make_mut(arr).set_null_mask(Some(mask));
             }
+            Array::FixedSizeBinaryArray(arr) => {
+                Arc::make_mut(arr).set_null_mask(Some(mask));
+            }
             Array::Null => {}
         }
     }
 
-    /// Returns a pointer to the backing data (contiguous bytes), length in elements, and element size.
+    /// Returns the raw value bytes for fixed-width arrays, without copying.
     ///
-    /// This is not logical length - it is total raw bytes in the buffer, so for non-fixed width
-    /// types such as bit-packed booleans or strings, please factor this in accordingly.
+    /// Covers numeric, datetime and fixed-size binary arrays, where the slice holds
+    /// `len * element size` bytes in native endianness, and boolean arrays, where it
+    /// holds the `ceil(len / 8)` bit-packed bytes. The validity mask is not included,
+    /// so null slots contribute whatever placeholder value they hold.
+    ///
+    /// Returns `None` for strings, categoricals and `Null`.
+    pub fn values_as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Array::NumericArray(inner) => match inner {
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int8(a) => Some(values_bytes(a.data.as_slice())),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int16(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::Int32(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::Int64(a) => Some(values_bytes(a.data.as_slice())),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt8(a) => Some(values_bytes(a.data.as_slice())),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt16(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::UInt32(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::UInt64(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::Float32(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::Float64(a) => Some(values_bytes(a.data.as_slice())),
+                NumericArray::Null => None,
+            },
+            #[cfg(feature = "datetime")]
+            Array::TemporalArray(inner) => match inner {
+                TemporalArray::Datetime32(a) => Some(values_bytes(a.data.as_slice())),
+                TemporalArray::Datetime64(a) => Some(values_bytes(a.data.as_slice())),
+                TemporalArray::Null => None,
+            },
+            Array::BooleanArray(a) => Some(a.data.to_bytes().0),
+            Array::FixedSizeBinaryArray(a) => Some(&a.data[..a.len * a.width]),
+            Array::TextArray(_) | Array::Null => None,
+        }
+    }
+
+    /// Returns a pointer to the backing data (contiguous bytes), length in elements, and element size.
+    ///
+    /// This is not logical length - it is total raw bytes in the buffer, so for non-fixed width
+    /// types such as bit-packed booleans or strings, please factor this in accordingly.
     pub fn data_ptr_and_byte_len(&self) -> (*const u8, usize, usize) {
         match self {
             Array::NumericArray(inner) => match inner {
@@ -2664,6 +3534,7 @@ impl Array {
                 ),
                 TemporalArray::Null => (std::ptr::null(), 0, 0),
             },
+            Array::FixedSizeBinaryArray(a) => (a.data.as_ptr(), a.len(), a.width),
             Array::Null => (std::ptr::null(), 0, 0),
         }
     }
@@ -2725,6 +3596,9 @@ impl Array {
                 }
                 TemporalArray::Null => None,
             },
+            Array::FixedSizeBinaryArray(a) => {
+                a.null_mask.as_ref().map(|m| (m.as_ptr(), m.capacity()))
+            }
             Array::Null => None,
         }
     }
@@ -2789,10 +3663,35 @@ impl Array {
                 TemporalArray::Datetime64(a) => a.null_count(),
                 TemporalArray::Null => 0,
             },
+            Array::FixedSizeBinaryArray(a) => a.null_count(),
             Array::Null => 0,
         }
     }
 
+    /// Returns a boolean array with a set bit at every null position.
+    ///
+    /// The result has no null mask of its own. An array without a null mask
+    /// yields all `false`.
+    pub fn is_null(&self) -> BooleanArray<()> {
+        let data = match self.null_mask() {
+            Some(mask) => mask.invert(),
+            None => Bitmask::new_set_all(self.len(), false),
+        };
+        BooleanArray::new(data, None)
+    }
+
+    /// Returns a boolean array with a set bit at every non-null position.
+    ///
+    /// The result has no null mask of its own. An array without a null mask
+    /// yields all `true`.
+    pub fn is_valid(&self) -> BooleanArray<()> {
+        let data = match self.null_mask() {
+            Some(mask) => mask.clone(),
+            None => Bitmask::new_set_all(self.len(), true),
+        };
+        BooleanArray::new(data, None)
+    }
+
     /// Appends all values (and null mask if present) from `other` into `self`.
     ///
     /// Panics if the two arrays are of different variants or incompatible types.
@@ -2809,6 +3708,9 @@ impl Array {
             (Array::TextArray(lhs), Array::TextArray(rhs)) => lhs.append_array(rhs),
             #[cfg(feature = "datetime")]
             (Array::TemporalArray(lhs), Array::TemporalArray(rhs)) => lhs.append_array(rhs),
+            (Array::FixedSizeBinaryArray(a), Array::FixedSizeBinaryArray(b)) => {
+                Arc::make_mut(a).append_array(b)
+            }
             (Array::Null, Array::Null) => (),
             (lhs, rhs) => panic!(
                 "Cannot append {:?} into {:?}",
@@ -2827,6 +3729,7 @@ impl Array {
             (Array::TextArray(lhs), Array::TextArray(rhs)) => lhs.append_range(rhs, offset, len),
             #[cfg(feature = "datetime")]
             (Array::TemporalArray(lhs), Array::TemporalArray(rhs)) => lhs.append_range(rhs, offset, len),
+            (Array::FixedSizeBinaryArray(a), Array::FixedSizeBinaryArray(b)) => Arc::make_mut(a).append_range(b, offset, len),
             (Array::Null, Array::Null) => Ok(()),
             (lhs, rhs) => Err(MinarrowError::TypeError {
                 from: "Array",
@@ -2840,6 +3743,184 @@ impl Array {
         }
     }
 
+    /// Concatenates many arrays of the same type in one pass.
+    ///
+    /// Folding `Concatenate::concat` over N arrays reallocates at every step.
+    /// This sizes the output once, then copies each input into a single aligned
+    /// data buffer and a single null mask. String offsets are rebased as they are
+    /// copied, and categorical dictionaries are merged up front in first-seen order,
+    /// so the result matches the pairwise fold.
+    ///
+    /// A single input is returned as a cheap `Arc` clone, and an empty slice
+    /// yields `Array::Null`.
+    ///
+    /// # Errors
+    /// - `IncompatibleTypeError` if the arrays differ in type, or datetime arrays
+    ///   differ in time unit.
+    /// - `Overflow` if the combined string bytes or dictionary do not fit the
+    ///   offset or code type.
+    pub fn concat_many(arrays: &[Array]) -> Result<Array, MinarrowError> {
+        let Some(first) = arrays.first() else {
+            return Ok(Array::Null);
+        };
+        let dtype = first.arrow_type();
+        for (idx, arr) in arrays.iter().enumerate().skip(1) {
+            if array_category_name(arr) != array_category_name(first) || arr.arrow_type() != dtype {
+                return Err(MinarrowError::IncompatibleTypeError {
+                    from: "Array",
+                    to: "Array",
+                    message: Some(format!(
+                        "concat_many: array {} is {:?} but array 0 is {:?}",
+                        idx,
+                        arr.arrow_type(),
+                        dtype
+                    )),
+                });
+            }
+        }
+        if arrays.len() == 1 {
+            return Ok(first.clone());
+        }
+
+        let total: usize = arrays.iter().map(|a| a.len()).sum();
+        let null_mask = if arrays.iter().any(|a| a.null_mask().is_some()) {
+            Some(concat_bitmasks(
+                arrays.iter().map(|a| (a.null_mask(), a.len())),
+                total,
+            ))
+        } else {
+            None
+        };
+
+        // Inner arrays of one variant, already checked to match `first`.
+        macro_rules! parts {
+            ($outer:ident :: $variant:ident) => {
+                arrays
+                    .iter()
+                    .map(|a| match a {
+                        Array::$outer($outer::$variant(inner)) => inner.as_ref(),
+                        _ => unreachable!("concat_many: variant checked above"),
+                    })
+                    .collect::<Vec<_>>()
+            };
+        }
+        macro_rules! numeric {
+            ($variant:ident, $wrapper:ident) => {{
+                let parts = parts!(NumericArray::$variant);
+                let data = concat_slices(parts.iter().map(|p| p.data.as_slice()), total);
+                Array::NumericArray(NumericArray::$variant(Arc::new($wrapper::new(
+                    data, null_mask,
+                ))))
+            }};
+        }
+        macro_rules! categorical {
+            ($variant:ident) => {{
+                let parts = parts!(TextArray::$variant);
+                let merged = concat_categoricals(&parts, total, null_mask)?;
+                Array::TextArray(TextArray::$variant(Arc::new(merged)))
+            }};
+        }
+        #[cfg(feature = "datetime")]
+        macro_rules! temporal {
+            ($variant:ident) => {{
+                let parts = parts!(TemporalArray::$variant);
+                let unit = parts[0].time_unit;
+                if let Some(p) = parts.iter().find(|p| p.time_unit != unit) {
+                    return Err(MinarrowError::IncompatibleTypeError {
+                        from: "DatetimeArray",
+                        to: "DatetimeArray",
+                        message: Some(format!(
+                            "concat_many: cannot concatenate time units {:?} and {:?}",
+                            unit, p.time_unit
+                        )),
+                    });
+                }
+                let data = concat_slices(parts.iter().map(|p| p.data.as_slice()), total);
+                Array::TemporalArray(TemporalArray::$variant(Arc::new(DatetimeArray::new(
+                    data,
+                    null_mask,
+                    Some(unit),
+                ))))
+            }};
+        }
+
+        Ok(match first {
+            Array::NumericArray(num) => match num {
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int8(_) => numeric!(Int8, IntegerArray),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::Int16(_) => numeric!(Int16, IntegerArray),
+                NumericArray::Int32(_) => numeric!(Int32, IntegerArray),
+                NumericArray::Int64(_) => numeric!(Int64, IntegerArray),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt8(_) => numeric!(UInt8, IntegerArray),
+                #[cfg(feature = "extended_numeric_types")]
+                NumericArray::UInt16(_) => numeric!(UInt16, IntegerArray),
+                NumericArray::UInt32(_) => numeric!(UInt32, IntegerArray),
+                NumericArray::UInt64(_) => numeric!(UInt64, IntegerArray),
+                NumericArray::Float32(_) => numeric!(Float32, FloatArray),
+                NumericArray::Float64(_) => numeric!(Float64, FloatArray),
+                NumericArray::Null => first.clone(),
+            },
+            Array::TextArray(text) => match text {
+                TextArray::String32(_) => {
+                    let parts = parts!(TextArray::String32);
+                    let merged = concat_strings(&parts, total, null_mask)?;
+                    Array::TextArray(TextArray::String32(Arc::new(merged)))
+                }
+                #[cfg(feature = "large_string")]
+                TextArray::String64(_) => {
+                    let parts = parts!(TextArray::String64);
+                    let merged = concat_strings(&parts, total, null_mask)?;
+                    Array::TextArray(TextArray::String64(Arc::new(merged)))
+                }
+                #[cfg(feature = "default_categorical_8")]
+                TextArray::Categorical8(_) => categorical!(Categorical8),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical16(_) => categorical!(Categorical16),
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                TextArray::Categorical32(_) => categorical!(Categorical32),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical64(_) => categorical!(Categorical64),
+                TextArray::Null => first.clone(),
+            },
+            #[cfg(feature = "datetime")]
+            Array::TemporalArray(temporal) => match temporal {
+                TemporalArray::Datetime32(_) => temporal!(Datetime32),
+                TemporalArray::Datetime64(_) => temporal!(Datetime64),
+                TemporalArray::Null => first.clone(),
+            },
+            Array::BooleanArray(_) => {
+                let parts: Vec<&BooleanArray<()>> = arrays
+                    .iter()
+                    .map(|a| match a {
+                        Array::BooleanArray(inner) => inner.as_ref(),
+                        _ => unreachable!("concat_many: variant checked above"),
+                    })
+                    .collect();
+                let data = concat_bitmasks(parts.iter().map(|p| (Some(&p.data), p.len)), total);
+                Array::BooleanArray(Arc::new(BooleanArray::new(data, null_mask)))
+            }
+            Array::FixedSizeBinaryArray(fsb) => {
+                let width = fsb.width;
+                let data = concat_slices(
+                    arrays.iter().map(|a| match a {
+                        Array::FixedSizeBinaryArray(inner) => inner.data.as_slice(),
+                        _ => unreachable!("concat_many: variant checked above"),
+                    }),
+                    total * width,
+                );
+                Array::FixedSizeBinaryArray(Arc::new(FixedSizeBinaryArray::new(
+                    data, width, null_mask,
+                )))
+            }
+            Array::Null => Array::Null,
+        })
+    }
+
     /// Inserts all values (and null mask if present) from `other` into `self` at the specified index.
     ///
     /// This is an **O(n)** operation.
@@ -2855,6 +3936,9 @@ impl Array {
             (Array::TextArray(lhs), Array::TextArray(rhs)) => lhs.insert_rows(index, rhs),
             #[cfg(feature = "datetime")]
             (Array::TemporalArray(lhs), Array::TemporalArray(rhs)) => lhs.insert_rows(index, rhs),
+            (Array::FixedSizeBinaryArray(a), Array::FixedSizeBinaryArray(b)) => {
+                Arc::make_mut(a).insert_rows(index, b)
+            }
             (Array::Null, Array::Null) => Ok(()),
             (lhs, rhs) => Err(MinarrowError::TypeError {
                 from: "Array",
@@ -2905,6 +3989,13 @@ impl Array {
                     FieldArray::new((**field).clone(), Array::TemporalArray(right)),
                 ]))
             }
+            Array::FixedSizeBinaryArray(arr) => {
+                let (left, right) = arr.split(index)?;
+                Ok(SuperArray::from_field_array_chunks(vec![
+                    FieldArray::new((**field).clone(), Array::FixedSizeBinaryArray(left)),
+                    FieldArray::new((**field).clone(), Array::FixedSizeBinaryArray(right)),
+                ]))
+            }
             Array::Null => Err(MinarrowError::IndexError(
                 "Cannot split Null array".to_string(),
             )),
@@ -2948,6 +4039,101 @@ impl Array {
         arrow::array::make_array(array_data)
     }
 
+    /// Import a borrowed arrow-rs array via Arrow C FFI.
+    ///
+    /// The inverse of `to_apache_arrow`. Buffers are shared with the arrow-rs
+    /// array rather than copied where the layout allows, and are released when
+    /// the last Minarrow reference drops. Use `try_from_arrow_with_field` to
+    /// also keep the logical type, e.g. a timestamp's timezone.
+    #[cfg(feature = "cast_arrow")]
+    #[inline]
+    pub fn try_from_arrow(array: &dyn arrow::array::Array) -> Result<Self, MinarrowError> {
+        Self::try_from_arrow_with_field(array).map(|(array, _)| array)
+    }
+
+    /// Import a borrowed arrow-rs array via Arrow C FFI, returning it with the
+    /// `Field` parsed from its exported schema.
+    ///
+    /// The field carries the exact Arrow type, including dictionary index
+    /// widths and timestamp timezones. Its name is empty, as arrow-rs arrays
+    /// are unnamed.
+    ///
+    /// # Errors
+    /// - `TypeError` if the array's type has no Minarrow equivalent, or the
+    ///   arrow-rs export fails.
+    /// - `NotImplemented` for Arrow `Null` and `Interval` arrays.
+    #[cfg(feature = "cast_arrow")]
+    pub fn try_from_arrow_with_field(
+        array: &dyn arrow::array::Array,
+    ) -> Result<(Self, Field), MinarrowError> {
+        use crate::ffi::arrow_c_ffi::{ArrowArray, ArrowSchema, import_from_c_owned};
+
+        // Import reads buffers from their start and expects string offsets to
+        // begin at zero, so a sliced array is compacted first
+        let mut data = array.to_data();
+        let sliced_offsets = match data.data_type() {
+            arrow::datatypes::DataType::Utf8 => data.buffer::<i32>(0).first() != Some(&0),
+            arrow::datatypes::DataType::LargeUtf8 => data.buffer::<i64>(0).first() != Some(&0),
+            _ => false,
+        };
+        if data.offset() != 0 || sliced_offsets {
+            let mut compact = arrow::array::MutableArrayData::new(vec![&data], false, data.len());
+            compact.extend(0, 0, data.len());
+            data = compact.freeze();
+        }
+        let (ffi_arr, ffi_sch) =
+            arrow::array::ffi::to_ffi(&data).map_err(|e| MinarrowError::TypeError {
+                from: "arrow-rs Array",
+                to: "Array",
+                message: Some(e.to_string()),
+            })?;
+
+        // Validate up front, as the importer panics on unsupported layouts
+        let dtype = match ffi_sch.dictionary() {
+            Some(values) => {
+                let dtype = ArrowType::from_dictionary_format_string(ffi_sch.format())?;
+                let values_type = ArrowType::from_format_string(values.format())?;
+                #[cfg(feature = "large_string")]
+                let text = matches!(values_type, ArrowType::String | ArrowType::LargeString);
+                #[cfg(not(feature = "large_string"))]
+                let text = matches!(values_type, ArrowType::String);
+                if !text {
+                    return Err(MinarrowError::TypeError {
+                        from: "arrow-rs Array",
+                        to: "Array",
+                        message: Some(format!(
+                            "dictionary values must be strings, found {}",
+                            values_type
+                        )),
+                    });
+                }
+                dtype
+            }
+            None => ArrowType::from_format_string(ffi_sch.format())?,
+        };
+        #[cfg(feature = "datetime")]
+        let unsupported = matches!(dtype, ArrowType::Null | ArrowType::Interval(_));
+        #[cfg(not(feature = "datetime"))]
+        let unsupported = matches!(dtype, ArrowType::Null);
+        if unsupported {
+            return Err(MinarrowError::NotImplemented {
+                feature: format!("Array::try_from_arrow for {}", dtype),
+            });
+        }
+
+        // Both FFI structs match the C Data Interface layout. The array's release
+        // callback moves into the import, while `ffi_sch` stays owned here and
+        // releases itself once the import has read it.
+        let ffi_arr = std::mem::ManuallyDrop::new(ffi_arr);
+        let arr_box =
+            Box::new(unsafe { std::ptr::read(&*ffi_arr as *const _ as *const ArrowArray) });
+        let sch_box =
+            Box::new(unsafe { std::ptr::read(&ffi_sch as *const _ as *const ArrowSchema) });
+        let (array, field) = unsafe { import_from_c_owned(arr_box, sch_box) };
+        drop(ffi_sch);
+        Ok((Arc::unwrap_or_clone(array), field))
+    }
+
     // ** The below 2 polars functions are tested under tests/polars.rs **
 
     /// Build a Polars Series using a derived Field (dtype/nullability from the array).
@@ -3068,6 +4254,9 @@ impl Array {
             crate::ffi::arrow_dtype::ArrowType::Utf8View => {
                 polars_arrow::datatypes::ArrowDataType::Utf8
             }
+            crate::ffi::arrow_dtype::ArrowType::FixedSizeBinary(width) => {
+                polars_arrow::datatypes::ArrowDataType::FixedSizeBinary(*width as usize)
+            }
 
             #[cfg(feature = "datetime")]
             crate::ffi::arrow_dtype::ArrowType::Date32 => {
@@ -3182,6 +4371,386 @@ impl Array {
     }
 }
 
+/// Describes a numeric type as `(is_float, is_signed, bits)`.
+fn numeric_kind(t: &ArrowType) -> Option<(bool, bool, u32)> {
+    Some(match t {
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => (false, true, 8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => (false, true, 16),
+        ArrowType::Int32 => (false, true, 32),
+        ArrowType::Int64 => (false, true, 64),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => (false, false, 8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => (false, false, 16),
+        ArrowType::UInt32 => (false, false, 32),
+        ArrowType::UInt64 => (false, false, 64),
+        ArrowType::Float32 => (true, true, 32),
+        ArrowType::Float64 => (true, true, 64),
+        _ => return None,
+    })
+}
+
+/// Common numeric supertype of `a` and `b`, per [`Array::concat_promoting`].
+pub(crate) fn promote_numeric(a: &ArrowType, b: &ArrowType) -> Option<ArrowType> {
+    let (a_float, a_signed, a_bits) = numeric_kind(a)?;
+    let (b_float, b_signed, b_bits) = numeric_kind(b)?;
+    if a_float || b_float {
+        let small = |float: bool, bits: u32| if float { bits == 32 } else { bits <= 16 };
+        return Some(if small(a_float, a_bits) && small(b_float, b_bits) {
+            ArrowType::Float32
+        } else {
+            ArrowType::Float64
+        });
+    }
+    let (signed, bits) = if a_signed == b_signed {
+        (a_signed, a_bits.max(b_bits))
+    } else {
+        let (s_bits, u_bits) = if a_signed {
+            (a_bits, b_bits)
+        } else {
+            (b_bits, a_bits)
+        };
+        (true, s_bits.max(u_bits * 2))
+    };
+    Some(match (signed, bits) {
+        #[cfg(feature = "extended_numeric_types")]
+        (true, 8) => ArrowType::Int8,
+        #[cfg(feature = "extended_numeric_types")]
+        (true, 16) => ArrowType::Int16,
+        (true, 32) => ArrowType::Int32,
+        (true, 64) => ArrowType::Int64,
+        #[cfg(feature = "extended_numeric_types")]
+        (false, 8) => ArrowType::UInt8,
+        #[cfg(feature = "extended_numeric_types")]
+        (false, 16) => ArrowType::UInt16,
+        (false, 32) => ArrowType::UInt32,
+        (false, 64) => ArrowType::UInt64,
+        _ => ArrowType::Float64,
+    })
+}
+
+/// Converts a numeric buffer into a `NumericArray` of the `target` type.
+fn numeric_to<S: Numeric + Display>(
+    data: &[S],
+    null_mask: Option<&Bitmask>,
+    target: &ArrowType,
+) -> Result<NumericArray, MinarrowError> {
+    macro_rules! convert {
+        ($variant:ident, $arr:ident, $t:ty, $name:literal) => {
+            NumericArray::$variant(Arc::new($arr::new(
+                cast_numeric_values::<S, $t>(data, null_mask, $name)?,
+                null_mask.cloned(),
+            )))
+        };
+    }
+    Ok(match target {
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => convert!(Int8, IntegerArray, i8, "i8"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => convert!(Int16, IntegerArray, i16, "i16"),
+        ArrowType::Int32 => convert!(Int32, IntegerArray, i32, "i32"),
+        ArrowType::Int64 => convert!(Int64, IntegerArray, i64, "i64"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => convert!(UInt8, IntegerArray, u8, "u8"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => convert!(UInt16, IntegerArray, u16, "u16"),
+        ArrowType::UInt32 => convert!(UInt32, IntegerArray, u32, "u32"),
+        ArrowType::UInt64 => convert!(UInt64, IntegerArray, u64, "u64"),
+        ArrowType::Float32 => convert!(Float32, FloatArray, f32, "f32"),
+        ArrowType::Float64 => convert!(Float64, FloatArray, f64, "f64"),
+        other => {
+            return Err(MinarrowError::TypeError {
+                from: "NumericArray",
+                to: "NumericArray",
+                message: Some(format!("{} is not a numeric type", other)),
+            });
+        }
+    })
+}
+
+/// Converts each valid value into `D`, rejecting any value that does not
+/// round-trip exactly. Null slots are filled with the default value.
+fn cast_numeric_values<S: Numeric + Display, D: Numeric>(
+    data: &[S],
+    null_mask: Option<&Bitmask>,
+    target: &'static str,
+) -> Result<Vec64<D>, MinarrowError> {
+    let mut out = Vec64::with_capacity(data.len());
+    for (i, &v) in data.iter().enumerate() {
+        if null_mask.is_some_and(|m| !m.get(i)) {
+            out.push(D::default());
+            continue;
+        }
+        let Some(d) = <D as NumCast>::from(v) else {
+            return Err(MinarrowError::Overflow {
+                value: v.to_string(),
+                target,
+            });
+        };
+        // NaN never equals itself, so it is accepted once the target holds it.
+        let is_nan = v.to_f64().is_some_and(f64::is_nan);
+        if !is_nan && <S as NumCast>::from(d) != Some(v) {
+            return Err(MinarrowError::LossyCast {
+                value: v.to_string(),
+                target,
+            });
+        }
+        out.push(d);
+    }
+    Ok(out)
+}
+
+/// Converts a numeric buffer into a `NumericArray` of the `target` type for
+/// [`Array::cast_with`], applying `on_overflow` to values that do not fit.
+fn cast_numeric_to<S: Numeric + Display>(
+    data: &[S],
+    null_mask: Option<&Bitmask>,
+    target: &ArrowType,
+    on_overflow: CastOverflow,
+) -> Result<NumericArray, MinarrowError> {
+    macro_rules! convert {
+        ($variant:ident, $arr:ident, $t:ty, $name:literal) => {{
+            let (values, mask) = cast_values_with::<S, $t>(data, null_mask, $name, on_overflow)?;
+            NumericArray::$variant(Arc::new($arr::new(values, mask)))
+        }};
+    }
+    Ok(match target {
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => convert!(Int8, IntegerArray, i8, "i8"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => convert!(Int16, IntegerArray, i16, "i16"),
+        ArrowType::Int32 => convert!(Int32, IntegerArray, i32, "i32"),
+        ArrowType::Int64 => convert!(Int64, IntegerArray, i64, "i64"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => convert!(UInt8, IntegerArray, u8, "u8"),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => convert!(UInt16, IntegerArray, u16, "u16"),
+        ArrowType::UInt32 => convert!(UInt32, IntegerArray, u32, "u32"),
+        ArrowType::UInt64 => convert!(UInt64, IntegerArray, u64, "u64"),
+        ArrowType::Float32 => convert!(Float32, FloatArray, f32, "f32"),
+        ArrowType::Float64 => convert!(Float64, FloatArray, f64, "f64"),
+        other => {
+            return Err(MinarrowError::TypeError {
+                from: "NumericArray",
+                to: "NumericArray",
+                message: Some(format!("{} is not a numeric type", other)),
+            });
+        }
+    })
+}
+
+/// Converts each valid value into `D` via `NumCast`, which truncates floats
+/// toward zero. Out-of-range values either fail or are nulled per `on_overflow`.
+fn cast_values_with<S: Numeric + Display, D: Numeric>(
+    data: &[S],
+    null_mask: Option<&Bitmask>,
+    target: &'static str,
+    on_overflow: CastOverflow,
+) -> Result<(Vec64<D>, Option<Bitmask>), MinarrowError> {
+    let mut out = Vec64::with_capacity(data.len());
+    let mut mask = null_mask.cloned();
+    for (i, &v) in data.iter().enumerate() {
+        if null_mask.is_some_and(|m| !m.get(i)) {
+            out.push(D::default());
+            continue;
+        }
+        match <D as NumCast>::from(v) {
+            Some(d) => out.push(d),
+            None => match on_overflow {
+                CastOverflow::Error => {
+                    return Err(MinarrowError::Overflow {
+                        value: v.to_string(),
+                        target,
+                    });
+                }
+                CastOverflow::Null => {
+                    out.push(D::default());
+                    mask.get_or_insert_with(|| Bitmask::new_set_all(data.len(), true))
+                        .set(i, false);
+                }
+            },
+        }
+    }
+    Ok((out, mask))
+}
+
+/// Parses every text value as the numeric `target` type, nulling failures.
+///
+/// `target` must already be known to be numeric.
+fn parse_text_to(text: &TextArray, target: &ArrowType) -> NumericArray {
+    macro_rules! convert {
+        ($variant:ident, $arr:ident, $t:ty) => {{
+            let (values, mask) = parse_text_values::<$t>(text);
+            NumericArray::$variant(Arc::new($arr::new(values, Some(mask))))
+        }};
+    }
+    match target {
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => convert!(Int8, IntegerArray, i8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => convert!(Int16, IntegerArray, i16),
+        ArrowType::Int32 => convert!(Int32, IntegerArray, i32),
+        ArrowType::Int64 => convert!(Int64, IntegerArray, i64),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => convert!(UInt8, IntegerArray, u8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => convert!(UInt16, IntegerArray, u16),
+        ArrowType::UInt32 => convert!(UInt32, IntegerArray, u32),
+        ArrowType::UInt64 => convert!(UInt64, IntegerArray, u64),
+        ArrowType::Float32 => convert!(Float32, FloatArray, f32),
+        ArrowType::Float64 => convert!(Float64, FloatArray, f64),
+        _ => unreachable!("parse_text_to called with non-numeric target"),
+    }
+}
+
+/// Parses each trimmed string of `text` as `D`. Nulls and parse failures are
+/// marked invalid in the returned mask.
+fn parse_text_values<D: Numeric + FromStr>(text: &TextArray) -> (Vec64<D>, Bitmask) {
+    fn parse_all<'a, D: Numeric + FromStr>(
+        len: usize,
+        get: impl Fn(usize) -> Option<&'a str>,
+    ) -> (Vec64<D>, Bitmask) {
+        let mut out = Vec64::with_capacity(len);
+        let mut mask = Bitmask::new_set_all(len, true);
+        for i in 0..len {
+            match get(i).and_then(|s| s.trim().parse::<D>().ok()) {
+                Some(v) => out.push(v),
+                None => {
+                    out.push(D::default());
+                    mask.set(i, false);
+                }
+            }
+        }
+        (out, mask)
+    }
+    match text {
+        TextArray::String32(a) => parse_all(a.len(), |i| a.get_str(i)),
+        #[cfg(feature = "large_string")]
+        TextArray::String64(a) => parse_all(a.len(), |i| a.get_str(i)),
+        #[cfg(feature = "default_categorical_8")]
+        TextArray::Categorical8(a) => parse_all(a.len(), |i| a.get_str(i)),
+        #[cfg(feature = "extended_categorical")]
+        TextArray::Categorical16(a) => parse_all(a.len(), |i| a.get_str(i)),
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        TextArray::Categorical32(a) => parse_all(a.len(), |i| a.get_str(i)),
+        #[cfg(feature = "extended_categorical")]
+        TextArray::Categorical64(a) => parse_all(a.len(), |i| a.get_str(i)),
+        TextArray::Null => (Vec64::new(), Bitmask::new_set_all(0, true)),
+    }
+}
+
+/// Wraps `arr` as the `String` or `LargeString` array named by `target`.
+fn string_array_as(arr: StringArray<u32>, target: &ArrowType) -> Array {
+    match target {
+        #[cfg(feature = "large_string")]
+        ArrowType::LargeString => Array::from_string64(StringArray::<u64>::from(&arr)),
+        _ => Array::from_string32(arr),
+    }
+}
+
+/// Element-wise text transforms dispatched by `Array::transform_text`.
+#[derive(Clone, Copy)]
+enum TextTransform {
+    Upper,
+    Lower,
+    Trim,
+    TrimStart,
+    TrimEnd,
+}
+
+impl TextTransform {
+    fn name(self) -> &'static str {
+        match self {
+            TextTransform::Upper => "to_uppercase",
+            TextTransform::Lower => "to_lowercase",
+            TextTransform::Trim => "trim",
+            TextTransform::TrimStart => "trim_start",
+            TextTransform::TrimEnd => "trim_end",
+        }
+    }
+
+    fn apply_str<T: Integer>(self, arr: &StringArray<T>) -> Result<StringArray<T>, KernelError> {
+        use crate::kernels::string::{
+            ltrim_str, rtrim_str, to_lowercase_str, to_uppercase_str, trim_str,
+        };
+        let window = (arr, 0, arr.len());
+        match self {
+            TextTransform::Upper => to_uppercase_str(window),
+            TextTransform::Lower => to_lowercase_str(window),
+            TextTransform::Trim => trim_str(window),
+            TextTransform::TrimStart => ltrim_str(window),
+            TextTransform::TrimEnd => rtrim_str(window),
+        }
+    }
+
+    fn apply_dict<T: Integer>(
+        self,
+        arr: &CategoricalArray<T>,
+    ) -> Result<CategoricalArray<T>, KernelError> {
+        use crate::kernels::string::{
+            ltrim_dict, rtrim_dict, to_lowercase_dict, to_uppercase_dict, trim_dict,
+        };
+        let window = (arr, 0, arr.len());
+        match self {
+            TextTransform::Upper => to_uppercase_dict(window),
+            TextTransform::Lower => to_lowercase_dict(window),
+            TextTransform::Trim => trim_dict(window),
+            TextTransform::TrimStart => ltrim_dict(window),
+            TextTransform::TrimEnd => rtrim_dict(window),
+        }
+    }
+}
+
+/// Reinterprets a fixed-width value slice as its underlying native-endian bytes.
+#[inline]
+pub(crate) fn values_bytes<T: Copy>(data: &[T]) -> &[u8] {
+    // Safety: `T` is a plain fixed-width value type, and the returned slice
+    // covers exactly the same memory and lifetime as `data`.
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
+
+/// Gathers `data[i]` for each index into a new buffer.
+#[inline]
+fn take_values<T: Copy>(data: &[T], indices: &[usize]) -> Vec64<T> {
+    let mut out = Vec64::with_capacity(indices.len());
+    out.extend(indices.iter().map(|&i| data[i]));
+    out
+}
+
+/// Gathers validity bits for each index, returning `None` when there is no source mask.
+#[inline]
+fn take_null_mask(mask: Option<&Bitmask>, indices: &[usize]) -> Option<Bitmask> {
+    mask.map(|m| {
+        let mut out = Bitmask::new_set_all(indices.len(), true);
+        for (pos, &i) in indices.iter().enumerate() {
+            if !m.get(i) {
+                out.set(pos, false);
+            }
+        }
+        out
+    })
+}
+
+/// Gathers string values for each index, rebuilding the offsets buffer.
+fn take_strings<T: Integer>(arr: &StringArray<T>, indices: &[usize]) -> StringArray<T> {
+    let mut offsets = Vec64::with_capacity(indices.len() + 1);
+    let mut data = Vec64::new();
+    offsets.push(T::zero());
+    for &i in indices {
+        let start = arr.offsets[i].to_usize();
+        let end = arr.offsets[i + 1].to_usize();
+        data.extend_from_slice(&arr.data[start..end]);
+        offsets.push(T::from_usize(data.len()));
+    }
+    let null_mask = take_null_mask(arr.null_mask.as_ref(), indices);
+    StringArray::new(data, null_mask, offsets)
+}
+
 #[inline(always)]
 pub fn cast_slice<'a, U, T>(data: &'a [U], offset: usize, len: usize) -> Option<&'a [T]> {
     // Safety: The caller is matching on a specific variant where U == T.
@@ -3269,6 +4838,10 @@ impl Display for Array {
                 writeln!(f, "Array<Boolean>")?;
                 Display::fmt(arr, f)
             }
+            Array::FixedSizeBinaryArray(arr) => {
+                writeln!(f, "Array<FixedSizeBinary({})>", arr.width)?;
+                Display::fmt(arr, f)
+            }
             Array::NumericArray(arr) => {
                 writeln!(f, "Array<Numeric>")?;
                 Display::fmt(arr, f)
@@ -3317,7 +4890,9 @@ pub fn extract_string_option_values64_owned<T: AsRef<str>>(
     }
 
     let mask = if has_nulls {
-        Some(Bitmask::from_bytes(null_bytes, len))
+        let mut mask = Bitmask::new(null_bytes, len);
+        mask.mask_trailing_bits();
+        Some(mask)
     } else {
         None
     };
@@ -3349,7 +4924,9 @@ pub fn extract_categorical_option_values64(
     }
 
     let mask = if has_nulls {
-        Some(Bitmask::from_bytes(null_bytes, len))
+        let mut mask = Bitmask::new(null_bytes, len);
+        mask.mask_trailing_bits();
+        Some(mask)
     } else {
         None
     };
@@ -3380,7 +4957,9 @@ pub fn extract_option_values64<T: Default + Copy>(
     }
 
     let mask = if has_nulls {
-        Some(Bitmask::from_bytes(null_bytes, len))
+        let mut mask = Bitmask::new(null_bytes, len);
+        mask.mask_trailing_bits();
+        Some(mask)
     } else {
         None
     };
@@ -4082,6 +5661,182 @@ mod tests {
         assert_eq!(arr.len(), 2);
     }
 
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_array_reductions() {
+        use crate::Scalar;
+
+        let mut ints = IntegerArray::<i32>::default();
+        ints.push(i32::MAX);
+        ints.push_null();
+        ints.push(i32::MAX);
+        ints.push(-4);
+        let arr = Array::from_int32(ints);
+        assert_eq!(arr.sum().unwrap(), Scalar::Int64(2 * i32::MAX as i64 - 4));
+        assert_eq!(arr.min().unwrap(), Scalar::Int32(-4));
+        assert_eq!(arr.max().unwrap(), Scalar::Int32(i32::MAX));
+        assert_eq!(
+            arr.mean().unwrap(),
+            Scalar::Float64((2 * i32::MAX as i64 - 4) as f64 / 3.0)
+        );
+
+        let floats = Array::from_float32(FloatArray::from_slice(&[1.5, f32::NAN, -2.0]));
+        assert_eq!(floats.min().unwrap(), Scalar::Float32(-2.0));
+        assert_eq!(floats.max().unwrap(), Scalar::Float32(1.5));
+
+        let dense = Array::from_float64(FloatArray::from_slice(&[0.5; 37]));
+        assert_eq!(dense.sum().unwrap(), Scalar::Float64(18.5));
+
+        let mut all_null = IntegerArray::<u32>::default();
+        all_null.push_null();
+        let all_null = Array::from_uint32(all_null);
+        assert_eq!(all_null.sum().unwrap(), Scalar::Null(ArrowType::UInt64));
+        assert_eq!(all_null.mean().unwrap(), Scalar::Null(ArrowType::Float64));
+        assert_eq!(
+            Array::from_int64(IntegerArray::default()).max().unwrap(),
+            Scalar::Null(ArrowType::Int64)
+        );
+        assert_eq!(Array::Null.sum().unwrap(), Scalar::Null(ArrowType::Null));
+
+        // A total outside Int64 is null rather than wrapped, for both the scalar
+        // and SIMD paths, while the mean still uses the exact total.
+        let mut big = IntegerArray::<i64>::default();
+        big.push(i64::MAX);
+        big.push(i64::MAX);
+        big.push_null();
+        let big = Array::from_int64(big);
+        assert_eq!(big.sum().unwrap(), Scalar::Null(ArrowType::Int64));
+        assert_eq!(big.mean().unwrap(), Scalar::Float64(i64::MAX as f64));
+        let dense = Array::from_uint64(IntegerArray::from_slice(&[u64::MAX; 9]));
+        assert_eq!(dense.sum().unwrap(), Scalar::Null(ArrowType::UInt64));
+        assert_eq!(dense.mean().unwrap(), Scalar::Float64(u64::MAX as f64));
+        let back = Array::from_int64(IntegerArray::from_slice(&[i64::MAX, 1, -2]));
+        assert_eq!(back.sum().unwrap(), Scalar::Int64(i64::MAX - 1));
+
+        let text = Array::from_string32(StringArray::from_slice(&["a"]));
+        assert!(matches!(text.sum(), Err(MinarrowError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_array_boolean_reductions() {
+        let arr = Array::from_bool(BooleanArray::from_slice(&[true, false, true]));
+        assert_eq!(arr.count_true().unwrap(), 2);
+        assert_eq!(arr.all().unwrap(), Some(false));
+        assert_eq!(arr.any().unwrap(), Some(true));
+
+        assert_eq!(Array::Null.all().unwrap(), None);
+        assert_eq!(Array::Null.count_true().unwrap(), 0);
+
+        let ints = Array::from_int32(IntegerArray::from_slice(&[1]));
+        assert!(matches!(ints.any(), Err(MinarrowError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_numeric_cumulative_scans() {
+        let mut ints = IntegerArray::<i32>::default();
+        ints.push(i32::MAX);
+        ints.push_null();
+        ints.push(i32::MAX);
+        ints.push(-4);
+        let num = NumericArray::Int32(Arc::new(ints));
+
+        let NumericArray::Int64(sums) = num.cumsum() else {
+            panic!("Expected Int64 cumsum");
+        };
+        assert_eq!(sums.get(0), Some(i32::MAX as i64));
+        assert_eq!(sums.get(1), None);
+        assert_eq!(sums.get(2), Some(2 * i32::MAX as i64));
+        assert_eq!(sums.get(3), Some(2 * i32::MAX as i64 - 4));
+
+        let NumericArray::Int32(mins) = num.cummin() else {
+            panic!("Expected Int32 cummin");
+        };
+        assert_eq!(mins.get(1), None);
+        assert_eq!(mins.get(3), Some(-4));
+        let NumericArray::Int32(maxs) = num.cummax() else {
+            panic!("Expected Int32 cummax");
+        };
+        assert_eq!(maxs.get(3), Some(i32::MAX));
+
+        let floats = NumericArray::Float32(Arc::new(FloatArray::from_slice(&[1.5, 2.5])));
+        let NumericArray::Float64(sums) = floats.cumsum() else {
+            panic!("Expected Float64 cumsum");
+        };
+        assert_eq!(sums.data.as_slice(), &[1.5, 4.0]);
+        assert_eq!(NumericArray::Null.cumsum(), NumericArray::Null);
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_array_shift() {
+        use crate::Scalar;
+
+        let arr = crate::arr_i32_opt!(Some(1), None, Some(3), Some(4));
+        let values = |a: &Array| {
+            (0..a.len())
+                .map(|i| a.value_to_string(i))
+                .collect::<Vec<_>>()
+        };
+
+        let lag = arr.shift(1, None).unwrap();
+        assert_eq!(values(&lag), vec!["null", "1", "null", "3"]);
+        let lead = arr.shift(-2, Some(Scalar::Int32(0))).unwrap();
+        assert_eq!(values(&lead), vec!["3", "4", "0", "0"]);
+        let filled = arr.shift(2, Some(Scalar::Int32(-1))).unwrap();
+        assert_eq!(values(&filled), vec!["-1", "-1", "1", "null"]);
+        assert_eq!(values(&arr.shift(9, None).unwrap()), vec!["null"; 4]);
+        assert!(matches!(
+            arr.shift(1, Some(Scalar::Float64(0.0))),
+            Err(MinarrowError::TypeError { .. })
+        ));
+
+        let text = Array::from_string32(StringArray::from_slice(&["a", "bb", "ccc"]));
+        let lead = text.shift(-1, Some(Scalar::String32("z".into()))).unwrap();
+        assert_eq!(values(&lead), vec!["bb", "ccc", "z"]);
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_numeric_quantiles() {
+        use crate::{Interpolation, Scalar};
+
+        let mut ints = IntegerArray::<i32>::default();
+        for v in [7, 1, 4, 10] {
+            ints.push(v);
+        }
+        ints.push_null();
+        let num = NumericArray::Int32(Arc::new(ints));
+        assert_eq!(num.median(), Scalar::Float64(5.5));
+        assert_eq!(num.quantile(0.5, Interpolation::Lower), Scalar::Int32(4));
+        assert_eq!(num.quantile(0.5, Interpolation::Higher), Scalar::Int32(7));
+        assert_eq!(num.quantile(0.5, Interpolation::Nearest), Scalar::Int32(4));
+        assert_eq!(num.quantile(0.6, Interpolation::Nearest), Scalar::Int32(7));
+        assert_eq!(
+            num.quantiles(&[0.0, 1.0 / 3.0, 1.0], Interpolation::Linear),
+            vec![
+                Scalar::Float64(1.0),
+                Scalar::Float64(4.0),
+                Scalar::Float64(10.0)
+            ]
+        );
+
+        let floats = NumericArray::Float64(Arc::new(FloatArray::from_slice(&[f64::NAN, 2.0])));
+        assert_eq!(floats.median(), Scalar::Float64(2.0));
+
+        let empty = NumericArray::Int64(Arc::new(IntegerArray::default()));
+        assert_eq!(empty.median(), Scalar::Null(ArrowType::Float64));
+        assert_eq!(
+            empty.quantile(0.9, Interpolation::Higher),
+            Scalar::Null(ArrowType::Int64)
+        );
+        assert_eq!(
+            NumericArray::Null
+                .quantiles(&[0.1, 0.2], Interpolation::Linear)
+                .len(),
+            2
+        );
+    }
+
     #[test]
     fn test_array_arrow_type() {
         assert_eq!(Array::Null.arrow_type(), ArrowType::Null);
@@ -4709,6 +6464,30 @@ mod tests {
         assert_eq!(h1.finish(), h2.finish());
     }
 
+    #[cfg(feature = "hash")]
+    #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+    #[test]
+    fn test_hash_all_matches_across_arrays() {
+        let words = ["a", "b", "a", "c"];
+        let strings = Array::from_string32(StringArray::<u32>::from_slice(&words));
+        let cats = Array::from_categorical32(CategoricalArray::<u32>::from_values(words));
+        let hashes = strings.hash_all(7);
+        assert_eq!(hashes.len(), 4);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        // Categoricals hash the decoded text, not the dictionary index.
+        assert_eq!(cats.hash_all(7), hashes);
+        assert_ne!(strings.hash_all(8), hashes);
+
+        let mut ints = IntegerArray::<i64>::from_slice(&[5, 6, 5]);
+        ints.set_null_mask(Some(Bitmask::from_bools(&[true, false, false])));
+        let ints = Array::from_int64(ints).hash_all(7);
+        assert_eq!(ints[1], ints[2], "nulls share one hash");
+        assert_ne!(ints[0], ints[1]);
+        let dense = Array::from_int64(IntegerArray::<i64>::from_slice(&[5])).hash_all(7);
+        assert_eq!(dense[0], ints[0]);
+    }
+
     #[cfg(feature = "hash")]
     #[test]
     fn test_hash_element_at_float_to_bits() {
@@ -4726,6 +6505,409 @@ mod tests {
         array.hash_element_at(1, &mut h2);
         assert_eq!(h1.finish(), h2.finish());
     }
+
+    #[test]
+    fn test_array_take() {
+        let ints = Array::from_int32(IntegerArray::new(
+            crate::vec64![10, 20, 30],
+            Some(Bitmask::from_bools(&[true, false, true])),
+        ));
+        let taken = ints.take(&[2, 1, 2, 0]);
+        assert_eq!(taken.len(), 4);
+        let a = taken.num().i32().unwrap();
+        assert_eq!(a.data.as_slice(), &[30, 20, 30, 10]);
+        assert!(a.is_null(1));
+        assert!(!a.is_null(0));
+
+        let strs = Array::from_string32(StringArray::from_slice(&["a", "bb", "ccc"]));
+        let taken = strs.take(&[2, 0]);
+        let s = taken.str().str32().unwrap();
+        assert_eq!(s.get_str(0), Some("ccc"));
+        assert_eq!(s.get_str(1), Some("a"));
+
+        let bools = Array::from_bool(BooleanArray::from_slice(&[true, false, false]));
+        let b = bools.take(&[1, 0]).bool();
+        assert_eq!(b.get(0), Some(false));
+        assert_eq!(b.get(1), Some(true));
+    }
+
+    #[test]
+    fn test_array_head_tail() {
+        let arr = Array::from_int32(IntegerArray::from_slice(&[1, 2, 3, 4]));
+        let first = Array::from_int32(IntegerArray::from_slice(&[1, 2]));
+        let last = Array::from_int32(IntegerArray::from_slice(&[2, 3, 4]));
+        assert_eq!(arr.head(2), first);
+        assert_eq!(arr.tail(3), last);
+        assert_eq!(arr.head(10), arr);
+        assert_eq!(arr.tail(0).len(), 0);
+
+        let text = Array::from_string32(StringArray::from_slice(&["a", "bb", "ccc"]));
+        let tail = text.tail(1);
+        assert_eq!(tail.len(), 1);
+        let strs = tail.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(strs.get_str(0), Some("ccc"));
+    }
+
+    #[test]
+    fn test_array_reverse() {
+        let mut s = StringArray::<u32>::from_slice(&["a", "bb", "", "dddd"]);
+        s.set_null(2);
+        let rev = Array::from_string32(s).reverse();
+        let r = rev.str().str32().unwrap();
+        assert_eq!(r.offsets.as_slice(), &[0, 4, 4, 6, 7]);
+        assert_eq!(r.get_str(0), Some("dddd"));
+        assert_eq!(r.get_str(1), None);
+        assert_eq!(r.get_str(2), Some("bb"));
+        assert_eq!(r.get_str(3), Some("a"));
+
+        let ints = Array::from_int64(IntegerArray::new(
+            crate::vec64![1, 2, 3],
+            Some(Bitmask::from_bools(&[false, true, true])),
+        ));
+        let r = ints.reverse().num().i64().unwrap();
+        assert_eq!(r.data.as_slice(), &[3, 2, 1]);
+        assert!(r.is_null(2));
+        assert_eq!(r.null_count(), 1);
+
+        let empty = Array::from_int32(IntegerArray::default());
+        assert_eq!(empty.reverse().len(), 0);
+    }
+
+    #[test]
+    fn test_array_rle() {
+        let mask = Bitmask::from_bools(&[true, true, true, false, false, true, true]);
+        let ints = Array::from_int32(IntegerArray::new(
+            crate::vec64![1, 1, 7, 0, 0, 7, 7],
+            Some(mask),
+        ));
+        let (values, lengths) = ints.rle();
+        let v = values.clone().num().i32().unwrap();
+        assert_eq!(v.data.as_slice(), &[1, 7, 0, 7]);
+        assert!(v.is_null(2));
+        assert_eq!(lengths.data.as_slice(), &[2, 1, 2, 2]);
+        assert_eq!(ints.dedup_consecutive(), values);
+        assert_eq!(Array::decode_rle(&values, &lengths).unwrap(), ints);
+
+        let mut s = StringArray::<u32>::from_slice(&["a", "a", "b", "b", "b", "a"]);
+        s.set_null(5);
+        let strings = Array::from_string32(s);
+        let (values, lengths) = strings.rle();
+        assert_eq!(lengths.data.as_slice(), &[2, 3, 1]);
+        assert_eq!(Array::decode_rle(&values, &lengths).unwrap(), strings);
+
+        let bools = Array::from_bool(BooleanArray::from_slice(&[true, true, false]));
+        assert_eq!(bools.rle().1.data.as_slice(), &[2, 1]);
+
+        let nan = Array::from_float64(FloatArray::from_slice(&[f64::NAN, f64::NAN, 1.0]));
+        assert_eq!(nan.dedup_consecutive().len(), 2);
+
+        let empty = Array::from_int32(IntegerArray::default());
+        assert_eq!(empty.rle().1.len(), 0);
+
+        let short = IntegerArray::from_slice(&[1u64]);
+        assert!(matches!(
+            Array::decode_rle(&values, &short),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+
+        let pair = Array::from_int32(IntegerArray::from_slice(&[1, 2]));
+        let wraps = IntegerArray::from_slice(&[u64::MAX, 1]);
+        assert!(matches!(
+            Array::decode_rle(&pair, &wraps),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        let huge = IntegerArray::from_slice(&[1u64 << 62, 0]);
+        assert!(matches!(
+            Array::decode_rle(&pair, &huge),
+            Err(MinarrowError::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_array_take_out_of_bounds() {
+        let ints = Array::from_int32(IntegerArray::new(crate::vec64![1, 2], None));
+        ints.take(&[2]);
+    }
+
+    #[test]
+    fn test_array_filter() {
+        let ints = Array::from_int32(IntegerArray::new(
+            crate::vec64![10, 20, 30, 40],
+            Some(Bitmask::from_bools(&[true, false, true, true])),
+        ));
+        let keep = Bitmask::from_bools(&[false, true, true, false]);
+        let filtered = ints.filter(&keep).unwrap();
+        let a = filtered.num().i32().unwrap();
+        assert_eq!(a.data.as_slice(), &[20, 30]);
+        assert!(a.is_null(0));
+        assert!(!a.is_null(1));
+
+        let strs = Array::from_string32(StringArray::from_slice(&["a", "bb", "ccc"]));
+        let filtered = strs
+            .filter(&Bitmask::from_bools(&[true, false, true]))
+            .unwrap();
+        let s = filtered.str().str32().unwrap();
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.get_str(1), Some("ccc"));
+
+        assert!(matches!(
+            ints.filter(&Bitmask::new_set_all(3, true)),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_concat_promoting() {
+        let a = Array::from_int32(IntegerArray::new(crate::vec64![1, 2], None));
+        let b = Array::from_int64(IntegerArray::new(crate::vec64![3], None));
+        let out = a.clone().concat_promoting(b).unwrap();
+        assert_eq!(out.arrow_type(), ArrowType::Int64);
+        assert_eq!(out.num().i64().unwrap().data.as_slice(), &[1, 2, 3]);
+
+        let u = Array::from_uint32(IntegerArray::new(crate::vec64![4u32], None));
+        let out = a.clone().concat_promoting(u).unwrap();
+        assert_eq!(out.arrow_type(), ArrowType::Int64);
+
+        let f = Array::from_float32(FloatArray::new(crate::vec64![0.5f32], None));
+        let out = a.clone().concat_promoting(f).unwrap();
+        assert_eq!(out.arrow_type(), ArrowType::Float64);
+        assert_eq!(out.len(), 3);
+
+        let u64s = Array::from_uint64(IntegerArray::new(crate::vec64![5u64], None));
+        let out = a.clone().concat_promoting(u64s).unwrap();
+        assert_eq!(out.arrow_type(), ArrowType::Float64);
+
+        let text = Array::from_string32(StringArray::from_slice(&["x"]));
+        assert!(matches!(
+            a.concat_promoting(text),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cast() {
+        let floats = Array::from_float64(FloatArray::new(
+            crate::vec64![1.9, -2.5, 3e12, f64::NAN],
+            Some(Bitmask::from_bools(&[true, true, true, false])),
+        ));
+        assert!(matches!(
+            floats.cast(ArrowType::Int32),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        let ints = floats
+            .cast_with(ArrowType::Int32, CastOverflow::Null)
+            .unwrap();
+        let a = ints.num_ref().unwrap().i32_ref().unwrap();
+        assert_eq!(a.get(0), Some(1));
+        assert_eq!(a.get(1), Some(-2));
+        assert_eq!(a.get(2), None);
+        assert_eq!(a.get(3), None);
+
+        let text = ints.cast(ArrowType::String).unwrap();
+        let s = text.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(s.get_str(1), Some("-2"));
+        assert_eq!(s.get_str(2), None);
+
+        let raw = Array::from_string32(StringArray::from_vec(
+            vec![" 42 ", "x", "7", ""],
+            Some(Bitmask::from_bools(&[true, true, true, false])),
+        ));
+        let parsed = raw.cast(ArrowType::Int64).unwrap();
+        let p = parsed.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!(p.get(0), Some(42));
+        assert_eq!(p.get(1), None);
+        assert_eq!(p.get(2), Some(7));
+        assert_eq!(p.get(3), None);
+
+        let same = raw.cast(ArrowType::String).unwrap();
+        assert_eq!(same.len(), 4);
+        assert!(matches!(
+            raw.cast(ArrowType::Boolean),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_text_transforms() {
+        let arr = Array::from_string32(StringArray::from_vec(
+            vec!["  Grüße ", "x", " ÀB"],
+            Some(Bitmask::from_bools(&[true, false, true])),
+        ));
+        let upper = arr.trim().unwrap().to_uppercase().unwrap();
+        let s = upper.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(s.get_str(0), Some("GRÜSSE"));
+        assert_eq!(s.get_str(1), None);
+        assert_eq!(s.get_str(2), Some("ÀB"));
+
+        let lower = arr.trim_start().unwrap().to_lowercase().unwrap();
+        let s = lower.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(s.get_str(0), Some("grüße "));
+        assert_eq!(s.get_str(2), Some("àb"));
+
+        let end = arr.trim_end().unwrap();
+        assert_eq!(
+            end.str_ref().unwrap().str32_ref().unwrap().get_str(0),
+            Some("  Grüße")
+        );
+
+        let ints = Array::from_int32(IntegerArray::new(crate::vec64![1], None));
+        assert!(matches!(
+            ints.to_uppercase(),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_slice_owned() {
+        let strs = Array::from_string32(StringArray::from_vec(
+            vec!["a", "bb", "ccc", "dddd", "e"],
+            Some(Bitmask::from_bools(&[true, true, false, true, true])),
+        ));
+        let out = strs.slice(1, 3).unwrap();
+        let s = out.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(s.offsets.as_slice(), &[0, 2, 5, 9]);
+        assert_eq!(s.data.len(), 9);
+        assert_eq!(s.get_str(0), Some("bb"));
+        assert_eq!(s.get_str(1), None);
+        assert_eq!(s.get_str(2), Some("dddd"));
+        assert_eq!(s.null_mask.as_ref().unwrap().len(), 3);
+
+        let ints = Array::from_int64(IntegerArray::new(crate::vec64![1, 2, 3, 4], None));
+        let out = ints.slice(2, 2).unwrap();
+        assert_eq!(
+            out.num_ref().unwrap().i64_ref().unwrap().data.as_slice(),
+            &[3, 4]
+        );
+        assert_eq!(ints.slice(4, 0).unwrap().len(), 0);
+
+        assert!(matches!(
+            ints.slice(3, 2),
+            Err(MinarrowError::IndexError(_))
+        ));
+        assert!(matches!(
+            ints.slice(usize::MAX, 2),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_null_is_valid() {
+        let strs = Array::from_string32(StringArray::from_vec(
+            vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"],
+            Some(Bitmask::from_bools(&[
+                true, false, true, true, false, true, true, true, true, false,
+            ])),
+        ));
+        let nulls = strs.is_null();
+        let valid = strs.is_valid();
+        assert!(nulls.null_mask.is_none() && valid.null_mask.is_none());
+        assert_eq!(nulls.len(), 10);
+        assert_eq!(nulls.data.iter_set().collect::<Vec<_>>(), vec![1, 4, 9]);
+        assert_eq!(valid.data.count_ones(), 7);
+        assert_eq!(nulls.data.count_ones() + valid.data.count_ones(), 10);
+
+        let ints = Array::from_int32(IntegerArray::new(crate::vec64![1, 2, 3], None));
+        assert!(ints.is_null().data.all_unset());
+        assert!(ints.is_valid().data.all_set());
+        assert_eq!(ints.is_valid().len(), 3);
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_fill_null() {
+        use crate::Scalar;
+
+        let mut floats = FloatArray::<f64>::from_slice(&[1.0, f64::NAN, 0.0]);
+        floats.set_null(2);
+        let out = Array::from_float64(floats)
+            .fill_null(Scalar::Float64(9.0))
+            .unwrap();
+        let f = out.num_ref().unwrap().f64_ref().unwrap();
+        assert!(f.null_mask.is_none());
+        assert_eq!(f.data[0], 1.0);
+        assert!(f.data[1].is_nan());
+        assert_eq!(f.data[2], 9.0);
+
+        let strs = Array::from_string32(StringArray::from_vec(
+            vec!["a", "", "c"],
+            Some(Bitmask::from_bools(&[true, false, true])),
+        ));
+        let out = strs.fill_null(Scalar::String32("zz".into())).unwrap();
+        let s = out.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(s.get_str(1), Some("zz"));
+        assert_eq!(out.null_count(), 0);
+
+        let ints = Array::from_int32(IntegerArray::new(crate::vec64![1, 2], None));
+        assert_eq!(ints.fill_null(Scalar::Null(ArrowType::Null)).unwrap(), ints);
+        assert!(matches!(
+            ints.fill_null(Scalar::Int64(0)),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_values_as_bytes() {
+        let ints = Array::from_int32(IntegerArray::new(crate::vec64![1, 258], None));
+        let bytes = ints.values_as_bytes().unwrap();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(&bytes[4..], &258i32.to_ne_bytes());
+
+        let bools = Array::from_bool(BooleanArray::from_slice(&[true, false, true]));
+        assert_eq!(bools.values_as_bytes().unwrap(), &[0b0000_0101]);
+
+        let strs = Array::from_string32(StringArray::from_slice(&["a"]));
+        assert!(strs.values_as_bytes().is_none());
+        assert!(Array::Null.values_as_bytes().is_none());
+    }
+
+    #[test]
+    fn test_to_common_numeric() {
+        let ints = Array::from_int32(IntegerArray::new(
+            crate::vec64![1, -2, 3],
+            Some(Bitmask::from_bools(&[true, true, false])),
+        ));
+        let wide = ints.to_common_numeric(ArrowType::Int64).unwrap();
+        let a = wide.i64_ref().unwrap();
+        assert_eq!(a.get(0), Some(1));
+        assert_eq!(a.get(1), Some(-2));
+        assert_eq!(a.get(2), None);
+
+        let floats = ints.to_common_numeric(ArrowType::Float64).unwrap();
+        assert_eq!(floats.f64_ref().unwrap().get(1), Some(-2.0));
+
+        let same = ints.to_common_numeric(ArrowType::Int32).unwrap();
+        assert_eq!(same.i32_ref().unwrap().data.as_slice(), &[1, -2, 3]);
+    }
+
+    #[test]
+    fn test_to_common_numeric_rejects_loss() {
+        let big = Array::from_int64(IntegerArray::new(crate::vec64![1, i64::MAX], None));
+        assert!(matches!(
+            big.to_common_numeric(ArrowType::Int32),
+            Err(MinarrowError::Overflow { .. })
+        ));
+
+        let neg = Array::from_int32(IntegerArray::new(crate::vec64![-1], None));
+        assert!(matches!(
+            neg.to_common_numeric(ArrowType::UInt32),
+            Err(MinarrowError::Overflow { .. })
+        ));
+
+        let frac = Array::from_float64(FloatArray::new(crate::vec64![1.0, 2.5], None));
+        assert!(matches!(
+            frac.to_common_numeric(ArrowType::Int64),
+            Err(MinarrowError::LossyCast { .. })
+        ));
+
+        // Narrowing is fine when the values fit.
+        let small = Array::from_int64(IntegerArray::new(crate::vec64![7, 8], None));
+        let narrowed = small.to_common_numeric(ArrowType::Int32).unwrap();
+        assert_eq!(narrowed.i32_ref().unwrap().data.as_slice(), &[7, 8]);
+
+        let text = Array::from_string32(StringArray::from_slice(&["a"]));
+        assert!(text.to_common_numeric(ArrowType::Int64).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -5287,6 +7469,7 @@ impl Shape for Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(temporal_array) => temporal_array.shape(),
             Array::BooleanArray(boolean_array) => boolean_array.shape(),
+            Array::FixedSizeBinaryArray(fsb_array) => fsb_array.shape(),
             Array::Null => ShapeDim::Rank0(0),
         }
     }
@@ -5308,6 +7491,11 @@ impl Concatenate for Array {
                 let b = Arc::try_unwrap(b).unwrap_or_else(|arc| (*arc).clone());
                 Ok(Array::BooleanArray(Arc::new(a.concat(b)?)))
             }
+            (Array::FixedSizeBinaryArray(a), Array::FixedSizeBinaryArray(b)) => {
+                let a = Arc::try_unwrap(a).unwrap_or_else(|arc| (*arc).clone());
+                let b = Arc::try_unwrap(b).unwrap_or_else(|arc| (*arc).clone());
+                Ok(Array::FixedSizeBinaryArray(Arc::new(a.concat(b)?)))
+            }
             (Array::Null, Array::Null) => Ok(Array::Null),
             (lhs, rhs) => Err(MinarrowError::IncompatibleTypeError {
                 from: "Array",
@@ -5322,14 +7510,183 @@ impl Concatenate for Array {
     }
 }
 
+/// Error for an `Array::fill_null` scalar that does not match the array type.
+#[cfg(feature = "scalar_type")]
+fn fill_type_error(array: &Array, value: &crate::Scalar) -> MinarrowError {
+    MinarrowError::TypeError {
+        from: "Scalar",
+        to: array_category_name(array),
+        message: Some(format!(
+            "cannot fill nulls of a {} array with {}",
+            array.arrow_type(),
+            value.to_annotated_string()
+        )),
+    }
+}
+
+/// Rebuilds a string array with every null replaced by `value`.
+#[cfg(feature = "scalar_type")]
+fn fill_null_str<T: Integer>(a: &StringArray<T>, value: &str) -> StringArray<T> {
+    let strings: Vec<&str> = (0..a.len())
+        .map(|i| a.get_str(i).unwrap_or(value))
+        .collect();
+    StringArray::from_vec(strings, None)
+}
+
+/// Copies a datetime array with every null replaced by `value`, keeping its time unit.
+#[cfg(all(feature = "scalar_type", feature = "datetime"))]
+fn fill_null_datetime<T: Integer>(a: &DatetimeArray<T>, value: T) -> DatetimeArray<T> {
+    let mask = a.null_mask.as_ref();
+    let data: Vec64<T> = a
+        .data
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            if mask.is_none_or(|m| m.get(i)) {
+                v
+            } else {
+                value
+            }
+        })
+        .collect();
+    DatetimeArray::new(data, None, Some(a.time_unit))
+}
+
+/// Copies slices end to end into one `Vec64` allocated for `total` elements.
+fn concat_slices<'a, T: Copy + 'a>(
+    slices: impl Iterator<Item = &'a [T]>,
+    total: usize,
+) -> Vec64<T> {
+    let mut out = Vec64::with_capacity(total);
+    for s in slices {
+        out.extend_from_slice(s);
+    }
+    out
+}
+
+/// Joins bitmasks end to end into one allocation. A `None` part is all set.
+fn concat_bitmasks<'a>(
+    parts: impl Iterator<Item = (Option<&'a Bitmask>, usize)>,
+    total: usize,
+) -> Bitmask {
+    let mut out = Bitmask::new(Vec64::with_capacity(total.div_ceil(8)), 0);
+    for (mask, len) in parts {
+        match mask {
+            Some(m) => out.extend_from_bitmask_range(m, 0, len),
+            None => out.push_bits(true, len),
+        }
+    }
+    out
+}
+
+/// Concatenates string arrays, rebasing each input's offsets onto the shared data buffer.
+fn concat_strings<T: Integer>(
+    parts: &[&StringArray<T>],
+    total: usize,
+    null_mask: Option<Bitmask>,
+) -> Result<StringArray<T>, MinarrowError> {
+    let byte_range = |s: &StringArray<T>| {
+        let n = s.offsets.len() - 1;
+        (s.offsets[0].to_usize(), s.offsets[n].to_usize())
+    };
+    let n_bytes: usize = parts
+        .iter()
+        .map(|s| {
+            let (start, end) = byte_range(s);
+            end - start
+        })
+        .sum();
+    if T::try_from(n_bytes).is_err() {
+        return Err(MinarrowError::Overflow {
+            value: n_bytes.to_string(),
+            target: std::any::type_name::<T>(),
+        });
+    }
+
+    let mut offsets: Vec64<T> = Vec64::with_capacity(total + 1);
+    let mut data: Vec64<u8> = Vec64::with_capacity(n_bytes);
+    offsets.push(T::zero());
+    for s in parts {
+        let (start, end) = byte_range(s);
+        let base = data.len();
+        offsets.extend(
+            s.offsets[1..]
+                .iter()
+                .map(|&o| T::from_usize(o.to_usize() - start + base)),
+        );
+        data.extend_from_slice(&s.data[start..end]);
+    }
+    Ok(StringArray::new(data, null_mask, offsets))
+}
+
+/// Concatenates categorical arrays under one merged dictionary.
+///
+/// Inputs sharing the first dictionary have their codes copied as-is. Otherwise
+/// the dictionary is built first, in first-seen order, and each input's codes
+/// are remapped through a lookup table as they are written.
+fn concat_categoricals<T: Integer>(
+    parts: &[&CategoricalArray<T>],
+    total: usize,
+    null_mask: Option<Bitmask>,
+) -> Result<CategoricalArray<T>, MinarrowError> {
+    let first = &parts[0].unique_values;
+    let mut data: Vec64<T> = Vec64::with_capacity(total);
+    let shared = parts.iter().all(|p| {
+        std::ptr::eq(p.unique_values.as_ptr(), first.as_ptr()) || p.unique_values == *first
+    });
+    if shared {
+        for p in parts {
+            data.extend_from_slice(p.data.as_slice());
+        }
+        return Ok(CategoricalArray::new(data, first.clone(), null_mask));
+    }
+
+    let mut dict: Vec64<String> = first.clone();
+    let mut lookup: std::collections::HashMap<&str, usize> = first
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.as_str(), i))
+        .collect();
+    let remaps: Vec<Vec<usize>> = parts
+        .iter()
+        .map(|p| {
+            p.unique_values
+                .iter()
+                .map(|v| {
+                    *lookup.entry(v.as_str()).or_insert_with(|| {
+                        dict.push(v.clone());
+                        dict.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    if !crate::structs::variants::categorical::dictionary_fits::<T>(dict.len()) {
+        return Err(MinarrowError::Overflow {
+            value: dict.len().to_string(),
+            target: std::any::type_name::<T>(),
+        });
+    }
+
+    for (p, remap) in parts.iter().zip(&remaps) {
+        data.extend(
+            p.data
+                .iter()
+                .map(|&code| T::from_usize(remap[code.to_usize()])),
+        );
+    }
+    Ok(CategoricalArray::new(data, dict, null_mask))
+}
+
 /// Helper function to get the category name for error messages
-fn array_category_name(arr: &Array) -> &'static str {
+pub(crate) fn array_category_name(arr: &Array) -> &'static str {
     match arr {
         Array::NumericArray(_) => "NumericArray",
         Array::TextArray(_) => "TextArray",
         #[cfg(feature = "datetime")]
         Array::TemporalArray(_) => "TemporalArray",
         Array::BooleanArray(_) => "BooleanArray",
+        Array::FixedSizeBinaryArray(_) => "FixedSizeBinaryArray",
         Array::Null => "Null",
     }
 }
@@ -5431,6 +7788,24 @@ mod concat_tests {
         }
     }
 
+    #[test]
+    fn test_array_concat_fixed_size_binary() {
+        let arr1 =
+            Array::from_fixed_size_binary(FixedSizeBinaryArray::from_slices(&[b"ab", b"cd"], 2));
+        let arr2 = Array::from_fixed_size_binary(FixedSizeBinaryArray::from_slices(&[b"ef"], 2));
+
+        let result = arr1.concat(arr2).unwrap();
+
+        match result {
+            Array::FixedSizeBinaryArray(arr) => {
+                assert_eq!(arr.len(), 3);
+                assert_eq!(arr.value(0), Some(&b"ab"[..]));
+                assert_eq!(arr.value(2), Some(&b"ef"[..]));
+            }
+            _ => panic!("Expected FixedSizeBinaryArray"),
+        }
+    }
+
     #[test]
     fn test_array_concat_mismatched_types() {
         let arr1 = Array::from_int32(IntegerArray::from_slice(&[1, 2, 3]));
@@ -5454,4 +7829,91 @@ mod concat_tests {
 
         assert!(matches!(result, Array::Null));
     }
+
+    /// Folds `Concatenate::concat` pairwise, the result `concat_many` must match.
+    fn fold_concat(arrays: &[Array]) -> Array {
+        let mut iter = arrays.iter().cloned();
+        let first = iter.next().unwrap();
+        iter.fold(first, |acc, a| acc.concat(a).unwrap())
+    }
+
+    #[test]
+    fn test_concat_many_matches_pairwise() {
+        #[allow(unused_mut)]
+        let mut cases = vec![
+            vec![
+                crate::arr_i32![1, 2, 3],
+                crate::arr_i32_opt![Some(4), None],
+                crate::arr_i32![6, 7],
+            ],
+            vec![
+                crate::arr_f64_opt![None, Some(1.5)],
+                crate::arr_f64![2.5, 3.5],
+            ],
+            vec![
+                crate::arr_bool![true, false, true, true, false, true, false, true, true],
+                crate::arr_bool_opt![Some(false), None, Some(true)],
+            ],
+            vec![
+                crate::arr_str32!["a", "bb"],
+                crate::arr_str32_opt![None, Some("ccc")],
+                crate::arr_str32!["", "e"],
+            ],
+        ];
+        #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+        cases.push(vec![
+            crate::arr_cat32!["x", "y", "x"],
+            crate::arr_cat32!["z", "y"],
+            crate::arr_cat32!["w", "x"],
+        ]);
+        for arrays in cases {
+            let many = Array::concat_many(&arrays).unwrap();
+            assert_eq!(many, fold_concat(&arrays));
+        }
+    }
+
+    #[test]
+    fn test_concat_many_string_slices() {
+        let base = crate::arr_str32!["aa", "bbb", "c", "dd"];
+        let arrays = vec![base.slice_clone(1, 2), base.slice_clone(3, 1), base];
+        let many = Array::concat_many(&arrays).unwrap();
+        let strs = many.try_str32_ref().unwrap();
+        let got: Vec<_> = (0..many.len()).map(|i| strs.get_str(i).unwrap()).collect();
+        assert_eq!(got, vec!["bbb", "c", "dd", "aa", "bbb", "c", "dd"]);
+    }
+
+    #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+    #[test]
+    fn test_concat_many_categorical_dictionary() {
+        let arrays = vec![crate::arr_cat32!["x", "y"], crate::arr_cat32!["y", "z"]];
+        match Array::concat_many(&arrays).unwrap() {
+            Array::TextArray(TextArray::Categorical32(arr)) => {
+                assert_eq!(arr.unique_values.as_slice(), &["x", "y", "z"]);
+                assert_eq!(arr.data.as_slice(), &[0, 1, 1, 2]);
+            }
+            _ => panic!("Expected Categorical32 array"),
+        }
+    }
+    #[test]
+    fn test_concat_many_edge_cases() {
+        assert!(matches!(Array::concat_many(&[]).unwrap(), Array::Null));
+
+        let single = crate::arr_i64![1, 2];
+        let out = Array::concat_many(std::slice::from_ref(&single)).unwrap();
+        match (&single, &out) {
+            (
+                Array::NumericArray(NumericArray::Int64(a)),
+                Array::NumericArray(NumericArray::Int64(b)),
+            ) => {
+                assert!(Arc::ptr_eq(a, b))
+            }
+            _ => panic!("Expected Int64 arrays"),
+        }
+
+        let mixed = vec![crate::arr_i32![1, 2], crate::arr_i64![3, 4]];
+        assert!(matches!(
+            Array::concat_many(&mixed),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+    }
 }
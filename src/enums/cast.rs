@@ -0,0 +1,29 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Cast Module** - *Array Cast Options*
+//!
+//! Defines how `Array::cast_with` handles numeric values that do not fit the target type.
+
+/// # CastOverflow
+///
+/// What `Array::cast_with` does with a value outside the target type's range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CastOverflow {
+    /// Fail the whole cast with `MinarrowError::Overflow`.
+    #[default]
+    Error,
+    /// Replace the offending value with null and carry on.
+    Null,
+}
@@ -30,6 +30,9 @@ use std::{
     sync::Arc,
 };
 
+use crate::kernels::{aggregate, unary};
+#[cfg(feature = "scalar_type")]
+use crate::{ArrowType, Interpolation, Scalar};
 use crate::{Bitmask, FloatArray, IntegerArray, MaskedArray, Vec64};
 use crate::{BooleanArray, StringArray};
 use crate::{
@@ -167,6 +170,504 @@ impl NumericArray {
         }
     }
 
+    /// Sum of the non-null values, or a `Scalar::Null` of the sum type when
+    /// there are none.
+    ///
+    /// Integer sums widen to `Int64` or `UInt64`. They accumulate exactly, and
+    /// a total outside the widened type is also `Scalar::Null` rather than a
+    /// wrapped value. Float sums are returned as `Float64`.
+    #[cfg(feature = "scalar_type")]
+    pub fn sum(&self) -> Scalar {
+        match self.sum_count() {
+            (WideSum::Null, _) => Scalar::Null(ArrowType::Null),
+            (WideSum::Int(_), 0) => Scalar::Null(ArrowType::Int64),
+            (WideSum::UInt(_), 0) => Scalar::Null(ArrowType::UInt64),
+            (WideSum::Float(_), 0) => Scalar::Null(ArrowType::Float64),
+            (WideSum::Int(v), _) => {
+                i64::try_from(v).map_or(Scalar::Null(ArrowType::Int64), Scalar::Int64)
+            }
+            (WideSum::UInt(v), _) => {
+                u64::try_from(v).map_or(Scalar::Null(ArrowType::UInt64), Scalar::UInt64)
+            }
+            (WideSum::Float(v), _) => Scalar::Float64(v),
+        }
+    }
+
+    /// Arithmetic mean of the non-null values as `Float64`,
+    /// or `Scalar::Null(ArrowType::Float64)` when there are none.
+    ///
+    /// Integer means divide the exact total, so they hold even where `sum`
+    /// falls outside `Int64` or `UInt64`.
+    #[cfg(feature = "scalar_type")]
+    pub fn mean(&self) -> Scalar {
+        let (total, n) = match self.sum_count() {
+            (WideSum::Null, _) | (_, 0) => return Scalar::Null(ArrowType::Float64),
+            (WideSum::Int(v), n) => (v as f64, n),
+            (WideSum::UInt(v), n) => (v as f64, n),
+            (WideSum::Float(v), n) => (v, n),
+        };
+        Scalar::Float64(total / n as f64)
+    }
+
+    /// Variance of the non-null values as `Float64`, with `ddof` delta degrees
+    /// of freedom, so `1` gives the sample variance and `0` the population one.
+    ///
+    /// Returns `Scalar::Null(ArrowType::Float64)` when there are no more than
+    /// `ddof` values.
+    #[cfg(feature = "scalar_type")]
+    pub fn var(&self, ddof: usize) -> Scalar {
+        macro_rules! var {
+            ($a:expr) => {
+                aggregate::variance($a.data.as_slice(), $a.null_mask.as_ref(), ddof)
+            };
+        }
+        let v = match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => var!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => var!(a),
+            NumericArray::Int32(a) => var!(a),
+            NumericArray::Int64(a) => var!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => var!(a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => var!(a),
+            NumericArray::UInt32(a) => var!(a),
+            NumericArray::UInt64(a) => var!(a),
+            NumericArray::Float32(a) => var!(a),
+            NumericArray::Float64(a) => var!(a),
+            NumericArray::Null => None,
+        };
+        v.map_or(Scalar::Null(ArrowType::Float64), Scalar::Float64)
+    }
+
+    /// Standard deviation of the non-null values as `Float64`, i.e. the square
+    /// root of [`NumericArray::var`] with the same `ddof`.
+    #[cfg(feature = "scalar_type")]
+    pub fn std(&self, ddof: usize) -> Scalar {
+        match self.var(ddof) {
+            Scalar::Float64(v) => Scalar::Float64(v.sqrt()),
+            null => null,
+        }
+    }
+
+    /// Smallest non-null value, in the array's own type.
+    /// `NaN` is ignored. Returns a `Scalar::Null` of the array's type when there
+    /// are no values.
+    #[cfg(feature = "scalar_type")]
+    pub fn min(&self) -> Scalar {
+        self.min_max()
+            .map_or_else(|| self.typed_null(), |(lo, _)| lo)
+    }
+
+    /// Largest non-null value, in the array's own type.
+    /// `NaN` is ignored. Returns a `Scalar::Null` of the array's type when there
+    /// are no values.
+    #[cfg(feature = "scalar_type")]
+    pub fn max(&self) -> Scalar {
+        self.min_max()
+            .map_or_else(|| self.typed_null(), |(_, hi)| hi)
+    }
+
+    /// Quantile `q` of the non-null values, resolved with `interp`.
+    ///
+    /// `q` is a fraction in `[0, 1]`, e.g. `0.5` for the median. `Linear` yields
+    /// `Float64`, while `Lower`, `Higher` and `Nearest` yield one of the values in
+    /// the array's own type. `NaN` is ignored. Returns a `Scalar::Null` of the
+    /// result type when there are no values.
+    ///
+    /// Finds the neighbours by quickselect, so no full sort is needed.
+    ///
+    /// # Panics
+    /// Panics if `q` is outside `[0, 1]`.
+    #[cfg(feature = "scalar_type")]
+    pub fn quantile(&self, q: f64, interp: Interpolation) -> Scalar {
+        self.quantiles(&[q], interp)
+            .pop()
+            .expect("one quantile requested")
+    }
+
+    /// Linearly interpolated median of the non-null values, as `Float64`.
+    ///
+    /// Returns `Scalar::Null(ArrowType::Float64)` when there are no values.
+    #[cfg(feature = "scalar_type")]
+    pub fn median(&self) -> Scalar {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// Quantiles at each of `qs`, resolved with `interp` as in `quantile`.
+    ///
+    /// The non-null values are sorted once and every cut point read off it.
+    ///
+    /// # Panics
+    /// Panics if any `q` is outside `[0, 1]`.
+    #[cfg(feature = "scalar_type")]
+    pub fn quantiles(&self, qs: &[f64], interp: Interpolation) -> Vec<Scalar> {
+        macro_rules! bounds {
+            ($variant:ident, $a:expr) => {{
+                let mut vals = aggregate::valid_values($a.data.as_slice(), $a.null_mask.as_ref());
+                aggregate::quantile_bounds(&mut vals, qs)
+                    .into_iter()
+                    .map(|b| {
+                        b.map(|(lo, hi, frac)| (Scalar::$variant(lo), Scalar::$variant(hi), frac))
+                    })
+                    .collect::<Vec<_>>()
+            }};
+        }
+        let bounds = match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => bounds!(Int8, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => bounds!(Int16, a),
+            NumericArray::Int32(a) => bounds!(Int32, a),
+            NumericArray::Int64(a) => bounds!(Int64, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => bounds!(UInt8, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => bounds!(UInt16, a),
+            NumericArray::UInt32(a) => bounds!(UInt32, a),
+            NumericArray::UInt64(a) => bounds!(UInt64, a),
+            NumericArray::Float32(a) => bounds!(Float32, a),
+            NumericArray::Float64(a) => bounds!(Float64, a),
+            NumericArray::Null => aggregate::quantile_bounds::<f64>(&mut [], qs)
+                .into_iter()
+                .map(|_| None)
+                .collect(),
+        };
+        bounds
+            .into_iter()
+            .map(|b| match (b, interp) {
+                (None, Interpolation::Linear) => Scalar::Null(ArrowType::Float64),
+                (None, _) => self.typed_null(),
+                (Some((lo, hi, frac)), Interpolation::Linear) => {
+                    let lo = lo.f64();
+                    Scalar::Float64(lo + (hi.f64() - lo) * frac)
+                }
+                (Some((lo, _, _)), Interpolation::Lower) => lo,
+                (Some((_, hi, _)), Interpolation::Higher) => hi,
+                (Some((lo, hi, frac)), Interpolation::Nearest) => {
+                    if frac <= 0.5 {
+                        lo
+                    } else {
+                        hi
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Running sum of the non-null values, one per slot.
+    ///
+    /// Null slots stay null. Output types match `sum`: integer sums widen to
+    /// `Int64` or `UInt64`, wrapping on overflow, and float sums are `Float64`.
+    pub fn cumsum(&self) -> NumericArray {
+        let mask = self.null_mask().cloned();
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => NumericArray::Int64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_signed(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => NumericArray::Int64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_signed(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::Int32(a) => NumericArray::Int64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_signed(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::Int64(a) => NumericArray::Int64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_signed(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => NumericArray::UInt64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_unsigned(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => NumericArray::UInt64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_unsigned(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::UInt32(a) => NumericArray::UInt64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_unsigned(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::UInt64(a) => NumericArray::UInt64(Arc::new(IntegerArray::new(
+                aggregate::cumsum_unsigned(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::Float32(a) => NumericArray::Float64(Arc::new(FloatArray::new(
+                aggregate::cumsum_float(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::Float64(a) => NumericArray::Float64(Arc::new(FloatArray::new(
+                aggregate::cumsum_float(a.data.as_slice(), a.null_mask.as_ref()),
+                mask,
+            ))),
+            NumericArray::Null => NumericArray::Null,
+        }
+    }
+
+    /// Running minimum of the non-null values, in the array's own type.
+    ///
+    /// Null slots stay null and `NaN` is ignored once a value has been seen.
+    pub fn cummin(&self) -> NumericArray {
+        self.scan_same_type(ScanOp::Min)
+    }
+
+    /// Running maximum of the non-null values, in the array's own type.
+    ///
+    /// Null slots stay null and `NaN` is ignored once a value has been seen.
+    pub fn cummax(&self) -> NumericArray {
+        self.scan_same_type(ScanOp::Max)
+    }
+
+    /// Elementwise minimum against `other`, in the two arrays' common numeric type.
+    ///
+    /// A length-1 side is broadcast. Nulls on either side yield null, and a `NaN`
+    /// in `self` is left as-is. See [`comparison::min_with`](crate::kernels::comparison::min_with).
+    pub fn min_with(&self, other: &NumericArray) -> Result<NumericArray, MinarrowError> {
+        crate::kernels::comparison::min_with(self, other)
+    }
+
+    /// Elementwise maximum against `other`, in the two arrays' common numeric type.
+    ///
+    /// A length-1 side is broadcast. Nulls on either side yield null, and a `NaN`
+    /// in `self` is left as-is. See [`comparison::max_with`](crate::kernels::comparison::max_with).
+    pub fn max_with(&self, other: &NumericArray) -> Result<NumericArray, MinarrowError> {
+        crate::kernels::comparison::max_with(self, other)
+    }
+
+    /// Elementwise minimum against a scalar. A `Scalar::Null` yields all nulls.
+    #[cfg(feature = "scalar_type")]
+    pub fn min_with_scalar(&self, other: &Scalar) -> Result<NumericArray, MinarrowError> {
+        crate::kernels::comparison::min_with_scalar(self, other)
+    }
+
+    /// Elementwise maximum against a scalar. A `Scalar::Null` yields all nulls.
+    #[cfg(feature = "scalar_type")]
+    pub fn max_with_scalar(&self, other: &Scalar) -> Result<NumericArray, MinarrowError> {
+        crate::kernels::comparison::max_with_scalar(self, other)
+    }
+
+    /// Bounds every value into `[lo, hi]`.
+    ///
+    /// The result takes the common numeric type of the array and both bounds.
+    /// Null slots stay null and `NaN` values are left as-is. A `Scalar::Null`
+    /// bound leaves that side open.
+    ///
+    /// # Errors
+    /// - `KernelError::InvalidArguments` if `lo > hi`
+    /// - `TypeError` if a bound is not numeric
+    #[cfg(feature = "scalar_type")]
+    pub fn clamp(&self, lo: Scalar, hi: Scalar) -> Result<NumericArray, MinarrowError> {
+        use crate::kernels::comparison::{exact_values, scalar_operand};
+        if let (Some(l), Some(h)) = (scalar_operand(&lo, "clamp")?, scalar_operand(&hi, "clamp")?)
+            && let (l, h) = (exact_values(&l)[0], exact_values(&h)[0])
+            && l > h
+        {
+            return Err(crate::enums::error::KernelError::InvalidArguments(format!(
+                "NumericArray::clamp: lower bound {} exceeds upper bound {}",
+                l, h
+            ))
+            .into());
+        }
+        let out = if lo.is_null() {
+            self.clone()
+        } else {
+            self.max_with_scalar(&lo)?
+        };
+        if hi.is_null() {
+            Ok(out)
+        } else {
+            out.min_with_scalar(&hi)
+        }
+    }
+
+    /// Absolute value of every element, in the array's own type.
+    ///
+    /// # Errors
+    /// - `Overflow` if a valid integer is its type's `MIN`
+    pub fn abs(&self) -> Result<NumericArray, MinarrowError> {
+        unary::abs(self)
+    }
+
+    /// Negation of every element, in the array's own type.
+    ///
+    /// # Errors
+    /// - `Overflow` for a signed `MIN` or a non-zero unsigned value
+    pub fn neg(&self) -> Result<NumericArray, MinarrowError> {
+        unary::neg(self)
+    }
+
+    /// Square root of every element. `Float32` stays `Float32`, everything else is `Float64`.
+    ///
+    /// Negative values give `NaN`, or an error when `error_on_negative` is set.
+    /// See [`unary::sqrt`].
+    pub fn sqrt(&self, error_on_negative: bool) -> Result<NumericArray, MinarrowError> {
+        unary::sqrt(self, error_on_negative)
+    }
+
+    /// Rounds floats half away from zero. Integer arrays are returned unchanged.
+    pub fn round(&self) -> NumericArray {
+        unary::round(self)
+    }
+
+    /// Rounds floats down. Integer arrays are returned unchanged.
+    pub fn floor(&self) -> NumericArray {
+        unary::floor(self)
+    }
+
+    /// Rounds floats up. Integer arrays are returned unchanged.
+    pub fn ceil(&self) -> NumericArray {
+        unary::ceil(self)
+    }
+
+    /// Raises every element to `exp`. See [`unary::pow`] for the result type.
+    #[cfg(feature = "scalar_type")]
+    pub fn pow(&self, exp: Scalar) -> Result<NumericArray, MinarrowError> {
+        unary::pow(self, &exp)
+    }
+
+    /// Applies a type-preserving prefix scan to whichever variant is held.
+    fn scan_same_type(&self, op: ScanOp) -> NumericArray {
+        macro_rules! scan {
+            ($variant:ident, $wrapper:ident, $a:expr) => {{
+                let data = $a.data.as_slice();
+                let mask = $a.null_mask.as_ref();
+                let out = match op {
+                    ScanOp::Min => aggregate::cummin(data, mask),
+                    ScanOp::Max => aggregate::cummax(data, mask),
+                };
+                NumericArray::$variant(Arc::new($wrapper::new(out, $a.null_mask.clone())))
+            }};
+        }
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => scan!(Int8, IntegerArray, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => scan!(Int16, IntegerArray, a),
+            NumericArray::Int32(a) => scan!(Int32, IntegerArray, a),
+            NumericArray::Int64(a) => scan!(Int64, IntegerArray, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => scan!(UInt8, IntegerArray, a),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => scan!(UInt16, IntegerArray, a),
+            NumericArray::UInt32(a) => scan!(UInt32, IntegerArray, a),
+            NumericArray::UInt64(a) => scan!(UInt64, IntegerArray, a),
+            NumericArray::Float32(a) => scan!(Float32, FloatArray, a),
+            NumericArray::Float64(a) => scan!(Float64, FloatArray, a),
+            NumericArray::Null => NumericArray::Null,
+        }
+    }
+
+    /// `Scalar::Null` carrying this array's type, for empty reductions.
+    #[cfg(feature = "scalar_type")]
+    fn typed_null(&self) -> Scalar {
+        Scalar::Null(crate::Array::NumericArray(self.clone()).arrow_type())
+    }
+
+    /// Exact sum and count of the non-null values.
+    #[cfg(feature = "scalar_type")]
+    fn sum_count(&self) -> (WideSum, usize) {
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => {
+                let (s, n) = aggregate::sum_signed(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Int(s), n)
+            }
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => {
+                let (s, n) = aggregate::sum_signed(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Int(s), n)
+            }
+            NumericArray::Int32(a) => {
+                let (s, n) = aggregate::sum_signed(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Int(s), n)
+            }
+            NumericArray::Int64(a) => {
+                #[cfg(feature = "simd")]
+                if a.null_mask.is_none() {
+                    let s = aggregate::sum_i64_dense_simd(a.data.as_slice());
+                    return (WideSum::Int(s), a.len());
+                }
+                let (s, n) = aggregate::sum_signed(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Int(s), n)
+            }
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => {
+                let (s, n) = aggregate::sum_unsigned(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::UInt(s), n)
+            }
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => {
+                let (s, n) = aggregate::sum_unsigned(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::UInt(s), n)
+            }
+            NumericArray::UInt32(a) => {
+                let (s, n) = aggregate::sum_unsigned(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::UInt(s), n)
+            }
+            NumericArray::UInt64(a) => {
+                #[cfg(feature = "simd")]
+                if a.null_mask.is_none() {
+                    let s = aggregate::sum_u64_dense_simd(a.data.as_slice());
+                    return (WideSum::UInt(s), a.len());
+                }
+                let (s, n) = aggregate::sum_unsigned(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::UInt(s), n)
+            }
+            NumericArray::Float32(a) => {
+                let (s, n) = aggregate::sum_float(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Float(s), n)
+            }
+            NumericArray::Float64(a) => {
+                #[cfg(feature = "simd")]
+                if a.null_mask.is_none() {
+                    let s = aggregate::sum_f64_dense_simd(a.data.as_slice());
+                    return (WideSum::Float(s), a.len());
+                }
+                let (s, n) = aggregate::sum_float(a.data.as_slice(), a.null_mask.as_ref());
+                (WideSum::Float(s), n)
+            }
+            NumericArray::Null => (WideSum::Null, 0),
+        }
+    }
+
+    #[cfg(feature = "scalar_type")]
+    fn min_max(&self) -> Option<(Scalar, Scalar)> {
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Int8(lo), Scalar::Int8(hi))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Int16(lo), Scalar::Int16(hi))),
+            NumericArray::Int32(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Int32(lo), Scalar::Int32(hi))),
+            NumericArray::Int64(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Int64(lo), Scalar::Int64(hi))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::UInt8(lo), Scalar::UInt8(hi))),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::UInt16(lo), Scalar::UInt16(hi))),
+            NumericArray::UInt32(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::UInt32(lo), Scalar::UInt32(hi))),
+            NumericArray::UInt64(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::UInt64(lo), Scalar::UInt64(hi))),
+            NumericArray::Float32(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Float32(lo), Scalar::Float32(hi))),
+            NumericArray::Float64(a) => aggregate::min_max(a.data.as_slice(), a.null_mask.as_ref())
+                .map(|(lo, hi)| (Scalar::Float64(lo), Scalar::Float64(hi))),
+            NumericArray::Null => None,
+        }
+    }
+
     /// Appends all values (and null mask if present) from `other` into `self`.
     ///
     /// Panics if the two arrays are of different variants or incompatible types.
@@ -718,6 +1219,23 @@ impl NumericArray {
     }
 }
 
+/// Type-preserving prefix scans selected by `NumericArray::scan_same_type`.
+#[derive(Clone, Copy)]
+enum ScanOp {
+    Min,
+    Max,
+}
+
+/// Exact running total behind `NumericArray::sum` and `NumericArray::mean`.
+#[cfg(feature = "scalar_type")]
+#[derive(Clone, Copy)]
+enum WideSum {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Null,
+}
+
 impl Display for NumericArray {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
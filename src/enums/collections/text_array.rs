@@ -606,7 +606,16 @@ impl Shape for TextArray {
 }
 
 impl Concatenate for TextArray {
+    /// Concatenates two arrays of the same variant.
+    ///
+    /// Categorical dictionaries are merged and the second array's codes remapped.
+    /// With `extended_categorical`, a merged dictionary too large for the code
+    /// type moves both sides to the next wider categorical variant first.
     fn concat(self, other: Self) -> Result<Self, MinarrowError> {
+        #[cfg(feature = "extended_categorical")]
+        if let Some((lhs, rhs)) = widen_for_concat(&self, &other) {
+            return lhs.concat(rhs);
+        }
         match (self, other) {
             (TextArray::String32(a), TextArray::String32(b)) => {
                 let a = Arc::try_unwrap(a).unwrap_or_else(|arc| (*arc).clone());
@@ -657,6 +666,37 @@ impl Concatenate for TextArray {
     }
 }
 
+/// When both sides are the same categorical variant and their merged dictionary
+/// overflows its code type, returns both moved to the next wider variant.
+#[cfg(feature = "extended_categorical")]
+fn widen_for_concat(lhs: &TextArray, rhs: &TextArray) -> Option<(TextArray, TextArray)> {
+    use crate::structs::variants::categorical::dictionary_fits;
+    macro_rules! widen {
+        ($a:expr, $b:expr, $code:ty, $wider:ident, $wide:ty) => {{
+            if dictionary_fits::<$code>($a.merged_cardinality($b)) {
+                return None;
+            }
+            Some((
+                TextArray::$wider(Arc::new($a.widen::<$wide>())),
+                TextArray::$wider(Arc::new($b.widen::<$wide>())),
+            ))
+        }};
+    }
+    match (lhs, rhs) {
+        #[cfg(feature = "default_categorical_8")]
+        (TextArray::Categorical8(a), TextArray::Categorical8(b)) => {
+            widen!(a, b, u8, Categorical16, u16)
+        }
+        (TextArray::Categorical16(a), TextArray::Categorical16(b)) => {
+            widen!(a, b, u16, Categorical32, u32)
+        }
+        (TextArray::Categorical32(a), TextArray::Categorical32(b)) => {
+            widen!(a, b, u32, Categorical64, u64)
+        }
+        _ => None,
+    }
+}
+
 /// Helper function to get the variant name for error messages
 fn text_variant_name(arr: &TextArray) -> &'static str {
     match arr {
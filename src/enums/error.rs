@@ -20,7 +20,7 @@
 //!
 //! ## Covers
 //! - Array length mismatches, overflow, lossy casts, null handling,
-//! type incompatibility, and invalid conversions.  
+//!   type incompatibility, invalid conversions, I/O failures and parse errors.
 //! - Implements `Display` for readable output and `Error` for integration
 //! with standard Rust error handling.
 
@@ -64,6 +64,11 @@ pub enum MinarrowError {
         feature: String,
     },
     IndexError(String),
+    IoError(String),
+    ParseError {
+        line: usize,
+        message: String,
+    },
 }
 
 impl fmt::Display for MinarrowError {
@@ -143,6 +148,12 @@ impl fmt::Display for MinarrowError {
             MinarrowError::IndexError(message) => {
                 write!(f, "Index error: {}", message)
             }
+            MinarrowError::IoError(message) => {
+                write!(f, "I/O error: {}", message)
+            }
+            MinarrowError::ParseError { line, message } => {
+                write!(f, "Parse error on line {}: {}", line, message)
+            }
         }
     }
 }
@@ -205,6 +216,12 @@ impl fmt::Display for KernelError {
 
 impl Error for KernelError {}
 
+impl From<std::io::Error> for MinarrowError {
+    fn from(err: std::io::Error) -> Self {
+        MinarrowError::IoError(err.to_string())
+    }
+}
+
 impl From<KernelError> for MinarrowError {
     fn from(err: KernelError) -> Self {
         MinarrowError::KernelError(Some(err.to_string()))
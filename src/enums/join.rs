@@ -0,0 +1,29 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Join Module** - *Table Join Options*
+//!
+//! Defines the join strategies supported by `Table::join`.
+
+/// # JoinType
+///
+/// Which rows `Table::join` keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JoinType {
+    /// Keep only rows whose key matches on both sides.
+    #[default]
+    Inner,
+    /// Keep every left row, filling right columns with nulls where there is no match.
+    Left,
+}
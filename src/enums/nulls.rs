@@ -0,0 +1,29 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Nulls Module** - *Null Handling Options*
+//!
+//! Defines the options controlling how null values are treated by table cleaning methods.
+
+/// # DropHow
+///
+/// Rule used by `Table::drop_nulls` to decide whether a row is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DropHow {
+    /// Drop a row if any of the considered columns is null.
+    #[default]
+    Any,
+    /// Drop a row only if all of the considered columns are null.
+    All,
+}
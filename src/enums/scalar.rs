@@ -30,7 +30,11 @@ use num_traits::Pow;
 #[cfg(feature = "scalar_type")]
 use crate::DatetimeArray;
 #[cfg(feature = "scalar_type")]
-use crate::{Array, Bitmask, BooleanArray, FloatArray, IntegerArray, MaskedArray, StringArray};
+use crate::enums::error::MinarrowError;
+#[cfg(feature = "scalar_type")]
+use crate::{
+    Array, ArrowType, Bitmask, BooleanArray, FloatArray, IntegerArray, MaskedArray, StringArray,
+};
 
 /// # Scalar
 ///
@@ -42,10 +46,14 @@ use crate::{Array, Bitmask, BooleanArray, FloatArray, IntegerArray, MaskedArray,
 ///   These also downcast to that type, including for e.g., string operations.
 /// - There are also `try_<type>` methods that can be used to attempt it gracefully
 /// without the risk of panicking.
+/// - Builds from Rust literals via `From`, e.g. `Scalar::from(5)` or `Scalar::from("a")`,
+///   and converts back via `TryFrom`, which only accepts the matching variant.
 #[cfg(feature = "scalar_type")]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Scalar {
-    Null,
+    /// A null of the given logical type, so that e.g. an empty reduction keeps
+    /// its result type. `ArrowType::Null` when the type is unknown.
+    Null(ArrowType),
     Boolean(bool),
     // Signed integers
     #[cfg(feature = "extended_numeric_types")]
@@ -100,7 +108,7 @@ impl Scalar {
             Scalar::UInt64(v) => *v != 0,
             Scalar::Float32(v) => *v != 0.0,
             Scalar::Float64(v) => *v != 0.0,
-            Scalar::Null => panic!("Cannot convert Null to bool"),
+            Scalar::Null(_) => panic!("Cannot convert Null to bool"),
             Scalar::String32(s) => {
                 let s = s.trim();
                 if s.is_empty() {
@@ -165,7 +173,7 @@ impl Scalar {
             Scalar::UInt64(v) => i8::try_from(*v).expect("u64 out of range for i8"),
             Scalar::Float32(v) => i8::try_from(*v as i32).expect("f32 out of range for i8"),
             Scalar::Float64(v) => i8::try_from(*v as i32).expect("f64 out of range for i8"),
-            Scalar::Null => panic!("Cannot convert Null to i8"),
+            Scalar::Null(_) => panic!("Cannot convert Null to i8"),
             Scalar::String32(s) => s.parse::<i8>().expect("Cannot parse string as i8"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i8>().expect("Cannot parse string as i8"),
@@ -203,7 +211,7 @@ impl Scalar {
             Scalar::UInt64(v) => i16::try_from(*v).expect("u64 out of range for i16"),
             Scalar::Float32(v) => i16::try_from(*v as i32).expect("f32 out of range for i16"),
             Scalar::Float64(v) => i16::try_from(*v as i32).expect("f64 out of range for i16"),
-            Scalar::Null => panic!("Cannot convert Null to i16"),
+            Scalar::Null(_) => panic!("Cannot convert Null to i16"),
             Scalar::String32(s) => s.parse::<i16>().expect("Cannot parse string as i16"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i16>().expect("Cannot parse string as i16"),
@@ -244,7 +252,7 @@ impl Scalar {
             Scalar::UInt64(v) => i32::try_from(*v).expect("u64 out of range for i32"),
             Scalar::Float32(v) => *v as i32,
             Scalar::Float64(v) => *v as i32,
-            Scalar::Null => panic!("Cannot convert Null to i32"),
+            Scalar::Null(_) => panic!("Cannot convert Null to i32"),
             Scalar::String32(s) => s.parse::<i32>().expect("Cannot parse string as i32"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i32>().expect("Cannot parse string as i32"),
@@ -291,7 +299,7 @@ impl Scalar {
             }
             Scalar::Float32(v) => *v as i64,
             Scalar::Float64(v) => *v as i64,
-            Scalar::Null => panic!("Cannot convert Null to i64"),
+            Scalar::Null(_) => panic!("Cannot convert Null to i64"),
             Scalar::String32(s) => s.parse::<i64>().expect("Cannot parse string as i64"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i64>().expect("Cannot parse string as i64"),
@@ -332,7 +340,7 @@ impl Scalar {
             Scalar::UInt64(v) => u8::try_from(*v).expect("u64 out of range for u8"),
             Scalar::Float32(v) => u8::try_from(*v as i32).expect("f32 out of range for u8"),
             Scalar::Float64(v) => u8::try_from(*v as i32).expect("f64 out of range for u8"),
-            Scalar::Null => panic!("Cannot convert Null to u8"),
+            Scalar::Null(_) => panic!("Cannot convert Null to u8"),
             Scalar::String32(s) => s.parse::<u8>().expect("Cannot parse string as u8"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u8>().expect("Cannot parse string as u8"),
@@ -373,7 +381,7 @@ impl Scalar {
             Scalar::UInt64(v) => u16::try_from(*v).expect("u64 out of range for u16"),
             Scalar::Float32(v) => u16::try_from(*v as i32).expect("f32 out of range for u16"),
             Scalar::Float64(v) => u16::try_from(*v as i32).expect("f64 out of range for u16"),
-            Scalar::Null => panic!("Cannot convert Null to u16"),
+            Scalar::Null(_) => panic!("Cannot convert Null to u16"),
             Scalar::String32(s) => s.parse::<u16>().expect("Cannot parse string as u16"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u16>().expect("Cannot parse string as u16"),
@@ -414,7 +422,7 @@ impl Scalar {
             Scalar::UInt64(v) => u32::try_from(*v).expect("u64 out of range for u32"),
             Scalar::Float32(v) => *v as u32,
             Scalar::Float64(v) => *v as u32,
-            Scalar::Null => panic!("Cannot convert Null to u32"),
+            Scalar::Null(_) => panic!("Cannot convert Null to u32"),
             Scalar::String32(s) => s.parse::<u32>().expect("Cannot parse string as u32"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u32>().expect("Cannot parse string as u32"),
@@ -491,7 +499,7 @@ impl Scalar {
                     panic!("f64 out of range for u64")
                 }
             }
-            Scalar::Null => panic!("Cannot convert Null to u64"),
+            Scalar::Null(_) => panic!("Cannot convert Null to u64"),
             Scalar::String32(s) => s.parse::<u64>().expect("Cannot parse string as u64"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u64>().expect("Cannot parse string as u64"),
@@ -539,7 +547,7 @@ impl Scalar {
                     0.0
                 }
             }
-            Scalar::Null => panic!("Cannot convert Null to f32"),
+            Scalar::Null(_) => panic!("Cannot convert Null to f32"),
             Scalar::String32(s) => s.parse::<f32>().expect("Cannot parse string as f32"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<f32>().expect("Cannot parse string as f32"),
@@ -580,7 +588,7 @@ impl Scalar {
                     0.0
                 }
             }
-            Scalar::Null => panic!("Cannot convert Null to f64"),
+            Scalar::Null(_) => panic!("Cannot convert Null to f64"),
             Scalar::String32(s) => s.parse::<f64>().expect("Cannot parse string as f64"),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<f64>().expect("Cannot parse string as f64"),
@@ -618,7 +626,7 @@ impl Scalar {
             Scalar::UInt64(v) => v.to_string(),
             Scalar::Float32(v) => v.to_string(),
             Scalar::Float64(v) => v.to_string(),
-            Scalar::Null => panic!("Cannot convert Null to String"),
+            Scalar::Null(_) => panic!("Cannot convert Null to String"),
             #[cfg(feature = "datetime")]
             Scalar::Datetime32(v) => v.to_string(),
             #[cfg(feature = "datetime")]
@@ -651,7 +659,7 @@ impl Scalar {
                     panic!("i32 out of range for dt32 (negative value)")
                 }
             }
-            Scalar::Null => panic!("Cannot convert Null to dt32"),
+            Scalar::Null(_) => panic!("Cannot convert Null to dt32"),
             Scalar::Boolean(b) => {
                 if *b {
                     1
@@ -744,7 +752,7 @@ impl Scalar {
                     panic!("i64 out of range for dt64 (negative value)")
                 }
             }
-            Scalar::Null => panic!("Cannot convert Null to dt64"),
+            Scalar::Null(_) => panic!("Cannot convert Null to dt64"),
             Scalar::Boolean(b) => {
                 if *b {
                     1
@@ -813,7 +821,7 @@ impl Scalar {
             Scalar::UInt64(v) => Some(*v != 0),
             Scalar::Float32(v) => Some(*v != 0.0),
             Scalar::Float64(v) => Some(*v != 0.0),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => {
                 let s = s.trim();
                 if s.is_empty() {
@@ -875,7 +883,7 @@ impl Scalar {
             Scalar::UInt64(v) => i8::try_from(*v).ok(),
             Scalar::Float32(v) => i8::try_from(*v as i32).ok(),
             Scalar::Float64(v) => i8::try_from(*v as i32).ok(),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<i8>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i8>().ok(),
@@ -904,7 +912,7 @@ impl Scalar {
             Scalar::UInt64(v) => i16::try_from(*v).ok(),
             Scalar::Float32(v) => i16::try_from(*v as i32).ok(),
             Scalar::Float64(v) => i16::try_from(*v as i32).ok(),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<i16>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i16>().ok(),
@@ -936,7 +944,7 @@ impl Scalar {
             Scalar::UInt64(v) => i32::try_from(*v).ok(),
             Scalar::Float32(v) => Some(*v as i32),
             Scalar::Float64(v) => Some(*v as i32),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<i32>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i32>().ok(),
@@ -974,7 +982,7 @@ impl Scalar {
             }
             Scalar::Float32(v) => Some(*v as i64),
             Scalar::Float64(v) => Some(*v as i64),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<i64>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<i64>().ok(),
@@ -1006,7 +1014,7 @@ impl Scalar {
             Scalar::UInt64(v) => u8::try_from(*v).ok(),
             Scalar::Float32(v) => u8::try_from(*v as i32).ok(),
             Scalar::Float64(v) => u8::try_from(*v as i32).ok(),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<u8>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u8>().ok(),
@@ -1038,7 +1046,7 @@ impl Scalar {
             Scalar::UInt64(v) => u16::try_from(*v).ok(),
             Scalar::Float32(v) => u16::try_from(*v as i32).ok(),
             Scalar::Float64(v) => u16::try_from(*v as i32).ok(),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<u16>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u16>().ok(),
@@ -1070,7 +1078,7 @@ impl Scalar {
             Scalar::UInt64(v) => u32::try_from(*v).ok(),
             Scalar::Float32(v) => Some(*v as u32),
             Scalar::Float64(v) => Some(*v as u32),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<u32>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u32>().ok(),
@@ -1138,7 +1146,7 @@ impl Scalar {
                     None
                 }
             }
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<u64>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<u64>().ok(),
@@ -1183,7 +1191,7 @@ impl Scalar {
             Scalar::Float32(v) => Some(*v),
             Scalar::Float64(v) => Some(*v as f32),
             Scalar::Boolean(v) => Some(if *v { 1.0 } else { 0.0 }),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<f32>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<f32>().ok(),
@@ -1215,7 +1223,7 @@ impl Scalar {
             Scalar::Float32(v) => Some(*v as f64),
             Scalar::Float64(v) => Some(*v),
             Scalar::Boolean(v) => Some(if *v { 1.0 } else { 0.0 }),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::String32(s) => s.parse::<f64>().ok(),
             #[cfg(feature = "large_string")]
             Scalar::String64(s) => s.parse::<f64>().ok(),
@@ -1250,7 +1258,7 @@ impl Scalar {
             Scalar::UInt64(v) => Some(v.to_string()),
             Scalar::Float32(v) => Some(v.to_string()),
             Scalar::Float64(v) => Some(v.to_string()),
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             #[cfg(feature = "datetime")]
             Scalar::Datetime32(v) => Some(v.to_string()),
             #[cfg(feature = "datetime")]
@@ -1280,7 +1288,7 @@ impl Scalar {
                     None
                 }
             }
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::Boolean(b) => Some(if *b { 1 } else { 0 }),
             Scalar::Int64(v) => {
                 if *v >= 0 && *v <= u32::MAX as i64 {
@@ -1364,7 +1372,7 @@ impl Scalar {
                     None
                 }
             }
-            Scalar::Null => None,
+            Scalar::Null(_) => None,
             Scalar::Boolean(b) => Some(if *b { 1 } else { 0 }),
             Scalar::Int32(v) => {
                 if *v >= 0 {
@@ -1416,6 +1424,172 @@ impl Scalar {
         }
     }
 
+    /// Returns true for `Scalar::Null`, whatever its type.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Scalar::Null(_))
+    }
+
+    /// Arrow type of the value, matching `Array::arrow_type` for the array that
+    /// `array_from_value` would build.
+    ///
+    /// A typed null reports its type. `Interval` carries no unit and reports `Null`.
+    pub fn arrow_type(&self) -> ArrowType {
+        match self {
+            Scalar::Null(t) => t.clone(),
+            Scalar::Boolean(_) => ArrowType::Boolean,
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int8(_) => ArrowType::Int8,
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int16(_) => ArrowType::Int16,
+            Scalar::Int32(_) => ArrowType::Int32,
+            Scalar::Int64(_) => ArrowType::Int64,
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt8(_) => ArrowType::UInt8,
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt16(_) => ArrowType::UInt16,
+            Scalar::UInt32(_) => ArrowType::UInt32,
+            Scalar::UInt64(_) => ArrowType::UInt64,
+            Scalar::Float32(_) => ArrowType::Float32,
+            Scalar::Float64(_) => ArrowType::Float64,
+            Scalar::String32(_) => ArrowType::String,
+            #[cfg(feature = "large_string")]
+            Scalar::String64(_) => ArrowType::LargeString,
+            #[cfg(feature = "datetime")]
+            Scalar::Datetime32(_) => ArrowType::Date32,
+            #[cfg(feature = "datetime")]
+            Scalar::Datetime64(_) => ArrowType::Date64,
+            #[cfg(feature = "datetime")]
+            Scalar::Interval => ArrowType::Null,
+        }
+    }
+
+    /// Casts to the `target` type with the same rules as [`Array::cast`].
+    ///
+    /// Numeric values out of range for the target are an error, and text that
+    /// fails to parse as the target becomes a null of that type. A null casts to
+    /// a null of the target type.
+    ///
+    /// # Errors
+    /// - `TypeError` for any combination `Array::cast` does not support.
+    /// - `Overflow` if a numeric value does not fit the target.
+    pub fn cast(&self, target: ArrowType) -> Result<Scalar, MinarrowError> {
+        match self {
+            Scalar::Null(_) => return Ok(Scalar::Null(target)),
+            #[cfg(feature = "datetime")]
+            Scalar::Interval => {
+                return Err(MinarrowError::TypeError {
+                    from: "Scalar",
+                    to: "ArrowType",
+                    message: Some(format!("cannot cast Interval to {}", target)),
+                });
+            }
+            _ => {}
+        }
+        let cast = self.clone().array_from_value(1).cast(target)?;
+        Ok(cast.get_scalar(0).expect("cast preserves length"))
+    }
+
+    /// Returns the value as an `f64` when the conversion is exact.
+    ///
+    /// Every float and every integer of at most 32 bits promotes losslessly.
+    /// 64-bit integers are accepted only when they round-trip, i.e. up to 2^53
+    /// in magnitude or otherwise exactly representable.
+    ///
+    /// Unlike [`Scalar::f64`] this never panics, and unlike [`Scalar::try_f64`]
+    /// it does not parse strings or round.
+    ///
+    /// # Errors
+    /// - `NullError` for `Scalar::Null`.
+    /// - `LossyCast` for a 64-bit integer with no exact `f64`.
+    /// - `TypeError` for non-numeric values.
+    pub fn as_f64(&self) -> Result<f64, MinarrowError> {
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int8(v) => Ok(*v as f64),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int16(v) => Ok(*v as f64),
+            Scalar::Int32(v) => Ok(*v as f64),
+            Scalar::Int64(v) => exact_numeric(*v, "f64"),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt8(v) => Ok(*v as f64),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt16(v) => Ok(*v as f64),
+            Scalar::UInt32(v) => Ok(*v as f64),
+            Scalar::UInt64(v) => exact_numeric(*v, "f64"),
+            Scalar::Float32(v) => Ok(*v as f64),
+            Scalar::Float64(v) => Ok(*v),
+            other => Err(extract_error(other, "f64")),
+        }
+    }
+
+    /// Returns the value as an `i64` when the conversion is exact.
+    ///
+    /// Every signed integer and every unsigned integer of at most 32 bits
+    /// promotes losslessly. A `UInt64` must be at most `i64::MAX`, and a float
+    /// must be a whole number within range.
+    ///
+    /// # Errors
+    /// - `NullError` for `Scalar::Null`.
+    /// - `Overflow` for a value outside the `i64` range, or `NaN`.
+    /// - `LossyCast` for a float with a fractional part.
+    /// - `TypeError` for non-numeric values.
+    pub fn as_i64(&self) -> Result<i64, MinarrowError> {
+        match self {
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int8(v) => Ok(*v as i64),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int16(v) => Ok(*v as i64),
+            Scalar::Int32(v) => Ok(*v as i64),
+            Scalar::Int64(v) => Ok(*v),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt8(v) => Ok(*v as i64),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt16(v) => Ok(*v as i64),
+            Scalar::UInt32(v) => Ok(*v as i64),
+            Scalar::UInt64(v) => exact_numeric(*v, "i64"),
+            Scalar::Float32(v) => exact_numeric(*v, "i64"),
+            Scalar::Float64(v) => exact_numeric(*v, "i64"),
+            other => Err(extract_error(other, "i64")),
+        }
+    }
+
+    /// Renders the value together with its type, for logs and error messages.
+    ///
+    /// Numbers use a literal-style suffix, e.g. `42i64` or `1.25f32`, strings are
+    /// quoted and suffixed, e.g. `"abc"str32`, and booleans render as `bool(true)`.
+    /// Typed nulls render as e.g. `null(Int64)`, and untyped ones as `null`.
+    pub fn to_annotated_string(&self) -> String {
+        match self {
+            Scalar::Null(ArrowType::Null) => "null".to_string(),
+            Scalar::Null(t) => format!("null({})", t),
+            Scalar::Boolean(v) => format!("bool({})", v),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int8(v) => format!("{}i8", v),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::Int16(v) => format!("{}i16", v),
+            Scalar::Int32(v) => format!("{}i32", v),
+            Scalar::Int64(v) => format!("{}i64", v),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt8(v) => format!("{}u8", v),
+            #[cfg(feature = "extended_numeric_types")]
+            Scalar::UInt16(v) => format!("{}u16", v),
+            Scalar::UInt32(v) => format!("{}u32", v),
+            Scalar::UInt64(v) => format!("{}u64", v),
+            Scalar::Float32(v) => format!("{:?}f32", v),
+            Scalar::Float64(v) => format!("{:?}f64", v),
+            Scalar::String32(v) => format!("{:?}str32", v),
+            #[cfg(feature = "large_string")]
+            Scalar::String64(v) => format!("{:?}str64", v),
+            #[cfg(feature = "datetime")]
+            Scalar::Datetime32(v) => format!("{}dt32", v),
+            #[cfg(feature = "datetime")]
+            Scalar::Datetime64(v) => format!("{}dt64", v),
+            #[cfg(feature = "datetime")]
+            Scalar::Interval => "interval".to_string(),
+        }
+    }
+
     /// Converts a scalar Value to an Array by repeating the scalar `len` times.
     pub fn array_from_value(self, len: usize) -> Array {
         match self {
@@ -1517,7 +1691,7 @@ impl Scalar {
                 }
                 Array::from_string64(arr)
             }
-            Scalar::Null => {
+            Scalar::Null(ArrowType::Null) => {
                 // Allocate with null mask for len elements (all null)
                 let arr = BooleanArray {
                     data: Bitmask::new_set_all(len, false),
@@ -1527,6 +1701,7 @@ impl Scalar {
                 };
                 Array::from_bool(arr)
             }
+            Scalar::Null(t) => Array::null_array(&t, len),
             #[cfg(feature = "datetime")]
             Scalar::Datetime32(v) => {
                 let mut arr = DatetimeArray::<i32>::with_capacity(len, false, None);
@@ -1549,6 +1724,46 @@ impl Scalar {
     }
 }
 
+/// Converts `v` into `D`, rejecting any value that does not round-trip exactly.
+#[cfg(feature = "scalar_type")]
+fn exact_numeric<S, D>(v: S, target: &'static str) -> Result<D, MinarrowError>
+where
+    S: num_traits::NumCast + Copy + PartialEq + std::fmt::Display,
+    D: num_traits::NumCast + Copy,
+{
+    let Some(d) = <D as num_traits::NumCast>::from(v) else {
+        return Err(MinarrowError::Overflow {
+            value: v.to_string(),
+            target,
+        });
+    };
+    if <S as num_traits::NumCast>::from(d) != Some(v) {
+        return Err(MinarrowError::LossyCast {
+            value: v.to_string(),
+            target,
+        });
+    }
+    Ok(d)
+}
+
+/// Error for a typed extractor called on a null or non-numeric scalar.
+#[cfg(feature = "scalar_type")]
+fn extract_error(value: &Scalar, target: &'static str) -> MinarrowError {
+    match value {
+        Scalar::Null(t) => MinarrowError::NullError {
+            message: Some(format!(
+                "cannot extract {} from a null of type {}",
+                target, t
+            )),
+        },
+        other => MinarrowError::TypeError {
+            from: "Scalar",
+            to: target,
+            message: Some(format!("{} is not numeric", other.to_annotated_string())),
+        },
+    }
+}
+
 #[cfg(feature = "hash")]
 impl Eq for Scalar {}
 
@@ -1557,7 +1772,7 @@ impl std::hash::Hash for Scalar {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
-            Scalar::Null => {}
+            Scalar::Null(t) => t.hash(state),
             Scalar::Boolean(v) => v.hash(state),
             #[cfg(feature = "extended_numeric_types")]
             Scalar::Int8(v) => v.hash(state),
@@ -1659,6 +1874,67 @@ impl From<&str> for Scalar {
     }
 }
 
+// reciprocal conversions, which only accept the matching variant
+#[cfg(feature = "scalar_type")]
+macro_rules! impl_scalar_try_from {
+    ($($variant:ident: $t:ty),+ $(,)?) => {
+        $(
+            impl TryFrom<Scalar> for $t {
+                type Error = MinarrowError;
+
+                #[inline]
+                fn try_from(v: Scalar) -> Result<Self, Self::Error> {
+                    match v {
+                        Scalar::$variant(x) => Ok(x),
+                        other => Err(try_from_error(&other, stringify!($t), stringify!($variant))),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "scalar_type")]
+impl_scalar_try_from!(Boolean: bool, Int32: i32, Int64: i64, UInt32: u32, UInt64: u64);
+
+#[cfg(feature = "scalar_type")]
+impl_scalar_try_from!(Float32: f32, Float64: f64);
+
+#[cfg(all(feature = "scalar_type", feature = "extended_numeric_types"))]
+impl_scalar_try_from!(Int8: i8, Int16: i16, UInt8: u8, UInt16: u16);
+
+#[cfg(feature = "scalar_type")]
+impl TryFrom<Scalar> for String {
+    type Error = MinarrowError;
+
+    #[inline]
+    fn try_from(v: Scalar) -> Result<Self, Self::Error> {
+        match v {
+            Scalar::String32(s) => Ok(s),
+            #[cfg(feature = "large_string")]
+            Scalar::String64(s) => Ok(s),
+            other => Err(try_from_error(&other, "String", "String32")),
+        }
+    }
+}
+
+/// Error for a `TryFrom<Scalar>` conversion given a null or another variant.
+#[cfg(feature = "scalar_type")]
+fn try_from_error(value: &Scalar, target: &'static str, expected: &str) -> MinarrowError {
+    match value {
+        Scalar::Null(_) => extract_error(value, target),
+        other => MinarrowError::TypeError {
+            from: "Scalar",
+            to: target,
+            message: Some(format!(
+                "expected a {} scalar, found {}",
+                expected,
+                other.to_annotated_string()
+            )),
+        },
+    }
+}
+
 #[cfg(feature = "scalar_type")]
 use std::ops::{Add, Mul, Sub};
 
@@ -1671,7 +1947,7 @@ impl Add for Scalar {
 
         match (self, rhs) {
             // Nulls propagate
-            (Null, _) | (_, Null) => Null,
+            (Null(t), _) | (_, Null(t)) => Null(t),
 
             // Float promotion
             (Float64(a), b) => Float64(a + b.f64()),
@@ -1754,7 +2030,7 @@ impl Sub for Scalar {
         use Scalar::*;
 
         match (self, rhs) {
-            (Null, _) | (_, Null) => Null,
+            (Null(t), _) | (_, Null(t)) => Null(t),
 
             (Float64(a), b) => Float64(a - b.f64()),
             (a, Float64(b)) => Float64(a.f64() - b),
@@ -1825,7 +2101,7 @@ impl Mul for Scalar {
         use Scalar::*;
 
         match (self, rhs) {
-            (Null, _) | (_, Null) => Null,
+            (Null(t), _) | (_, Null(t)) => Null(t),
 
             (Float64(a), b) => Float64(a * b.f64()),
             (a, Float64(b)) => Float64(a.f64() * b),
@@ -1923,7 +2199,7 @@ impl Pow<Scalar> for Scalar {
 
         match (self, rhs) {
             // Nulls propagate
-            (Null, _) | (_, Null) => Null,
+            (Null(t), _) | (_, Null(t)) => Null(t),
 
             #[cfg(feature = "datetime")]
             (Interval, _) => panic!("Cannot exponentiate Interval"),
@@ -2055,6 +2331,147 @@ mod tests {
     use super::*;
     use crate::Scalar::{Float32, Float64, Int32, String32, String64};
 
+    #[test]
+    fn test_to_annotated_string() {
+        assert_eq!(Scalar::Int64(42).to_annotated_string(), "42i64");
+        assert_eq!(Scalar::UInt32(7).to_annotated_string(), "7u32");
+        assert_eq!(Scalar::Float32(1.25).to_annotated_string(), "1.25f32");
+        assert_eq!(Scalar::Float64(1.0).to_annotated_string(), "1.0f64");
+        assert_eq!(
+            Scalar::String32("abc".to_owned()).to_annotated_string(),
+            "\"abc\"str32"
+        );
+        assert_eq!(Scalar::Boolean(true).to_annotated_string(), "bool(true)");
+        assert_eq!(Scalar::Null(ArrowType::Null).to_annotated_string(), "null");
+    }
+
+    #[test]
+    fn test_try_from_scalar() {
+        assert_eq!(i32::try_from(Scalar::from(5)).unwrap(), 5);
+        assert_eq!(i64::try_from(Scalar::from(5i64)).unwrap(), 5);
+        assert_eq!(f64::try_from(Scalar::from(2.5)).unwrap(), 2.5);
+        assert!(bool::try_from(Scalar::from(true)).unwrap());
+        assert_eq!(Scalar::from("abc"), String32("abc".into()));
+        assert_eq!(String::try_from(Scalar::from("abc")).unwrap(), "abc");
+
+        assert!(matches!(
+            i32::try_from(Scalar::Int64(5)),
+            Err(MinarrowError::TypeError { to: "i32", .. })
+        ));
+        assert!(matches!(
+            String::try_from(Scalar::Boolean(true)),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(matches!(
+            f64::try_from(Scalar::Null(ArrowType::Float64)),
+            Err(MinarrowError::NullError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_typed_null() {
+        let null = Scalar::Null(ArrowType::Int64);
+        assert!(null.is_null());
+        assert!(!Scalar::Int64(0).is_null());
+        assert_eq!(null.arrow_type(), ArrowType::Int64);
+        assert_eq!(null.to_annotated_string(), "null(Int64)");
+        assert_ne!(null, Scalar::Null(ArrowType::Null));
+        assert_eq!(Scalar::Int64(1) + null.clone(), null);
+
+        let arr = null.array_from_value(3);
+        assert_eq!(arr.arrow_type(), ArrowType::Int64);
+        assert_eq!(arr.null_count(), 3);
+
+        let arr = Array::from(Scalar::Null(ArrowType::Int64));
+        assert_eq!(arr.arrow_type(), ArrowType::Int64);
+        assert_eq!((arr.len(), arr.null_count()), (1, 1));
+        assert!(matches!(
+            Array::from(Scalar::Null(ArrowType::Null)),
+            Array::Null
+        ));
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(
+            Scalar::Int32(5).cast(ArrowType::Float64).unwrap(),
+            Float64(5.0)
+        );
+        assert_eq!(
+            Scalar::Float64(2.5).cast(ArrowType::String).unwrap(),
+            String32("2.5".into())
+        );
+        assert_eq!(
+            String32(" 42 ".into()).cast(ArrowType::Int64).unwrap(),
+            Scalar::Int64(42)
+        );
+        assert_eq!(
+            String32("x".into()).cast(ArrowType::Int64).unwrap(),
+            Scalar::Null(ArrowType::Int64)
+        );
+        assert_eq!(
+            Scalar::Null(ArrowType::Int64)
+                .cast(ArrowType::String)
+                .unwrap(),
+            Scalar::Null(ArrowType::String)
+        );
+        assert!(matches!(
+            Scalar::Int64(-1).cast(ArrowType::UInt32),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        assert!(matches!(
+            Scalar::Boolean(true).cast(ArrowType::Int32),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_as_f64_and_as_i64() {
+        assert_eq!(Int32(-3).as_f64().unwrap(), -3.0);
+        assert_eq!(Float32(0.5).as_f64().unwrap(), 0.5);
+        assert_eq!(Scalar::Int64(1 << 53).as_f64().unwrap(), 9007199254740992.0);
+        assert_eq!(
+            Scalar::UInt64(1 << 60).as_f64().unwrap(),
+            (1u64 << 60) as f64
+        );
+        assert!(matches!(
+            Scalar::Int64((1 << 53) + 1).as_f64(),
+            Err(MinarrowError::LossyCast { .. })
+        ));
+        assert!(matches!(
+            Scalar::Int64(i64::MAX).as_f64(),
+            Err(MinarrowError::LossyCast { .. })
+        ));
+
+        assert_eq!(Scalar::UInt32(u32::MAX).as_i64().unwrap(), u32::MAX as i64);
+        assert_eq!(Float64(-2.0).as_i64().unwrap(), -2);
+        assert!(matches!(
+            Float64(2.5).as_i64(),
+            Err(MinarrowError::LossyCast { .. })
+        ));
+        assert!(matches!(
+            Float64(f64::NAN).as_i64(),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        assert!(matches!(
+            Scalar::UInt64(u64::MAX).as_i64(),
+            Err(MinarrowError::Overflow { .. })
+        ));
+
+        assert!(matches!(
+            Scalar::Null(ArrowType::Float64).as_f64(),
+            Err(MinarrowError::NullError { .. })
+        ));
+        assert!(matches!(
+            String32("1".into()).as_i64(),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(matches!(
+            Scalar::Boolean(true).as_f64(),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
     #[test]
     fn test_bool() {
         assert_eq!(Scalar::Boolean(true).bool(), true);
@@ -2089,7 +2506,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to bool")]
     fn test_bool_null_panics() {
-        Scalar::Null.bool();
+        Scalar::Null(ArrowType::Null).bool();
     }
 
     #[test]
@@ -2146,7 +2563,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to i8")]
     fn test_i8_null_panics() {
-        Scalar::Null.i8();
+        Scalar::Null(ArrowType::Null).i8();
     }
 
     #[cfg(feature = "extended_numeric_types")]
@@ -2197,7 +2614,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to i32")]
     fn test_i32_null_panics() {
-        Scalar::Null.i32();
+        Scalar::Null(ArrowType::Null).i32();
     }
 
     #[test]
@@ -2257,7 +2674,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to f32")]
     fn test_f32_null_panics() {
-        Scalar::Null.f32();
+        Scalar::Null(ArrowType::Null).f32();
     }
 
     #[test]
@@ -2287,7 +2704,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to String")]
     fn test_str_null_panics() {
-        Scalar::Null.str();
+        Scalar::Null(ArrowType::Null).str();
     }
 
     #[cfg(feature = "datetime")]
@@ -2307,14 +2724,14 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot convert Null to dt32")]
     fn test_dt32_null_panics() {
-        Scalar::Null.dt32();
+        Scalar::Null(ArrowType::Null).dt32();
     }
 
     #[cfg(feature = "datetime")]
     #[test]
     #[should_panic(expected = "Cannot convert Null to dt64")]
     fn test_dt64_null_panics() {
-        Scalar::Null.dt64();
+        Scalar::Null(ArrowType::Null).dt64();
     }
 
     #[cfg(feature = "datetime")]
@@ -2341,7 +2758,7 @@ mod tests {
     #[test]
     #[should_panic]
     fn test_any_null_panics() {
-        Scalar::Null.u64();
+        Scalar::Null(ArrowType::Null).u64();
     }
 
     #[cfg(feature = "hash")]
@@ -2362,7 +2779,10 @@ mod tests {
             hash_one(&String32("hello".into())),
             hash_one(&String32("hello".into()))
         );
-        assert_eq!(hash_one(&Scalar::Null), hash_one(&Scalar::Null));
+        assert_eq!(
+            hash_one(&Scalar::Null(ArrowType::Null)),
+            hash_one(&Scalar::Null(ArrowType::Null))
+        );
     }
 
     #[cfg(feature = "hash")]
@@ -2403,7 +2823,7 @@ mod tests {
     fn test_scalar_eq() {
         assert_eq!(Int32(5), Int32(5));
         assert_ne!(Int32(5), Int32(6));
-        assert_eq!(Scalar::Null, Scalar::Null);
+        assert_eq!(Scalar::Null(ArrowType::Null), Scalar::Null(ArrowType::Null));
     }
 
     #[cfg(test)]
@@ -2420,7 +2840,7 @@ mod tests {
             assert_eq!(Scalar::UInt64(1).try_bool(), Some(true));
             assert_eq!(Scalar::Float32(0.0).try_bool(), Some(false));
             assert_eq!(Scalar::Float64(-3.2).try_bool(), Some(true));
-            assert_eq!(Scalar::Null.try_bool(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_bool(), None);
             assert_eq!(Scalar::String32("t".into()).try_bool(), Some(true));
             assert_eq!(Scalar::String32("False".into()).try_bool(), Some(false));
             assert_eq!(Scalar::String32("".into()).try_bool(), None);
@@ -2463,7 +2883,7 @@ mod tests {
             assert_eq!(Scalar::UInt16(200).try_i8(), None);
             assert_eq!(Scalar::Float32(12.0).try_i8(), Some(12));
             assert_eq!(Scalar::Float64(10.5).try_i8(), Some(10));
-            assert_eq!(Scalar::Null.try_i8(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_i8(), None);
             assert_eq!(Scalar::String32("44".into()).try_i8(), Some(44));
             assert_eq!(Scalar::String32("err".into()).try_i8(), None);
             assert_eq!(Scalar::Boolean(true).try_i8(), Some(1));
@@ -2499,7 +2919,7 @@ mod tests {
             assert_eq!(Scalar::Float32(5.0).try_i16(), Some(5));
             assert_eq!(Scalar::Float32(40000.0).try_i16(), None);
             assert_eq!(Scalar::Float64(-7.9).try_i16(), Some(-7));
-            assert_eq!(Scalar::Null.try_i16(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_i16(), None);
             assert_eq!(Scalar::String32("9".into()).try_i16(), Some(9));
             assert_eq!(Scalar::String32("bad".into()).try_i16(), None);
             assert_eq!(Scalar::Boolean(true).try_i16(), Some(1));
@@ -2527,7 +2947,7 @@ mod tests {
             assert_eq!(Scalar::UInt64(u64::from(u32::MAX) + 1).try_i32(), None);
             assert_eq!(Scalar::Float32(11.7).try_i32(), Some(11));
             assert_eq!(Scalar::Float64(8.9).try_i32(), Some(8));
-            assert_eq!(Scalar::Null.try_i32(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_i32(), None);
             assert_eq!(Scalar::String32("10".into()).try_i32(), Some(10));
             assert_eq!(Scalar::String32("bad".into()).try_i32(), None);
             assert_eq!(Scalar::Boolean(true).try_i32(), Some(1));
@@ -2561,7 +2981,7 @@ mod tests {
             assert_eq!(Scalar::UInt64(u64::MAX).try_i64(), None);
             assert_eq!(Scalar::Float32(1.2).try_i64(), Some(1));
             assert_eq!(Scalar::Float64(-1.2).try_i64(), Some(-1));
-            assert_eq!(Scalar::Null.try_i64(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_i64(), None);
             assert_eq!(Scalar::String32("99".into()).try_i64(), Some(99));
             assert_eq!(Scalar::String32("bad".into()).try_i64(), None);
             assert_eq!(Scalar::Boolean(true).try_i64(), Some(1));
@@ -2602,7 +3022,7 @@ mod tests {
             assert_eq!(Scalar::String32("7".into()).try_u8(), Some(7));
             assert_eq!(Scalar::String32("bad".into()).try_u8(), None);
             assert_eq!(Scalar::Boolean(true).try_u8(), Some(1));
-            assert_eq!(Scalar::Null.try_u8(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_u8(), None);
 
             #[cfg(feature = "extended_numeric_types")]
             {
@@ -2633,8 +3053,8 @@ mod tests {
             assert_eq!(Scalar::Boolean(false).try_f64(), Some(0.0));
             assert_eq!(Scalar::String32("1.0".into()).try_f32(), Some(1.0));
             assert_eq!(Scalar::String32("bad".into()).try_f32(), None);
-            assert_eq!(Scalar::Null.try_f32(), None);
-            assert_eq!(Scalar::Null.try_f64(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_f32(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_f64(), None);
 
             #[cfg(feature = "extended_numeric_types")]
             {
@@ -2663,7 +3083,7 @@ mod tests {
             assert_eq!(Scalar::Boolean(true).try_str(), Some("true".to_string()));
             assert_eq!(Scalar::Float32(1.0).try_str(), Some("1".to_string()));
             assert_eq!(Scalar::Int64(-5).try_str(), Some("-5".to_string()));
-            assert_eq!(Scalar::Null.try_str(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_str(), None);
 
             #[cfg(feature = "extended_numeric_types")]
             {
@@ -2702,7 +3122,7 @@ mod tests {
             assert_eq!(Scalar::Float64(-5.0).try_dt32(), None);
             assert_eq!(Scalar::String32("33".into()).try_dt32(), Some(33));
             assert_eq!(Scalar::String32("bad".into()).try_dt32(), None);
-            assert_eq!(Scalar::Null.try_dt32(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_dt32(), None);
             assert_eq!(Scalar::Boolean(true).try_dt32(), Some(1));
             assert_eq!(Scalar::Boolean(false).try_dt32(), Some(0));
             assert_eq!(Scalar::Datetime64(1).try_dt32(), Some(1));
@@ -2738,7 +3158,7 @@ mod tests {
             assert_eq!(Scalar::Float64(-5.0).try_dt64(), None);
             assert_eq!(Scalar::String32("8".into()).try_dt64(), Some(8));
             assert_eq!(Scalar::String32("notnum".into()).try_dt64(), None);
-            assert_eq!(Scalar::Null.try_dt64(), None);
+            assert_eq!(Scalar::Null(ArrowType::Null).try_dt64(), None);
             assert_eq!(Scalar::Boolean(true).try_dt64(), Some(1));
             assert_eq!(Scalar::Boolean(false).try_dt64(), Some(0));
             assert_eq!(Scalar::Datetime32(12).try_dt64(), Some(12));
@@ -0,0 +1,140 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Sort Module** - *Row Ordering Options*
+//!
+//! Defines the ordering options used by the array and table sorting methods.
+
+use std::cmp::Ordering;
+
+use vec64::Vec64;
+
+use crate::Bitmask;
+
+/// # SortOrder
+///
+/// Direction in which a sort key is ordered.
+///
+/// Nulls are always placed last, regardless of direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortOrder {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Applies this direction to an ascending `Ordering`.
+    #[inline]
+    pub fn apply(self, ord: Ordering) -> Ordering {
+        match self {
+            SortOrder::Ascending => ord,
+            SortOrder::Descending => ord.reverse(),
+        }
+    }
+}
+
+/// # NullOrder
+///
+/// Placement of null entries when sorting an array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NullOrder {
+    /// Nulls precede all valid values.
+    First,
+    /// Nulls follow all valid values.
+    #[default]
+    Last,
+}
+
+/// # Side
+///
+/// Which end of a run of equal values `searchsorted` reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Side {
+    /// The first index at which the value could be inserted.
+    #[default]
+    Left,
+    /// The last index at which the value could be inserted.
+    Right,
+}
+
+/// Returns the insertion index in ascending `data` for each of `values`,
+/// with `less` as the strict ordering.
+pub(crate) fn search_sorted<T: Copy>(
+    data: &[T],
+    values: &[T],
+    side: Side,
+    less: impl Fn(T, T) -> bool,
+) -> Vec64<usize> {
+    let mut out = Vec64::with_capacity(values.len());
+    for &v in values {
+        let idx = match side {
+            Side::Left => data.partition_point(|&x| less(x, v)),
+            Side::Right => data.partition_point(|&x| !less(v, x)),
+        };
+        out.push(idx);
+    }
+    out
+}
+
+/// Returns the stable permutation that sorts `len` entries by `cmp`, with nulls
+/// placed according to `nulls`.
+///
+/// `cmp` is only called on valid positions. Null positions keep their
+/// original relative order, so an all-null input yields the identity.
+pub(crate) fn sort_permutation(
+    len: usize,
+    null_mask: Option<&Bitmask>,
+    nulls: NullOrder,
+    cmp: impl Fn(usize, usize) -> Ordering,
+) -> Vec64<u32> {
+    assert!(
+        len <= u32::MAX as usize,
+        "sort_permutation: length {} exceeds u32 index range",
+        len
+    );
+    let (mut valid, null_rows): (Vec<u32>, Vec<u32>) = match null_mask {
+        Some(mask) if mask.has_cleared() => (0..len as u32).partition(|&i| mask.get(i as usize)),
+        _ => ((0..len as u32).collect(), Vec::new()),
+    };
+    valid.sort_by(|&a, &b| cmp(a as usize, b as usize));
+
+    let mut out = Vec64::with_capacity(len);
+    match nulls {
+        NullOrder::First => {
+            out.extend_from_slice(&null_rows);
+            out.extend_from_slice(&valid);
+        }
+        NullOrder::Last => {
+            out.extend_from_slice(&valid);
+            out.extend_from_slice(&null_rows);
+        }
+    }
+    out
+}
+
+/// Builds the null mask for values gathered in `perm` order, or `None` if the
+/// source has no mask.
+pub(crate) fn permute_null_mask(null_mask: Option<&Bitmask>, perm: &[u32]) -> Option<Bitmask> {
+    let mask = null_mask?;
+    let mut out = Bitmask::new_set_all(perm.len(), true);
+    for (pos, &i) in perm.iter().enumerate() {
+        if !mask.get(i as usize) {
+            out.set(pos, false);
+        }
+    }
+    Some(out)
+}
@@ -71,6 +71,19 @@ pub enum IntervalUnit {
     MonthDaysNs,
 }
 
+/// # DurationRounding
+///
+/// What `DatetimeArray::add_duration_units` does with a duration that is not a
+/// whole number of the array's own unit, e.g. 1500 µs added to a millisecond array.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum DurationRounding {
+    /// Fail with `MinarrowError::LossyCast`.
+    #[default]
+    Error,
+    /// Round to the nearest whole unit, with ties away from zero.
+    Nearest,
+}
+
 impl Display for TimeUnit {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
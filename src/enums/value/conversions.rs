@@ -652,7 +652,7 @@ macro_rules! impl_tryfrom_value_numeric {
             fn try_from(v: Value) -> Result<Self, Self::Error> {
                 match v {
                     Value::Scalar(s) => match s {
-                        Scalar::Null => Err(MinarrowError::TypeError {
+                        Scalar::Null(_) => Err(MinarrowError::TypeError {
                             from: "Value::Scalar(Null)",
                             to: stringify!($t),
                             message: Some("Cannot convert Null to numeric type".to_owned()),
@@ -695,7 +695,7 @@ macro_rules! impl_tryfrom_value_option {
             fn try_from(v: Value) -> Result<Self, Self::Error> {
                 match v {
                     Value::Scalar(s) => match s {
-                        Scalar::Null => Ok(None),
+                        Scalar::Null(_) => Ok(None),
                         _ => Ok(Some(s.$method())),
                     },
                     _ => Err(MinarrowError::TypeError {
@@ -1578,7 +1578,7 @@ macro_rules! val_scalar_str64 {
 #[macro_export]
 macro_rules! val_scalar_null {
     () => {
-        $crate::Value::from($crate::Scalar::Null)
+        $crate::Value::from($crate::Scalar::Null($crate::ArrowType::Null))
     };
 }
 
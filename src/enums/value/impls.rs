@@ -221,7 +221,7 @@ impl Concatenate for Value {
                         Ok(Value::Array(Arc::new(crate::Array::from_datetime_i64(arr))))
                     }
                     // Null + Null
-                    (Null, Null) => Ok(Value::Array(Arc::new(crate::Array::Null))),
+                    (Null(_), Null(_)) => Ok(Value::Array(Arc::new(crate::Array::Null))),
                     // Mismatched scalar types
                     (lhs, rhs) => Err(MinarrowError::IncompatibleTypeError {
                         from: "Scalar",
@@ -440,7 +440,7 @@ impl Concatenate for Value {
 fn scalar_variant_name(scalar: &crate::Scalar) -> &'static str {
     use crate::Scalar::*;
     match scalar {
-        Null => "Null",
+        Null(_) => "Null",
         Boolean(_) => "Boolean",
         #[cfg(feature = "extended_numeric_types")]
         Int8(_) => "Int8",
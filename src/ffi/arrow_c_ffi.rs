@@ -54,14 +54,17 @@ use std::ffi::{CString, c_void};
 use std::sync::Arc;
 use std::{ptr, slice};
 
+#[cfg(feature = "views")]
+use crate::ArrayV;
+use crate::enums::error::MinarrowError;
 use crate::ffi::arrow_dtype::ArrowType;
 use crate::ffi::arrow_dtype::CategoricalIndexType;
 use crate::ffi::schema::Schema;
 use crate::structs::buffer::Buffer;
 use crate::structs::shared_buffer::SharedBuffer;
 use crate::{
-    Array, Bitmask, BooleanArray, CategoricalArray, Field, Float, FloatArray, Integer,
-    IntegerArray, MaskedArray, StringArray, TextArray, Vec64, vec64,
+    Array, Bitmask, BooleanArray, CategoricalArray, Field, FixedSizeBinaryArray, Float, FloatArray,
+    Integer, IntegerArray, MaskedArray, StringArray, TextArray, Vec64, vec64,
 };
 #[cfg(feature = "datetime")]
 use crate::{DatetimeArray, IntervalUnit, TemporalArray, TimeUnit};
@@ -117,6 +120,11 @@ impl ArrowArray {
     }
 }
 
+/// `ArrowSchema.flags` bit marking dictionary codes as ordered.
+const ARROW_FLAG_DICTIONARY_ORDERED: i64 = 1;
+/// `ArrowSchema.flags` bit marking the field as nullable.
+const ARROW_FLAG_NULLABLE: i64 = 2;
+
 /// ArrowSchema as per the Arrow C spec
 #[repr(C)]
 #[derive(Clone)]
@@ -262,19 +270,36 @@ unsafe extern "C" fn release_arrow_schema(s: *mut ArrowSchema) {
 }
 
 /// Constructs the Arrow C FFI format string for the given ArrowType.
+///
+/// # Panics
+/// Panics on a unit the Arrow format cannot express; see [`try_fmt_c`].
 pub fn fmt_c(dtype: ArrowType) -> CString {
+    try_fmt_c(&dtype).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Fallible form of [`fmt_c`].
+///
+/// # Errors
+/// - `TypeError` for a unit the Arrow format cannot express, such as
+///   `Timestamp(Days)` or `Time32(Nanoseconds)`
+pub(crate) fn try_fmt_c(dtype: &ArrowType) -> Result<CString, MinarrowError> {
     #[cfg(feature = "datetime")]
-    if let ArrowType::Timestamp(u, tz) = &dtype {
+    if let ArrowType::Timestamp(u, tz) = dtype {
         let unit_str = match u {
             TimeUnit::Seconds => "tss:",
             TimeUnit::Milliseconds => "tsm:",
             TimeUnit::Microseconds => "tsu:",
             TimeUnit::Nanoseconds => "tsn:",
-            TimeUnit::Days => panic!("Timestamp(Days) is invalid in Arrow C format"),
+            TimeUnit::Days => return Err(unsupported_unit(dtype)),
         };
         let tz_str = tz.as_deref().unwrap_or("");
         let format_str = format!("{}{}", unit_str, tz_str);
-        return CString::new(format_str).expect("CString formatting failed: invalid bytes");
+        return Ok(CString::new(format_str).expect("CString formatting failed: invalid bytes"));
+    }
+
+    if let ArrowType::FixedSizeBinary(width) = dtype {
+        let format_str = format!("w:{}", width);
+        return Ok(CString::new(format_str).expect("CString formatting failed: invalid bytes"));
     }
 
     let bytes: &'static [u8] = match dtype {
@@ -301,6 +326,9 @@ pub fn fmt_c(dtype: ArrowType) -> CString {
         #[cfg(feature = "large_string")]
         ArrowType::LargeString => b"U",
         ArrowType::Utf8View => b"vu",
+        ArrowType::FixedSizeBinary(_) => {
+            unreachable!("FixedSizeBinary case handled above")
+        }
 
         // ---- datetime ----
         #[cfg(feature = "datetime")]
@@ -312,26 +340,26 @@ pub fn fmt_c(dtype: ArrowType) -> CString {
         ArrowType::Time32(u) => match u {
             TimeUnit::Seconds => b"tts",
             TimeUnit::Milliseconds => b"ttm",
-            _ => panic!("Time32 supports Seconds or Milliseconds only"),
+            _ => return Err(unsupported_unit(dtype)),
         },
         #[cfg(feature = "datetime")]
         ArrowType::Time64(u) => match u {
             TimeUnit::Microseconds => b"ttu",
             TimeUnit::Nanoseconds => b"ttn",
-            _ => panic!("Time64 supports Microseconds or Nanoseconds only"),
+            _ => return Err(unsupported_unit(dtype)),
         },
 
         #[cfg(feature = "datetime")]
         ArrowType::Duration32(u) => match u {
             TimeUnit::Seconds => b"tDs",
             TimeUnit::Milliseconds => b"tDm",
-            _ => panic!("Duration32 supports Seconds or Milliseconds only"),
+            _ => return Err(unsupported_unit(dtype)),
         },
         #[cfg(feature = "datetime")]
         ArrowType::Duration64(u) => match u {
             TimeUnit::Microseconds => b"tDu",
             TimeUnit::Nanoseconds => b"tDn",
-            _ => panic!("Duration64 supports Microseconds or Nanoseconds only"),
+            _ => return Err(unsupported_unit(dtype)),
         },
 
         #[cfg(feature = "datetime")]
@@ -359,7 +387,17 @@ pub fn fmt_c(dtype: ArrowType) -> CString {
         },
     };
 
-    CString::new(bytes).expect("CString formatting failed: invalid bytes")
+    Ok(CString::new(bytes).expect("CString formatting failed: invalid bytes"))
+}
+
+/// Error for a temporal unit the Arrow C format has no code for.
+#[cfg(feature = "datetime")]
+fn unsupported_unit(dtype: &ArrowType) -> MinarrowError {
+    MinarrowError::TypeError {
+        from: "ArrowType",
+        to: "Arrow format string",
+        message: Some(format!("{:?} has no Arrow C format", dtype)),
+    }
 }
 
 #[cfg(feature = "datetime")]
@@ -491,6 +529,21 @@ pub fn export_to_c(array: Arc<Array>, schema: Schema) -> (*mut ArrowArray, *mut
     }
 }
 
+/// Exports the window of an `ArrayV` to Arrow C Data Interface pointers without copying.
+///
+/// The parent array's buffers are exported as-is and the view's window is
+/// expressed through the Arrow `offset` and `length` fields, which consumers
+/// apply to every buffer, including the validity bitmap.
+#[cfg(feature = "views")]
+pub fn export_view_to_c(view: &ArrayV, schema: Schema) -> (*mut ArrowArray, *mut ArrowSchema) {
+    let (c_arr, c_schema) = export_to_c(Arc::new(view.array.clone()), schema);
+    unsafe {
+        (*c_arr).offset = view.offset as i64;
+        (*c_arr).length = view.len() as i64;
+    }
+    (c_arr, c_schema)
+}
+
 /// Exports a Utf8 or LargeUtf8 string array to Arrow C format.
 fn export_string_array_to_c(
     array: &Arc<Array>,
@@ -567,7 +620,7 @@ fn export_categorical_array_to_c(
 
     let name_cstr = CString::new(schema.fields[0].name.clone()).unwrap();
 
-    let mut field = schema.fields[0].clone();
+    let mut field = (*schema.fields[0]).clone();
     field.dtype = match index_bits {
         #[cfg(feature = "default_categorical_8")]
         8 => ArrowType::Dictionary(crate::ffi::arrow_dtype::CategoricalIndexType::UInt8),
@@ -605,7 +658,14 @@ fn export_categorical_array_to_c(
         private_data: ptr::null_mut(),
     });
 
-    let flags = if field.nullable { 1 } else { 0 };
+    let mut flags = if field.nullable {
+        ARROW_FLAG_NULLABLE
+    } else {
+        0
+    };
+    if field.is_ordered_dictionary() {
+        flags |= ARROW_FLAG_DICTIONARY_ORDERED;
+    }
     let schema_box = Box::new(ArrowSchema {
         format: format_ptr,
         name: name_cstr.as_ptr(),
@@ -635,11 +695,34 @@ fn export_categorical_array_to_c(
 }
 
 /// Imports a Minarrow array from ArrowArray and ArrowSchema C pointers.
+///
+/// Panics on input that cannot be imported; see [`try_import_from_c`] for a
+/// fallible form.
+///
 /// # Safety
 /// Both pointers must be valid and follow the Arrow C Data Interface specification.
 pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSchema) -> Arc<Array> {
+    unsafe { try_import_from_c(arr_ptr, sch_ptr) }
+        .unwrap_or_else(|e| panic!("FFI import_from_c: {}", e))
+}
+
+/// Fallible form of [`import_from_c`].
+///
+/// Null pointers, and format strings or dictionary index types that do not map
+/// to a supported type, are reported as errors rather than panics. Buffers are
+/// still trusted to match the schema.
+///
+/// # Safety
+/// Both pointers must be valid, or null, and follow the Arrow C Data Interface
+/// specification.
+pub unsafe fn try_import_from_c(
+    arr_ptr: *const ArrowArray,
+    sch_ptr: *const ArrowSchema,
+) -> Result<Arc<Array>, MinarrowError> {
     if arr_ptr.is_null() || sch_ptr.is_null() {
-        panic!("FFI import_from_c: null pointer");
+        return Err(MinarrowError::NullError {
+            message: Some("try_import_from_c: null pointer".into()),
+        });
     }
     let arr = unsafe { &*arr_ptr };
     let sch = unsafe { &*sch_ptr };
@@ -719,14 +802,17 @@ pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSch
             };
             ArrowType::Timestamp(unit, tz)
         }
-        o => panic!("unsupported format {:?}", o),
+        _ if fmt.starts_with(b"w:") => ArrowType::FixedSizeBinary(
+            try_parse_fixed_size_width(fmt).ok_or_else(|| unsupported_format(fmt))?,
+        ),
+        _ => return Err(unsupported_format(fmt)),
     };
 
     // When default_categorical_8 is on without extended_categorical and Arrow sends
     // i32 dictionary indices, narrow them to u8 before building CategoricalArray<u8>
     #[cfg(all(feature = "default_categorical_8", not(feature = "extended_categorical")))]
     if is_dict && matches!(dtype, ArrowType::Int32 | ArrowType::UInt32) {
-        return unsafe { import_categorical_narrow_to_u8(arr, sch, None) };
+        return Ok(unsafe { import_categorical_narrow_to_u8(arr, sch, None) });
     }
 
     // if the array owns a dictionary, map the physical index dtype ➜ CategoricalIndexType
@@ -744,10 +830,13 @@ pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSch
             #[cfg(feature = "extended_numeric_types")]
             #[cfg(feature = "extended_categorical")]
             ArrowType::Int64 | ArrowType::UInt64 => CategoricalIndexType::UInt64,
-            _ => panic!(
-                "FFI import_from_c: unsupported dictionary index type {:?}",
-                dtype
-            ),
+            _ => {
+                return Err(MinarrowError::TypeError {
+                    from: "Arrow dictionary index",
+                    to: "CategoricalIndexType",
+                    message: Some(format!("unsupported dictionary index type {:?}", dtype)),
+                });
+            }
         })
     } else {
         None
@@ -755,10 +844,10 @@ pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSch
 
     if let Some(idx_ty) = maybe_cat_index {
         // SAFETY: we just verified pointers, types and dictionary presence
-        return unsafe { import_categorical(arr, sch, idx_ty, None) };
+        return Ok(unsafe { import_categorical(arr, sch, idx_ty, None) });
     }
 
-    if is_dict {
+    let array = if is_dict {
         unsafe {
             import_categorical(
                 arr,
@@ -796,6 +885,7 @@ pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSch
             #[cfg(feature = "large_string")]
             ArrowType::LargeString => unsafe { import_utf8::<u64>(arr, None) },
             ArrowType::Utf8View => unsafe { import_utf8_view(arr, None) },
+            ArrowType::FixedSizeBinary(w) => unsafe { import_fixed_size_binary(arr, None, w) },
             #[cfg(feature = "datetime")]
             ArrowType::Date32 => unsafe {
                 import_datetime::<i32>(arr, None, crate::TimeUnit::Days)
@@ -830,7 +920,8 @@ pub unsafe fn import_from_c(arr_ptr: *const ArrowArray, sch_ptr: *const ArrowSch
                 unsafe { import_categorical(arr, sch, idx, None) }
             }
         }
-    }
+    };
+    Ok(array)
 }
 
 /// Imports a Minarrow array from owned ArrowArray and ArrowSchema C pointers.
@@ -871,47 +962,46 @@ pub unsafe fn import_from_c_owned(
     // via ForeignBuffer. Dictionary strings are currently copied into Vec64<String>,
     // however this will be addressed in a future enhancement.
     if is_dict {
-        drop(sch_box);
-        let result = unsafe {
-            import_categorical(
-                arr,
-                sch,
-                match &dtype {
-                    ArrowType::Dictionary(i) => i.clone(),
-                    #[cfg(feature = "extended_numeric_types")]
-                    ArrowType::Int8 | ArrowType::UInt8 => {
-                        #[cfg(feature = "default_categorical_8")]
-                        {
-                            CategoricalIndexType::UInt8
-                        }
-                        #[cfg(not(feature = "default_categorical_8"))]
-                        panic!("default_categorical_8 not enabled")
-                    }
-                    #[cfg(feature = "extended_numeric_types")]
-                    ArrowType::Int16 | ArrowType::UInt16 => {
-                        #[cfg(feature = "extended_categorical")]
-                        {
-                            CategoricalIndexType::UInt16
-                        }
-                        #[cfg(not(feature = "extended_categorical"))]
-                        panic!("Extended categorical not enabled")
-                    }
-                    #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
-                    ArrowType::Int32 | ArrowType::UInt32 => CategoricalIndexType::UInt32,
-                    #[cfg(feature = "extended_numeric_types")]
-                    ArrowType::Int64 | ArrowType::UInt64 => {
-                        #[cfg(feature = "extended_categorical")]
-                        {
-                            CategoricalIndexType::UInt64
-                        }
-                        #[cfg(not(feature = "extended_categorical"))]
-                        panic!("Extended categorical not enabled")
-                    }
-                    _ => panic!("FFI: unsupported dictionary index type {:?}", dtype),
-                },
-                Some(arr_box),
-            )
+        // `sch` borrows from `sch_box`, and import_categorical reads its
+        // dictionary child, so the box is only dropped once the import is done.
+        let index_type = match &dtype {
+            ArrowType::Dictionary(i) => i.clone(),
+            #[cfg(feature = "extended_numeric_types")]
+            ArrowType::Int8 | ArrowType::UInt8 => {
+                #[cfg(feature = "default_categorical_8")]
+                {
+                    CategoricalIndexType::UInt8
+                }
+                #[cfg(not(feature = "default_categorical_8"))]
+                panic!("default_categorical_8 not enabled")
+            }
+            #[cfg(feature = "extended_numeric_types")]
+            ArrowType::Int16 | ArrowType::UInt16 => {
+                #[cfg(feature = "extended_categorical")]
+                {
+                    CategoricalIndexType::UInt16
+                }
+                #[cfg(not(feature = "extended_categorical"))]
+                panic!("Extended categorical not enabled")
+            }
+            #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+            ArrowType::Int32 | ArrowType::UInt32 => CategoricalIndexType::UInt32,
+            #[cfg(feature = "extended_numeric_types")]
+            ArrowType::Int64 | ArrowType::UInt64 => {
+                #[cfg(feature = "extended_categorical")]
+                {
+                    CategoricalIndexType::UInt64
+                }
+                #[cfg(not(feature = "extended_categorical"))]
+                panic!("Extended categorical not enabled")
+            }
+            _ => panic!("FFI: unsupported dictionary index type {:?}", dtype),
         };
+        let result = unsafe { import_categorical(arr, sch, index_type.clone(), Some(arr_box)) };
+        // A producer may omit the schema's dictionary child, in which case the
+        // field was parsed as the raw index type.
+        field.dtype = ArrowType::Dictionary(index_type);
+        drop(sch_box);
         return (result, field);
     }
 
@@ -939,6 +1029,7 @@ pub unsafe fn import_from_c_owned(
             #[cfg(feature = "large_string")]
             ArrowType::LargeString => import_utf8::<u64>(arr, Some(arr_box)),
             ArrowType::Utf8View => import_utf8_view(arr, Some(arr_box)),
+            ArrowType::FixedSizeBinary(w) => import_fixed_size_binary(arr, Some(arr_box), w),
             #[cfg(feature = "datetime")]
             ArrowType::Date32 => import_datetime::<i32>(arr, Some(arr_box), crate::TimeUnit::Days),
             #[cfg(feature = "datetime")]
@@ -1056,6 +1147,7 @@ unsafe fn import_array_zero_copy(
             #[cfg(feature = "large_string")]
             ArrowType::LargeString => import_utf8::<u64>(arr, Some(arr_box)),
             ArrowType::Utf8View => import_utf8_view(arr, Some(arr_box)),
+            ArrowType::FixedSizeBinary(w) => import_fixed_size_binary(arr, Some(arr_box), w),
             #[cfg(feature = "datetime")]
             ArrowType::Date32 => import_datetime::<i32>(arr, Some(arr_box), crate::TimeUnit::Days),
             #[cfg(feature = "datetime")]
@@ -1347,6 +1439,71 @@ unsafe fn import_utf8<T: Integer>(
     }
 }
 
+/// Imports a fixed-size binary array from Arrow C format.
+///
+/// # Arguments
+/// * `arr` - Reference to the ArrowArray containing the data
+/// * `ownership` - If `Some(box)`, takes ownership and does zero-copy via ForeignBuffer.
+///   If `None`, copies the data. This is needed for dictionary arrays inside
+///   categoricals, where the parent's release callback owns the child memory.
+/// * `width` - Byte width of each element, taken from the `w:<width>` format string
+///
+/// # Safety
+/// Expects two buffers: [nulls, values], with `length * width` value bytes.
+unsafe fn import_fixed_size_binary(
+    arr: &ArrowArray,
+    ownership: Option<Box<ArrowArray>>,
+    width: i32,
+) -> Arc<Array> {
+    let len = arr.length as usize;
+    let width = width as usize;
+    let buffers = unsafe { slice::from_raw_parts(arr.buffers, 2) };
+    let data_ptr = buffers[1];
+    let data_len = len * width;
+
+    // Null mask is always copied (small overhead)
+    let null_mask = if !buffers[0].is_null() {
+        Some(unsafe { Bitmask::from_raw_slice(buffers[0], len) })
+    } else {
+        None
+    };
+
+    // For empty arrays, create an empty buffer directly rather than using sentinel pointers
+    let buffer: Buffer<u8> = if data_len == 0 {
+        Buffer::default()
+    } else if let Some(arr_box) = ownership {
+        // Zero-copy: wrap foreign buffer
+        let foreign = ForeignBuffer {
+            ptr: data_ptr,
+            len: data_len,
+            array: Some(arr_box),
+        };
+        let shared = SharedBuffer::from_owner(foreign);
+        Buffer::from_shared(shared)
+    } else {
+        // Copy: dictionary arrays inside categoricals have their memory owned by the
+        // parent ArrowArray's release callback, so we can't take ownership of them
+        let data = unsafe { slice::from_raw_parts(data_ptr, data_len) };
+        Vec64::from(data).into()
+    };
+
+    let fsb_arr = FixedSizeBinaryArray {
+        data: buffer,
+        null_mask,
+        width,
+        len,
+    };
+    Arc::new(Array::FixedSizeBinaryArray(fsb_arr.into()))
+}
+
+/// Parses the byte width out of a `w:<width>` fixed-size binary format string.
+fn try_parse_fixed_size_width(fmt: &[u8]) -> Option<i32> {
+    std::str::from_utf8(&fmt[2..])
+        .ok()
+        .and_then(|w| w.parse::<i32>().ok())
+        .filter(|w| *w >= 0)
+}
+
 /// Imports a Utf8View array from Arrow C format into a MinArrow StringArray.
 ///
 /// Arrow's Utf8View layout uses 16-byte view structs per element plus variadic
@@ -1453,37 +1610,22 @@ unsafe fn import_utf8_view(arr: &ArrowArray, ownership: Option<Box<ArrowArray>>)
     Arc::new(Array::TextArray(TextArray::String32(Arc::new(str_arr))))
 }
 
-/// Imports a categorical array and dictionary from Arrow C format.
-///
-/// When `ownership` is `Some`, the codes buffer is zero-copy: a `ForeignBuffer`
-/// wraps the raw pointer and keeps the ArrowArray alive via its release callback.
-/// When `ownership` is `None`, the codes are copied.
+/// Imports the dictionary values of a dictionary-encoded array into `Vec64<String>`.
 ///
-/// Dictionary strings are always copied because Arrow stores them as contiguous
-/// offsets+data while MinArrow stores them as `Vec64<String>` with individual
-/// heap allocations.
+/// Shared by every categorical import path, so standalone arrays, array streams
+/// and record-batch columns all resolve the dictionary schema the same way.
+/// When the schema carries a dictionary child it is used directly. Some producers
+/// (e.g. pandas Series via `__arrow_c_stream__`) leave `sch.dictionary` null while
+/// still populating `arr.dictionary`; in that case a synthetic UTF-8 schema is used
+/// so `import_from_c` can interpret the values array.
 ///
 /// # Safety
-/// Caller must ensure dictionary pointers are valid and formatted correctly.
-unsafe fn import_categorical(
-    arr: &ArrowArray,
-    sch: &ArrowSchema,
-    index_type: CategoricalIndexType,
-    ownership: Option<Box<ArrowArray>>,
-) -> Arc<Array> {
-    // buffers: [null, codes]
-    let len = arr.length as usize;
-    let buffers = unsafe { slice::from_raw_parts(arr.buffers, 2) };
-    let null_ptr = buffers[0];
-    let codes_ptr = buffers[1];
-
-    // Import dictionary strings into Vec64<String>.
-    //
-    // When the schema carries a dictionary pointer we use it directly.
-    // Some producers (e.g. pandas Series via __arrow_c_stream__) leave
-    // sch.dictionary null while still populating arr.dictionary. In that
-    // case we construct a synthetic UTF-8 schema so import_from_c can
-    // interpret the dictionary values array.
+/// `arr.dictionary` must point to a valid ArrowArray, and `sch.dictionary` must be
+/// null or point to a valid ArrowSchema.
+unsafe fn import_dictionary_values(arr: &ArrowArray, sch: &ArrowSchema) -> Vec64<String> {
+    if arr.dictionary.is_null() {
+        panic!("FFI: dictionary-encoded array has no dictionary values");
+    }
     let synthetic_schema;
     let dict_sch_ptr: *const ArrowSchema = if !sch.dictionary.is_null() {
         sch.dictionary as *const _
@@ -1502,7 +1644,7 @@ unsafe fn import_categorical(
         &synthetic_schema as *const _
     };
     let dict = unsafe { import_from_c(arr.dictionary as *const _, dict_sch_ptr) };
-    let dict_strings = match dict.as_ref() {
+    match dict.as_ref() {
         Array::TextArray(TextArray::String32(s)) => (0..s.len())
             .map(|i| s.get(i).unwrap_or_default().to_string())
             .collect(),
@@ -1511,7 +1653,34 @@ unsafe fn import_categorical(
             .map(|i| s.get(i).unwrap_or_default().to_string())
             .collect(),
         _ => panic!("Expected String32 dictionary"),
-    };
+    }
+}
+
+/// Imports a categorical array and dictionary from Arrow C format.
+///
+/// When `ownership` is `Some`, the codes buffer is zero-copy: a `ForeignBuffer`
+/// wraps the raw pointer and keeps the ArrowArray alive via its release callback.
+/// When `ownership` is `None`, the codes are copied.
+///
+/// Dictionary strings are always copied because Arrow stores them as contiguous
+/// offsets+data while MinArrow stores them as `Vec64<String>` with individual
+/// heap allocations.
+///
+/// # Safety
+/// Caller must ensure dictionary pointers are valid and formatted correctly.
+unsafe fn import_categorical(
+    arr: &ArrowArray,
+    sch: &ArrowSchema,
+    index_type: CategoricalIndexType,
+    ownership: Option<Box<ArrowArray>>,
+) -> Arc<Array> {
+    // buffers: [null, codes]
+    let len = arr.length as usize;
+    let buffers = unsafe { slice::from_raw_parts(arr.buffers, 2) };
+    let null_ptr = buffers[0];
+    let codes_ptr = buffers[1];
+
+    let dict_strings = unsafe { import_dictionary_values(arr, sch) };
     let null_mask = if !null_ptr.is_null() {
         Some(unsafe { Bitmask::from_raw_slice(null_ptr, len) })
     } else {
@@ -1588,35 +1757,7 @@ unsafe fn import_categorical_narrow_to_u8(
     let null_ptr = buffers[0];
     let codes_ptr = buffers[1];
 
-    // Import dictionary strings into Vec64<String>, same as import_categorical
-    let synthetic_schema;
-    let dict_sch_ptr: *const ArrowSchema = if !sch.dictionary.is_null() {
-        sch.dictionary as *const _
-    } else {
-        synthetic_schema = ArrowSchema {
-            format: b"u\0".as_ptr() as *const i8,
-            name: b"\0".as_ptr() as *const i8,
-            metadata: ptr::null(),
-            flags: 0,
-            n_children: 0,
-            children: ptr::null_mut(),
-            dictionary: ptr::null_mut(),
-            release: None,
-            private_data: ptr::null_mut(),
-        };
-        &synthetic_schema as *const _
-    };
-    let dict = unsafe { import_from_c(arr.dictionary as *const _, dict_sch_ptr) };
-    let dict_strings = match dict.as_ref() {
-        Array::TextArray(TextArray::String32(s)) => (0..s.len())
-            .map(|i| s.get(i).unwrap_or_default().to_string())
-            .collect(),
-        #[cfg(feature = "large_string")]
-        Array::TextArray(TextArray::String64(s)) => (0..s.len())
-            .map(|i| s.get(i).unwrap_or_default().to_string())
-            .collect(),
-        _ => panic!("Expected String32 dictionary"),
-    };
+    let dict_strings = unsafe { import_dictionary_values(arr, sch) };
 
     let null_mask = if !null_ptr.is_null() {
         Some(unsafe { Bitmask::from_raw_slice(null_ptr, len) })
@@ -1781,7 +1922,11 @@ fn create_arrow_export(
     });
 
     // ArrowSchema
-    let flags = if field.nullable { 1 } else { 0 };
+    let flags = if field.nullable {
+        ARROW_FLAG_NULLABLE
+    } else {
+        0
+    };
     let schema_box = Box::new(ArrowSchema {
         format: format_ptr,
         name: name_cstr.as_ptr(),
@@ -2080,6 +2225,15 @@ unsafe extern "C" fn release_struct_schema(sch: *mut ArrowSchema) {
         };
     }
 
+    // Release the dictionary value schema, if any
+    if !s.dictionary.is_null() {
+        let dict = unsafe { &mut *s.dictionary };
+        if let Some(release) = dict.release {
+            unsafe { release(s.dictionary) };
+        }
+        let _ = unsafe { Box::from_raw(s.dictionary) };
+    }
+
     // Free the private data
     if !s.private_data.is_null() {
         let _ = unsafe { Box::from_raw(s.private_data as *mut StructSchemaHolder) };
@@ -2088,6 +2242,45 @@ unsafe extern "C" fn release_struct_schema(sch: *mut ArrowSchema) {
     unsafe { ptr::write_bytes(sch, 0, 1) };
 }
 
+/// Builds the dictionary value schema for a stream field.
+///
+/// Returns null for non-dictionary fields. MinArrow dictionaries are always
+/// UTF-8, so the child is a plain `"u"` schema released by `release_struct_schema`.
+fn export_dictionary_schema(field: &crate::Field) -> *mut ArrowSchema {
+    if !matches!(field.dtype, ArrowType::Dictionary(_)) {
+        return ptr::null_mut();
+    }
+    let dict_holder = Box::new(StructSchemaHolder {
+        format_cstr: CString::new("u").unwrap(),
+        name_cstr: CString::new("").unwrap(),
+        metadata_bytes: None,
+    });
+    Box::into_raw(Box::new(ArrowSchema {
+        format: dict_holder.format_cstr.as_ptr(),
+        name: dict_holder.name_cstr.as_ptr(),
+        metadata: ptr::null(),
+        flags: 0,
+        n_children: 0,
+        children: ptr::null_mut(),
+        dictionary: ptr::null_mut(),
+        release: Some(release_struct_schema),
+        private_data: Box::into_raw(dict_holder) as *mut c_void,
+    }))
+}
+
+/// Arrow C schema flags for a stream field.
+fn stream_field_flags(field: &crate::Field) -> i64 {
+    let mut flags = if field.nullable {
+        ARROW_FLAG_NULLABLE
+    } else {
+        0
+    };
+    if field.is_ordered_dictionary() {
+        flags |= ARROW_FLAG_DICTIONARY_ORDERED;
+    }
+    flags
+}
+
 // ── Stream export: record batches ───────────────────────────────────────
 
 /// Creates an ArrowArrayStream that yields record batches as struct arrays.
@@ -2149,7 +2342,7 @@ unsafe extern "C" fn rb_stream_get_schema(
     for field in &holder.fields {
         let format_cstr = fmt_c(field.dtype.clone());
         let name_cstr = CString::new(field.name.clone()).unwrap_or_default();
-        let flags = if field.nullable { 2 } else { 0 };
+        let flags = stream_field_flags(field);
 
         let metadata_bytes = if field.metadata.is_empty() {
             None
@@ -2174,7 +2367,7 @@ unsafe extern "C" fn rb_stream_get_schema(
             flags,
             n_children: 0,
             children: ptr::null_mut(),
-            dictionary: ptr::null_mut(),
+            dictionary: export_dictionary_schema(field),
             release: Some(release_struct_schema),
             private_data: Box::into_raw(child_holder) as *mut c_void,
         });
@@ -2300,7 +2493,7 @@ unsafe extern "C" fn arr_stream_get_schema(
 
     let format_cstr = fmt_c(field.dtype.clone());
     let name_cstr = CString::new(field.name.clone()).unwrap_or_default();
-    let flags = if field.nullable { 2 } else { 0 };
+    let flags = stream_field_flags(field);
 
     let schema_holder = Box::new(StructSchemaHolder {
         format_cstr,
@@ -2315,7 +2508,7 @@ unsafe extern "C" fn arr_stream_get_schema(
         flags,
         n_children: 0,
         children: ptr::null_mut(),
-        dictionary: ptr::null_mut(),
+        dictionary: export_dictionary_schema(field),
         release: Some(release_struct_schema),
         private_data: Box::into_raw(schema_holder) as *mut c_void,
     };
@@ -2628,44 +2821,51 @@ unsafe fn field_from_c_schema(schema: &ArrowSchema) -> crate::Field {
             .to_string_lossy()
             .into_owned()
     };
-    let nullable = (schema.flags & 2) != 0;
+    let nullable = (schema.flags & ARROW_FLAG_NULLABLE) != 0;
+    let ordered =
+        !schema.dictionary.is_null() && (schema.flags & ARROW_FLAG_DICTIONARY_ORDERED) != 0;
     let fmt = unsafe { std::ffi::CStr::from_ptr(schema.format).to_bytes() };
 
     let dtype = if !schema.dictionary.is_null() {
         // Dictionary-encoded: format string describes the index type, the
         // dictionary field describes the value type.
-        use crate::ffi::arrow_dtype::CategoricalIndexType;
-        let index_type = match fmt {
-            #[cfg(feature = "default_categorical_8")]
-            b"c" | b"C" => CategoricalIndexType::UInt8,
-            #[cfg(all(feature = "extended_numeric_types", feature = "extended_categorical"))]
-            b"s" | b"S" => CategoricalIndexType::UInt16,
-            #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
-            b"i" | b"I" => CategoricalIndexType::UInt32,
-            // When default_categorical_8 is on without extended_categorical,
-            // record as UInt8 for schema purposes - actual narrowing happens at import time
-            #[cfg(all(feature = "default_categorical_8", not(feature = "extended_categorical")))]
-            b"i" | b"I" => CategoricalIndexType::UInt8,
-            #[cfg(all(feature = "extended_numeric_types", feature = "extended_categorical"))]
-            b"l" | b"L" => CategoricalIndexType::UInt64,
-            _ => panic!(
+        let index_type = parse_dictionary_index_format(fmt).unwrap_or_else(|_| {
+            panic!(
                 "Unsupported dictionary index format: {:?}",
                 std::str::from_utf8(fmt).unwrap_or("??")
-            ),
-        };
+            )
+        });
         ArrowType::Dictionary(index_type)
     } else {
         parse_arrow_format(fmt)
     };
 
     let metadata = unsafe { decode_arrow_metadata(schema.metadata) };
-    crate::Field::new(name, dtype, nullable, metadata)
+    crate::Field::new(name, dtype, nullable, metadata).with_ordered(ordered)
 }
 
 /// Parses an Arrow C format string into an ArrowType.
-/// Shared between import_from_c, import_from_c_owned, and stream import.
+/// Shared between import_from_c_owned and stream import.
+///
+/// The struct marker `+s` maps to `Null`, as structs are handled at stream level.
 fn parse_arrow_format(fmt: &[u8]) -> ArrowType {
-    match fmt {
+    if fmt == b"+s" {
+        return ArrowType::Null;
+    }
+    try_parse_arrow_format(fmt).unwrap_or_else(|_| {
+        panic!(
+            "unsupported Arrow format {:?}",
+            std::str::from_utf8(fmt).unwrap_or("??")
+        )
+    })
+}
+
+/// Fallible core of [`parse_arrow_format`], backing `ArrowType::from_format_string`.
+///
+/// Dictionary types cannot be recognised here, as the C interface spells them
+/// with their index type; see [`parse_dictionary_index_format`].
+pub(crate) fn try_parse_arrow_format(fmt: &[u8]) -> Result<ArrowType, MinarrowError> {
+    Ok(match fmt {
         b"n" => ArrowType::Null,
         b"b" => ArrowType::Boolean,
         #[cfg(feature = "extended_numeric_types")]
@@ -2712,7 +2912,6 @@ fn parse_arrow_format(fmt: &[u8]) -> ArrowType {
         b"tiD" => ArrowType::Interval(crate::IntervalUnit::DaysTime),
         #[cfg(feature = "datetime")]
         b"tin" => ArrowType::Interval(crate::IntervalUnit::MonthDaysNs),
-        b"+s" => ArrowType::Null, // struct marker (handled at stream level)
         #[cfg(feature = "datetime")]
         _ if fmt.starts_with(b"tss")
             || fmt.starts_with(b"tsm")
@@ -2739,10 +2938,44 @@ fn parse_arrow_format(fmt: &[u8]) -> ArrowType {
             };
             ArrowType::Timestamp(unit, tz)
         }
-        o => panic!(
+        _ if fmt.starts_with(b"w:") => {
+            let width = try_parse_fixed_size_width(fmt).ok_or_else(|| unsupported_format(fmt))?;
+            ArrowType::FixedSizeBinary(width)
+        }
+        _ => return Err(unsupported_format(fmt)),
+    })
+}
+
+/// Maps a dictionary's index format string to its `CategoricalIndexType`.
+pub(crate) fn parse_dictionary_index_format(
+    fmt: &[u8],
+) -> Result<CategoricalIndexType, MinarrowError> {
+    Ok(match fmt {
+        #[cfg(feature = "default_categorical_8")]
+        b"c" | b"C" => CategoricalIndexType::UInt8,
+        #[cfg(all(feature = "extended_numeric_types", feature = "extended_categorical"))]
+        b"s" | b"S" => CategoricalIndexType::UInt16,
+        #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+        b"i" | b"I" => CategoricalIndexType::UInt32,
+        // When default_categorical_8 is on without extended_categorical,
+        // record as UInt8 for schema purposes - actual narrowing happens at import time
+        #[cfg(all(feature = "default_categorical_8", not(feature = "extended_categorical")))]
+        b"i" | b"I" => CategoricalIndexType::UInt8,
+        #[cfg(all(feature = "extended_numeric_types", feature = "extended_categorical"))]
+        b"l" | b"L" => CategoricalIndexType::UInt64,
+        _ => return Err(unsupported_format(fmt)),
+    })
+}
+
+/// Error for a format string that does not map to a supported `ArrowType`.
+fn unsupported_format(fmt: &[u8]) -> MinarrowError {
+    MinarrowError::TypeError {
+        from: "Arrow format string",
+        to: "ArrowType",
+        message: Some(format!(
             "unsupported Arrow format {:?}",
-            std::str::from_utf8(o).unwrap_or("??")
-        ),
+            String::from_utf8_lossy(fmt)
+        )),
     }
 }
 
@@ -2760,16 +2993,113 @@ mod tests {
     use crate::ffi::arrow_c_ffi::import_from_c;
     use crate::ffi::arrow_dtype::ArrowType;
     use crate::ffi::schema::Schema;
-    use crate::{Array, BooleanArray, Field, FloatArray, IntegerArray, MaskedArray, StringArray};
+    use crate::{
+        Array, BooleanArray, Field, FixedSizeBinaryArray, FloatArray, IntegerArray, MaskedArray,
+        StringArray,
+    };
 
     // Helper for constructing a one-field schema for the given type
     fn schema_for(name: &str, ty: ArrowType, nullable: bool) -> Schema {
         Schema {
-            fields: vec![Field::new(name, ty, nullable, None)],
+            fields: vec![Arc::new(Field::new(name, ty, nullable, None))],
             metadata: Default::default(),
         }
     }
 
+    #[test]
+    fn test_format_string_round_trip() {
+        #[allow(unused_mut)]
+        let mut types = vec![
+            ArrowType::Null,
+            ArrowType::Boolean,
+            ArrowType::Int32,
+            ArrowType::Int64,
+            ArrowType::UInt64,
+            ArrowType::Float64,
+            ArrowType::String,
+            ArrowType::Utf8View,
+            ArrowType::FixedSizeBinary(16),
+        ];
+        #[cfg(feature = "datetime")]
+        {
+            use crate::TimeUnit;
+            types.push(ArrowType::Date32);
+            types.push(ArrowType::Time64(TimeUnit::Nanoseconds));
+            types.push(ArrowType::Duration32(TimeUnit::Milliseconds));
+            types.push(ArrowType::Timestamp(TimeUnit::Microseconds, None));
+            types.push(ArrowType::Timestamp(
+                TimeUnit::Milliseconds,
+                Some("America/New_York".to_string()),
+            ));
+        }
+        for ty in types {
+            let fmt = ty.to_format_string().unwrap();
+            assert_eq!(ArrowType::from_format_string(&fmt).unwrap(), ty, "{}", fmt);
+        }
+
+        assert_eq!(ArrowType::Int64.to_format_string().unwrap(), "l");
+        assert_eq!(
+            ArrowType::FixedSizeBinary(4).to_format_string().unwrap(),
+            "w:4"
+        );
+        for bad in ["", "+s", "w:", "w:-1", "zz"] {
+            assert!(ArrowType::from_format_string(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_format_string_timestamp_tz() {
+        use crate::TimeUnit;
+        use crate::enums::error::MinarrowError;
+        let utc = ArrowType::Timestamp(TimeUnit::Milliseconds, Some("UTC".to_string()));
+        assert_eq!(utc.to_format_string().unwrap(), "tsm:UTC");
+        let naive = ArrowType::Timestamp(TimeUnit::Seconds, None);
+        assert_eq!(naive.to_format_string().unwrap(), "tss:");
+        // An empty zone and a missing colon both read back as no timezone
+        assert_eq!(ArrowType::from_format_string("tss").unwrap(), naive);
+        let offset = ArrowType::from_format_string("tsn:+05:30").unwrap();
+        assert_eq!(
+            offset,
+            ArrowType::Timestamp(TimeUnit::Nanoseconds, Some("+05:30".to_string()))
+        );
+
+        // Units the C format has no code for are an error rather than a panic
+        for bad in [
+            ArrowType::Timestamp(TimeUnit::Days, None),
+            ArrowType::Time32(TimeUnit::Nanoseconds),
+            ArrowType::Time64(TimeUnit::Seconds),
+            ArrowType::Duration32(TimeUnit::Days),
+            ArrowType::Duration64(TimeUnit::Milliseconds),
+        ] {
+            assert!(
+                matches!(bad.to_format_string(), Err(MinarrowError::TypeError { .. })),
+                "{:?}",
+                bad
+            );
+        }
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_format_string_dictionary() {
+        use crate::ffi::arrow_dtype::CategoricalIndexType;
+        let dict = ArrowType::Dictionary(CategoricalIndexType::UInt32);
+        let fmt = dict.to_format_string().unwrap();
+        assert_eq!(fmt, "I");
+        // Without the dictionary context the index format is a plain integer
+        let plain = ArrowType::from_format_string(&fmt).unwrap();
+        assert_eq!(plain, ArrowType::UInt32);
+        let parsed = ArrowType::from_dictionary_format_string(&fmt).unwrap();
+        assert_eq!(parsed, dict);
+        let signed = ArrowType::from_dictionary_format_string("i").unwrap();
+        assert_eq!(signed, dict);
+        assert!(ArrowType::from_dictionary_format_string("g").is_err());
+    }
+
     #[test]
     fn test_arrow_c_export_int32() {
         let mut arr = IntegerArray::<i32>::default();
@@ -2976,6 +3306,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_arrow_c_fixed_size_binary_round_trip() {
+        use super::import_from_c_owned;
+
+        let mut arr = FixedSizeBinaryArray::with_capacity(3, 4, true);
+        arr.push_value(&[1, 2, 3, 4]);
+        arr.push_null();
+        arr.push_value(&[9, 8, 7, 6]);
+
+        let array = Arc::new(Array::from_fixed_size_binary(arr));
+        let schema = schema_for("ids", ArrowType::FixedSizeBinary(4), true);
+
+        let (arr_ptr, sch_ptr) = export_to_c(array, schema);
+
+        unsafe {
+            let fmt = std::ffi::CStr::from_ptr((*sch_ptr).format).to_bytes();
+            assert_eq!(fmt, b"w:4");
+            assert_eq!((*arr_ptr).length, 3);
+
+            let (imported, field) =
+                import_from_c_owned(Box::from_raw(arr_ptr), Box::from_raw(sch_ptr));
+            assert_eq!(field.dtype, ArrowType::FixedSizeBinary(4));
+            match imported.as_ref() {
+                Array::FixedSizeBinaryArray(fsb) => {
+                    assert_eq!(fsb.len(), 3);
+                    assert_eq!(fsb.value(0), Some(&[1u8, 2, 3, 4][..]));
+                    assert_eq!(fsb.value(1), None);
+                    assert_eq!(fsb.value(2), Some(&[9u8, 8, 7, 6][..]));
+                }
+                other => panic!("expected FixedSizeBinaryArray, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_import_rejects_malformed_fixed_size_format() {
+        use super::{import_from_c_owned, try_import_from_c};
+        use crate::enums::error::MinarrowError;
+
+        let mut arr = FixedSizeBinaryArray::with_capacity(1, 2, false);
+        arr.push_value(&[1, 2]);
+        let array = Arc::new(Array::from_fixed_size_binary(arr));
+        let schema = schema_for("ids", ArrowType::FixedSizeBinary(2), false);
+        let (arr_ptr, sch_ptr) = export_to_c(array, schema);
+
+        let bad = std::ffi::CString::new("w:abc").unwrap();
+        unsafe {
+            let good = (*sch_ptr).format;
+            (*sch_ptr).format = bad.as_ptr();
+            assert!(matches!(
+                try_import_from_c(arr_ptr, sch_ptr),
+                Err(MinarrowError::TypeError { .. })
+            ));
+            (*sch_ptr).format = good;
+            assert!(try_import_from_c(arr_ptr, sch_ptr).is_ok());
+            assert!(matches!(
+                try_import_from_c(std::ptr::null(), sch_ptr),
+                Err(MinarrowError::NullError { .. })
+            ));
+            import_from_c_owned(Box::from_raw(arr_ptr), Box::from_raw(sch_ptr));
+        }
+    }
+
     #[cfg(feature = "datetime")]
     #[test]
     fn test_arrow_c_export_datetime() {
@@ -3160,12 +3553,12 @@ mod tests {
 
         let array = Arc::new(Array::from_int32(arr));
         let schema = Schema {
-            fields: vec![Field::new(
+            fields: vec![Arc::new(Field::new(
                 "col",
                 ArrowType::Int32,
                 false,
                 Some(meta.clone()),
-            )],
+            ))],
             metadata: Default::default(),
         };
 
@@ -3182,6 +3575,95 @@ mod tests {
         }
     }
 
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_dictionary_ordered_flag_round_trip() {
+        use super::{ARROW_FLAG_DICTIONARY_ORDERED, import_from_c_owned};
+        use crate::CategoricalArray;
+        use crate::ffi::arrow_dtype::CategoricalIndexType;
+
+        let cat = CategoricalArray::<u32>::from_values(["low", "high", "low"]);
+        let array = Arc::new(Array::from_categorical32(cat));
+        let field = Field::new(
+            "level",
+            ArrowType::Dictionary(CategoricalIndexType::UInt32),
+            false,
+            None,
+        )
+        .with_ordered(true);
+        let schema = Schema {
+            fields: vec![Arc::new(field)],
+            metadata: Default::default(),
+        };
+
+        let (arr_ptr, sch_ptr) = export_to_c(array, schema);
+
+        unsafe {
+            assert_ne!((*sch_ptr).flags & ARROW_FLAG_DICTIONARY_ORDERED, 0);
+            let arr_box = Box::from_raw(arr_ptr);
+            let sch_box = Box::from_raw(sch_ptr);
+            let (imported, field) = import_from_c_owned(arr_box, sch_box);
+            assert!(field.ordered, "ordered flag should survive round-trip");
+            assert!(!field.nullable);
+            assert_eq!(imported.len(), 3);
+        }
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_array_stream_dictionary_chunks() {
+        use super::{ArrowSchema, export_array_stream, import_array_stream};
+        use crate::ffi::arrow_dtype::CategoricalIndexType;
+        use crate::{CategoricalArray, TextArray};
+
+        let field = Field::new(
+            "animal",
+            ArrowType::Dictionary(CategoricalIndexType::UInt32),
+            true,
+            None,
+        );
+        let chunks = vec![
+            Arc::new(Array::from_categorical32(
+                CategoricalArray::<u32>::from_values(["cat", "dog", "cat"]),
+            )),
+            Arc::new(Array::from_categorical32(
+                CategoricalArray::<u32>::from_values(["bird", "fish"]),
+            )),
+        ];
+        let stream_ptr = Box::into_raw(export_array_stream(chunks, field));
+
+        unsafe {
+            // The stream schema must carry the dictionary value child
+            let mut sch = ArrowSchema::empty();
+            assert_eq!(((*stream_ptr).get_schema.unwrap())(stream_ptr, &mut sch), 0);
+            assert!(!sch.dictionary.is_null());
+            let dict_fmt = std::ffi::CStr::from_ptr((*sch.dictionary).format);
+            assert_eq!(dict_fmt.to_bytes(), b"u");
+            (sch.release.unwrap())(&mut sch);
+
+            let (arrays, field) = import_array_stream(stream_ptr);
+            let _ = Box::from_raw(stream_ptr);
+            assert_eq!(
+                field.dtype,
+                ArrowType::Dictionary(CategoricalIndexType::UInt32)
+            );
+            let uniques: Vec<Vec<String>> = arrays
+                .iter()
+                .map(|a| match a.as_ref() {
+                    Array::TextArray(TextArray::Categorical32(c)) => c.unique_values.to_vec(),
+                    other => panic!("expected Categorical32, got {:?}", other),
+                })
+                .collect();
+            assert_eq!(uniques, vec![vec!["cat", "dog"], vec!["bird", "fish"]]);
+        }
+    }
+
     #[test]
     fn test_field_metadata_round_trip_record_batch_stream() {
         use super::{
@@ -3199,7 +3681,7 @@ mod tests {
         arr.push(20);
         let array = Arc::new(Array::from_int32(arr));
         let col_schema = Schema {
-            fields: vec![field.clone()],
+            fields: vec![Arc::new(field.clone())],
             metadata: Default::default(),
         };
 
@@ -3249,7 +3731,7 @@ mod tests {
         let array = Arc::new(Array::from_int32(arr));
         let field = Field::new("col1", ArrowType::Int32, false, None);
         let col_schema = Schema {
-            fields: vec![field.clone()],
+            fields: vec![Arc::new(field.clone())],
             metadata: Default::default(),
         };
 
@@ -33,6 +33,8 @@
 //! ## Interoperability
 //! - Implements a focused subset of the public Arrow specification.
 //! - Maintains compatibility while keeping Minarrow minimal.
+//! - `to_format_string` and `from_format_string` convert to and from the Arrow
+//!   C data interface format strings used over FFI.
 //!
 //! ## Copyright Notice
 //! - The `Minarrow` crate is not affiliated with the `Apache Arrow` project.
@@ -47,9 +49,12 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 #[cfg(feature = "datetime")]
 use crate::DatetimeArray;
+use crate::enums::error::MinarrowError;
 #[cfg(feature = "datetime")]
 use crate::enums::time_units::{IntervalUnit, TimeUnit};
-use crate::{BooleanArray, CategoricalArray, Float, FloatArray, Integer, StringArray};
+use crate::{
+    BooleanArray, CategoricalArray, FixedSizeBinaryArray, Float, FloatArray, Integer, StringArray,
+};
 
 /// # ArrowType
 ///
@@ -64,6 +69,7 @@ use crate::{BooleanArray, CategoricalArray, Float, FloatArray, Integer, StringAr
 /// ## Coverage
 /// - **Core primitives**: integer, floating-point, boolean.
 /// - **Strings**: UTF-8 (`String`) and optionally large UTF-8 (`LargeString`).
+/// - **Fixed-width binary**: `FixedSizeBinary(width)` for hashes, UUIDs and similar keys.
 /// - **Dictionary-encoded strings**: via `Dictionary(CategoricalIndexType)`.
 /// - **Optional temporal types**: `date`, `time`, `duration`, `timestamp`, and `interval` with explicit units.
 /// - **`Null`**: placeholder or metadata-only fields.
@@ -115,6 +121,8 @@ pub enum ArrowType {
     #[cfg(feature = "large_string")]
     LargeString,
     Utf8View,
+    /// Fixed-width binary values, each `width` bytes long.
+    FixedSizeBinary(i32),
 
     // Integer size for the categorical dictionary key,
     // and therefore how much storage space for each entry there is,
@@ -239,6 +247,52 @@ impl<T: Integer> DatetimeArray<T> {
     }
 }
 
+impl FixedSizeBinaryArray {
+    /// The arrow type that backs this array, including its byte width.
+    pub fn arrow_type(&self) -> ArrowType {
+        ArrowType::FixedSizeBinary(self.width as i32)
+    }
+}
+
+impl ArrowType {
+    /// Returns the Arrow C data interface format string for this type,
+    /// e.g. `"l"` for `Int64` or `"tsm:UTC"` for a millisecond UTC timestamp.
+    ///
+    /// As in the C interface, a `Dictionary` type is spelt by its index type,
+    /// so it reads back through [`ArrowType::from_dictionary_format_string`].
+    ///
+    /// # Errors
+    /// - `TypeError` for a unit the Arrow format cannot express, such as
+    ///   `Timestamp(Days)` or `Time32(Nanoseconds)`
+    pub fn to_format_string(&self) -> Result<String, MinarrowError> {
+        let fmt = crate::ffi::arrow_c_ffi::try_fmt_c(self)?;
+        Ok(fmt.into_string().expect("Arrow format strings are ASCII"))
+    }
+
+    /// Parses an Arrow C data interface format string, the inverse of
+    /// [`ArrowType::to_format_string`] for non-dictionary types.
+    ///
+    /// A timestamp with an empty zone, such as `"tsm:"`, has no timezone.
+    ///
+    /// # Errors
+    /// - `TypeError` for a format Minarrow does not support, including nested
+    ///   types such as `"+s"` and types behind a disabled feature
+    pub fn from_format_string(fmt: &str) -> Result<ArrowType, MinarrowError> {
+        crate::ffi::arrow_c_ffi::try_parse_arrow_format(fmt.as_bytes())
+    }
+
+    /// Parses the index format string of a dictionary-encoded field into
+    /// `ArrowType::Dictionary`.
+    ///
+    /// # Errors
+    /// - `TypeError` if `fmt` is not an index type the enabled categorical
+    ///   features support
+    pub fn from_dictionary_format_string(fmt: &str) -> Result<ArrowType, MinarrowError> {
+        let index = crate::ffi::arrow_c_ffi::parse_dictionary_index_format(fmt.as_bytes())?;
+        Ok(ArrowType::Dictionary(index))
+    }
+}
+
 impl Display for ArrowType {
     /// Render the ArrowType as its variant name, including associated units where applicable.
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -290,6 +344,7 @@ impl Display for ArrowType {
             #[cfg(feature = "large_string")]
             ArrowType::LargeString => f.write_str("LargeString"),
             ArrowType::Utf8View => f.write_str("Utf8View"),
+            ArrowType::FixedSizeBinary(width) => write!(f, "FixedSizeBinary({width})"),
 
             ArrowType::Dictionary(key_type) => write!(f, "Dictionary({key_type})"),
         }
@@ -17,8 +17,10 @@
 //! Contains the `Schema` type for Arrow FFI compatibility.
 //!
 //! ## Overview
-//! - Stores a list of `Field` definitions and optional metadata.
+//! - Stores a list of `Arc<Field>` definitions and optional metadata.
 //! - Used when constructing `RecordBatch` for Arrow C-FFI.
+//! - Returned by `Table::schema`, sharing the table's fields, and usable to
+//!   validate other tables against it via `Schema::matches`.
 //! - In Minarrow, `FieldArray` is preferred for typical usage since it holds the `Field` directly.
 //!
 //! ## Design
@@ -27,40 +29,102 @@
 //!
 //! ## Usage
 //! - Construct directly with `Schema::new(fields, metadata)`.
-//! - Or convert from `Vec<Field>` using `Schema::from`.
+//! - Or convert from `Vec<Field>` or `Vec<Arc<Field>>` using `Schema::from`.
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
-use crate::Field;
+use crate::enums::error::MinarrowError;
+use crate::{Field, Table};
 
 /// # Schema
 ///
-/// Schema struct supporting `RecordBatch` construction for Arrow FFI compatibility only.
+/// Schema struct supporting `RecordBatch` construction for Arrow FFI compatibility,
+/// and schema validation between tables.
 ///
 /// ## Usage
 /// - In `Minarrow`, prefer `FieldArray` for typical use, as it holds `Field` directly.
-/// - This type usage resides in the FFI module. A dedicated schema abstraction is otherwise
-/// not utilised within this crate, as the same field definitions are embedded
-/// within the `Table` structure, to avoid layered indirection.
+/// - This type resides in the FFI module. `Table` does not store one, as the same
+///   field definitions are embedded within its columns, to avoid layered indirection.
+///   `Table::schema` builds one on demand by sharing those `Arc`'d fields.
 /// - You can construct it manually for custom FFI scenarios: see *examples/apache_arrow_ffi*.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Schema {
-    pub fields: Vec<Field>,
+    pub fields: Vec<Arc<Field>>,
     pub metadata: BTreeMap<String, String>,
 }
 
 impl Schema {
+    /// Constructs a schema from owned or `Arc`'d fields.
     #[inline]
-    pub fn new(fields: Vec<Field>, metadata: BTreeMap<String, String>) -> Self {
+    pub fn new<F: Into<Arc<Field>>>(
+        fields: impl IntoIterator<Item = F>,
+        metadata: BTreeMap<String, String>,
+    ) -> Self {
         Self {
-            fields: fields,
+            fields: fields.into_iter().map(Into::into).collect(),
             metadata,
         }
     }
+
+    /// Checks that `table` has exactly these fields, in order.
+    ///
+    /// Column names, types and nullability must all match. This is the check
+    /// `Table::concat` applies between its inputs. Metadata is not compared.
+    ///
+    /// # Errors
+    /// - `IncompatibleTypeError` describing the column counts, or the first
+    ///   column that differs.
+    pub fn matches(&self, table: &Table) -> Result<(), MinarrowError> {
+        let mismatch = |message: String| MinarrowError::IncompatibleTypeError {
+            from: "Schema",
+            to: "Table",
+            message: Some(message),
+        };
+        if self.fields.len() != table.cols.len() {
+            return Err(mismatch(format!(
+                "Schema has {} columns but table \"{}\" has {}",
+                self.fields.len(),
+                table.name,
+                table.cols.len()
+            )));
+        }
+        for (idx, (expected, col)) in self.fields.iter().zip(&table.cols).enumerate() {
+            let actual = &col.field;
+            if Arc::ptr_eq(expected, actual) {
+                continue;
+            }
+            if expected.name != actual.name {
+                return Err(mismatch(format!(
+                    "Column {} name mismatch: '{}' vs '{}'",
+                    idx, expected.name, actual.name
+                )));
+            }
+            if expected.dtype != actual.dtype {
+                return Err(mismatch(format!(
+                    "Column '{}' type mismatch: {:?} vs {:?}",
+                    expected.name, expected.dtype, actual.dtype
+                )));
+            }
+            if expected.nullable != actual.nullable {
+                return Err(mismatch(format!(
+                    "Column '{}' nullable mismatch: {} vs {}",
+                    expected.name, expected.nullable, actual.nullable
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl From<Vec<Field>> for Schema {
     fn from(fields: Vec<Field>) -> Self {
+        Self::new(fields, BTreeMap::new())
+    }
+}
+
+impl From<Vec<Arc<Field>>> for Schema {
+    fn from(fields: Vec<Arc<Field>>) -> Self {
         Self {
             fields,
             ..Default::default()
@@ -80,11 +144,50 @@ mod tests {
 
         let meta = std::collections::BTreeMap::new();
         let schema = Schema::new(vec![f1.clone(), f2.clone()], meta.clone());
-        assert_eq!(schema.fields[0], f1);
-        assert_eq!(schema.fields[1], f2);
+        assert_eq!(*schema.fields[0], f1);
+        assert_eq!(*schema.fields[1], f2);
 
         let schema2: Schema = vec![f1.clone(), f2.clone()].into();
-        assert_eq!(schema2.fields[0], f1);
+        assert_eq!(*schema2.fields[0], f1);
         assert!(schema2.metadata.is_empty());
     }
+
+    #[test]
+    fn test_schema_matches() {
+        use crate::{Table, fa_bool, fa_i32, fa_i64};
+
+        let schema = Schema::from(vec![
+            Field::new("a", ArrowType::Int32, false, None),
+            Field::new("b", ArrowType::Boolean, false, None),
+        ]);
+        let ok = Table::new(
+            "ok".into(),
+            Some(vec![fa_i32!("a", 1, 2), fa_bool!("b", true, false)]),
+        );
+        assert!(schema.matches(&ok).is_ok());
+
+        let short = Table::new("short".into(), Some(vec![fa_i32!("a", 1, 2)]));
+        assert!(schema.matches(&short).is_err());
+
+        let renamed = Table::new(
+            "renamed".into(),
+            Some(vec![fa_i32!("z", 1, 2), fa_bool!("b", true, false)]),
+        );
+        let err = schema.matches(&renamed).unwrap_err().to_string();
+        assert!(err.contains("name mismatch"));
+
+        let retyped = Table::new(
+            "retyped".into(),
+            Some(vec![fa_i64!("a", 1, 2), fa_bool!("b", true, false)]),
+        );
+        let err = schema.matches(&retyped).unwrap_err().to_string();
+        assert!(err.contains("type mismatch"));
+
+        let nullable = Schema::from(vec![
+            Field::new("a", ArrowType::Int32, true, None),
+            Field::new("b", ArrowType::Boolean, false, None),
+        ]);
+        let err = nullable.matches(&ok).unwrap_err().to_string();
+        assert!(err.contains("nullable mismatch"));
+    }
 }
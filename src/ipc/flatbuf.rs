@@ -0,0 +1,404 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **FlatBuffers** - *Minimal encoder and decoder for Arrow IPC metadata*
+//!
+//! Arrow IPC metadata is a handful of small FlatBuffers tables. Rather than pull in
+//! the `flatbuffers` crate and generated bindings, this module covers the subset
+//! the `Message.fbs` and `Schema.fbs` definitions need: scalar fields, strings,
+//! nested tables, vectors of tables and vectors of 8-byte aligned structs.
+//!
+//! The encoder writes front to back. Each table's vtable sits immediately before it,
+//! and every string, table or vector it references follows it, so all offsets point
+//! forwards as the format requires. Scalars are aligned to their width relative to
+//! the start of the buffer. The decoder bounds-checks every read.
+
+use crate::enums::error::MinarrowError;
+
+use super::invalid;
+
+/// A field value within a `TableBuilder`.
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+    Bool(bool),
+    U8(u8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Str(String),
+    Table(TableBuilder),
+    Tables(Vec<TableBuilder>),
+    /// Vector of inline structs. `data` holds `count` structs, each 8-byte aligned.
+    Structs {
+        data: Vec<u8>,
+        count: usize,
+    },
+}
+
+impl Value {
+    /// Inline width of the field within its table.
+    #[inline]
+    fn inline_size(&self) -> usize {
+        match self {
+            Value::Bool(_) | Value::U8(_) => 1,
+            Value::I16(_) => 2,
+            Value::I64(_) => 8,
+            // Scalars and offsets to out-of-line children
+            _ => 4,
+        }
+    }
+}
+
+/// A FlatBuffers table under construction. Fields are addressed by slot index,
+/// matching their declaration order in the `.fbs` schema.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableBuilder {
+    fields: Vec<Option<Value>>,
+}
+
+impl TableBuilder {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field in `slot`, returning the builder for chaining.
+    pub(crate) fn add(mut self, slot: usize, value: Value) -> Self {
+        if self.fields.len() <= slot {
+            self.fields.resize(slot + 1, None);
+        }
+        self.fields[slot] = Some(value);
+        self
+    }
+}
+
+/// Serialises `root` into a finished FlatBuffer, padded to a multiple of 8 bytes.
+pub(crate) fn finish(root: &TableBuilder) -> Vec<u8> {
+    let mut buf = vec![0u8; 4];
+    let root_pos = write_table(&mut buf, root);
+    patch_u32(&mut buf, 0, root_pos as u32);
+    pad_to(&mut buf, 8);
+    buf
+}
+
+#[inline]
+fn pad_to(buf: &mut Vec<u8>, align: usize) {
+    let len = buf.len().next_multiple_of(align);
+    buf.resize(len, 0);
+}
+
+#[inline]
+fn patch_u32(buf: &mut [u8], pos: usize, v: u32) {
+    buf[pos..pos + 4].copy_from_slice(&v.to_le_bytes());
+}
+
+/// Writes `table` and everything it references, returning the table's position.
+fn write_table(buf: &mut Vec<u8>, table: &TableBuilder) -> usize {
+    // Inline layout: the vtable soffset first, then fields widest first so each
+    // lands on its natural alignment without interior padding.
+    let mut order: Vec<(usize, &Value)> = table
+        .fields
+        .iter()
+        .enumerate()
+        .filter_map(|(slot, v)| v.as_ref().map(|v| (slot, v)))
+        .collect();
+    order.sort_by_key(|(_, v)| std::cmp::Reverse(v.inline_size()));
+    let table_align = if order.iter().any(|(_, v)| v.inline_size() == 8) {
+        8
+    } else {
+        4
+    };
+
+    let mut slot_offsets = vec![0u16; table.fields.len()];
+    let mut cursor = 4usize;
+    for (slot, v) in &order {
+        cursor = cursor.next_multiple_of(v.inline_size());
+        slot_offsets[*slot] = cursor as u16;
+        cursor += v.inline_size();
+    }
+    let inline_len = cursor;
+
+    // Vtable immediately before the table
+    pad_to(buf, 2);
+    let vtable_pos = buf.len();
+    buf.extend_from_slice(&((4 + 2 * slot_offsets.len()) as u16).to_le_bytes());
+    buf.extend_from_slice(&(inline_len as u16).to_le_bytes());
+    for off in &slot_offsets {
+        buf.extend_from_slice(&off.to_le_bytes());
+    }
+
+    pad_to(buf, table_align);
+    let table_pos = buf.len();
+    buf.resize(table_pos + inline_len, 0);
+    buf[table_pos..table_pos + 4].copy_from_slice(&((table_pos - vtable_pos) as i32).to_le_bytes());
+
+    let mut children = Vec::new();
+    for (slot, v) in &order {
+        let pos = table_pos + slot_offsets[*slot] as usize;
+        match v {
+            Value::Bool(b) => buf[pos] = *b as u8,
+            Value::U8(x) => buf[pos] = *x,
+            Value::I16(x) => buf[pos..pos + 2].copy_from_slice(&x.to_le_bytes()),
+            Value::I32(x) => buf[pos..pos + 4].copy_from_slice(&x.to_le_bytes()),
+            Value::I64(x) => buf[pos..pos + 8].copy_from_slice(&x.to_le_bytes()),
+            _ => children.push((pos, *v)),
+        }
+    }
+
+    for (field_pos, child) in children {
+        let child_pos = match child {
+            Value::Str(s) => {
+                pad_to(buf, 4);
+                let pos = buf.len();
+                buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                buf.extend_from_slice(s.as_bytes());
+                buf.push(0);
+                pos
+            }
+            Value::Table(t) => write_table(buf, t),
+            Value::Tables(ts) => {
+                pad_to(buf, 4);
+                let pos = buf.len();
+                buf.extend_from_slice(&(ts.len() as u32).to_le_bytes());
+                buf.resize(pos + 4 + 4 * ts.len(), 0);
+                for (i, t) in ts.iter().enumerate() {
+                    let elem_pos = pos + 4 + 4 * i;
+                    let t_pos = write_table(buf, t);
+                    patch_u32(buf, elem_pos, (t_pos - elem_pos) as u32);
+                }
+                pos
+            }
+            Value::Structs { data, count } => {
+                // The length prefix sits 4 bytes before an 8-byte boundary
+                pad_to(buf, 4);
+                if buf.len().is_multiple_of(8) {
+                    buf.extend_from_slice(&[0; 4]);
+                }
+                let pos = buf.len();
+                buf.extend_from_slice(&(*count as u32).to_le_bytes());
+                buf.extend_from_slice(data);
+                pos
+            }
+            _ => unreachable!("inline values are written above"),
+        };
+        patch_u32(buf, field_pos, (child_pos - field_pos) as u32);
+    }
+    table_pos
+}
+
+/// Read-only view of a FlatBuffers table within `buf`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+#[inline]
+fn read<const N: usize>(buf: &[u8], pos: usize) -> Result<[u8; N], MinarrowError> {
+    pos.checked_add(N)
+        .and_then(|end| buf.get(pos..end))
+        .map(|b| b.try_into().unwrap())
+        .ok_or_else(|| {
+            invalid(format!(
+                "flatbuffer read of {} bytes at {} is out of bounds",
+                N, pos
+            ))
+        })
+}
+
+#[inline]
+fn read_u32(buf: &[u8], pos: usize) -> Result<usize, MinarrowError> {
+    Ok(u32::from_le_bytes(read(buf, pos)?) as usize)
+}
+
+impl<'a> Table<'a> {
+    /// Returns the root table of a finished FlatBuffer.
+    pub(crate) fn root(buf: &'a [u8]) -> Result<Self, MinarrowError> {
+        let pos = read_u32(buf, 0)?;
+        Ok(Table { buf, pos })
+    }
+
+    /// Follows the uoffset stored at `pos`.
+    #[inline]
+    fn deref(&self, pos: usize) -> Result<usize, MinarrowError> {
+        pos.checked_add(read_u32(self.buf, pos)?)
+            .ok_or_else(|| invalid("flatbuffer offset overflows"))
+    }
+
+    /// Absolute position of `slot`'s inline value, or `None` when the field is absent.
+    fn field_pos(&self, slot: usize) -> Result<Option<usize>, MinarrowError> {
+        let soffset = i32::from_le_bytes(read(self.buf, self.pos)?) as i64;
+        let vtable = self.pos as i64 - soffset;
+        if vtable < 0 {
+            return Err(invalid("flatbuffer vtable offset is out of bounds"));
+        }
+        let vtable = vtable as usize;
+        let vtable_len = u16::from_le_bytes(read(self.buf, vtable)?) as usize;
+        let entry = 4 + 2 * slot;
+        if entry + 2 > vtable_len {
+            return Ok(None);
+        }
+        let off = u16::from_le_bytes(read(self.buf, vtable + entry)?) as usize;
+        Ok((off != 0).then_some(self.pos + off))
+    }
+
+    fn scalar<const N: usize>(&self, slot: usize) -> Result<Option<[u8; N]>, MinarrowError> {
+        match self.field_pos(slot)? {
+            Some(pos) => read(self.buf, pos).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn get_bool(&self, slot: usize, default: bool) -> Result<bool, MinarrowError> {
+        Ok(self.scalar::<1>(slot)?.map_or(default, |b| b[0] != 0))
+    }
+
+    pub(crate) fn get_u8(&self, slot: usize, default: u8) -> Result<u8, MinarrowError> {
+        Ok(self.scalar::<1>(slot)?.map_or(default, |b| b[0]))
+    }
+
+    pub(crate) fn get_i16(&self, slot: usize, default: i16) -> Result<i16, MinarrowError> {
+        Ok(self.scalar(slot)?.map_or(default, i16::from_le_bytes))
+    }
+
+    pub(crate) fn get_i32(&self, slot: usize, default: i32) -> Result<i32, MinarrowError> {
+        Ok(self.scalar(slot)?.map_or(default, i32::from_le_bytes))
+    }
+
+    pub(crate) fn get_i64(&self, slot: usize, default: i64) -> Result<i64, MinarrowError> {
+        Ok(self.scalar(slot)?.map_or(default, i64::from_le_bytes))
+    }
+
+    pub(crate) fn get_str(&self, slot: usize) -> Result<Option<&'a str>, MinarrowError> {
+        let Some(pos) = self.field_pos(slot)? else {
+            return Ok(None);
+        };
+        let start = self.deref(pos)?;
+        let len = read_u32(self.buf, start)?;
+        let bytes = (start + 4)
+            .checked_add(len)
+            .and_then(|end| self.buf.get(start + 4..end))
+            .ok_or_else(|| invalid("flatbuffer string is out of bounds"))?;
+        std::str::from_utf8(bytes)
+            .map(Some)
+            .map_err(|_| invalid("flatbuffer string is not valid UTF-8"))
+    }
+
+    pub(crate) fn get_table(&self, slot: usize) -> Result<Option<Table<'a>>, MinarrowError> {
+        match self.field_pos(slot)? {
+            Some(pos) => Ok(Some(Table {
+                buf: self.buf,
+                pos: self.deref(pos)?,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads a vector of tables, returning an empty vector when the field is absent.
+    pub(crate) fn get_tables(&self, slot: usize) -> Result<Vec<Table<'a>>, MinarrowError> {
+        let Some(pos) = self.field_pos(slot)? else {
+            return Ok(Vec::new());
+        };
+        let start = self.deref(pos)?;
+        let count = read_u32(self.buf, start)?;
+        if count > self.buf.len() / 4 {
+            return Err(invalid("flatbuffer vector length is out of bounds"));
+        }
+        (0..count)
+            .map(|i| {
+                let elem = start + 4 + 4 * i;
+                Ok(Table {
+                    buf: self.buf,
+                    pos: self.deref(elem)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Reads a vector of `size`-byte structs as its raw bytes, returning an empty
+    /// slice when the field is absent.
+    pub(crate) fn get_structs(&self, slot: usize, size: usize) -> Result<&'a [u8], MinarrowError> {
+        let Some(pos) = self.field_pos(slot)? else {
+            return Ok(&[]);
+        };
+        let start = self.deref(pos)?;
+        let count = read_u32(self.buf, start)?;
+        count
+            .checked_mul(size)
+            .and_then(|n| (start + 4).checked_add(n))
+            .and_then(|end| self.buf.get(start + 4..end))
+            .ok_or_else(|| invalid("flatbuffer struct vector is out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatbuffer_round_trip() {
+        let child = TableBuilder::new()
+            .add(0, Value::I32(64))
+            .add(1, Value::Bool(true));
+        let root = TableBuilder::new()
+            .add(0, Value::I16(4))
+            .add(1, Value::U8(3))
+            .add(2, Value::Table(child.clone()))
+            .add(3, Value::I64(-7))
+            .add(5, Value::Str("abc".into()))
+            .add(6, Value::Tables(vec![child.clone(), TableBuilder::new()]))
+            .add(
+                7,
+                Value::Structs {
+                    data: [1i64, 2, 3, 4]
+                        .iter()
+                        .flat_map(|x| x.to_le_bytes())
+                        .collect(),
+                    count: 2,
+                },
+            );
+        let buf = finish(&root);
+        assert_eq!(buf.len() % 8, 0);
+
+        let t = Table::root(&buf).unwrap();
+        assert_eq!(t.get_i16(0, 0).unwrap(), 4);
+        assert_eq!(t.get_u8(1, 0).unwrap(), 3);
+        assert_eq!(t.get_i64(3, 0).unwrap(), -7);
+        assert_eq!(t.get_i32(4, 9).unwrap(), 9);
+        assert_eq!(t.get_str(5).unwrap(), Some("abc"));
+        assert_eq!(t.get_str(8).unwrap(), None);
+
+        let c = t.get_table(2).unwrap().unwrap();
+        assert_eq!(c.get_i32(0, 0).unwrap(), 64);
+        assert!(c.get_bool(1, false).unwrap());
+
+        let ts = t.get_tables(6).unwrap();
+        assert_eq!(ts.len(), 2);
+        assert_eq!(ts[0].get_i32(0, 0).unwrap(), 64);
+        assert_eq!(ts[1].get_i32(0, 5).unwrap(), 5);
+
+        let structs = t.get_structs(7, 16).unwrap();
+        assert_eq!(structs.len(), 32);
+        let pos = structs.as_ptr() as usize - buf.as_ptr() as usize;
+        assert_eq!(pos % 8, 0);
+        assert_eq!(i64::from_le_bytes(structs[24..32].try_into().unwrap()), 4);
+    }
+
+    #[test]
+    fn test_flatbuffer_rejects_out_of_bounds() {
+        let buf = [0xFFu8, 0, 0, 0];
+        let err = Table::root(&buf).and_then(|t| t.get_i32(0, 0));
+        assert!(err.is_err());
+    }
+}
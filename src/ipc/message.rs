@@ -0,0 +1,663 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **IPC Messages** - *Encoding and decoding of Arrow IPC metadata*
+//!
+//! Maps `Schema`, `RecordBatch` and `DictionaryBatch` message headers to and from
+//! their FlatBuffers form, following `Schema.fbs` and `Message.fbs`. Slot numbers
+//! below are field declaration indices in those files.
+
+use std::collections::BTreeMap;
+
+use super::flatbuf::{Table, TableBuilder, Value, finish};
+use super::invalid;
+use crate::Field;
+use crate::enums::error::MinarrowError;
+#[cfg(feature = "datetime")]
+use crate::enums::time_units::TimeUnit;
+use crate::ffi::arrow_dtype::{ArrowType, CategoricalIndexType};
+use crate::ffi::schema::Schema;
+
+/// `MetadataVersion.V5`
+const METADATA_VERSION: i16 = 4;
+
+// `MessageHeader` union
+pub(crate) const HEADER_SCHEMA: u8 = 1;
+pub(crate) const HEADER_DICTIONARY_BATCH: u8 = 2;
+pub(crate) const HEADER_RECORD_BATCH: u8 = 3;
+
+// `Type` union
+const TYPE_INT: u8 = 2;
+const TYPE_FLOAT: u8 = 3;
+const TYPE_UTF8: u8 = 5;
+const TYPE_BOOL: u8 = 6;
+#[cfg(feature = "datetime")]
+const TYPE_DATE: u8 = 8;
+#[cfg(feature = "datetime")]
+const TYPE_TIME: u8 = 9;
+#[cfg(feature = "datetime")]
+const TYPE_TIMESTAMP: u8 = 10;
+const TYPE_FIXED_SIZE_BINARY: u8 = 15;
+#[cfg(feature = "datetime")]
+const TYPE_DURATION: u8 = 18;
+const TYPE_LARGE_UTF8: u8 = 20;
+
+/// Size of the `FieldNode` and `Buffer` structs.
+const STRUCT_SIZE: usize = 16;
+
+/// Size of the `Block` struct.
+const BLOCK_SIZE: usize = 24;
+
+/// Location of a message within an IPC file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Block {
+    /// Offset of the message's continuation marker.
+    pub offset: usize,
+    /// Length of the marker, length prefix and padded metadata.
+    pub metadata_len: usize,
+    pub body_len: usize,
+}
+
+/// A decoded IPC file `Footer`.
+pub(crate) struct Footer {
+    pub schema: Schema,
+    pub encodings: Vec<Option<DictEncoding>>,
+    pub dictionaries: Vec<Block>,
+    pub record_batches: Vec<Block>,
+}
+
+/// Dictionary encoding of a schema field, as declared by its `DictionaryEncoding` table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DictEncoding {
+    pub id: i64,
+    /// Whether dictionary values are `LargeUtf8` rather than `Utf8`.
+    pub large_values: bool,
+}
+
+/// A decoded `RecordBatch` header.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BatchHeader {
+    pub length: i64,
+    /// `(length, null_count)` per field node, in depth-first field order.
+    pub nodes: Vec<(i64, i64)>,
+    /// `(offset, length)` per body buffer.
+    pub buffers: Vec<(i64, i64)>,
+}
+
+/// A decoded `Message`.
+pub(crate) enum Header {
+    Schema(Schema, Vec<Option<DictEncoding>>),
+    RecordBatch(BatchHeader),
+    DictionaryBatch {
+        id: i64,
+        batch: BatchHeader,
+        is_delta: bool,
+    },
+    /// Message types the reader skips, such as tensors.
+    Other,
+}
+
+/// Wraps `header` in a `Message` table and serialises it.
+pub(crate) fn encode_message(header_type: u8, header: TableBuilder, body_len: i64) -> Vec<u8> {
+    finish(
+        &TableBuilder::new()
+            .add(0, Value::I16(METADATA_VERSION))
+            .add(1, Value::U8(header_type))
+            .add(2, Value::Table(header))
+            .add(3, Value::I64(body_len)),
+    )
+}
+
+/// Parses a `Message` flatbuffer, returning the header and the body length.
+pub(crate) fn decode_message(buf: &[u8]) -> Result<(Header, i64), MinarrowError> {
+    let msg = Table::root(buf)?;
+    let body_len = msg.get_i64(3, 0)?;
+    if body_len < 0 {
+        return Err(invalid("negative message body length"));
+    }
+    let header_type = msg.get_u8(1, 0)?;
+    let header = match header_type {
+        HEADER_SCHEMA | HEADER_RECORD_BATCH | HEADER_DICTIONARY_BATCH => msg
+            .get_table(2)?
+            .ok_or_else(|| invalid("message has no header"))?,
+        _ => return Ok((Header::Other, body_len)),
+    };
+    let header = match header_type {
+        HEADER_SCHEMA => {
+            let (schema, dicts) = decode_schema(header)?;
+            Header::Schema(schema, dicts)
+        }
+        HEADER_RECORD_BATCH => Header::RecordBatch(decode_batch(header)?),
+        _ => Header::DictionaryBatch {
+            id: header.get_i64(0, 0)?,
+            batch: decode_batch(
+                header
+                    .get_table(1)?
+                    .ok_or_else(|| invalid("dictionary batch has no data"))?,
+            )?,
+            is_delta: header.get_bool(2, false)?,
+        },
+    };
+    Ok((header, body_len))
+}
+
+/// Builds a `RecordBatch` header table.
+pub(crate) fn encode_batch(
+    length: usize,
+    nodes: &[(usize, usize)],
+    buffers: &[(usize, usize)],
+) -> TableBuilder {
+    let structs = |pairs: &[(usize, usize)]| Value::Structs {
+        data: pairs
+            .iter()
+            .flat_map(|&(a, b)| [(a as i64).to_le_bytes(), (b as i64).to_le_bytes()])
+            .flatten()
+            .collect(),
+        count: pairs.len(),
+    };
+    TableBuilder::new()
+        .add(0, Value::I64(length as i64))
+        .add(1, structs(nodes))
+        .add(2, structs(buffers))
+}
+
+/// Builds a `DictionaryBatch` header table.
+pub(crate) fn encode_dictionary_batch(
+    id: i64,
+    batch: TableBuilder,
+    is_delta: bool,
+) -> TableBuilder {
+    TableBuilder::new()
+        .add(0, Value::I64(id))
+        .add(1, Value::Table(batch))
+        .add(2, Value::Bool(is_delta))
+}
+
+/// Serialises the IPC file `Footer`, indexing the dictionary and record batch messages.
+pub(crate) fn encode_footer(
+    schema: &Schema,
+    dictionaries: &[Block],
+    record_batches: &[Block],
+) -> Result<Vec<u8>, MinarrowError> {
+    let blocks = |blocks: &[Block]| Value::Structs {
+        data: blocks
+            .iter()
+            .flat_map(|b| {
+                let mut bytes = [0u8; BLOCK_SIZE];
+                bytes[..8].copy_from_slice(&(b.offset as i64).to_le_bytes());
+                bytes[8..12].copy_from_slice(&(b.metadata_len as i32).to_le_bytes());
+                bytes[16..].copy_from_slice(&(b.body_len as i64).to_le_bytes());
+                bytes
+            })
+            .collect(),
+        count: blocks.len(),
+    };
+    Ok(finish(
+        &TableBuilder::new()
+            .add(0, Value::I16(METADATA_VERSION))
+            .add(1, Value::Table(encode_schema(schema)?))
+            .add(2, blocks(dictionaries))
+            .add(3, blocks(record_batches)),
+    ))
+}
+
+/// Parses the IPC file `Footer`, returning the schema, field dictionary encodings,
+/// and the dictionary and record batch blocks.
+pub(crate) fn decode_footer(buf: &[u8]) -> Result<Footer, MinarrowError> {
+    let footer = Table::root(buf)?;
+    let (schema, encodings) = decode_schema(
+        footer
+            .get_table(1)?
+            .ok_or_else(|| invalid("file footer has no schema"))?,
+    )?;
+    let blocks = |bytes: &[u8]| -> Result<Vec<Block>, MinarrowError> {
+        bytes
+            .chunks_exact(BLOCK_SIZE)
+            .map(|c| {
+                let offset = i64::from_le_bytes(c[..8].try_into().unwrap());
+                let metadata_len = i32::from_le_bytes(c[8..12].try_into().unwrap());
+                let body_len = i64::from_le_bytes(c[16..].try_into().unwrap());
+                match (
+                    usize::try_from(offset),
+                    usize::try_from(metadata_len),
+                    usize::try_from(body_len),
+                ) {
+                    (Ok(offset), Ok(metadata_len), Ok(body_len)) => Ok(Block {
+                        offset,
+                        metadata_len,
+                        body_len,
+                    }),
+                    _ => Err(invalid("file block has a negative offset or length")),
+                }
+            })
+            .collect()
+    };
+    Ok(Footer {
+        schema,
+        encodings,
+        dictionaries: blocks(footer.get_structs(2, BLOCK_SIZE)?)?,
+        record_batches: blocks(footer.get_structs(3, BLOCK_SIZE)?)?,
+    })
+}
+
+fn decode_batch(t: Table) -> Result<BatchHeader, MinarrowError> {
+    if t.get_table(3)?.is_some() {
+        return Err(MinarrowError::NotImplemented {
+            feature: "compressed Arrow IPC record batches".into(),
+        });
+    }
+    let pairs = |bytes: &[u8]| -> Vec<(i64, i64)> {
+        bytes
+            .chunks_exact(STRUCT_SIZE)
+            .map(|c| {
+                (
+                    i64::from_le_bytes(c[..8].try_into().unwrap()),
+                    i64::from_le_bytes(c[8..].try_into().unwrap()),
+                )
+            })
+            .collect()
+    };
+    Ok(BatchHeader {
+        length: t.get_i64(0, 0)?,
+        nodes: pairs(t.get_structs(1, STRUCT_SIZE)?),
+        buffers: pairs(t.get_structs(2, STRUCT_SIZE)?),
+    })
+}
+
+fn encode_metadata(metadata: &BTreeMap<String, String>) -> Value {
+    Value::Tables(
+        metadata
+            .iter()
+            .map(|(k, v)| {
+                TableBuilder::new()
+                    .add(0, Value::Str(k.clone()))
+                    .add(1, Value::Str(v.clone()))
+            })
+            .collect(),
+    )
+}
+
+fn decode_metadata(t: &Table, slot: usize) -> Result<BTreeMap<String, String>, MinarrowError> {
+    t.get_tables(slot)?
+        .into_iter()
+        .map(|kv| {
+            Ok((
+                kv.get_str(0)?.unwrap_or_default().to_string(),
+                kv.get_str(1)?.unwrap_or_default().to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Builds the `Schema` header table. Dictionary fields are given the field index
+/// as their dictionary id.
+pub(crate) fn encode_schema(schema: &Schema) -> Result<TableBuilder, MinarrowError> {
+    let fields = schema
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| encode_field(f, i as i64))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut t = TableBuilder::new()
+        .add(0, Value::I16(cfg!(target_endian = "big") as i16))
+        .add(1, Value::Tables(fields));
+    if !schema.metadata.is_empty() {
+        t = t.add(2, encode_metadata(&schema.metadata));
+    }
+    Ok(t)
+}
+
+fn int_type(bits: i32, signed: bool) -> TableBuilder {
+    TableBuilder::new()
+        .add(0, Value::I32(bits))
+        .add(1, Value::Bool(signed))
+}
+
+#[cfg(feature = "datetime")]
+fn unit_code(unit: &TimeUnit) -> Result<i16, MinarrowError> {
+    match unit {
+        TimeUnit::Seconds => Ok(0),
+        TimeUnit::Milliseconds => Ok(1),
+        TimeUnit::Microseconds => Ok(2),
+        TimeUnit::Nanoseconds => Ok(3),
+        TimeUnit::Days => Err(MinarrowError::IncompatibleTypeError {
+            from: "TimeUnit::Days",
+            to: "Arrow IPC TimeUnit",
+            message: Some("Days is only valid for Date32".into()),
+        }),
+    }
+}
+
+#[cfg(feature = "datetime")]
+fn unit_from_code(code: i16) -> Result<TimeUnit, MinarrowError> {
+    match code {
+        0 => Ok(TimeUnit::Seconds),
+        1 => Ok(TimeUnit::Milliseconds),
+        2 => Ok(TimeUnit::Microseconds),
+        3 => Ok(TimeUnit::Nanoseconds),
+        _ => Err(invalid(format!("unknown time unit {}", code))),
+    }
+}
+
+/// Index width of a categorical index type.
+pub(crate) fn index_bits(idx: &CategoricalIndexType) -> i32 {
+    match idx {
+        #[cfg(feature = "default_categorical_8")]
+        CategoricalIndexType::UInt8 => 8,
+        #[cfg(feature = "extended_categorical")]
+        CategoricalIndexType::UInt16 => 16,
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        CategoricalIndexType::UInt32 => 32,
+        #[cfg(feature = "extended_categorical")]
+        CategoricalIndexType::UInt64 => 64,
+    }
+}
+
+fn index_type(bits: i32) -> Result<CategoricalIndexType, MinarrowError> {
+    match bits {
+        #[cfg(feature = "default_categorical_8")]
+        8 => Ok(CategoricalIndexType::UInt8),
+        #[cfg(feature = "extended_categorical")]
+        16 => Ok(CategoricalIndexType::UInt16),
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        32 => Ok(CategoricalIndexType::UInt32),
+        #[cfg(feature = "extended_categorical")]
+        64 => Ok(CategoricalIndexType::UInt64),
+        _ => Err(MinarrowError::NotImplemented {
+            feature: format!("{}-bit dictionary indices in this build", bits),
+        }),
+    }
+}
+
+fn encode_field(field: &Field, dict_id: i64) -> Result<TableBuilder, MinarrowError> {
+    let empty = TableBuilder::new;
+    let mut dictionary = None;
+    let (type_id, ty) = match &field.dtype {
+        ArrowType::Boolean => (TYPE_BOOL, empty()),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => (TYPE_INT, int_type(8, true)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => (TYPE_INT, int_type(16, true)),
+        ArrowType::Int32 => (TYPE_INT, int_type(32, true)),
+        ArrowType::Int64 => (TYPE_INT, int_type(64, true)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => (TYPE_INT, int_type(8, false)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => (TYPE_INT, int_type(16, false)),
+        ArrowType::UInt32 => (TYPE_INT, int_type(32, false)),
+        ArrowType::UInt64 => (TYPE_INT, int_type(64, false)),
+        ArrowType::Float32 => (TYPE_FLOAT, empty().add(0, Value::I16(1))),
+        ArrowType::Float64 => (TYPE_FLOAT, empty().add(0, Value::I16(2))),
+        #[cfg(feature = "datetime")]
+        ArrowType::Date32 => (TYPE_DATE, empty().add(0, Value::I16(0))),
+        #[cfg(feature = "datetime")]
+        ArrowType::Date64 => (TYPE_DATE, empty().add(0, Value::I16(1))),
+        #[cfg(feature = "datetime")]
+        ArrowType::Time32(u) => (
+            TYPE_TIME,
+            empty()
+                .add(0, Value::I16(unit_code(u)?))
+                .add(1, Value::I32(32)),
+        ),
+        #[cfg(feature = "datetime")]
+        ArrowType::Time64(u) => (
+            TYPE_TIME,
+            empty()
+                .add(0, Value::I16(unit_code(u)?))
+                .add(1, Value::I32(64)),
+        ),
+        #[cfg(feature = "datetime")]
+        ArrowType::Timestamp(u, tz) => {
+            let mut t = empty().add(0, Value::I16(unit_code(u)?));
+            if let Some(tz) = tz {
+                t = t.add(1, Value::Str(tz.clone()));
+            }
+            (TYPE_TIMESTAMP, t)
+        }
+        #[cfg(feature = "datetime")]
+        ArrowType::Duration64(u) => (TYPE_DURATION, empty().add(0, Value::I16(unit_code(u)?))),
+        ArrowType::String => (TYPE_UTF8, empty()),
+        #[cfg(feature = "large_string")]
+        ArrowType::LargeString => (TYPE_LARGE_UTF8, empty()),
+        ArrowType::FixedSizeBinary(w) => (TYPE_FIXED_SIZE_BINARY, empty().add(0, Value::I32(*w))),
+        ArrowType::Dictionary(idx) => {
+            dictionary = Some(
+                empty()
+                    .add(0, Value::I64(dict_id))
+                    .add(1, Value::Table(int_type(index_bits(idx), false)))
+                    .add(2, Value::Bool(field.is_ordered_dictionary())),
+            );
+            (TYPE_UTF8, empty())
+        }
+        other => {
+            return Err(MinarrowError::NotImplemented {
+                feature: format!("Arrow IPC encoding of {} fields", other),
+            });
+        }
+    };
+    let mut t = empty()
+        .add(0, Value::Str(field.name.clone()))
+        .add(1, Value::Bool(field.nullable))
+        .add(2, Value::U8(type_id))
+        .add(3, Value::Table(ty))
+        // Readers expect the children vector even for flat types
+        .add(5, Value::Tables(Vec::new()));
+    if let Some(d) = dictionary {
+        t = t.add(4, Value::Table(d));
+    }
+    if !field.metadata.is_empty() {
+        t = t.add(6, encode_metadata(&field.metadata));
+    }
+    Ok(t)
+}
+
+fn decode_schema(t: Table) -> Result<(Schema, Vec<Option<DictEncoding>>), MinarrowError> {
+    if t.get_i16(0, 0)? != cfg!(target_endian = "big") as i16 {
+        return Err(MinarrowError::NotImplemented {
+            feature: "Arrow IPC streams with non-native endianness".into(),
+        });
+    }
+    let mut fields = Vec::new();
+    let mut dicts = Vec::new();
+    for f in t.get_tables(1)? {
+        let (field, dict) = decode_field(f)?;
+        fields.push(field);
+        dicts.push(dict);
+    }
+    Ok((Schema::new(fields, decode_metadata(&t, 2)?), dicts))
+}
+
+fn decode_field(t: Table) -> Result<(Field, Option<DictEncoding>), MinarrowError> {
+    let name = t.get_str(0)?.unwrap_or_default();
+    if !t.get_tables(5)?.is_empty() {
+        return Err(MinarrowError::NotImplemented {
+            feature: format!("Arrow IPC nested field '{}'", name),
+        });
+    }
+    let type_id = t.get_u8(2, 0)?;
+    let (dtype, dict, ordered) = match t.get_table(4)? {
+        Some(d) => {
+            if type_id != TYPE_UTF8 && type_id != TYPE_LARGE_UTF8 {
+                return Err(MinarrowError::NotImplemented {
+                    feature: format!("Arrow IPC dictionary of non-string values for '{}'", name),
+                });
+            }
+            // An absent index type means signed 32-bit
+            let index_bits = match d.get_table(1)? {
+                Some(idx) => idx.get_i32(0, 0)?,
+                None => 32,
+            };
+            let dict = DictEncoding {
+                id: d.get_i64(0, 0)?,
+                large_values: type_id == TYPE_LARGE_UTF8,
+            };
+            (
+                ArrowType::Dictionary(index_type(index_bits)?),
+                Some(dict),
+                d.get_bool(2, false)?,
+            )
+        }
+        None => {
+            let ty = t.get_table(3)?.unwrap_or(t);
+            (decode_type(type_id, ty, name)?, None, false)
+        }
+    };
+    let field = Field::new(
+        name,
+        dtype,
+        t.get_bool(1, false)?,
+        Some(decode_metadata(&t, 6)?),
+    )
+    .with_ordered(ordered);
+    Ok((field, dict))
+}
+
+/// Maps a `Type` union member to its `ArrowType`. Defaults follow `Schema.fbs`.
+fn decode_type(type_id: u8, ty: Table, name: &str) -> Result<ArrowType, MinarrowError> {
+    let unsupported = || MinarrowError::NotImplemented {
+        feature: format!("Arrow IPC type id {} for field '{}'", type_id, name),
+    };
+    Ok(match type_id {
+        TYPE_BOOL => ArrowType::Boolean,
+        TYPE_INT => match (ty.get_i32(0, 0)?, ty.get_bool(1, false)?) {
+            #[cfg(feature = "extended_numeric_types")]
+            (8, true) => ArrowType::Int8,
+            #[cfg(feature = "extended_numeric_types")]
+            (16, true) => ArrowType::Int16,
+            (32, true) => ArrowType::Int32,
+            (64, true) => ArrowType::Int64,
+            #[cfg(feature = "extended_numeric_types")]
+            (8, false) => ArrowType::UInt8,
+            #[cfg(feature = "extended_numeric_types")]
+            (16, false) => ArrowType::UInt16,
+            (32, false) => ArrowType::UInt32,
+            (64, false) => ArrowType::UInt64,
+            _ => return Err(unsupported()),
+        },
+        TYPE_FLOAT => match ty.get_i16(0, 0)? {
+            1 => ArrowType::Float32,
+            2 => ArrowType::Float64,
+            _ => return Err(unsupported()),
+        },
+        TYPE_UTF8 => ArrowType::String,
+        #[cfg(feature = "large_string")]
+        TYPE_LARGE_UTF8 => ArrowType::LargeString,
+        TYPE_FIXED_SIZE_BINARY => ArrowType::FixedSizeBinary(ty.get_i32(0, 0)?),
+        #[cfg(feature = "datetime")]
+        TYPE_DATE => match ty.get_i16(0, 1)? {
+            0 => ArrowType::Date32,
+            _ => ArrowType::Date64,
+        },
+        #[cfg(feature = "datetime")]
+        TYPE_TIME => {
+            let unit = unit_from_code(ty.get_i16(0, 1)?)?;
+            match ty.get_i32(1, 32)? {
+                32 => ArrowType::Time32(unit),
+                _ => ArrowType::Time64(unit),
+            }
+        }
+        #[cfg(feature = "datetime")]
+        TYPE_TIMESTAMP => ArrowType::Timestamp(
+            unit_from_code(ty.get_i16(0, 0)?)?,
+            ty.get_str(1)?.map(str::to_string),
+        ),
+        #[cfg(feature = "datetime")]
+        TYPE_DURATION => ArrowType::Duration64(unit_from_code(ty.get_i16(0, 1)?)?),
+        _ => return Err(unsupported()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_round_trip() {
+        let mut meta = BTreeMap::new();
+        meta.insert("k".to_string(), "v".to_string());
+        let schema = Schema::new(
+            vec![
+                Field::new("a", ArrowType::Int64, true, None),
+                Field::new("b", ArrowType::String, false, Some(meta.clone())),
+                Field::new("c", ArrowType::FixedSizeBinary(4), true, None),
+            ],
+            meta,
+        );
+        let buf = encode_message(HEADER_SCHEMA, encode_schema(&schema).unwrap(), 0);
+        match decode_message(&buf).unwrap() {
+            (Header::Schema(decoded, dicts), 0) => {
+                assert_eq!(decoded, schema);
+                assert_eq!(dicts, vec![None, None, None]);
+            }
+            _ => panic!("expected a schema message"),
+        }
+    }
+
+    #[test]
+    fn test_batch_header_round_trip() {
+        let header = encode_batch(3, &[(3, 1)], &[(0, 1), (64, 24)]);
+        let buf = encode_message(HEADER_RECORD_BATCH, header, 128);
+        match decode_message(&buf).unwrap() {
+            (Header::RecordBatch(b), 128) => {
+                assert_eq!(b.length, 3);
+                assert_eq!(b.nodes, vec![(3, 1)]);
+                assert_eq!(b.buffers, vec![(0, 1), (64, 24)]);
+            }
+            _ => panic!("expected a record batch message"),
+        }
+    }
+
+    #[test]
+    fn test_footer_round_trip() {
+        let schema = Schema::from(vec![Field::new("a", ArrowType::Int32, true, None)]);
+        let dicts = [Block {
+            offset: 64,
+            metadata_len: 128,
+            body_len: 64,
+        }];
+        let batches = [
+            Block {
+                offset: 256,
+                metadata_len: 192,
+                body_len: 128,
+            },
+            Block {
+                offset: 576,
+                metadata_len: 192,
+                body_len: 0,
+            },
+        ];
+        let buf = encode_footer(&schema, &dicts, &batches).unwrap();
+        let footer = decode_footer(&buf).unwrap();
+        assert_eq!(footer.schema, schema);
+        assert_eq!(footer.encodings, vec![None]);
+        assert_eq!(footer.dictionaries, dicts);
+        assert_eq!(footer.record_batches, batches);
+    }
+
+    #[test]
+    fn test_encode_unsupported_type() {
+        let schema = Schema::from(vec![Field::new("v", ArrowType::Utf8View, true, None)]);
+        assert!(matches!(
+            encode_schema(&schema),
+            Err(MinarrowError::NotImplemented { .. })
+        ));
+    }
+}
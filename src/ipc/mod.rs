@@ -0,0 +1,424 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **IPC Module** - *Arrow IPC streaming and file formats*
+//!
+//! Synchronous reading and writing of the Arrow IPC streaming format, the byte layout
+//! PyArrow exposes through `ipc.new_stream` and `ipc.open_stream`, and of the Arrow
+//! IPC file format, also known as Feather v2.
+//!
+//! ## Overview
+//! - `StreamWriter` writes a `Schema` message, then one `RecordBatch` message per
+//!   `Table`, with `DictionaryBatch` messages for categorical columns as their
+//!   dictionaries change.
+//! - `StreamReader` reads such a stream back, yielding a `Table` per record batch.
+//! - `FileWriter` writes the same messages between `ARROW1` magic bytes, followed by
+//!   a footer indexing every dictionary and record batch.
+//! - `FileReader` reads the footer, then decodes record batches on demand. Over a
+//!   `SharedBuffer`, such as a wrapped memory map, columns share its memory.
+//! - `Table::write_ipc_file` and `SuperTable::read_ipc_file` cover the common case.
+//!
+//! ## Format
+//! - Each message is the `0xFFFFFFFF` continuation marker, the metadata length, a
+//!   FlatBuffers `Message`, then the body. The stream ends with a zero length.
+//! - Body buffers are padded to 64 bytes, matching Minarrow's own buffer alignment.
+//!   In files, each body also starts on a 64-byte boundary of the file.
+//! - The validity buffer is omitted for columns with no nulls.
+//! - Fields use the same `Schema` and `ArrowType` mapping as the C Data Interface
+//!   export. Categorical fields are sent as dictionary-encoded `Utf8` with unsigned
+//!   indices, using the field index as the dictionary id.
+//! - Files cannot replace a dictionary, so a categorical column may only append
+//!   values to its dictionary between batches. These are written as deltas.
+//! - Types without an IPC mapping here, such as `Utf8View`, `Interval`,
+//!   `Duration32`, nested types and compressed bodies, return `NotImplemented`.
+
+mod flatbuf;
+mod message;
+mod reader;
+mod writer;
+
+pub use reader::{FileReader, StreamReader};
+pub use writer::{FileWriter, StreamWriter};
+
+use crate::enums::error::MinarrowError;
+
+/// Marks the start of an encapsulated message.
+pub(crate) const CONTINUATION: [u8; 4] = [0xFF; 4];
+
+/// Leading and trailing magic of the IPC file format.
+pub(crate) const FILE_MAGIC: [u8; 6] = *b"ARROW1";
+
+/// Alignment of each buffer within a message body.
+pub(crate) const BODY_ALIGNMENT: usize = 64;
+
+#[inline]
+pub(crate) fn invalid(message: impl std::fmt::Display) -> MinarrowError {
+    MinarrowError::IoError(format!("invalid Arrow IPC data: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::arrow_dtype::ArrowType;
+    use crate::ffi::schema::Schema;
+    use crate::structs::field_array::FieldArray;
+    use crate::{
+        Array, Bitmask, BooleanArray, Field, FixedSizeBinaryArray, FloatArray, IntegerArray,
+        MaskedArray, StringArray, Table, vec64,
+    };
+
+    fn round_trip(tables: &[Table]) -> Vec<Table> {
+        let fields = tables[0]
+            .cols
+            .iter()
+            .map(|c| (*c.field).clone())
+            .collect::<Vec<_>>();
+        let mut writer = StreamWriter::new(Vec::new(), Schema::from(fields)).unwrap();
+        for t in tables {
+            writer.write(t).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+        StreamReader::new(&bytes[..])
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    fn col(name: &str, dtype: ArrowType, array: Array) -> FieldArray {
+        FieldArray::new(Field::new(name, dtype, true, None), array)
+    }
+
+    fn assert_same(a: &Table, b: &Table) {
+        assert_eq!(a.n_rows, b.n_rows);
+        assert_eq!(a.cols.len(), b.cols.len());
+        for (x, y) in a.cols.iter().zip(&b.cols) {
+            assert_eq!(x.field, y.field);
+            assert_eq!(x.array, y.array);
+            assert_eq!(x.null_count, y.null_count);
+        }
+    }
+
+    #[test]
+    fn test_stream_framing() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col(
+                "a",
+                ArrowType::Int32,
+                Array::from_int32(IntegerArray::from_slice(&[1, 2])),
+            )]),
+        );
+        let mut writer =
+            StreamWriter::new(Vec::new(), Schema::from(vec![(*t.cols[0].field).clone()])).unwrap();
+        writer.write(&t).unwrap();
+        let bytes = writer.into_inner().unwrap();
+        assert_eq!(&bytes[..4], &CONTINUATION);
+        let meta_len = i32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        assert_eq!(meta_len % 8, 0);
+        assert_eq!(
+            &bytes[bytes.len() - 8..],
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_round_trip_primitives_with_nulls() {
+        let mut mask = Bitmask::new_set_all(4, true);
+        mask.set(1, false);
+        let t = Table::new(
+            "t".into(),
+            Some(vec![
+                col(
+                    "i",
+                    ArrowType::Int64,
+                    Array::from_int64(IntegerArray::new(vec64![1, 0, 3, 4], Some(mask.clone()))),
+                ),
+                col(
+                    "f",
+                    ArrowType::Float32,
+                    Array::from_float32(FloatArray::from_slice(&[0.5, 1.5, 2.5, 3.5])),
+                ),
+                col(
+                    "u",
+                    ArrowType::UInt32,
+                    Array::from_uint32(IntegerArray::from_slice(&[7, 8, 9, 10])),
+                ),
+                col(
+                    "b",
+                    ArrowType::Boolean,
+                    Array::from_bool(BooleanArray::from_slice(&[true, false, false, true])),
+                ),
+                col(
+                    "x",
+                    ArrowType::FixedSizeBinary(2),
+                    Array::from_fixed_size_binary(FixedSizeBinaryArray::new(
+                        vec64![1u8, 2, 3, 4, 5, 6, 7, 8],
+                        2,
+                        Some(mask),
+                    )),
+                ),
+            ]),
+        );
+        let out = round_trip(std::slice::from_ref(&t));
+        assert_eq!(out.len(), 1);
+        assert_same(&out[0], &t);
+        assert_eq!(out[0].cols[0].null_count, 1);
+    }
+
+    #[test]
+    fn test_round_trip_strings() {
+        let mut s = StringArray::<u32>::from_slice(&["alpha", "", "gamma"]);
+        s.push_null();
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col("s", ArrowType::String, Array::from_string32(s))]),
+        );
+        let out = round_trip(std::slice::from_ref(&t));
+        assert_same(&out[0], &t);
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_round_trip_categorical_dictionary_changes() {
+        use crate::ffi::arrow_dtype::CategoricalIndexType;
+        use crate::{CategoricalArray, Vec64};
+
+        let dtype = ArrowType::Dictionary(CategoricalIndexType::UInt32);
+        let make = |codes: Vec64<u32>, dict: &[&str]| {
+            let dict = dict.iter().map(|s| s.to_string()).collect();
+            Table::new(
+                "t".into(),
+                Some(vec![col(
+                    "c",
+                    dtype.clone(),
+                    Array::from_categorical32(CategoricalArray::new(codes, dict, None)),
+                )]),
+            )
+        };
+        let t1 = make(vec64![0, 1, 0], &["a", "b"]);
+        let t2 = make(vec64![1, 1], &["a", "b"]);
+        let t3 = make(vec64![2, 0], &["x", "y", "z"]);
+        let out = round_trip(&[t1.clone(), t2.clone(), t3.clone()]);
+        assert_eq!(out.len(), 3);
+        assert_same(&out[0], &t1);
+        assert_same(&out[1], &t2);
+        assert_same(&out[2], &t3);
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_round_trip_temporal() {
+        use crate::{DatetimeArray, TimeUnit};
+
+        let t = Table::new(
+            "t".into(),
+            Some(vec![
+                col(
+                    "ts",
+                    ArrowType::Timestamp(TimeUnit::Milliseconds, Some("UTC".into())),
+                    Array::from_datetime_i64(DatetimeArray::new(
+                        vec64![1_700_000_000_000i64, 0],
+                        None,
+                        Some(TimeUnit::Milliseconds),
+                    )),
+                ),
+                col(
+                    "d",
+                    ArrowType::Date32,
+                    Array::from_datetime_i32(DatetimeArray::new(
+                        vec64![19000i32, 19001],
+                        None,
+                        Some(TimeUnit::Days),
+                    )),
+                ),
+            ]),
+        );
+        let out = round_trip(std::slice::from_ref(&t));
+        assert_same(&out[0], &t);
+    }
+
+    #[test]
+    fn test_write_rejects_schema_mismatch() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col(
+                "a",
+                ArrowType::Int32,
+                Array::from_int32(IntegerArray::from_slice(&[1])),
+            )]),
+        );
+        let schema = Schema::from(vec![Field::new("a", ArrowType::Int64, true, None)]);
+        let mut writer = StreamWriter::new(Vec::new(), schema).unwrap();
+        assert!(matches!(
+            writer.write(&t),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_read_rejects_truncated_stream() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col(
+                "a",
+                ArrowType::Int32,
+                Array::from_int32(IntegerArray::from_slice(&[1, 2, 3])),
+            )]),
+        );
+        let mut writer =
+            StreamWriter::new(Vec::new(), Schema::from(vec![(*t.cols[0].field).clone()])).unwrap();
+        writer.write(&t).unwrap();
+        let bytes = writer.into_inner().unwrap();
+        let truncated = &bytes[..bytes.len() - 40];
+        let mut reader = StreamReader::new(truncated).unwrap();
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+
+    fn write_file(tables: &[Table]) -> Vec<u8> {
+        let fields = tables[0]
+            .cols
+            .iter()
+            .map(|c| (*c.field).clone())
+            .collect::<Vec<_>>();
+        let mut writer = FileWriter::new(Vec::new(), Schema::from(fields)).unwrap();
+        for t in tables {
+            writer.write(t).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn open_file(bytes: Vec<u8>) -> FileReader {
+        let mut data = crate::Vec64::with_capacity(bytes.len());
+        data.extend_from_slice(&bytes);
+        FileReader::new(crate::SharedBuffer::from_vec64(data)).unwrap()
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let make = |vals: &[i64], strs: &[&str]| {
+            Table::new(
+                "t".into(),
+                Some(vec![
+                    col(
+                        "i",
+                        ArrowType::Int64,
+                        Array::from_int64(IntegerArray::from_slice(vals)),
+                    ),
+                    col(
+                        "s",
+                        ArrowType::String,
+                        Array::from_string32(StringArray::from_slice(strs)),
+                    ),
+                ]),
+            )
+        };
+        let t1 = make(&[1, 2, 3], &["a", "bb", "ccc"]);
+        let t2 = make(&[4, 5], &["dddd", ""]);
+        let bytes = write_file(&[t1.clone(), t2.clone()]);
+        assert_eq!(&bytes[..8], b"ARROW1\0\0");
+        assert_eq!(&bytes[bytes.len() - 6..], &FILE_MAGIC);
+
+        let reader = open_file(bytes);
+        assert_eq!(reader.num_batches(), 2);
+        assert_eq!(reader.schema().fields.len(), 2);
+        assert_same(&reader.read_batch(1).unwrap(), &t2);
+        let out = reader.batches().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_same(&out[0], &t1);
+        assert_same(&out[1], &t2);
+        assert!(matches!(
+            reader.read_batch(2),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_file_read_shares_aligned_buffers() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col(
+                "f",
+                ArrowType::Float64,
+                Array::from_float64(FloatArray::from_slice(&[1.0, 2.0, 3.0])),
+            )]),
+        );
+        let reader = open_file(write_file(std::slice::from_ref(&t)));
+        let out = reader.read_batch(0).unwrap();
+        match &out.cols[0].array {
+            Array::NumericArray(crate::NumericArray::Float64(arr)) => {
+                assert!(arr.data.is_shared());
+                assert_eq!(arr.data.as_slice(), &[1.0, 2.0, 3.0]);
+            }
+            other => panic!("unexpected array {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_file_rejects_bad_magic() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![col(
+                "a",
+                ArrowType::Int32,
+                Array::from_int32(IntegerArray::from_slice(&[1])),
+            )]),
+        );
+        let mut bytes = write_file(std::slice::from_ref(&t));
+        let n = bytes.len();
+        bytes[n - 1] = b'0';
+        let mut data = crate::Vec64::with_capacity(n);
+        data.extend_from_slice(&bytes);
+        assert!(FileReader::new(crate::SharedBuffer::from_vec64(data)).is_err());
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_file_dictionary_deltas() {
+        use crate::ffi::arrow_dtype::CategoricalIndexType;
+        use crate::{CategoricalArray, Vec64};
+
+        let dtype = ArrowType::Dictionary(CategoricalIndexType::UInt32);
+        let make = |codes: Vec64<u32>, dict: &[&str]| {
+            let dict = dict.iter().map(|s| s.to_string()).collect();
+            Table::new(
+                "t".into(),
+                Some(vec![col(
+                    "c",
+                    dtype.clone(),
+                    Array::from_categorical32(CategoricalArray::new(codes, dict, None)),
+                )]),
+            )
+        };
+        let t1 = make(vec64![0, 1], &["a", "b"]);
+        let t2 = make(vec64![2, 0], &["a", "b", "c"]);
+        let reader = open_file(write_file(&[t1.clone(), t2.clone()]));
+        assert_same(&reader.read_batch(1).unwrap(), &t2);
+        assert_same(&reader.read_batch(0).unwrap(), &t1);
+
+        let t3 = make(vec64![0], &["z"]);
+        let schema = Schema::from(vec![(*t1.cols[0].field).clone()]);
+        let mut writer = FileWriter::new(Vec::new(), schema).unwrap();
+        writer.write(&t1).unwrap();
+        assert!(matches!(
+            writer.write(&t3),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+    }
+}
@@ -0,0 +1,728 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **IPC Readers** - *Arrow IPC stream and file formats to Tables*
+
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::message::{BatchHeader, Block, DictEncoding, Header, decode_footer, decode_message};
+use super::{BODY_ALIGNMENT, CONTINUATION, FILE_MAGIC, invalid};
+use crate::enums::error::MinarrowError;
+use crate::ffi::arrow_dtype::{ArrowType, CategoricalIndexType};
+use crate::ffi::schema::Schema;
+use crate::structs::buffer::Buffer;
+use crate::structs::field_array::FieldArray;
+use crate::structs::shared_buffer::SharedBuffer;
+use crate::traits::type_unions::Integer;
+use crate::{
+    Array, Bitmask, BooleanArray, CategoricalArray, Field, FixedSizeBinaryArray, FloatArray,
+    IntegerArray, StringArray, Table, Vec64,
+};
+#[cfg(feature = "datetime")]
+use crate::{DatetimeArray, TimeUnit};
+
+/// # StreamReader
+///
+/// Reads `Table`s from the Arrow IPC streaming format, one per record batch.
+///
+/// The schema message is read on construction. Dictionary batches are applied as
+/// they arrive, including deltas, and the stream ends at the end-of-stream marker
+/// or a clean end of input. Compressed bodies, nested types and non-native
+/// endianness are reported as `NotImplemented`.
+///
+/// Each message body is read into a single 64-byte aligned allocation, which the
+/// returned columns share wherever their buffers are 64-byte aligned within it.
+///
+/// Streams written by `StreamWriter`, or by PyArrow's `ipc.new_stream`, can be read
+/// with `StreamReader::new(reader)` and iterated for tables.
+pub struct StreamReader<R: Read> {
+    reader: R,
+    decoder: Decoder,
+    finished: bool,
+}
+
+impl<R: Read> StreamReader<R> {
+    /// Creates a reader and reads the schema message.
+    ///
+    /// # Errors
+    /// Returns `IoError` if the stream does not begin with a valid schema message,
+    /// and `NotImplemented` for field types this reader does not support.
+    pub fn new(mut reader: R) -> Result<Self, MinarrowError> {
+        match read_message(&mut reader)? {
+            Some((Header::Schema(schema, encodings), _)) => Ok(Self {
+                reader,
+                decoder: Decoder::new(schema, encodings),
+                finished: false,
+            }),
+            Some(_) => Err(invalid("stream does not begin with a schema message")),
+            None => Err(invalid("stream has no schema message")),
+        }
+    }
+
+    /// The stream's schema.
+    #[inline]
+    pub fn schema(&self) -> &Schema {
+        &self.decoder.schema
+    }
+
+    /// Reads messages until the next record batch, returning `None` at end of stream.
+    fn next_table(&mut self) -> Result<Option<Table>, MinarrowError> {
+        loop {
+            let Some((header, body)) = read_message(&mut self.reader)? else {
+                return Ok(None);
+            };
+            match header {
+                Header::DictionaryBatch {
+                    id,
+                    batch,
+                    is_delta,
+                } => self.decoder.apply_dictionary(id, &batch, &body, is_delta)?,
+                Header::RecordBatch(batch) => {
+                    return self.decoder.read_batch(&batch, &body, None).map(Some);
+                }
+                Header::Schema(..) => return Err(invalid("unexpected second schema message")),
+                Header::Other => {}
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for StreamReader<R> {
+    type Item = Result<Table, MinarrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let next = self.next_table().transpose();
+        if !matches!(next, Some(Ok(_))) {
+            self.finished = true;
+        }
+        next
+    }
+}
+
+/// # FileReader
+///
+/// Random-access reader for the Arrow IPC file format, also known as Feather v2.
+///
+/// Construction reads the footer and every dictionary batch. Record batches are
+/// only decoded on request, by index through `read_batch` or in order through
+/// `batches`.
+///
+/// Over a memory map, from `open` with the `mmap` feature or from
+/// `FileReader::new(SharedBuffer::from_owner(mmap))`, the returned columns share
+/// the mapping rather than copying it, for every buffer on a 64-byte boundary of
+/// the file. `FileWriter` places all buffers on one.
+pub struct FileReader {
+    data: SharedBuffer,
+    decoder: Decoder,
+    record_batches: Vec<Block>,
+    /// Length of each dictionary when each record batch was written, as
+    /// later deltas are already applied to `decoder`.
+    dictionary_lens: Vec<HashMap<i64, usize>>,
+}
+
+impl FileReader {
+    /// Reads the footer and dictionaries of the IPC file held in `data`.
+    ///
+    /// # Errors
+    /// Returns `IoError` if `data` is not a valid Arrow IPC file, and
+    /// `NotImplemented` for field types this reader does not support.
+    pub fn new(data: SharedBuffer) -> Result<Self, MinarrowError> {
+        let bytes = data.as_slice();
+        let n = bytes.len();
+        let magic = FILE_MAGIC.len();
+        if n < 2 * magic + 6 || !bytes.starts_with(&FILE_MAGIC) || !bytes.ends_with(&FILE_MAGIC) {
+            return Err(invalid("missing ARROW1 file magic"));
+        }
+        let footer_end = n - magic - 4;
+        let footer_len = i32::from_le_bytes(bytes[footer_end..n - magic].try_into().unwrap());
+        let footer_start = usize::try_from(footer_len)
+            .ok()
+            .and_then(|len| footer_end.checked_sub(len))
+            .filter(|&start| start >= magic)
+            .ok_or_else(|| invalid("file footer length is out of bounds"))?;
+        let footer = decode_footer(&bytes[footer_start..footer_end])?;
+
+        let mut decoder = Decoder::new(footer.schema, footer.encodings);
+        let mut dictionaries = footer.dictionaries;
+        dictionaries.sort_by_key(|b| b.offset);
+        let mut order: Vec<usize> = (0..footer.record_batches.len()).collect();
+        order.sort_by_key(|&i| footer.record_batches[i].offset);
+        let mut dictionary_lens = vec![HashMap::new(); footer.record_batches.len()];
+        let mut pending = dictionaries.iter().peekable();
+        for i in order {
+            let offset = footer.record_batches[i].offset;
+            while let Some(block) = pending.next_if(|b| b.offset < offset) {
+                decoder.apply_dictionary_block(&data, block)?;
+            }
+            dictionary_lens[i] = decoder
+                .dictionaries
+                .iter()
+                .map(|(&id, values)| (id, values.len()))
+                .collect();
+        }
+        for block in pending {
+            decoder.apply_dictionary_block(&data, block)?;
+        }
+        Ok(Self {
+            data,
+            decoder,
+            record_batches: footer.record_batches,
+            dictionary_lens,
+        })
+    }
+
+    /// Opens the IPC file at `path`.
+    ///
+    /// With the `mmap` feature the file is memory-mapped via `SharedBuffer::from_mmap`,
+    /// so aligned columns are never copied. Otherwise it is read into a 64-byte
+    /// aligned buffer.
+    ///
+    /// # Errors
+    /// Returns `IoError` if the file cannot be read or is not a valid Arrow IPC file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, MinarrowError> {
+        #[cfg(feature = "mmap")]
+        {
+            Self::new(SharedBuffer::from_mmap(path.as_ref())?)
+        }
+        #[cfg(not(feature = "mmap"))]
+        {
+            Self::read(path.as_ref())
+        }
+    }
+
+    /// Reads the whole file into a 64-byte aligned buffer and opens it.
+    #[cfg(not(feature = "mmap"))]
+    fn read(path: &Path) -> Result<Self, MinarrowError> {
+        let mut file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len() as usize;
+        let mut data = Vec64::with_capacity(len);
+        data.resize(len, 0u8);
+        file.read_exact(&mut data)?;
+        Self::new(SharedBuffer::from_vec64(data))
+    }
+
+    /// The file's schema.
+    #[inline]
+    pub fn schema(&self) -> &Schema {
+        &self.decoder.schema
+    }
+
+    /// Number of record batches in the file.
+    #[inline]
+    pub fn num_batches(&self) -> usize {
+        self.record_batches.len()
+    }
+
+    /// Decodes record batch `idx`.
+    ///
+    /// # Errors
+    /// Returns `IndexError` if `idx` is out of range, and `IoError` if the batch is invalid.
+    pub fn read_batch(&self, idx: usize) -> Result<Table, MinarrowError> {
+        let block = self.record_batches.get(idx).ok_or_else(|| {
+            MinarrowError::IndexError(format!(
+                "record batch {} out of range for {} batches",
+                idx,
+                self.record_batches.len()
+            ))
+        })?;
+        match message_at(&self.data, block)? {
+            (Header::RecordBatch(batch), body) => {
+                self.decoder
+                    .read_batch(&batch, &body, Some(&self.dictionary_lens[idx]))
+            }
+            _ => Err(invalid("record batch block does not hold a record batch")),
+        }
+    }
+
+    /// Lazily decodes each record batch in file order.
+    pub fn batches(&self) -> impl Iterator<Item = Result<Table, MinarrowError>> + '_ {
+        (0..self.num_batches()).map(|i| self.read_batch(i))
+    }
+}
+
+/// Parses the message a footer block points at, returning its header and body.
+fn message_at(data: &SharedBuffer, block: &Block) -> Result<(Header, SharedBuffer), MinarrowError> {
+    let bytes = data.as_slice();
+    let body_start = block
+        .offset
+        .checked_add(block.metadata_len)
+        .filter(|&end| end <= bytes.len())
+        .ok_or_else(|| invalid("file block is out of bounds"))?;
+    let mut start = block.offset;
+    if bytes[start..body_start].starts_with(&CONTINUATION) {
+        start += CONTINUATION.len();
+    }
+    let len = bytes
+        .get(start..start + 4)
+        .map(|b| i32::from_le_bytes(b.try_into().unwrap()))
+        .ok_or_else(|| invalid("file block is too short"))?;
+    let metadata = usize::try_from(len)
+        .ok()
+        .map(|len| start + 4..start + 4 + len)
+        .filter(|range| range.end <= body_start)
+        .ok_or_else(|| invalid("message metadata overruns its file block"))?;
+    let (header, body_len) = decode_message(&bytes[metadata])?;
+    let body_end = usize::try_from(body_len)
+        .ok()
+        .and_then(|len| body_start.checked_add(len))
+        .filter(|&end| end <= bytes.len())
+        .ok_or_else(|| invalid("message body is out of bounds"))?;
+    Ok((header, data.slice(body_start..body_end)))
+}
+
+/// Schema and dictionary state shared by the stream and file readers.
+struct Decoder {
+    schema: Schema,
+    /// Dictionary encoding of each field, if any.
+    encodings: Vec<Option<DictEncoding>>,
+    /// Current dictionary values by dictionary id.
+    dictionaries: HashMap<i64, Vec64<String>>,
+}
+
+impl Decoder {
+    fn new(schema: Schema, encodings: Vec<Option<DictEncoding>>) -> Self {
+        Self {
+            schema,
+            encodings,
+            dictionaries: HashMap::new(),
+        }
+    }
+
+    /// Replaces dictionary `id`, or appends to it for a delta batch.
+    fn apply_dictionary(
+        &mut self,
+        id: i64,
+        batch: &BatchHeader,
+        body: &SharedBuffer,
+        is_delta: bool,
+    ) -> Result<(), MinarrowError> {
+        let encoding = self
+            .encodings
+            .iter()
+            .flatten()
+            .find(|e| e.id == id)
+            .ok_or_else(|| invalid(format!("unknown dictionary id {}", id)))?;
+        let values = read_dictionary(&mut Body::new(batch, body), encoding.large_values)?;
+        match self.dictionaries.get_mut(&id) {
+            Some(existing) if is_delta => existing.extend(values),
+            _ => {
+                self.dictionaries.insert(id, values);
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the dictionary batch a file block points at.
+    fn apply_dictionary_block(
+        &mut self,
+        data: &SharedBuffer,
+        block: &Block,
+    ) -> Result<(), MinarrowError> {
+        match message_at(data, block)? {
+            (
+                Header::DictionaryBatch {
+                    id,
+                    batch,
+                    is_delta,
+                },
+                body,
+            ) => self.apply_dictionary(id, &batch, &body, is_delta),
+            _ => Err(invalid("dictionary block does not hold a dictionary batch")),
+        }
+    }
+
+    /// Decodes a record batch, truncating each dictionary to `dictionary_lens`
+    /// where given.
+    fn read_batch(
+        &self,
+        batch: &BatchHeader,
+        body: &SharedBuffer,
+        dictionary_lens: Option<&HashMap<i64, usize>>,
+    ) -> Result<Table, MinarrowError> {
+        let n_rows =
+            usize::try_from(batch.length).map_err(|_| invalid("negative record batch length"))?;
+        let mut body = Body::new(batch, body);
+        let mut cols = Vec::with_capacity(self.schema.fields.len());
+        for (field, encoding) in self.schema.fields.iter().zip(&self.encodings) {
+            let dict = match encoding {
+                Some(e) => {
+                    let values = self
+                        .dictionaries
+                        .get(&e.id)
+                        .filter(|_| dictionary_lens.is_none_or(|lens| lens.contains_key(&e.id)))
+                        .ok_or_else(|| {
+                            invalid(format!("no dictionary received for field '{}'", field.name))
+                        })?;
+                    let len = dictionary_lens.map_or(values.len(), |lens| lens[&e.id]);
+                    Some(&values[..len])
+                }
+                None => None,
+            };
+            let array = read_column(field, dict, &mut body)?;
+            if array.len() != n_rows {
+                return Err(invalid(format!(
+                    "field '{}' has {} rows, record batch has {}",
+                    field.name,
+                    array.len(),
+                    n_rows
+                )));
+            }
+            cols.push(FieldArray::new_arc(field.clone(), array));
+        }
+        #[cfg(feature = "table_metadata")]
+        let mut table =
+            Table::new_with_metadata(String::new(), Some(cols), self.schema.metadata.clone());
+        #[cfg(not(feature = "table_metadata"))]
+        let mut table = Table::new(String::new(), Some(cols));
+        table.n_rows = n_rows;
+        Ok(table)
+    }
+}
+
+/// Fills `buf`, returning `false` if the input ended before the first byte.
+fn read_or_eof<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<bool, MinarrowError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(invalid("stream ends mid-message")),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(true)
+}
+
+/// Reads one encapsulated message and its body. Returns `None` at the end-of-stream
+/// marker or a clean end of input.
+fn read_message<R: Read>(reader: &mut R) -> Result<Option<(Header, SharedBuffer)>, MinarrowError> {
+    // Bodies grow in steps so a corrupt length cannot force a huge allocation
+    const CHUNK: usize = 1 << 20;
+    loop {
+        let mut word = [0u8; 4];
+        if !read_or_eof(reader, &mut word)? {
+            return Ok(None);
+        }
+        // Streams from before Arrow 0.15 omit the continuation marker
+        if word == CONTINUATION && !read_or_eof(reader, &mut word)? {
+            return Ok(None);
+        }
+        let len = i32::from_le_bytes(word);
+        if len == 0 {
+            return Ok(None);
+        }
+        let len = usize::try_from(len).map_err(|_| invalid("negative metadata length"))?;
+        let mut metadata = vec![0u8; len];
+        reader.read_exact(&mut metadata)?;
+        let (header, body_len) = decode_message(&metadata)?;
+
+        let body_len = usize::try_from(body_len).map_err(|_| invalid("negative body length"))?;
+        let mut body = Vec64::with_capacity(body_len.min(CHUNK));
+        while body.len() < body_len {
+            let filled = body.len();
+            body.resize((filled + CHUNK).min(body_len), 0u8);
+            reader
+                .read_exact(&mut body[filled..])
+                .map_err(|_| invalid("stream ends mid-message body"))?;
+        }
+        if !matches!(header, Header::Other) {
+            return Ok(Some((header, SharedBuffer::from_vec64(body))));
+        }
+    }
+}
+
+/// Walks the field nodes and buffers of a record batch body in order.
+struct Body<'a> {
+    header: &'a BatchHeader,
+    body: &'a SharedBuffer,
+    node: usize,
+    buffer: usize,
+}
+
+impl<'a> Body<'a> {
+    fn new(header: &'a BatchHeader, body: &'a SharedBuffer) -> Self {
+        Self {
+            header,
+            body,
+            node: 0,
+            buffer: 0,
+        }
+    }
+
+    /// `(length, null_count)` of the next field node.
+    fn next_node(&mut self) -> Result<(usize, usize), MinarrowError> {
+        let &(len, nulls) = self
+            .header
+            .nodes
+            .get(self.node)
+            .ok_or_else(|| invalid("record batch has too few field nodes"))?;
+        self.node += 1;
+        match (usize::try_from(len), usize::try_from(nulls)) {
+            (Ok(len), Ok(nulls)) if nulls <= len => Ok((len, nulls)),
+            _ => Err(invalid("field node has an invalid length or null count")),
+        }
+    }
+
+    /// Zero-copy view of the next buffer.
+    fn next_buffer(&mut self) -> Result<SharedBuffer, MinarrowError> {
+        let &(offset, len) = self
+            .header
+            .buffers
+            .get(self.buffer)
+            .ok_or_else(|| invalid("record batch has too few buffers"))?;
+        self.buffer += 1;
+        usize::try_from(offset)
+            .ok()
+            .zip(usize::try_from(len).ok())
+            .and_then(|(o, l)| o.checked_add(l).map(|end| o..end))
+            .filter(|range| range.end <= self.body.len())
+            .map(|range| self.body.slice(range))
+            .ok_or_else(|| invalid("buffer lies outside the message body"))
+    }
+
+    /// Reads the validity buffer for a node with `null_count` nulls.
+    fn validity(
+        &mut self,
+        len: usize,
+        null_count: usize,
+    ) -> Result<Option<Bitmask>, MinarrowError> {
+        let buf = self.next_buffer()?;
+        if null_count == 0 {
+            return Ok(None);
+        }
+        Bitmask::from_bytes(buf, len)
+            .map(Some)
+            .map_err(|_| invalid("validity buffer is too short"))
+    }
+}
+
+/// Takes the first `n` native-endian values of `buf`.
+///
+/// A 64-byte aligned buffer is shared as is. Anything else is copied into a fresh
+/// aligned allocation.
+fn read_values<T: Copy>(buf: &SharedBuffer, n: usize) -> Result<Buffer<T>, MinarrowError> {
+    let size = n
+        .checked_mul(size_of::<T>())
+        .filter(|&size| size <= buf.len())
+        .ok_or_else(|| invalid("values buffer is too short"))?;
+    if n == 0 {
+        return Ok(Buffer::from_vec64(Vec64::new()));
+    }
+    let bytes = buf.slice(..size);
+    if (bytes.as_ptr() as usize).is_multiple_of(BODY_ALIGNMENT) {
+        return Ok(Buffer::from_shared(bytes));
+    }
+    let mut out = Vec64::with_capacity(n);
+    // Safety: `out` has capacity for `n` values, `bytes` holds exactly `size`
+    // bytes, and every bit pattern is a valid `T` for the integer and float types used here.
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), out.as_mut_ptr() as *mut u8, size);
+        out.set_len(n);
+    }
+    Ok(Buffer::from_vec64(out))
+}
+
+/// Reads a Utf8 or LargeUtf8 layout, checking offsets and UTF-8 validity.
+fn read_utf8<T: Integer>(
+    body: &mut Body,
+    len: usize,
+) -> Result<(Buffer<T>, Buffer<u8>), MinarrowError> {
+    let offsets: Buffer<T> = read_values(&body.next_buffer()?, len + 1)?;
+    let data = body.next_buffer()?;
+    let mut prev = offsets[0].to_usize();
+    if prev > data.len() {
+        return Err(invalid("string offsets are out of bounds"));
+    }
+    for &o in offsets.iter().skip(1) {
+        let o = o.to_usize();
+        if o < prev || o > data.len() || std::str::from_utf8(&data[prev..o]).is_err() {
+            return Err(invalid("string offsets are out of order or not UTF-8"));
+        }
+        prev = o;
+    }
+    let values = read_values(&data, prev)?;
+    Ok((offsets, values))
+}
+
+/// Decodes the string values of a dictionary batch.
+fn read_dictionary(body: &mut Body, large: bool) -> Result<Vec64<String>, MinarrowError> {
+    let (len, _) = body.next_node()?;
+    // Null dictionary entries are read as empty strings
+    body.next_buffer()?;
+    let to_strings = |offsets: &[usize], data: &[u8]| -> Vec64<String> {
+        offsets
+            .windows(2)
+            .map(|w| String::from_utf8_lossy(&data[w[0]..w[1]]).into_owned())
+            .collect()
+    };
+    if large {
+        let (offsets, data) = read_utf8::<u64>(body, len)?;
+        let offsets: Vec<usize> = offsets.iter().map(|&o| o.to_usize()).collect();
+        Ok(to_strings(&offsets, &data))
+    } else {
+        let (offsets, data) = read_utf8::<u32>(body, len)?;
+        let offsets: Vec<usize> = offsets.iter().map(|&o| o.to_usize()).collect();
+        Ok(to_strings(&offsets, &data))
+    }
+}
+
+/// Reads dictionary codes, checking each valid one indexes into `dict`.
+///
+/// The codes are shared with the body unless a null slot holds an out-of-range
+/// code, in which case they are copied with such slots set to zero.
+fn read_codes<T: Integer>(
+    body: &mut Body,
+    len: usize,
+    null_mask: Option<Bitmask>,
+    dict: &[String],
+) -> Result<CategoricalArray<T>, MinarrowError> {
+    let mut data: Buffer<T> = read_values(&body.next_buffer()?, len)?;
+    let is_valid = |i: usize| null_mask.as_ref().is_none_or(|m| m.get(i));
+    let mut clamp = false;
+    for (i, &code) in data.iter().enumerate() {
+        if code.to_usize() < dict.len() {
+            continue;
+        }
+        if is_valid(i) {
+            return Err(invalid(format!(
+                "dictionary code {} is out of range for {} values",
+                code.to_usize(),
+                dict.len()
+            )));
+        }
+        clamp = true;
+    }
+    if clamp {
+        let mut codes = Vec64::with_capacity(len);
+        codes.extend(data.iter().enumerate().map(|(i, &code)| {
+            if is_valid(i) && code.to_usize() < dict.len() {
+                code
+            } else {
+                T::default()
+            }
+        }));
+        data = Buffer::from_vec64(codes);
+    }
+    Ok(CategoricalArray {
+        data,
+        unique_values: dict.iter().cloned().collect(),
+        null_mask,
+    })
+}
+
+/// Decodes the next column of `field`'s type from the body.
+fn read_column(
+    field: &Field,
+    dict: Option<&[String]>,
+    body: &mut Body,
+) -> Result<Array, MinarrowError> {
+    let (len, null_count) = body.next_node()?;
+    let mask = body.validity(len, null_count)?;
+    macro_rules! values {
+        () => {
+            read_values(&body.next_buffer()?, len)?
+        };
+    }
+    Ok(match &field.dtype {
+        ArrowType::Boolean => {
+            let data = Bitmask::from_bytes(body.next_buffer()?, len)
+                .map_err(|_| invalid("boolean buffer is too short"))?;
+            Array::from_bool(BooleanArray::new(data, mask))
+        }
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => Array::from_int8(IntegerArray::new(values!(), mask)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => Array::from_int16(IntegerArray::new(values!(), mask)),
+        ArrowType::Int32 => Array::from_int32(IntegerArray::new(values!(), mask)),
+        ArrowType::Int64 => Array::from_int64(IntegerArray::new(values!(), mask)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => Array::from_uint8(IntegerArray::new(values!(), mask)),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => Array::from_uint16(IntegerArray::new(values!(), mask)),
+        ArrowType::UInt32 => Array::from_uint32(IntegerArray::new(values!(), mask)),
+        ArrowType::UInt64 => Array::from_uint64(IntegerArray::new(values!(), mask)),
+        ArrowType::Float32 => Array::from_float32(FloatArray::new(values!(), mask)),
+        ArrowType::Float64 => Array::from_float64(FloatArray::new(values!(), mask)),
+        #[cfg(feature = "datetime")]
+        ArrowType::Date32 => {
+            Array::from_datetime_i32(DatetimeArray::new(values!(), mask, Some(TimeUnit::Days)))
+        }
+        #[cfg(feature = "datetime")]
+        ArrowType::Time32(u) => {
+            Array::from_datetime_i32(DatetimeArray::new(values!(), mask, Some(*u)))
+        }
+        #[cfg(feature = "datetime")]
+        ArrowType::Date64 => Array::from_datetime_i64(DatetimeArray::new(
+            values!(),
+            mask,
+            Some(TimeUnit::Milliseconds),
+        )),
+        #[cfg(feature = "datetime")]
+        ArrowType::Time64(u) | ArrowType::Timestamp(u, _) | ArrowType::Duration64(u) => {
+            Array::from_datetime_i64(DatetimeArray::new(values!(), mask, Some(*u)))
+        }
+        ArrowType::String => {
+            let (offsets, data) = read_utf8::<u32>(body, len)?;
+            Array::from_string32(StringArray::new(data, mask, offsets))
+        }
+        #[cfg(feature = "large_string")]
+        ArrowType::LargeString => {
+            let (offsets, data) = read_utf8::<u64>(body, len)?;
+            Array::from_string64(StringArray::new(data, mask, offsets))
+        }
+        ArrowType::FixedSizeBinary(w) => {
+            let width = usize::try_from(*w).map_err(|_| invalid("negative binary width"))?;
+            let n_bytes = len
+                .checked_mul(width)
+                .ok_or_else(|| invalid("fixed-size binary length overflows"))?;
+            let data: Buffer<u8> = read_values(&body.next_buffer()?, n_bytes)?;
+            Array::from_fixed_size_binary(FixedSizeBinaryArray::new(data, width, mask))
+        }
+        ArrowType::Dictionary(idx) => {
+            let dict = dict.ok_or_else(|| invalid("dictionary field without a dictionary"))?;
+            match idx {
+                #[cfg(feature = "default_categorical_8")]
+                CategoricalIndexType::UInt8 => {
+                    Array::from_categorical8(read_codes(body, len, mask, dict)?)
+                }
+                #[cfg(feature = "extended_categorical")]
+                CategoricalIndexType::UInt16 => {
+                    Array::from_categorical16(read_codes(body, len, mask, dict)?)
+                }
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                CategoricalIndexType::UInt32 => {
+                    Array::from_categorical32(read_codes(body, len, mask, dict)?)
+                }
+                #[cfg(feature = "extended_categorical")]
+                CategoricalIndexType::UInt64 => {
+                    Array::from_categorical64(read_codes(body, len, mask, dict)?)
+                }
+            }
+        }
+        other => {
+            return Err(MinarrowError::NotImplemented {
+                feature: format!("Arrow IPC decoding of {} fields", other),
+            });
+        }
+    })
+}
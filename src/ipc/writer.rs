@@ -0,0 +1,487 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **IPC Stream Writer** - *Tables to Arrow IPC streaming format*
+
+use std::io::Write;
+
+use super::message::{
+    Block, HEADER_DICTIONARY_BATCH, HEADER_RECORD_BATCH, HEADER_SCHEMA, encode_batch,
+    encode_dictionary_batch, encode_footer, encode_message, encode_schema,
+};
+use super::{BODY_ALIGNMENT, CONTINUATION, FILE_MAGIC};
+use crate::enums::array::values_bytes;
+use crate::enums::error::MinarrowError;
+use crate::ffi::schema::Schema;
+use crate::{Array, Field, Table, TextArray, Vec64};
+#[cfg(feature = "datetime")]
+use crate::{ArrowType, TemporalArray, TimeUnit};
+
+/// # StreamWriter
+///
+/// Writes `Table`s as record batches in the Arrow IPC streaming format.
+///
+/// The schema message is written on construction. Each call to `write` emits one
+/// record batch, preceded by a dictionary batch for every categorical column whose
+/// dictionary differs from the one last sent. A dictionary that only gained values
+/// at the end is sent as a delta. `finish` writes the end-of-stream marker.
+///
+/// ## Example
+/// ```rust
+/// use minarrow::ffi::schema::Schema;
+/// use minarrow::ipc::{StreamReader, StreamWriter};
+/// use minarrow::{Array, ArrowType, Field, FieldArray, IntegerArray, Table};
+///
+/// let field = Field::new("x", ArrowType::Int64, false, None);
+/// let col = FieldArray::new(
+///     field.clone(),
+///     Array::from_int64(IntegerArray::from_slice(&[1, 2, 3])),
+/// );
+/// let table = Table::new("t".into(), Some(vec![col]));
+///
+/// let mut writer = StreamWriter::new(Vec::new(), Schema::from(vec![field])).unwrap();
+/// writer.write(&table).unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let tables: Vec<Table> = StreamReader::new(&bytes[..])
+///     .unwrap()
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(tables[0].n_rows, 3);
+/// ```
+pub struct StreamWriter<W: Write> {
+    inner: MessageWriter<W>,
+    finished: bool,
+}
+
+impl<W: Write> StreamWriter<W> {
+    /// Creates a writer and writes the schema message.
+    ///
+    /// # Errors
+    /// Returns `NotImplemented` if a field type has no IPC encoding here, such as
+    /// `Utf8View` or `Interval`, and `IoError` if the write fails.
+    pub fn new(writer: W, schema: Schema) -> Result<Self, MinarrowError> {
+        let mut inner = MessageWriter::new(writer, schema);
+        inner.write_schema()?;
+        Ok(Self {
+            inner,
+            finished: false,
+        })
+    }
+
+    /// The schema every written table must match.
+    #[inline]
+    pub fn schema(&self) -> &Schema {
+        &self.inner.schema
+    }
+
+    /// Writes `table` as a record batch.
+    ///
+    /// # Errors
+    /// Returns `IncompatibleTypeError` if the table's columns do not match the schema
+    /// by count, name and type, and `IoError` if the write fails or the stream is finished.
+    pub fn write(&mut self, table: &Table) -> Result<(), MinarrowError> {
+        if self.finished {
+            return Err(MinarrowError::IoError(
+                "cannot write to a finished Arrow IPC stream".into(),
+            ));
+        }
+        self.inner.write_table(table, true).map(|_| ())
+    }
+
+    /// Writes the end-of-stream marker and flushes. Calling it again is a no-op.
+    pub fn finish(&mut self) -> Result<(), MinarrowError> {
+        if !self.finished {
+            self.inner.write_eos()?;
+            self.inner.writer.flush()?;
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    /// Finishes the stream if needed and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, MinarrowError> {
+        self.finish()?;
+        Ok(self.inner.writer)
+    }
+}
+
+/// # FileWriter
+///
+/// Writes `Table`s as record batches in the Arrow IPC file format, also known as
+/// Feather v2.
+///
+/// The file is the `ARROW1` magic, the same messages as the streaming format, then a
+/// footer indexing every dictionary and record batch so readers can seek to any
+/// batch. Every buffer lands on a 64-byte boundary of the file, so a memory-mapped
+/// file can be read back by `FileReader` without copying.
+///
+/// The file format does not allow a dictionary to be replaced, so a categorical
+/// column's dictionary may only grow at the end from one batch to the next.
+pub struct FileWriter<W: Write> {
+    inner: MessageWriter<W>,
+    dictionary_blocks: Vec<Block>,
+    record_blocks: Vec<Block>,
+    finished: bool,
+}
+
+impl<W: Write> FileWriter<W> {
+    /// Creates a writer and writes the file header and schema message.
+    ///
+    /// # Errors
+    /// Returns `NotImplemented` if a field type has no IPC encoding here, and
+    /// `IoError` if the write fails.
+    pub fn new(writer: W, schema: Schema) -> Result<Self, MinarrowError> {
+        let mut inner = MessageWriter::new(writer, schema);
+        inner.write_bytes(&FILE_MAGIC)?;
+        inner.write_bytes(&[0; 2])?;
+        inner.write_schema()?;
+        Ok(Self {
+            inner,
+            dictionary_blocks: Vec::new(),
+            record_blocks: Vec::new(),
+            finished: false,
+        })
+    }
+
+    /// The schema every written table must match.
+    #[inline]
+    pub fn schema(&self) -> &Schema {
+        &self.inner.schema
+    }
+
+    /// Writes `table` as a record batch.
+    ///
+    /// # Errors
+    /// Returns `IncompatibleTypeError` if the table's columns do not match the schema,
+    /// or a categorical column's dictionary changed other than by appending values,
+    /// and `IoError` if the write fails or the file is finished.
+    pub fn write(&mut self, table: &Table) -> Result<(), MinarrowError> {
+        if self.finished {
+            return Err(MinarrowError::IoError(
+                "cannot write to a finished Arrow IPC file".into(),
+            ));
+        }
+        let (dictionaries, batch) = self.inner.write_table(table, false)?;
+        self.dictionary_blocks.extend(dictionaries);
+        self.record_blocks.push(batch);
+        Ok(())
+    }
+
+    /// Writes the footer and trailing magic, then flushes. Calling it again is a no-op.
+    pub fn finish(&mut self) -> Result<(), MinarrowError> {
+        if !self.finished {
+            self.inner.write_eos()?;
+            let footer = encode_footer(
+                &self.inner.schema,
+                &self.dictionary_blocks,
+                &self.record_blocks,
+            )?;
+            self.inner.write_bytes(&footer)?;
+            self.inner
+                .write_bytes(&(footer.len() as i32).to_le_bytes())?;
+            self.inner.write_bytes(&FILE_MAGIC)?;
+            self.inner.writer.flush()?;
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    /// Finishes the file if needed and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, MinarrowError> {
+        self.finish()?;
+        Ok(self.inner.writer)
+    }
+}
+
+/// Message encoding shared by the stream and file writers.
+struct MessageWriter<W: Write> {
+    writer: W,
+    /// Bytes written so far, used for file block offsets and body alignment.
+    position: usize,
+    schema: Schema,
+    /// Dictionary last written for each field, `None` until first sent.
+    dictionaries: Vec<Option<Vec64<String>>>,
+}
+
+impl<W: Write> MessageWriter<W> {
+    fn new(writer: W, schema: Schema) -> Self {
+        Self {
+            writer,
+            position: 0,
+            dictionaries: vec![None; schema.fields.len()],
+            schema,
+        }
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), MinarrowError> {
+        self.writer.write_all(bytes)?;
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    fn write_schema(&mut self) -> Result<(), MinarrowError> {
+        let header = encode_message(HEADER_SCHEMA, encode_schema(&self.schema)?, 0);
+        self.write_message(&header, &[]).map(|_| ())
+    }
+
+    #[inline]
+    fn write_eos(&mut self) -> Result<(), MinarrowError> {
+        self.write_bytes(&CONTINUATION)?;
+        self.write_bytes(&0i32.to_le_bytes())
+    }
+
+    /// Writes an encapsulated message: continuation marker, metadata length, metadata,
+    /// then each body buffer padded to `BODY_ALIGNMENT`.
+    ///
+    /// The metadata is zero-padded so the body starts on a `BODY_ALIGNMENT` boundary
+    /// of the output, which keeps every buffer aligned for zero-copy reads.
+    fn write_message(
+        &mut self,
+        metadata: &[u8],
+        buffers: &[&[u8]],
+    ) -> Result<Block, MinarrowError> {
+        let offset = self.position;
+        let prefix_len = (offset + 8 + metadata.len()).next_multiple_of(BODY_ALIGNMENT) - offset;
+        let padding = [0u8; BODY_ALIGNMENT];
+        self.write_bytes(&CONTINUATION)?;
+        self.write_bytes(&((prefix_len - 8) as i32).to_le_bytes())?;
+        self.write_bytes(metadata)?;
+        self.write_bytes(&padding[..prefix_len - 8 - metadata.len()])?;
+        let body_start = self.position;
+        for buf in buffers {
+            self.write_bytes(buf)?;
+            self.write_bytes(&padding[..buf.len().next_multiple_of(BODY_ALIGNMENT) - buf.len()])?;
+        }
+        Ok(Block {
+            offset,
+            metadata_len: prefix_len,
+            body_len: self.position - body_start,
+        })
+    }
+
+    /// Writes any dictionary batches `table` needs, then its record batch, returning
+    /// their blocks. Changed dictionaries are sent as deltas when they only grew at
+    /// the end, and otherwise replaced if `allow_replacement` is set.
+    fn write_table(
+        &mut self,
+        table: &Table,
+        allow_replacement: bool,
+    ) -> Result<(Vec<Block>, Block), MinarrowError> {
+        if table.cols.len() != self.schema.fields.len() {
+            return Err(mismatch(format!(
+                "table has {} columns, schema has {}",
+                table.cols.len(),
+                self.schema.fields.len()
+            )));
+        }
+        for (field, col) in self.schema.fields.iter().zip(&table.cols) {
+            if col.field.name != field.name || col.field.dtype != field.dtype {
+                return Err(mismatch(format!(
+                    "column '{}' ({}) does not match field '{}' ({})",
+                    col.field.name, col.field.dtype, field.name, field.dtype
+                )));
+            }
+            check_layout(field, &col.array)?;
+        }
+
+        let mut dictionary_blocks = Vec::new();
+        for (i, col) in table.cols.iter().enumerate() {
+            let Some(dict) = dictionary(&col.array) else {
+                continue;
+            };
+            let (values, is_delta) = match &self.dictionaries[i] {
+                Some(prev) if prev == dict => continue,
+                Some(prev) if dict.starts_with(prev) => (&dict[prev.len()..], true),
+                Some(_) if !allow_replacement => {
+                    return Err(mismatch(format!(
+                        "dictionary for column '{}' changed, but the Arrow IPC file format \
+                         only allows values to be appended",
+                        col.field.name
+                    )));
+                }
+                _ => (&dict[..], false),
+            };
+            let (offsets, data) = dictionary_buffers(values)?;
+            let buffers: [&[u8]; 3] = [&[], &offsets, &data];
+            let (layout, body_len) = body_layout(&buffers);
+            let batch = encode_batch(values.len(), &[(values.len(), 0)], &layout);
+            let header = encode_message(
+                HEADER_DICTIONARY_BATCH,
+                encode_dictionary_batch(i as i64, batch, is_delta),
+                body_len as i64,
+            );
+            dictionary_blocks.push(self.write_message(&header, &buffers)?);
+            self.dictionaries[i] = Some(dict.clone());
+        }
+
+        let mut nodes = Vec::with_capacity(table.cols.len());
+        let mut buffers = Vec::with_capacity(table.cols.len() * 3);
+        for col in &table.cols {
+            nodes.push((col.array.len(), col.null_count));
+            column_buffers(&col.array, col.null_count, &mut buffers)?;
+        }
+        let (layout, body_len) = body_layout(&buffers);
+        let header = encode_message(
+            HEADER_RECORD_BATCH,
+            encode_batch(table.n_rows, &nodes, &layout),
+            body_len as i64,
+        );
+        let batch = self.write_message(&header, &buffers)?;
+        Ok((dictionary_blocks, batch))
+    }
+}
+
+#[inline]
+fn mismatch(message: String) -> MinarrowError {
+    MinarrowError::IncompatibleTypeError {
+        from: "Table",
+        to: "Arrow IPC schema",
+        message: Some(message),
+    }
+}
+
+/// `(offset, length)` of each body buffer, and the total body length.
+fn body_layout(buffers: &[&[u8]]) -> (Vec<(usize, usize)>, usize) {
+    let mut offset = 0;
+    let layout = buffers
+        .iter()
+        .map(|b| {
+            let entry = (offset, b.len());
+            offset += b.len().next_multiple_of(BODY_ALIGNMENT);
+            entry
+        })
+        .collect();
+    (layout, offset)
+}
+
+/// Checks the array's physical layout agrees with the field's logical type.
+fn check_layout(field: &Field, array: &Array) -> Result<(), MinarrowError> {
+    let ok = match (&field.dtype, array) {
+        #[cfg(feature = "datetime")]
+        (dtype, Array::TemporalArray(arr)) => {
+            let expected = match dtype {
+                ArrowType::Date32 => Some((false, TimeUnit::Days)),
+                ArrowType::Time32(u) => Some((false, *u)),
+                ArrowType::Date64 => Some((true, TimeUnit::Milliseconds)),
+                ArrowType::Time64(u) | ArrowType::Timestamp(u, _) | ArrowType::Duration64(u) => {
+                    Some((true, *u))
+                }
+                _ => None,
+            };
+            match (expected, arr) {
+                (Some((false, u)), TemporalArray::Datetime32(a)) => a.time_unit == u,
+                (Some((true, u)), TemporalArray::Datetime64(a)) => a.time_unit == u,
+                _ => false,
+            }
+        }
+        (dtype, array) => *dtype == array.arrow_type(),
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(mismatch(format!(
+            "column '{}' holds data that cannot be encoded as {}",
+            field.name, field.dtype
+        )))
+    }
+}
+
+/// The dictionary of a categorical array.
+fn dictionary(array: &Array) -> Option<&Vec64<String>> {
+    match array {
+        #[cfg(feature = "default_categorical_8")]
+        Array::TextArray(TextArray::Categorical8(a)) => Some(&a.unique_values),
+        #[cfg(feature = "extended_categorical")]
+        Array::TextArray(TextArray::Categorical16(a)) => Some(&a.unique_values),
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        Array::TextArray(TextArray::Categorical32(a)) => Some(&a.unique_values),
+        #[cfg(feature = "extended_categorical")]
+        Array::TextArray(TextArray::Categorical64(a)) => Some(&a.unique_values),
+        _ => None,
+    }
+}
+
+/// Utf8 offsets and data buffers for a dictionary's values.
+fn dictionary_buffers(dict: &[String]) -> Result<(Vec<u8>, Vec<u8>), MinarrowError> {
+    let mut offsets = Vec::with_capacity((dict.len() + 1) * 4);
+    let mut data = Vec::new();
+    offsets.extend_from_slice(&0i32.to_le_bytes());
+    for s in dict {
+        data.extend_from_slice(s.as_bytes());
+        let end = i32::try_from(data.len()).map_err(|_| MinarrowError::Overflow {
+            value: data.len().to_string(),
+            target: "i32 dictionary offset",
+        })?;
+        offsets.extend_from_slice(&end.to_le_bytes());
+    }
+    Ok((offsets, data))
+}
+
+/// Appends the validity and data buffers of `array` in Arrow IPC order.
+fn column_buffers<'a>(
+    array: &'a Array,
+    null_count: usize,
+    buffers: &mut Vec<&'a [u8]>,
+) -> Result<(), MinarrowError> {
+    // The validity buffer may be omitted when there are no nulls
+    buffers.push(match array.null_mask() {
+        Some(mask) if null_count > 0 => mask.to_bytes().0,
+        _ => &[],
+    });
+    match array {
+        Array::TextArray(TextArray::String32(a)) => {
+            buffers.push(values_bytes(a.offsets.as_slice()));
+            buffers.push(a.data.as_slice());
+        }
+        #[cfg(feature = "large_string")]
+        Array::TextArray(TextArray::String64(a)) => {
+            buffers.push(values_bytes(a.offsets.as_slice()));
+            buffers.push(a.data.as_slice());
+        }
+        #[cfg(feature = "default_categorical_8")]
+        Array::TextArray(TextArray::Categorical8(a)) => {
+            buffers.push(values_bytes(a.data.as_slice()))
+        }
+        #[cfg(feature = "extended_categorical")]
+        Array::TextArray(TextArray::Categorical16(a)) => {
+            buffers.push(values_bytes(a.data.as_slice()))
+        }
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        Array::TextArray(TextArray::Categorical32(a)) => {
+            buffers.push(values_bytes(a.data.as_slice()))
+        }
+        #[cfg(feature = "extended_categorical")]
+        Array::TextArray(TextArray::Categorical64(a)) => {
+            buffers.push(values_bytes(a.data.as_slice()))
+        }
+        other => {
+            buffers.push(
+                other
+                    .values_as_bytes()
+                    .ok_or_else(|| MinarrowError::NotImplemented {
+                        feature: format!("Arrow IPC encoding of {} arrays", other.arrow_type()),
+                    })?,
+            )
+        }
+    }
+    Ok(())
+}
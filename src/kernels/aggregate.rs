@@ -0,0 +1,522 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Aggregate Kernels Module** - *Whole-Array Reductions*
+//!
+//! Null-skipping reductions over a single numeric buffer, backing
+//! `NumericArray::{sum, mean, var, std, min, max}`, and the matching prefix scans
+//! behind `NumericArray::{cumsum, cummin, cummax}`. Bucket counts for
+//! `IntegerArray::histogram` and `FloatArray::histogram` also live here,
+//! as do the order statistics behind `NumericArray::{quantile, quantiles}`.
+//!
+//! ## Semantics
+//! - Null slots are skipped. A buffer with no valid values reduces to `None`.
+//! - Integer sums accumulate in `i128` or `u128`, so they are exact. Float sums accumulate in `f64`.
+//! - `NaN` values are ignored by `min_max`, `cummin`, `cummax` and `valid_values`.
+//! - Scans return one value per input slot. Null slots carry the running value
+//!   and callers reuse the input mask, so they stay null.
+//! - Dense 64-bit buffers are summed with `std::simd` when the `simd` feature is enabled.
+
+include!(concat!(env!("OUT_DIR"), "/simd_lanes.rs"));
+
+#[cfg(feature = "simd")]
+use std::simd::Simd;
+#[cfg(feature = "simd")]
+use std::simd::num::SimdFloat;
+
+use num_traits::ToPrimitive;
+
+use crate::{Bitmask, Vec64};
+
+/// Calls `f` with every value whose validity bit is set, or every value when `mask` is `None`.
+#[inline(always)]
+fn for_each_valid<T: Copy>(data: &[T], mask: Option<&Bitmask>, mut f: impl FnMut(T)) {
+    match mask {
+        None => data.iter().for_each(|&v| f(v)),
+        Some(m) => {
+            for (i, &v) in data.iter().enumerate() {
+                if m.get(i) {
+                    f(v);
+                }
+            }
+        }
+    }
+}
+
+/// Sums the valid signed integers as `i128`, returning the sum and the number of values.
+///
+/// No buffer holds enough `i64` values to overflow the accumulator.
+#[inline]
+pub fn sum_signed<T: Copy + Into<i64>>(data: &[T], mask: Option<&Bitmask>) -> (i128, usize) {
+    let (mut sum, mut n) = (0i128, 0usize);
+    for_each_valid(data, mask, |v| {
+        sum += v.into() as i128;
+        n += 1;
+    });
+    (sum, n)
+}
+
+/// Sums the valid unsigned integers as `u128`, returning the sum and the number of values.
+///
+/// No buffer holds enough `u64` values to overflow the accumulator.
+#[inline]
+pub fn sum_unsigned<T: Copy + Into<u64>>(data: &[T], mask: Option<&Bitmask>) -> (u128, usize) {
+    let (mut sum, mut n) = (0u128, 0usize);
+    for_each_valid(data, mask, |v| {
+        sum += v.into() as u128;
+        n += 1;
+    });
+    (sum, n)
+}
+
+/// Sums the valid floats as `f64`, returning the sum and the number of values.
+#[inline]
+pub fn sum_float<T: Copy + Into<f64>>(data: &[T], mask: Option<&Bitmask>) -> (f64, usize) {
+    let (mut sum, mut n) = (0.0f64, 0usize);
+    for_each_valid(data, mask, |v| {
+        sum += v.into();
+        n += 1;
+    });
+    (sum, n)
+}
+
+/// Chunks summed per lane before the split halves are flushed to 128 bits.
+///
+/// Each half is below `2^32`, so a lane stays below `2^62` within a block.
+#[cfg(feature = "simd")]
+const SPLIT_SUM_BLOCK: usize = 1 << 30;
+
+/// Exact SIMD sum of a dense `i64` buffer, as `i128`.
+///
+/// Each lane sums the low and high 32-bit halves of its values separately,
+/// so neither accumulator overflows, and the halves recombine in `i128`.
+#[cfg(feature = "simd")]
+#[inline]
+pub fn sum_i64_dense_simd(data: &[i64]) -> i128 {
+    let low_bits = Simd::<i64, W64>::splat(0xFFFF_FFFF);
+    let shift = Simd::<i64, W64>::splat(32);
+    let (mut lo_sum, mut hi_sum) = (0i128, 0i128);
+    for block in data.chunks(W64 * SPLIT_SUM_BLOCK) {
+        let chunks = block.chunks_exact(W64);
+        let tail = chunks.remainder();
+        let (mut lo, mut hi) = (Simd::<i64, W64>::splat(0), Simd::<i64, W64>::splat(0));
+        for c in chunks {
+            let v = Simd::from_slice(c);
+            lo += v & low_bits;
+            hi += v >> shift;
+        }
+        lo_sum += lo.to_array().iter().map(|&x| x as i128).sum::<i128>();
+        hi_sum += hi.to_array().iter().map(|&x| x as i128).sum::<i128>();
+        lo_sum += tail.iter().map(|&v| v as i128).sum::<i128>();
+    }
+    (hi_sum << 32) + lo_sum
+}
+
+/// Exact SIMD sum of a dense `u64` buffer, as `u128`.
+///
+/// Split into 32-bit halves per lane as in [`sum_i64_dense_simd`].
+#[cfg(feature = "simd")]
+#[inline]
+pub fn sum_u64_dense_simd(data: &[u64]) -> u128 {
+    let low_bits = Simd::<u64, W64>::splat(0xFFFF_FFFF);
+    let shift = Simd::<u64, W64>::splat(32);
+    let (mut lo_sum, mut hi_sum) = (0u128, 0u128);
+    for block in data.chunks(W64 * SPLIT_SUM_BLOCK) {
+        let chunks = block.chunks_exact(W64);
+        let tail = chunks.remainder();
+        let (mut lo, mut hi) = (Simd::<u64, W64>::splat(0), Simd::<u64, W64>::splat(0));
+        for c in chunks {
+            let v = Simd::from_slice(c);
+            lo += v & low_bits;
+            hi += v >> shift;
+        }
+        lo_sum += lo.to_array().iter().map(|&x| x as u128).sum::<u128>();
+        hi_sum += hi.to_array().iter().map(|&x| x as u128).sum::<u128>();
+        lo_sum += tail.iter().map(|&v| v as u128).sum::<u128>();
+    }
+    (hi_sum << 32) + lo_sum
+}
+
+/// SIMD sum of a dense `f64` buffer.
+///
+/// Lanes are accumulated separately, so the result can differ from a
+/// sequential sum in the last bits.
+#[cfg(feature = "simd")]
+#[inline]
+pub fn sum_f64_dense_simd(data: &[f64]) -> f64 {
+    let chunks = data.chunks_exact(W64);
+    let tail = chunks.remainder();
+    let mut acc = Simd::<f64, W64>::splat(0.0);
+    for c in chunks {
+        acc += Simd::from_slice(c);
+    }
+    acc.reduce_sum() + tail.iter().sum::<f64>()
+}
+
+/// Variance of the valid values with `ddof` delta degrees of freedom, or `None`
+/// when there are no more than `ddof` of them.
+///
+/// Accumulates in `f64` with Welford's update, so large offsets do not cancel.
+/// A `NaN` value makes the result `NaN`, as with `sum_float`.
+#[inline]
+pub fn variance<T: Copy + ToPrimitive>(
+    data: &[T],
+    mask: Option<&Bitmask>,
+    ddof: usize,
+) -> Option<f64> {
+    let (mut n, mut mean, mut m2) = (0usize, 0.0f64, 0.0f64);
+    for_each_valid(data, mask, |v| {
+        let x = v.to_f64().unwrap_or(f64::NAN);
+        n += 1;
+        let delta = x - mean;
+        mean += delta / n as f64;
+        m2 += delta * (x - mean);
+    });
+    (n > ddof).then(|| m2 / (n - ddof) as f64)
+}
+
+/// Minimum and maximum of the valid values, or `None` when there are none.
+///
+/// Values that do not compare equal to themselves, i.e. `NaN`, are skipped.
+#[inline]
+pub fn min_max<T: Copy + PartialOrd>(data: &[T], mask: Option<&Bitmask>) -> Option<(T, T)> {
+    let mut acc: Option<(T, T)> = None;
+    for_each_valid(data, mask, |v| {
+        if v.partial_cmp(&v).is_none() {
+            return;
+        }
+        acc = Some(match acc {
+            None => (v, v),
+            Some((lo, hi)) => (if v < lo { v } else { lo }, if v > hi { v } else { hi }),
+        });
+    });
+    acc
+}
+
+/// Inclusive prefix scan over the valid values.
+///
+/// `step` folds each valid value into the running accumulator, which is `None`
+/// until the first one. Every slot receives the accumulator after its own step,
+/// or `A::default()` before any valid value. The output is built in one aligned
+/// allocation; dense inputs are the place to add SIMD paths later.
+#[inline]
+pub fn scan<T: Copy, A: Copy + Default>(
+    data: &[T],
+    mask: Option<&Bitmask>,
+    mut step: impl FnMut(Option<A>, T) -> Option<A>,
+) -> Vec64<A> {
+    let mut out = Vec64::with_capacity(data.len());
+    let mut acc: Option<A> = None;
+    for (i, &v) in data.iter().enumerate() {
+        if mask.is_none_or(|m| m.get(i)) {
+            acc = step(acc, v);
+        }
+        out.push(acc.unwrap_or_default());
+    }
+    out
+}
+
+/// Running sum of the valid signed integers, widened to `i64` and wrapping on overflow.
+#[inline]
+pub fn cumsum_signed<T: Copy + Into<i64>>(data: &[T], mask: Option<&Bitmask>) -> Vec64<i64> {
+    scan(data, mask, |acc, v| {
+        Some(acc.unwrap_or(0i64).wrapping_add(v.into()))
+    })
+}
+
+/// Running sum of the valid unsigned integers, widened to `u64` and wrapping on overflow.
+#[inline]
+pub fn cumsum_unsigned<T: Copy + Into<u64>>(data: &[T], mask: Option<&Bitmask>) -> Vec64<u64> {
+    scan(data, mask, |acc, v| {
+        Some(acc.unwrap_or(0u64).wrapping_add(v.into()))
+    })
+}
+
+/// Running sum of the valid floats, accumulated as `f64`.
+#[inline]
+pub fn cumsum_float<T: Copy + Into<f64>>(data: &[T], mask: Option<&Bitmask>) -> Vec64<f64> {
+    scan(data, mask, |acc, v| Some(acc.unwrap_or(0.0) + v.into()))
+}
+
+/// Running minimum of the valid values. `NaN` is skipped unless nothing precedes it.
+#[inline]
+pub fn cummin<T: Copy + PartialOrd + Default>(data: &[T], mask: Option<&Bitmask>) -> Vec64<T> {
+    scan(data, mask, |acc, v| match acc {
+        Some(a) if v.partial_cmp(&v).is_none() || v >= a => Some(a),
+        _ => Some(v),
+    })
+}
+
+/// Running maximum of the valid values. `NaN` is skipped unless nothing precedes it.
+#[inline]
+pub fn cummax<T: Copy + PartialOrd + Default>(data: &[T], mask: Option<&Bitmask>) -> Vec64<T> {
+    scan(data, mask, |acc, v| match acc {
+        Some(a) if v.partial_cmp(&v).is_none() || v <= a => Some(a),
+        _ => Some(v),
+    })
+}
+
+/// Copies out the valid values, skipping `NaN`, for the order statistics below.
+#[inline]
+pub fn valid_values<T: Copy + PartialOrd>(data: &[T], mask: Option<&Bitmask>) -> Vec<T> {
+    let mut out = Vec::with_capacity(data.len());
+    for_each_valid(data, mask, |v| {
+        if v.partial_cmp(&v).is_some() {
+            out.push(v);
+        }
+    });
+    out
+}
+
+/// Lower and higher neighbours of each quantile in `qs`, with the fractional
+/// position between them, or `None` for each when `values` is empty.
+///
+/// Quantile `q` sits at position `q * (n - 1)` among the `n` sorted values.
+/// A single quantile is found by quickselect in linear time, while several
+/// sort `values` once and read each off. Either way `values` is reordered.
+///
+/// # Panics
+/// Panics if a `q` is outside `[0, 1]`.
+pub fn quantile_bounds<T: Copy + PartialOrd>(
+    values: &mut [T],
+    qs: &[f64],
+) -> Vec<Option<(T, T, f64)>> {
+    if let Some(q) = qs.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+        panic!("quantile: q must be in [0, 1], got {}", q);
+    }
+    if values.is_empty() {
+        return vec![None; qs.len()];
+    }
+    let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal);
+    let last = values.len() - 1;
+    let position = |q: f64| {
+        let pos = q * last as f64;
+        (pos.floor() as usize, pos.ceil() as usize, pos - pos.floor())
+    };
+
+    if let [q] = qs {
+        let (lo, hi, frac) = position(*q);
+        let (_, &mut low, above) = values.select_nth_unstable_by(lo, cmp);
+        // Everything above the selected slot is at least as large, so the
+        // higher neighbour is the smallest of those.
+        let high = if hi == lo {
+            low
+        } else {
+            *above.iter().min_by(|a, b| cmp(a, b)).expect("hi < len")
+        };
+        return vec![Some((low, high, frac))];
+    }
+
+    values.sort_unstable_by(cmp);
+    qs.iter()
+        .map(|&q| {
+            let (lo, hi, frac) = position(q);
+            Some((values[lo], values[hi], frac))
+        })
+        .collect()
+}
+
+/// Counts the valid values falling in each half-open bucket `[edges[i], edges[i + 1])`.
+///
+/// Each value is mapped through `key` before the edge lookup. The result holds
+/// `edges.len() - 1` counts. Values outside the edges are dropped, unless
+/// `out_of_range` is set, in which case the count below `edges[0]` is prepended
+/// and the count at or above the last edge appended. Keys that do not compare
+/// equal to themselves, i.e. `NaN`, are always dropped.
+///
+/// # Panics
+/// Panics if `edges` has fewer than two entries or is not strictly increasing.
+pub fn histogram<T: Copy, E: PartialOrd>(
+    data: &[T],
+    mask: Option<&Bitmask>,
+    edges: &[E],
+    out_of_range: bool,
+    key: impl Fn(T) -> E,
+) -> Vec64<u64> {
+    assert!(
+        edges.len() >= 2,
+        "histogram: at least two edges are required"
+    );
+    assert!(
+        edges.windows(2).all(|w| w[0] < w[1]),
+        "histogram: edges must be strictly increasing"
+    );
+    // Slot 0 counts underflow, slot `edges.len()` overflow, and slot `i` bucket `i - 1`.
+    let mut counts = vec![0u64; edges.len() + 1];
+    for_each_valid(data, mask, |v| {
+        let k = key(v);
+        if k.partial_cmp(&k).is_some() {
+            counts[edges.partition_point(|e| *e <= k)] += 1;
+        }
+    });
+    if out_of_range {
+        Vec64::from_slice(&counts)
+    } else {
+        Vec64::from_slice(&counts[1..edges.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sums_skip_nulls() {
+        let mask = Bitmask::from_bools(&[true, false, true]);
+        assert_eq!(sum_signed(&[1i32, 100, -4], Some(&mask)), (-3, 2));
+        assert_eq!(
+            sum_unsigned(&[u32::MAX, 0, u32::MAX], None),
+            (2 * u32::MAX as u128, 3)
+        );
+        // Totals past the 64-bit range stay exact.
+        assert_eq!(
+            sum_signed(&[i64::MAX, i64::MAX, -1], None),
+            (2 * i64::MAX as i128 - 1, 3)
+        );
+        assert_eq!(
+            sum_unsigned(&[u64::MAX, u64::MAX], None),
+            (2 * u64::MAX as u128, 2)
+        );
+        assert_eq!(sum_float(&[1.5f32, 2.0, 0.5], Some(&mask)), (2.0, 2));
+        assert_eq!(sum_signed::<i64>(&[], None), (0, 0));
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_dense_simd_sums_match_scalar() {
+        let ints: Vec<i64> = (-50..53).collect();
+        assert_eq!(sum_i64_dense_simd(&ints), sum_signed(&ints, None).0);
+        let uints: Vec<u64> = (0..103).collect();
+        assert_eq!(sum_u64_dense_simd(&uints), sum_unsigned(&uints, None).0);
+        let extremes: Vec<i64> = (0..103)
+            .map(|i| if i % 3 == 0 { i64::MIN } else { i64::MAX - i })
+            .collect();
+        assert_eq!(sum_i64_dense_simd(&extremes), sum_signed(&extremes, None).0);
+        let big: Vec<u64> = (0..103).map(|i| u64::MAX - i).collect();
+        assert_eq!(sum_u64_dense_simd(&big), sum_unsigned(&big, None).0);
+        let floats: Vec<f64> = (0..103).map(|v| v as f64 * 0.5).collect();
+        assert_eq!(sum_f64_dense_simd(&floats), sum_float(&floats, None).0);
+    }
+
+    #[test]
+    fn test_variance() {
+        let mask = Bitmask::from_bools(&[true, false, true, true, true]);
+        let data = [2i64, 1000, 4, 4, 6];
+        assert_eq!(variance(&data, Some(&mask), 0), Some(2.0));
+        assert_eq!(variance(&data, Some(&mask), 1), Some(8.0 / 3.0));
+        assert_eq!(variance(&[1e9f64 + 1.0, 1e9 + 3.0], None, 1), Some(2.0));
+        assert_eq!(variance(&[5u8], None, 1), None);
+        assert!(variance(&[1.0, f64::NAN], None, 0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mask = Bitmask::from_bools(&[true, false, true, true]);
+        assert_eq!(min_max(&[3i32, -9, 7, 1], Some(&mask)), Some((1, 7)));
+        assert_eq!(min_max(&[f64::NAN, 2.0, -1.0], None), Some((-1.0, 2.0)));
+        assert_eq!(min_max::<f64>(&[f64::NAN], None), None);
+        assert_eq!(min_max::<i32>(&[], None), None);
+    }
+
+    #[test]
+    fn test_cumulative_scans() {
+        let mask = Bitmask::from_bools(&[false, true, false, true, true]);
+        let ints = [7i32, i32::MAX, 100, i32::MAX, -3];
+        assert_eq!(
+            cumsum_signed(&ints, Some(&mask)).as_slice(),
+            &[
+                0,
+                i32::MAX as i64,
+                i32::MAX as i64,
+                2 * i32::MAX as i64,
+                2 * i32::MAX as i64 - 3
+            ]
+        );
+        assert_eq!(
+            cumsum_unsigned(&[1u8, 255, 255], None).as_slice(),
+            &[1, 256, 511]
+        );
+        assert_eq!(
+            cumsum_float(&[0.5f32, 1.0, 2.0], None).as_slice(),
+            &[0.5, 1.5, 3.5]
+        );
+        assert_eq!(
+            cummin(&ints, Some(&mask)).as_slice(),
+            &[0, i32::MAX, i32::MAX, i32::MAX, -3]
+        );
+        assert_eq!(cummax(&[2i64, 1, 5, 3], None).as_slice(), &[2, 2, 5, 5]);
+
+        let floats = cummin(&[f64::NAN, 3.0, f64::NAN, 1.0], None);
+        assert!(floats[0].is_nan());
+        assert_eq!(&floats[1..], &[3.0, 3.0, 1.0]);
+        assert!(cummax::<u32>(&[], None).is_empty());
+    }
+
+    #[test]
+    fn test_quantile_bounds() {
+        let mask = Bitmask::from_bools(&[true, true, false, true, true]);
+        let mut vals = valid_values(&[4.0, f64::NAN, 100.0, 1.0, 3.0], Some(&mask));
+        assert_eq!(vals.len(), 3);
+        assert_eq!(
+            quantile_bounds(&mut vals, &[0.5]),
+            vec![Some((3.0, 3.0, 0.0))]
+        );
+
+        let mut ints = vec![9i32, 1, 5, 7, 3];
+        assert_eq!(
+            quantile_bounds(&mut ints.clone(), &[0.375]),
+            vec![Some((3, 5, 0.5))]
+        );
+        assert_eq!(
+            quantile_bounds(&mut ints, &[0.0, 0.375, 1.0]),
+            vec![Some((1, 1, 0.0)), Some((3, 5, 0.5)), Some((9, 9, 0.0))]
+        );
+        assert_eq!(
+            quantile_bounds::<i32>(&mut [], &[0.5, 0.9]),
+            vec![None, None]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "q must be in [0, 1]")]
+    fn test_quantile_bounds_rejects_out_of_range() {
+        quantile_bounds(&mut [1i32, 2], &[1.5]);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mask = Bitmask::from_bools(&[true, true, false, true, true, true]);
+        let data = [-5i32, 0, 3, 4, 9, 10];
+        let edges = [0, 5, 10];
+        assert_eq!(
+            histogram(&data, Some(&mask), &edges, false, |v| v).as_slice(),
+            &[2, 1]
+        );
+        assert_eq!(
+            histogram(&data, Some(&mask), &edges, true, |v| v).as_slice(),
+            &[1, 2, 1, 1]
+        );
+        let floats = [0.5f32, f32::NAN, 1.5];
+        assert_eq!(
+            histogram(&floats, None, &[0.0, 1.0, 2.0], true, f64::from).as_slice(),
+            &[0, 1, 1, 0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn test_histogram_rejects_unsorted_edges() {
+        histogram(&[1i64], None, &[3, 1], false, |v| v);
+    }
+}
@@ -143,6 +143,46 @@ pub fn not_mask(src: BitmaskVT<'_>) -> Bitmask {
     }
 }
 
+// --- Whole-Mask Entry Points ---
+
+/// Bitwise AND of two whole bitmasks, 64 bits at a time.
+///
+/// Unused bits past `len` in the final word are cleared in the result.
+///
+/// # Panics
+/// Panics if the masks differ in length.
+#[inline]
+pub fn and(lhs: &Bitmask, rhs: &Bitmask) -> Bitmask {
+    assert_eq!(lhs.len(), rhs.len(), "bitmask and: length mismatch");
+    and_masks((lhs, 0, lhs.len()), (rhs, 0, rhs.len()))
+}
+
+/// Bitwise OR of two whole bitmasks. See [`and`].
+///
+/// # Panics
+/// Panics if the masks differ in length.
+#[inline]
+pub fn or(lhs: &Bitmask, rhs: &Bitmask) -> Bitmask {
+    assert_eq!(lhs.len(), rhs.len(), "bitmask or: length mismatch");
+    or_masks((lhs, 0, lhs.len()), (rhs, 0, rhs.len()))
+}
+
+/// Bitwise XOR of two whole bitmasks. See [`and`].
+///
+/// # Panics
+/// Panics if the masks differ in length.
+#[inline]
+pub fn xor(lhs: &Bitmask, rhs: &Bitmask) -> Bitmask {
+    assert_eq!(lhs.len(), rhs.len(), "bitmask xor: length mismatch");
+    xor_masks((lhs, 0, lhs.len()), (rhs, 0, rhs.len()))
+}
+
+/// Bitwise NOT of a whole bitmask, leaving the bits past `len` cleared.
+#[inline]
+pub fn not(src: &Bitmask) -> Bitmask {
+    not_mask((src, 0, src.len()))
+}
+
 // --- Set Logic ---
 
 /// Performs bitwise inclusion: output bit is true if lhs bit is present in rhs bit-set.
@@ -241,6 +241,39 @@ mod tests {
         assert_eq!(mask.bits[15], 0xCA);
     }
 
+    #[test]
+    fn test_whole_mask_ops() {
+        use crate::kernels::bitmask::dispatch::{and, not, or, xor};
+        let len = 70;
+        let a = Bitmask::from_bools(&(0..len).map(|i| i % 2 == 0).collect::<Vec<_>>());
+        let b = Bitmask::from_bools(&(0..len).map(|i| i % 3 == 0).collect::<Vec<_>>());
+
+        let both = and(&a, &b);
+        let either = or(&a, &b);
+        let one = xor(&a, &b);
+        for i in 0..len {
+            let (x, y) = (i % 2 == 0, i % 3 == 0);
+            assert_eq!(both.get(i), x && y);
+            assert_eq!(either.get(i), x || y);
+            assert_eq!(one.get(i), x ^ y);
+        }
+        assert_eq!(both.count_ones(), (0..len).filter(|i| i % 6 == 0).count());
+
+        // Bits past len in the final partial word must stay clear
+        let inverted = not(&Bitmask::new_set_all(len, false));
+        assert_eq!(inverted.len(), len);
+        assert_eq!(inverted.count_ones(), len);
+        assert_eq!(inverted.bits[8], 0b0011_1111);
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn test_whole_mask_length_mismatch() {
+        let a = Bitmask::new_set_all(8, true);
+        let b = Bitmask::new_set_all(9, true);
+        let _ = crate::kernels::bitmask::dispatch::and(&a, &b);
+    }
+
     #[test]
     fn test_bitmask_window_bytes() {
         let mut mask = Bitmask::new_set_all(24, false);
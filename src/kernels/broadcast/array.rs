@@ -170,7 +170,7 @@ pub fn broadcast_array_to_scalar(
         Scalar::Datetime64(val) => {
             Array::from_datetime_i64(DatetimeArray::from_slice(&[*val], None))
         }
-        Scalar::Null => Array::Null,
+        Scalar::Null(_) => Array::Null,
         #[cfg(feature = "datetime")]
         Scalar::Interval => {
             return Err(MinarrowError::NotImplemented {
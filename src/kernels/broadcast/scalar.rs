@@ -198,7 +198,7 @@ pub fn broadcast_scalar_to_array(
         Scalar::Datetime64(val) => {
             Array::from_datetime_i64(DatetimeArray::from_slice(&[*val], None))
         }
-        Scalar::Null => Array::Null,
+        Scalar::Null(_) => Array::Null,
         #[cfg(feature = "datetime")]
         Scalar::Interval => {
             return Err(MinarrowError::NotImplemented {
@@ -590,7 +590,7 @@ pub fn broadcast_scalar_to_text_arrayview(
         (Scalar::String64(val), TextArray::Categorical64(_)) => {
             Array::from_categorical64(CategoricalArray::<u64>::from_values(vec![val.as_str()]))
         }
-        (Scalar::Null, _) | (Scalar::Boolean(_), _) => {
+        (Scalar::Null(_), _) | (Scalar::Boolean(_), _) => {
             return Err(MinarrowError::NotImplemented {
                 feature: "Non-string scalar with TextArrayView".to_string(),
             });
@@ -689,7 +689,7 @@ pub fn broadcast_text_arrayview_to_scalar(
         (TextArray::Categorical64(_), Scalar::String64(val)) => {
             Array::from_categorical64(CategoricalArray::<u64>::from_values(vec![val.as_str()]))
         }
-        (_, Scalar::Null) | (_, Scalar::Boolean(_)) => {
+        (_, Scalar::Null(_)) | (_, Scalar::Boolean(_)) => {
             return Err(MinarrowError::NotImplemented {
                 feature: "Non-string scalar with TextArrayView".to_string(),
             });
@@ -767,7 +767,7 @@ pub fn broadcast_scalar_to_fieldarray(
         Scalar::Datetime64(val) => {
             Array::from_datetime_i64(DatetimeArray::from_slice(&[*val], None))
         }
-        Scalar::Null => Array::Null,
+        Scalar::Null(_) => Array::Null,
         #[cfg(feature = "datetime")]
         Scalar::Interval => {
             return Err(MinarrowError::NotImplemented {
@@ -812,7 +812,7 @@ pub fn broadcast_fieldarray_to_scalar(
         Scalar::Datetime64(val) => {
             Array::from_datetime_i64(DatetimeArray::from_slice(&[*val], None))
         }
-        Scalar::Null => Array::Null,
+        Scalar::Null(_) => Array::Null,
         #[cfg(feature = "datetime")]
         Scalar::Interval => {
             return Err(MinarrowError::NotImplemented {
@@ -868,7 +868,7 @@ pub fn broadcast_scalar_to_temporal_arrayview(
                 feature: "String scalar with TemporalArrayView".to_string(),
             });
         }
-        Scalar::Null => {
+        Scalar::Null(_) => {
             return Err(MinarrowError::NullError { message: None });
         }
     };
@@ -920,7 +920,7 @@ pub fn broadcast_temporal_arrayview_to_scalar(
                 feature: "String scalar with TemporalArrayView".to_string(),
             });
         }
-        Scalar::Null => {
+        Scalar::Null(_) => {
             return Err(MinarrowError::NullError { message: None });
         }
     };
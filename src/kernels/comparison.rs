@@ -0,0 +1,742 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Comparison Kernels Module** - *Numeric Predicates*
+//!
+//! Elementwise comparisons between numeric arrays, producing `BooleanArray<()>`
+//! masks suitable for `Array::filter` and `Table::filter`, plus the elementwise
+//! `min_with` / `max_with` selections built on the same ordering.
+//!
+//! ## Semantics
+//! - Arrow three-valued logic: a null on either side yields null in the output.
+//! - Mixed numeric types are compared exactly by value, never through a lossy
+//!   cast, so comparisons do not fail on valid numeric input.
+//! - `min_with` / `max_with` return the common type from the same lattice as
+//!   `Array::concat_promoting`. Integers selected into a float result are rounded.
+//! - A length-1 operand is broadcast against the other side.
+//! - `NaN` compares unequal to everything, including itself.
+//! - `min_with` / `max_with` keep a `NaN` on the left as-is and never select a
+//!   `NaN` from the right.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[cfg(feature = "scalar_type")]
+use crate::Scalar;
+use crate::enums::array::promote_numeric;
+use crate::enums::error::{KernelError, MinarrowError};
+use crate::enums::operators::ComparisonOperator;
+use crate::{
+    Array, ArrowType, Bitmask, BooleanArray, FloatArray, IntegerArray, NumericArray, Vec64,
+    Vec64Ext,
+};
+
+/// Compares `lhs` and `rhs` elementwise with `op`, comparing mismatched
+/// numeric types exactly and broadcasting a length-1 side.
+///
+/// # Errors
+/// - `NotImplemented` for operators other than `==`, `!=`, `<`, `<=`, `>`, `>=`
+/// - `KernelError` if the lengths differ and neither side has length 1
+/// - `NullError` if either side is an untyped null array
+pub fn resolve_binary_comparison(
+    op: ComparisonOperator,
+    lhs: &NumericArray,
+    rhs: &NumericArray,
+) -> Result<BooleanArray<()>, MinarrowError> {
+    let test: fn(Ordering) -> bool = match op {
+        ComparisonOperator::Equals => Ordering::is_eq,
+        ComparisonOperator::NotEquals => Ordering::is_ne,
+        ComparisonOperator::LessThan => Ordering::is_lt,
+        ComparisonOperator::LessThanOrEqualTo => Ordering::is_le,
+        ComparisonOperator::GreaterThan => Ordering::is_gt,
+        ComparisonOperator::GreaterThanOrEqualTo => Ordering::is_ge,
+        _ => {
+            return Err(MinarrowError::NotImplemented {
+                feature: format!("resolve_binary_comparison with {:?}", op),
+            });
+        }
+    };
+
+    let ctx = "resolve_binary_comparison";
+    let len = broadcast_len(ctx, lhs.len(), rhs.len())?;
+    check_typed(ctx, lhs, rhs)?;
+    let masks = (lhs.null_mask(), lhs.len(), rhs.null_mask(), rhs.len(), len);
+
+    macro_rules! cmp {
+        ($l:expr, $r:expr) => {
+            compare_by(masks, op, test, |i, j| $l.data[i].partial_cmp(&$r.data[j]))
+        };
+    }
+    Ok(match (lhs, rhs) {
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::Int8(l), NumericArray::Int8(r)) => cmp!(l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::Int16(l), NumericArray::Int16(r)) => cmp!(l, r),
+        (NumericArray::Int32(l), NumericArray::Int32(r)) => cmp!(l, r),
+        (NumericArray::Int64(l), NumericArray::Int64(r)) => cmp!(l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::UInt8(l), NumericArray::UInt8(r)) => cmp!(l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::UInt16(l), NumericArray::UInt16(r)) => cmp!(l, r),
+        (NumericArray::UInt32(l), NumericArray::UInt32(r)) => cmp!(l, r),
+        (NumericArray::UInt64(l), NumericArray::UInt64(r)) => cmp!(l, r),
+        (NumericArray::Float32(l), NumericArray::Float32(r)) => cmp!(l, r),
+        (NumericArray::Float64(l), NumericArray::Float64(r)) => cmp!(l, r),
+        _ => {
+            let (l, r) = (exact_values(lhs), exact_values(rhs));
+            compare_by(masks, op, test, |i, j| l[i].partial_cmp(&r[j]))
+        }
+    })
+}
+
+/// Compares each element of `lhs` against a scalar. A `Scalar::Null` yields
+/// an all-null result.
+#[cfg(feature = "scalar_type")]
+pub fn resolve_scalar_comparison(
+    op: ComparisonOperator,
+    lhs: &NumericArray,
+    rhs: &Scalar,
+) -> Result<BooleanArray<()>, MinarrowError> {
+    match scalar_operand(rhs, "compare")? {
+        Some(rhs) => resolve_binary_comparison(op, lhs, &rhs),
+        None => {
+            let len = lhs.len();
+            Ok(BooleanArray::new(
+                Bitmask::new_set_all(len, false),
+                Some(Bitmask::new_set_all(len, false)),
+            ))
+        }
+    }
+}
+
+/// Elementwise `lhs == rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn eq(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::Equals, lhs, rhs)
+}
+
+/// Elementwise `lhs != rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn ne(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::NotEquals, lhs, rhs)
+}
+
+/// Elementwise `lhs < rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn lt(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::LessThan, lhs, rhs)
+}
+
+/// Elementwise `lhs <= rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn le(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::LessThanOrEqualTo, lhs, rhs)
+}
+
+/// Elementwise `lhs > rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn gt(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::GreaterThan, lhs, rhs)
+}
+
+/// Elementwise `lhs >= rhs`. See [`resolve_binary_comparison`].
+#[inline]
+pub fn ge(lhs: &NumericArray, rhs: &NumericArray) -> Result<BooleanArray<()>, MinarrowError> {
+    resolve_binary_comparison(ComparisonOperator::GreaterThanOrEqualTo, lhs, rhs)
+}
+
+/// Elementwise minimum of `lhs` and `rhs`, in their common numeric type.
+///
+/// Nulls on either side yield null. A `NaN` in `lhs` is kept, and a `NaN` in
+/// `rhs` is never selected. Errors as for [`resolve_binary_comparison`], and
+/// with `IncompatibleTypeError` if the types have no common numeric type.
+#[inline]
+pub fn min_with(lhs: &NumericArray, rhs: &NumericArray) -> Result<NumericArray, MinarrowError> {
+    resolve_binary_extremum(lhs, rhs, false)
+}
+
+/// Elementwise maximum of `lhs` and `rhs`, in their common numeric type.
+///
+/// Nulls on either side yield null. A `NaN` in `lhs` is kept, and a `NaN` in
+/// `rhs` is never selected. Errors as for [`resolve_binary_comparison`], and
+/// with `IncompatibleTypeError` if the types have no common numeric type.
+#[inline]
+pub fn max_with(lhs: &NumericArray, rhs: &NumericArray) -> Result<NumericArray, MinarrowError> {
+    resolve_binary_extremum(lhs, rhs, true)
+}
+
+/// Elementwise minimum of each element of `lhs` and a scalar. A `Scalar::Null`
+/// yields an all-null result in the type of `lhs`.
+#[cfg(feature = "scalar_type")]
+pub fn min_with_scalar(lhs: &NumericArray, rhs: &Scalar) -> Result<NumericArray, MinarrowError> {
+    match scalar_operand(rhs, "take the minimum of")? {
+        Some(rhs) => min_with(lhs, &rhs),
+        None => Ok(all_null_like(lhs)),
+    }
+}
+
+/// Elementwise maximum of each element of `lhs` and a scalar. A `Scalar::Null`
+/// yields an all-null result in the type of `lhs`.
+#[cfg(feature = "scalar_type")]
+pub fn max_with_scalar(lhs: &NumericArray, rhs: &Scalar) -> Result<NumericArray, MinarrowError> {
+    match scalar_operand(rhs, "take the maximum of")? {
+        Some(rhs) => max_with(lhs, &rhs),
+        None => Ok(all_null_like(lhs)),
+    }
+}
+
+/// Shared body of `min_with` and `max_with`.
+fn resolve_binary_extremum(
+    lhs: &NumericArray,
+    rhs: &NumericArray,
+    pick_max: bool,
+) -> Result<NumericArray, MinarrowError> {
+    let ctx = if pick_max { "max_with" } else { "min_with" };
+    let len = broadcast_len(ctx, lhs.len(), rhs.len())?;
+    check_typed(ctx, lhs, rhs)?;
+    let mask = combined_validity(lhs.null_mask(), lhs.len(), rhs.null_mask(), rhs.len(), len);
+
+    macro_rules! pick {
+        ($variant:ident, $wrapper:ident, $l:expr, $r:expr) => {{
+            let data = extremum_values($l.data.as_slice(), $r.data.as_slice(), len, pick_max);
+            NumericArray::$variant(Arc::new($wrapper::new(data, mask)))
+        }};
+    }
+    Ok(match (lhs, rhs) {
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::Int8(l), NumericArray::Int8(r)) => pick!(Int8, IntegerArray, l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::Int16(l), NumericArray::Int16(r)) => pick!(Int16, IntegerArray, l, r),
+        (NumericArray::Int32(l), NumericArray::Int32(r)) => pick!(Int32, IntegerArray, l, r),
+        (NumericArray::Int64(l), NumericArray::Int64(r)) => pick!(Int64, IntegerArray, l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::UInt8(l), NumericArray::UInt8(r)) => pick!(UInt8, IntegerArray, l, r),
+        #[cfg(feature = "extended_numeric_types")]
+        (NumericArray::UInt16(l), NumericArray::UInt16(r)) => pick!(UInt16, IntegerArray, l, r),
+        (NumericArray::UInt32(l), NumericArray::UInt32(r)) => pick!(UInt32, IntegerArray, l, r),
+        (NumericArray::UInt64(l), NumericArray::UInt64(r)) => pick!(UInt64, IntegerArray, l, r),
+        (NumericArray::Float32(l), NumericArray::Float32(r)) => pick!(Float32, FloatArray, l, r),
+        (NumericArray::Float64(l), NumericArray::Float64(r)) => pick!(Float64, FloatArray, l, r),
+        _ => {
+            let l_type = Array::NumericArray(lhs.clone()).arrow_type();
+            let r_type = Array::NumericArray(rhs.clone()).arrow_type();
+            let target = promote_numeric(&l_type, &r_type).ok_or_else(|| {
+                MinarrowError::IncompatibleTypeError {
+                    from: "NumericArray",
+                    to: "NumericArray",
+                    message: Some(format!(
+                        "no common numeric type for {} and {}",
+                        l_type, r_type
+                    )),
+                }
+            })?;
+            let (l, r) = (exact_values(lhs), exact_values(rhs));
+            let picked = extremum_values(&l, &r, len, pick_max);
+            exact_into(&target, &picked, mask)
+        }
+    })
+}
+
+/// Resolves the broadcast length of two operands. `ctx` names the caller in errors.
+fn broadcast_len(ctx: &str, l_len: usize, r_len: usize) -> Result<usize, MinarrowError> {
+    match (l_len, r_len) {
+        _ if l_len == r_len => Ok(l_len),
+        (1, n) | (n, 1) => Ok(n),
+        _ => Err(KernelError::LengthMismatch(format!(
+            "{} => cannot broadcast arrays of length {} and {}",
+            ctx, l_len, r_len
+        ))
+        .into()),
+    }
+}
+
+/// Rejects untyped null operands, which have no values to compare.
+fn check_typed(ctx: &str, lhs: &NumericArray, rhs: &NumericArray) -> Result<(), MinarrowError> {
+    if matches!(lhs, NumericArray::Null) || matches!(rhs, NumericArray::Null) {
+        return Err(MinarrowError::NullError {
+            message: Some(format!("{}: untyped null array", ctx)),
+        });
+    }
+    Ok(())
+}
+
+/// A numeric value widened without loss, for ordering values of different types.
+///
+/// Every supported integer fits in `i128`, and `f32` widens exactly to `f64`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Exact {
+    Int(i128),
+    Float(f64),
+}
+
+impl PartialEq for Exact {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl std::fmt::Display for Exact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Exact::Int(v) => write!(f, "{}", v),
+            Exact::Float(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl PartialOrd for Exact {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (*self, *other) {
+            (Exact::Int(a), Exact::Int(b)) => Some(a.cmp(&b)),
+            (Exact::Float(a), Exact::Float(b)) => a.partial_cmp(&b),
+            (Exact::Int(a), Exact::Float(b)) => int_float_cmp(a, b),
+            (Exact::Float(a), Exact::Int(b)) => int_float_cmp(b, a).map(Ordering::reverse),
+        }
+    }
+}
+
+/// Orders an integer against a float without rounding either side.
+/// `None` if `f` is `NaN`.
+fn int_float_cmp(i: i128, f: f64) -> Option<Ordering> {
+    if f.is_nan() {
+        return None;
+    }
+    // Rounds up to 2^127, so every i128 lies in [-limit, limit).
+    let limit = i128::MAX as f64;
+    if f >= limit {
+        return Some(Ordering::Less);
+    }
+    if f < -limit {
+        return Some(Ordering::Greater);
+    }
+    // `whole` is integral and in range, so the cast is exact.
+    let whole = f.trunc();
+    Some(
+        i.cmp(&(whole as i128))
+            .then_with(|| 0.0.partial_cmp(&(f - whole)).unwrap_or(Ordering::Equal)),
+    )
+}
+
+/// Widens every slot of `arr`, null or not, to an `Exact` value.
+pub(crate) fn exact_values(arr: &NumericArray) -> Vec64<Exact> {
+    macro_rules! widen {
+        ($a:expr, $variant:ident, $t:ty) => {
+            Vec64::from_exact_iter($a.data.iter().map(|&v| Exact::$variant(v as $t)))
+        };
+    }
+    match arr {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(a) => widen!(a, Int, i128),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(a) => widen!(a, Int, i128),
+        NumericArray::Int32(a) => widen!(a, Int, i128),
+        NumericArray::Int64(a) => widen!(a, Int, i128),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(a) => widen!(a, Int, i128),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(a) => widen!(a, Int, i128),
+        NumericArray::UInt32(a) => widen!(a, Int, i128),
+        NumericArray::UInt64(a) => widen!(a, Int, i128),
+        NumericArray::Float32(a) => widen!(a, Float, f64),
+        NumericArray::Float64(a) => widen!(a, Float, f64),
+        NumericArray::Null => Vec64::new(),
+    }
+}
+
+/// Builds a `target`-typed array from values already chosen from its inputs.
+///
+/// `target` comes from `promote_numeric`, so an integer target holds every
+/// value exactly, while a float target rounds integers to the nearest value.
+fn exact_into(target: &ArrowType, values: &[Exact], mask: Option<Bitmask>) -> NumericArray {
+    macro_rules! narrow {
+        ($variant:ident, $wrapper:ident, $t:ty) => {
+            NumericArray::$variant(Arc::new($wrapper::new(
+                Vec64::from_exact_iter(values.iter().map(|v| match *v {
+                    Exact::Int(i) => i as $t,
+                    Exact::Float(f) => f as $t,
+                })),
+                mask,
+            )))
+        };
+    }
+    match target {
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int8 => narrow!(Int8, IntegerArray, i8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::Int16 => narrow!(Int16, IntegerArray, i16),
+        ArrowType::Int32 => narrow!(Int32, IntegerArray, i32),
+        ArrowType::Int64 => narrow!(Int64, IntegerArray, i64),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt8 => narrow!(UInt8, IntegerArray, u8),
+        #[cfg(feature = "extended_numeric_types")]
+        ArrowType::UInt16 => narrow!(UInt16, IntegerArray, u16),
+        ArrowType::UInt32 => narrow!(UInt32, IntegerArray, u32),
+        ArrowType::UInt64 => narrow!(UInt64, IntegerArray, u64),
+        ArrowType::Float32 => narrow!(Float32, FloatArray, f32),
+        _ => narrow!(Float64, FloatArray, f64),
+    }
+}
+
+/// Converts a scalar operand into a length-1 `NumericArray`, or `None` for
+/// `Scalar::Null`. `verb` completes the "cannot ... with" error message.
+#[cfg(feature = "scalar_type")]
+pub(crate) fn scalar_operand(
+    rhs: &Scalar,
+    verb: &str,
+) -> Result<Option<NumericArray>, MinarrowError> {
+    match Array::from_scalars(std::slice::from_ref(rhs)) {
+        Array::NumericArray(rhs) => Ok(Some(rhs)),
+        Array::Null => Ok(None),
+        other => Err(MinarrowError::TypeError {
+            from: "Scalar",
+            to: "NumericArray",
+            message: Some(format!(
+                "cannot {} a numeric array with {}",
+                verb,
+                other.arrow_type()
+            )),
+        }),
+    }
+}
+
+/// Same-typed array of `arr.len()` slots, all null.
+#[cfg(feature = "scalar_type")]
+pub(crate) fn all_null_like(arr: &NumericArray) -> NumericArray {
+    let mask = Some(Bitmask::new_set_all(arr.len(), false));
+    macro_rules! nulls {
+        ($variant:ident, $wrapper:ident, $a:expr) => {
+            NumericArray::$variant(Arc::new($wrapper::new($a.data.clone(), mask)))
+        };
+    }
+    match arr {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(a) => nulls!(Int8, IntegerArray, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(a) => nulls!(Int16, IntegerArray, a),
+        NumericArray::Int32(a) => nulls!(Int32, IntegerArray, a),
+        NumericArray::Int64(a) => nulls!(Int64, IntegerArray, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(a) => nulls!(UInt8, IntegerArray, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(a) => nulls!(UInt16, IntegerArray, a),
+        NumericArray::UInt32(a) => nulls!(UInt32, IntegerArray, a),
+        NumericArray::UInt64(a) => nulls!(UInt64, IntegerArray, a),
+        NumericArray::Float32(a) => nulls!(Float32, FloatArray, a),
+        NumericArray::Float64(a) => nulls!(Float64, FloatArray, a),
+        NumericArray::Null => NumericArray::Null,
+    }
+}
+
+/// Selects the smaller, or with `pick_max` the larger, of each pair. The left
+/// value wins ties and unordered pairs. A side of length 1 is broadcast.
+fn extremum_values<T: PartialOrd + Copy>(
+    lhs: &[T],
+    rhs: &[T],
+    len: usize,
+    pick_max: bool,
+) -> Vec64<T> {
+    let l_idx = |i: usize| if lhs.len() == 1 { 0 } else { i };
+    let r_idx = |i: usize| if rhs.len() == 1 { 0 } else { i };
+    Vec64::from_exact_iter((0..len).map(|i| {
+        let (l, r) = (lhs[l_idx(i)], rhs[r_idx(i)]);
+        let take_r = if pick_max { r > l } else { r < l };
+        if take_r { r } else { l }
+    }))
+}
+
+/// Validity of a binary result: set only where both sides are valid.
+/// Returns `None` when neither side has a mask.
+fn combined_validity(
+    lhs_mask: Option<&Bitmask>,
+    lhs_len: usize,
+    rhs_mask: Option<&Bitmask>,
+    rhs_len: usize,
+    len: usize,
+) -> Option<Bitmask> {
+    if lhs_mask.is_none() && rhs_mask.is_none() {
+        return None;
+    }
+    let l_idx = |i: usize| if lhs_len == 1 { 0 } else { i };
+    let r_idx = |i: usize| if rhs_len == 1 { 0 } else { i };
+    let mut mask = Bitmask::new_set_all(len, true);
+    for i in 0..len {
+        let valid =
+            lhs_mask.is_none_or(|m| m.get(l_idx(i))) && rhs_mask.is_none_or(|m| m.get(r_idx(i)));
+        if !valid {
+            mask.set(i, false);
+        }
+    }
+    Some(mask)
+}
+
+/// Core comparison loop. `cmp(i, j)` orders the values at `lhs[i]` and
+/// `rhs[j]`. `masks` holds each side's validity and length, then the output
+/// length. A side of length 1 is broadcast.
+fn compare_by(
+    masks: (Option<&Bitmask>, usize, Option<&Bitmask>, usize, usize),
+    op: ComparisonOperator,
+    test: fn(Ordering) -> bool,
+    cmp: impl Fn(usize, usize) -> Option<Ordering>,
+) -> BooleanArray<()> {
+    let (lhs_mask, lhs_len, rhs_mask, rhs_len, len) = masks;
+    let l_idx = |i: usize| if lhs_len == 1 { 0 } else { i };
+    let r_idx = |i: usize| if rhs_len == 1 { 0 } else { i };
+
+    let mut data = Bitmask::new_set_all(len, false);
+    for i in 0..len {
+        let hit = match cmp(l_idx(i), r_idx(i)) {
+            Some(ord) => test(ord),
+            // Unordered (NaN) values only satisfy `!=`.
+            None => op == ComparisonOperator::NotEquals,
+        };
+        if hit {
+            data.set(i, true);
+        }
+    }
+
+    let null_mask = combined_validity(lhs_mask, lhs_len, rhs_mask, rhs_len, len);
+    if let Some(mask) = &null_mask {
+        for i in 0..len {
+            if !mask.get(i) {
+                data.set(i, false);
+            }
+        }
+    }
+    BooleanArray::new(data, null_mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FloatArray, IntegerArray, MaskedArray, vec64};
+
+    fn bools(arr: &BooleanArray<()>) -> Vec<Option<bool>> {
+        (0..arr.len()).map(|i| arr.get(i)).collect()
+    }
+
+    #[test]
+    fn test_compare_same_type_with_nulls() {
+        let a = NumericArray::Int32(
+            IntegerArray::new(
+                vec64![1, 2, 3, 4],
+                Some(Bitmask::from_bools(&[true, true, false, true])),
+            )
+            .into(),
+        );
+        let b = NumericArray::Int32(IntegerArray::new(vec64![1, 5, 3, 0], None).into());
+
+        assert_eq!(
+            bools(&eq(&a, &b).unwrap()),
+            vec![Some(true), Some(false), None, Some(false)]
+        );
+        assert_eq!(
+            bools(&lt(&a, &b).unwrap()),
+            vec![Some(false), Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            bools(&ge(&a, &b).unwrap()),
+            vec![Some(true), Some(false), None, Some(true)]
+        );
+    }
+
+    #[test]
+    fn test_compare_promotes_and_broadcasts() {
+        let a = NumericArray::Int32(IntegerArray::new(vec64![1, 2, 3], None).into());
+        let b = NumericArray::Int64(IntegerArray::new(vec64![2], None).into());
+        let out = gt(&a, &b).unwrap();
+        assert_eq!(bools(&out), vec![Some(false), Some(false), Some(true)]);
+        assert!(out.null_mask.is_none());
+
+        let f = NumericArray::Float64(FloatArray::new(vec64![1.0, f64::NAN, 3.5], None).into());
+        assert_eq!(
+            bools(&ne(&f, &a).unwrap()),
+            vec![Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(
+            bools(&le(&f, &a).unwrap()),
+            vec![Some(true), Some(false), Some(false)]
+        );
+
+        let short = NumericArray::Int32(IntegerArray::new(vec64![1, 2], None).into());
+        assert!(matches!(eq(&a, &short), Err(MinarrowError::KernelError(_))));
+    }
+
+    #[test]
+    fn test_compare_mixed_types_exactly() {
+        let big = (1i64 << 53) + 1;
+        let ints = NumericArray::Int64(IntegerArray::new(vec64![big, -1, i64::MAX], None).into());
+        let floats =
+            NumericArray::Float64(FloatArray::new(vec64![(1u64 << 53) as f64], None).into());
+        assert_eq!(
+            bools(&gt(&ints, &floats).unwrap()),
+            vec![Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(
+            bools(&eq(&ints, &floats).unwrap()),
+            vec![Some(false), Some(false), Some(false)]
+        );
+
+        let half = NumericArray::Float64(FloatArray::new(vec64![-0.5, f64::INFINITY], None).into());
+        let pair = NumericArray::Int32(IntegerArray::new(vec64![-1, i32::MAX], None).into());
+        assert_eq!(
+            bools(&lt(&pair, &half).unwrap()),
+            vec![Some(true), Some(true)]
+        );
+
+        let unsigned = NumericArray::UInt64(IntegerArray::new(vec64![u64::MAX, 0, 9], None).into());
+        assert_eq!(
+            bools(&lt(&ints, &unsigned).unwrap()),
+            vec![Some(true), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_min_max_with_mixed_types_exactly() {
+        let ints = NumericArray::Int64(IntegerArray::new(vec64![-1, i64::MAX], None).into());
+        let unsigned = NumericArray::UInt64(IntegerArray::new(vec64![u64::MAX, 0], None).into());
+        let hi = max_with(&ints, &unsigned).unwrap();
+        assert_eq!(
+            hi.f64_ref().unwrap().data.as_slice(),
+            &[u64::MAX as f64, i64::MAX as f64]
+        );
+
+        let big = (1i64 << 53) + 1;
+        let a = NumericArray::Int64(IntegerArray::new(vec64![big], None).into());
+        let f = NumericArray::Float64(FloatArray::new(vec64![1.5], None).into());
+        let lo = min_with(&a, &f).unwrap();
+        assert_eq!(lo.f64_ref().unwrap().data.as_slice(), &[1.5]);
+    }
+
+    #[test]
+    fn test_compare_then_filter() {
+        let a = Array::from_int32(IntegerArray::new(vec64![5, 1, 7, 3], None));
+        let b = NumericArray::Int32(IntegerArray::new(vec64![4], None).into());
+        let mask = gt(a.num_ref().unwrap(), &b).unwrap();
+        let out = a.filter(&mask.data).unwrap();
+        assert_eq!(out.num().i32().unwrap().data.as_slice(), &[5, 7]);
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_compare_scalar() {
+        let a = NumericArray::Int64(IntegerArray::new(vec64![1, 2, 3], None).into());
+        let out =
+            resolve_scalar_comparison(ComparisonOperator::LessThan, &a, &Scalar::Int32(2)).unwrap();
+        assert_eq!(bools(&out), vec![Some(true), Some(false), Some(false)]);
+
+        let big = NumericArray::Int64(IntegerArray::new(vec64![(1 << 53) + 1], None).into());
+        let out = resolve_scalar_comparison(
+            ComparisonOperator::GreaterThan,
+            &big,
+            &Scalar::Float64((1u64 << 53) as f64),
+        )
+        .unwrap();
+        assert_eq!(bools(&out), vec![Some(true)]);
+
+        let out = resolve_scalar_comparison(
+            ComparisonOperator::Equals,
+            &a,
+            &Scalar::Null(crate::ArrowType::Null),
+        )
+        .unwrap();
+        assert_eq!(bools(&out), vec![None, None, None]);
+    }
+
+    fn values<T: Copy>(data: &[T], mask: Option<&Bitmask>) -> Vec<Option<T>> {
+        (0..data.len())
+            .map(|i| mask.is_none_or(|m| m.get(i)).then(|| data[i]))
+            .collect()
+    }
+
+    #[test]
+    fn test_min_max_with_promotes_broadcasts_and_keeps_nulls() {
+        let a = NumericArray::Int32(
+            IntegerArray::new(
+                vec64![1, 5, 3, 9],
+                Some(Bitmask::from_bools(&[true, true, false, true])),
+            )
+            .into(),
+        );
+        let b = NumericArray::Int64(IntegerArray::new(vec64![4, 4, 4, 10], None).into());
+
+        let lo = min_with(&a, &b).unwrap();
+        let lo = lo.i64_ref().unwrap();
+        assert_eq!(
+            values(lo.data.as_slice(), lo.null_mask.as_ref()),
+            vec![Some(1), Some(4), None, Some(9)]
+        );
+
+        let four = NumericArray::Int32(IntegerArray::new(vec64![4], None).into());
+        let hi = max_with(&a, &four).unwrap();
+        let hi = hi.i32_ref().unwrap();
+        assert_eq!(
+            values(hi.data.as_slice(), hi.null_mask.as_ref()),
+            vec![Some(4), Some(5), None, Some(9)]
+        );
+
+        let short = NumericArray::Int32(IntegerArray::new(vec64![1, 2], None).into());
+        assert!(matches!(
+            min_with(&a, &short),
+            Err(MinarrowError::KernelError(_))
+        ));
+    }
+
+    #[test]
+    fn test_min_max_with_nan() {
+        let f = NumericArray::Float64(FloatArray::new(vec64![f64::NAN, 2.0], None).into());
+        let g = NumericArray::Float64(FloatArray::new(vec64![1.0, f64::NAN], None).into());
+        let out = min_with(&f, &g).unwrap();
+        let data = out.f64_ref().unwrap().data.as_slice();
+        assert!(data[0].is_nan());
+        assert_eq!(data[1], 2.0);
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_clamp() {
+        let a = NumericArray::Float64(
+            FloatArray::new(
+                vec64![-3.0, 0.5, f64::NAN, 7.0, 2.0],
+                Some(Bitmask::from_bools(&[true, true, true, true, false])),
+            )
+            .into(),
+        );
+        let out = a.clamp(Scalar::Int32(0), Scalar::Float64(1.0)).unwrap();
+        let arr = out.f64_ref().unwrap();
+        assert_eq!(arr.data[0], 0.0);
+        assert_eq!(arr.data[1], 0.5);
+        assert!(arr.data[2].is_nan());
+        assert_eq!(arr.data[3], 1.0);
+        assert_eq!(arr.get(4), None);
+
+        let ints = NumericArray::Int32(IntegerArray::new(vec64![-5, 5, 50], None).into());
+        let open = Scalar::Null(crate::ArrowType::Null);
+        let out = ints.clamp(Scalar::Int32(0), open.clone()).unwrap();
+        assert_eq!(out.i32_ref().unwrap().data.as_slice(), &[0, 5, 50]);
+
+        let bad = ints.clamp(Scalar::Int32(5), Scalar::Int32(1));
+        assert!(matches!(bad, Err(MinarrowError::KernelError(_))));
+
+        let big = NumericArray::Int64(IntegerArray::new(vec64![0], None).into());
+        let close = big.clamp(Scalar::Int64((1 << 53) + 1), Scalar::Int64(1 << 53));
+        assert!(matches!(close, Err(MinarrowError::KernelError(_))));
+        let out = big
+            .clamp(Scalar::Int64(i64::MAX), Scalar::Float64(f64::INFINITY))
+            .unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[i64::MAX as f64]);
+
+        let all_null = ints.min_with_scalar(&open).unwrap();
+        assert_eq!(all_null.null_mask().unwrap().count_zeros(), 3);
+    }
+}
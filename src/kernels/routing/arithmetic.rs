@@ -188,7 +188,7 @@ pub fn scalar_arithmetic(
         (Scalar::String64(l), Scalar::String32(r), Add) => Scalar::String64(format!("{}{}", l, r)),
 
         // Null handling
-        (Scalar::Null, _, _) | (_, Scalar::Null, _) => {
+        (Scalar::Null(_), _, _) | (_, Scalar::Null(_), _) => {
             return Err(MinarrowError::NullError {
                 message: Some("Arithmetic operations with null values not supported".to_string()),
             });
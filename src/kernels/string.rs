@@ -2811,6 +2811,26 @@ mod tests {
         assert_eq!(out.get(1), Some(" world"));
     }
 
+    #[test]
+    fn test_case_unicode_u64_offsets() {
+        let a = str_array::<u64>(&["straße", "ÉCOLE", "ǅ"]);
+        let upper = to_uppercase_str((&a, 0, a.len())).unwrap();
+        assert_eq!(upper.get(0), Some("STRASSE"));
+        assert_eq!(upper.get(2), Some("Ǆ"));
+        assert_eq!(upper.offsets[3], upper.data.len() as u64);
+        let lower = to_lowercase_str((&a, 0, a.len())).unwrap();
+        assert_eq!(lower.get(1), Some("école"));
+        assert_eq!(lower.get(2), Some("ǆ"));
+    }
+
+    #[test]
+    fn test_trim_unicode_whitespace() {
+        let a = str_array::<u64>(&["\u{3000}x\u{a0}", "\ty "]);
+        let out = trim_str((&a, 0, a.len())).unwrap();
+        assert_eq!(out.get(0), Some("x"));
+        assert_eq!(out.get(1), Some("y"));
+    }
+
     #[test]
     fn test_reverse_str() {
         let a = str_array::<u32>(&["abc", "hello"]);
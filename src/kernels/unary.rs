@@ -0,0 +1,439 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Unary Kernels Module** - *Elementwise Math*
+//!
+//! Single-operand numeric kernels backing
+//! `NumericArray::{abs, neg, sqrt, round, floor, ceil, pow}`.
+//!
+//! ## Semantics
+//! - Null slots stay null and are never checked, so whatever bytes sit under
+//!   a null cannot raise an error.
+//! - Integer `abs` and `neg` are checked, so `MIN` and any non-zero unsigned
+//!   negation fail with `Overflow` rather than wrapping.
+//! - `round`, `floor` and `ceil` are the identity on integers. `round` rounds
+//!   half away from zero, as `f64::round` does.
+//! - `sqrt` keeps `Float32` as `Float32` and widens integers to `Float64`.
+//! - Dense float buffers run through `std::simd` when the `simd` feature is enabled.
+
+include!(concat!(env!("OUT_DIR"), "/simd_lanes.rs"));
+
+#[cfg(feature = "simd")]
+use std::simd::num::SimdFloat;
+#[cfg(feature = "simd")]
+use std::simd::{Simd, StdFloat};
+use std::sync::Arc;
+
+use num_traits::{Float, PrimInt, Zero};
+
+#[cfg(feature = "scalar_type")]
+use crate::Scalar;
+use crate::enums::error::{KernelError, MinarrowError};
+use crate::{Bitmask, FloatArray, IntegerArray, NumericArray, Vec64};
+
+/// Elementwise float operation applied by [`float_unary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatUnaryOp {
+    Abs,
+    Neg,
+    Sqrt,
+    Round,
+    Floor,
+    Ceil,
+}
+
+/// Applies `op` to every value, including those under a null.
+#[inline]
+pub fn float_unary<T: Float>(op: FloatUnaryOp, data: &[T]) -> Vec64<T> {
+    let f: fn(T) -> T = match op {
+        FloatUnaryOp::Abs => T::abs,
+        FloatUnaryOp::Neg => |v: T| -v,
+        FloatUnaryOp::Sqrt => T::sqrt,
+        FloatUnaryOp::Round => T::round,
+        FloatUnaryOp::Floor => T::floor,
+        FloatUnaryOp::Ceil => T::ceil,
+    };
+    data.iter().map(|&v| f(v)).collect()
+}
+
+macro_rules! float_unary_simd {
+    ($name:ident, $t:ty, $lanes:ident) => {
+        /// SIMD version of [`float_unary`] for a dense buffer.
+        #[cfg(feature = "simd")]
+        #[inline]
+        pub fn $name(op: FloatUnaryOp, data: &[$t]) -> Vec64<$t> {
+            let mut out = Vec64::with_capacity(data.len());
+            let chunks = data.chunks_exact($lanes);
+            let tail = chunks.remainder();
+            for c in chunks {
+                let v = Simd::<$t, $lanes>::from_slice(c);
+                let r = match op {
+                    FloatUnaryOp::Abs => v.abs(),
+                    FloatUnaryOp::Neg => -v,
+                    FloatUnaryOp::Sqrt => v.sqrt(),
+                    FloatUnaryOp::Round => v.round(),
+                    FloatUnaryOp::Floor => v.floor(),
+                    FloatUnaryOp::Ceil => v.ceil(),
+                };
+                out.extend_from_slice(r.as_array());
+            }
+            out.extend_from_slice(&float_unary(op, tail));
+            out
+        }
+    };
+}
+
+float_unary_simd!(float_unary_f32_simd, f32, W32);
+float_unary_simd!(float_unary_f64_simd, f64, W64);
+
+/// Applies a checked integer operation to every valid value.
+///
+/// Null slots are written as zero. Returns the first valid value for which
+/// `f` gives `None`.
+#[inline]
+pub fn int_checked<T: PrimInt>(
+    data: &[T],
+    mask: Option<&Bitmask>,
+    f: impl Fn(T) -> Option<T>,
+) -> Result<Vec64<T>, T> {
+    let mut out = Vec64::with_capacity(data.len());
+    for (i, &v) in data.iter().enumerate() {
+        if mask.is_some_and(|m| !m.get(i)) {
+            out.push(T::zero());
+        } else {
+            out.push(f(v).ok_or(v)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Absolute value, in the array's own type.
+///
+/// # Errors
+/// - `Overflow` for a signed `MIN`
+pub fn abs(arr: &NumericArray) -> Result<NumericArray, MinarrowError> {
+    map_checked(arr, FloatUnaryOp::Abs)
+}
+
+/// Negation, in the array's own type.
+///
+/// # Errors
+/// - `Overflow` for a signed `MIN` or any non-zero unsigned value
+pub fn neg(arr: &NumericArray) -> Result<NumericArray, MinarrowError> {
+    map_checked(arr, FloatUnaryOp::Neg)
+}
+
+/// Square root. Integers widen to `Float64`.
+///
+/// Negative values give `NaN`, unless `error_on_negative` is set.
+///
+/// # Errors
+/// - `KernelError::InvalidArguments` for a valid negative value when
+///   `error_on_negative` is set
+pub fn sqrt(arr: &NumericArray, error_on_negative: bool) -> Result<NumericArray, MinarrowError> {
+    let arr = match arr {
+        NumericArray::Float32(_) | NumericArray::Float64(_) | NumericArray::Null => arr.clone(),
+        other => other.clone().cow_into_f64(),
+    };
+    if error_on_negative {
+        macro_rules! check {
+            ($a:expr) => {
+                let mask = $a.null_mask.as_ref();
+                let bad = $a
+                    .data
+                    .iter()
+                    .enumerate()
+                    .find(|&(i, &v)| v < 0.0 && mask.is_none_or(|m| m.get(i)));
+                if let Some((i, v)) = bad {
+                    return Err(KernelError::InvalidArguments(format!(
+                        "sqrt: negative value {} at index {}",
+                        v, i
+                    ))
+                    .into());
+                }
+            };
+        }
+        match &arr {
+            NumericArray::Float32(a) => {
+                check!(a);
+            }
+            NumericArray::Float64(a) => {
+                check!(a);
+            }
+            _ => {}
+        }
+    }
+    Ok(map_float(&arr, FloatUnaryOp::Sqrt))
+}
+
+/// Rounds floats to the nearest integer, half away from zero. Integers are returned as-is.
+pub fn round(arr: &NumericArray) -> NumericArray {
+    map_float(arr, FloatUnaryOp::Round)
+}
+
+/// Rounds floats down. Integers are returned as-is.
+pub fn floor(arr: &NumericArray) -> NumericArray {
+    map_float(arr, FloatUnaryOp::Floor)
+}
+
+/// Rounds floats up. Integers are returned as-is.
+pub fn ceil(arr: &NumericArray) -> NumericArray {
+    map_float(arr, FloatUnaryOp::Ceil)
+}
+
+/// Raises every value to `exp`.
+///
+/// Integers with a non-negative integer exponent stay in their own type and
+/// are checked for overflow. A float, negative or beyond-`u32` exponent widens
+/// integers to `Float64`. Floats keep their type. A `Scalar::Null` exponent yields an
+/// all-null result in the array's type.
+///
+/// # Errors
+/// - `TypeError` if `exp` is not numeric
+/// - `Overflow` if an integer power does not fit the array's type
+#[cfg(feature = "scalar_type")]
+pub fn pow(arr: &NumericArray, exp: &Scalar) -> Result<NumericArray, MinarrowError> {
+    let exp_arr = match crate::kernels::comparison::scalar_operand(exp, "raise")? {
+        Some(e) => e,
+        None => return Ok(crate::kernels::comparison::all_null_like(arr)),
+    };
+    let float_exp = matches!(exp_arr, NumericArray::Float32(_) | NumericArray::Float64(_));
+    let int_exp = if float_exp {
+        None
+    } else {
+        exp.try_i64().and_then(|e| u32::try_from(e).ok())
+    };
+
+    macro_rules! powf {
+        ($variant:ident, $t:ty, $a:expr) => {{
+            let e = exp.try_f64().unwrap_or(f64::NAN) as $t;
+            let data: Vec64<$t> = $a.data.iter().map(|&v| v.powf(e)).collect();
+            NumericArray::$variant(Arc::new(FloatArray::new(data, $a.null_mask.clone())))
+        }};
+    }
+    macro_rules! powi {
+        ($variant:ident, $a:expr) => {
+            match int_exp {
+                Some(e) => {
+                    let data = int_checked($a.data.as_slice(), $a.null_mask.as_ref(), |v| {
+                        v.checked_pow(e)
+                    })
+                    .map_err(|v| overflow(v, e))?;
+                    NumericArray::$variant(Arc::new(IntegerArray::new(data, $a.null_mask.clone())))
+                }
+                None => match arr.clone().cow_into_f64() {
+                    NumericArray::Float64(f) => powf!(Float64, f64, f),
+                    _ => unreachable!("cow_into_f64 yields Float64"),
+                },
+            }
+        };
+    }
+    Ok(match arr {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(a) => powi!(Int8, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(a) => powi!(Int16, a),
+        NumericArray::Int32(a) => powi!(Int32, a),
+        NumericArray::Int64(a) => powi!(Int64, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(a) => powi!(UInt8, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(a) => powi!(UInt16, a),
+        NumericArray::UInt32(a) => powi!(UInt32, a),
+        NumericArray::UInt64(a) => powi!(UInt64, a),
+        NumericArray::Float32(a) => powf!(Float32, f32, a),
+        NumericArray::Float64(a) => powf!(Float64, f64, a),
+        NumericArray::Null => NumericArray::Null,
+    })
+}
+
+/// `Overflow` error for an integer `v` raised to `e`.
+#[cfg(feature = "scalar_type")]
+fn overflow<T: std::fmt::Display>(v: T, e: u32) -> MinarrowError {
+    MinarrowError::Overflow {
+        value: format!("{}^{}", v, e),
+        target: std::any::type_name::<T>(),
+    }
+}
+
+/// Applies `op` to every float variant, returning integer variants as-is.
+fn map_float(arr: &NumericArray, op: FloatUnaryOp) -> NumericArray {
+    match arr {
+        NumericArray::Float32(a) => {
+            #[cfg(feature = "simd")]
+            let data = float_unary_f32_simd(op, a.data.as_slice());
+            #[cfg(not(feature = "simd"))]
+            let data = float_unary(op, a.data.as_slice());
+            NumericArray::Float32(Arc::new(FloatArray::new(data, a.null_mask.clone())))
+        }
+        NumericArray::Float64(a) => {
+            #[cfg(feature = "simd")]
+            let data = float_unary_f64_simd(op, a.data.as_slice());
+            #[cfg(not(feature = "simd"))]
+            let data = float_unary(op, a.data.as_slice());
+            NumericArray::Float64(Arc::new(FloatArray::new(data, a.null_mask.clone())))
+        }
+        other => other.clone(),
+    }
+}
+
+/// Applies `op` to float variants, and its checked form to integer variants.
+///
+/// Only `Abs` and `Neg` are valid here.
+fn map_checked(arr: &NumericArray, op: FloatUnaryOp) -> Result<NumericArray, MinarrowError> {
+    macro_rules! checked {
+        ($variant:ident, $t:ty, $a:expr) => {{
+            let f = |v: $t| match op {
+                FloatUnaryOp::Abs if v < <$t as Zero>::zero() => v.checked_neg(),
+                FloatUnaryOp::Abs => Some(v),
+                _ => v.checked_neg(),
+            };
+            let data = int_checked($a.data.as_slice(), $a.null_mask.as_ref(), f).map_err(|v| {
+                MinarrowError::Overflow {
+                    value: format!("{:?}({})", op, v),
+                    target: stringify!($t),
+                }
+            })?;
+            NumericArray::$variant(Arc::new(IntegerArray::new(data, $a.null_mask.clone())))
+        }};
+    }
+    Ok(match arr {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(a) => checked!(Int8, i8, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(a) => checked!(Int16, i16, a),
+        NumericArray::Int32(a) => checked!(Int32, i32, a),
+        NumericArray::Int64(a) => checked!(Int64, i64, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(a) => checked!(UInt8, u8, a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(a) => checked!(UInt16, u16, a),
+        NumericArray::UInt32(a) => checked!(UInt32, u32, a),
+        NumericArray::UInt64(a) => checked!(UInt64, u64, a),
+        _ => map_float(arr, op),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MaskedArray;
+
+    fn ints(values: &[i32], mask: Option<Bitmask>) -> NumericArray {
+        NumericArray::Int32(Arc::new(IntegerArray::new(
+            Vec64::from_iter(values.iter().copied()),
+            mask,
+        )))
+    }
+
+    fn floats(values: &[f64]) -> NumericArray {
+        NumericArray::Float64(Arc::new(FloatArray::from_slice(values)))
+    }
+
+    #[test]
+    fn test_abs_neg() {
+        let mask = Bitmask::from_bools(&[true, false, true]);
+        let out = abs(&ints(&[-3, i32::MIN, 4], Some(mask.clone()))).unwrap();
+        let out = out.i32_ref().unwrap();
+        assert_eq!(out.get(0), Some(3));
+        assert_eq!(out.get(1), None);
+        assert_eq!(out.get(2), Some(4));
+
+        let out = neg(&ints(&[-3, 0, 4], None)).unwrap();
+        assert_eq!(out.i32_ref().unwrap().data.as_slice(), &[3, 0, -4]);
+
+        assert!(matches!(
+            abs(&ints(&[i32::MIN], None)),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        assert!(matches!(
+            neg(&ints(&[1, i32::MIN], None)),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        let unsigned = NumericArray::UInt32(Arc::new(IntegerArray::from_slice(&[0, 2])));
+        assert!(neg(&unsigned).is_err());
+
+        let out = abs(&floats(&[-1.5, 2.0])).unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[1.5, 2.0]);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let out = sqrt(&ints(&[4, 9], None), false).unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[2.0, 3.0]);
+
+        let out = sqrt(&floats(&[16.0, -1.0]), false).unwrap();
+        let out = out.f64_ref().unwrap();
+        assert_eq!(out.data[0], 4.0);
+        assert!(out.data[1].is_nan());
+
+        assert!(sqrt(&floats(&[16.0, -1.0]), true).is_err());
+        // A negative value under a null is not an error
+        let masked = NumericArray::Float64(Arc::new(FloatArray::new(
+            Vec64::from_iter([-1.0, 4.0]),
+            Some(Bitmask::from_bools(&[false, true])),
+        )));
+        assert!(sqrt(&masked, true).is_ok());
+
+        let f32s = NumericArray::Float32(Arc::new(FloatArray::from_slice(&[25.0f32])));
+        assert_eq!(sqrt(&f32s, true).unwrap().f32_ref().unwrap().data[0], 5.0);
+    }
+
+    #[test]
+    fn test_rounding() {
+        // Long enough to cover both SIMD lanes and the scalar tail
+        let data: Vec<f64> = (0..37).map(|i| i as f64 * 0.5 - 9.25).collect();
+        let arr = floats(&data);
+        let r = round(&arr);
+        let f = floor(&arr);
+        let c = ceil(&arr);
+        for (i, &v) in data.iter().enumerate() {
+            assert_eq!(r.f64_ref().unwrap().data[i], v.round());
+            assert_eq!(f.f64_ref().unwrap().data[i], v.floor());
+            assert_eq!(c.f64_ref().unwrap().data[i], v.ceil());
+        }
+        assert_eq!(
+            round(&floats(&[2.5, -2.5]))
+                .f64_ref()
+                .unwrap()
+                .data
+                .as_slice(),
+            &[3.0, -3.0]
+        );
+        assert_eq!(floor(&ints(&[7], None)), ints(&[7], None));
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_pow() {
+        let out = pow(&ints(&[2, 3], None), &Scalar::Int32(3)).unwrap();
+        assert_eq!(out.i32_ref().unwrap().data.as_slice(), &[8, 27]);
+
+        let out = pow(&ints(&[4], None), &Scalar::Float64(0.5)).unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[2.0]);
+        let out = pow(&ints(&[2], None), &Scalar::Int32(-1)).unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[0.5]);
+
+        let out = pow(&floats(&[9.0]), &Scalar::Int64(2)).unwrap();
+        assert_eq!(out.f64_ref().unwrap().data.as_slice(), &[81.0]);
+
+        assert!(matches!(
+            pow(&ints(&[i32::MAX], None), &Scalar::Int32(2)),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        assert!(pow(&ints(&[1], None), &Scalar::String32("x".into())).is_err());
+        let out = pow(&ints(&[1, 2], None), &Scalar::Null(crate::ArrowType::Int32)).unwrap();
+        assert_eq!(out.null_mask().unwrap().null_count(), 2);
+    }
+}
@@ -100,8 +100,12 @@ pub use ::vec64::{Vec64, Vec64Alloc, vec64};
 
 /// **Array**, **TextArray**, **NumericArray**...- *All the *High-Level Array containers* are here.*
 pub mod enums {
+    pub mod aggregate;
     pub mod array;
+    pub mod cast;
     pub mod error;
+    pub mod join;
+    pub mod nulls;
     #[cfg(feature = "scalar_type")]
     pub mod scalar;
     pub mod time_units;
@@ -115,19 +119,23 @@ pub mod enums {
     }
     pub mod operators;
     pub mod shape_dim;
+    pub mod sort;
 }
 
 /// Contains SIMD-accelerated kernels for the 'essentials' that are highly coupled to this crate
 /// The extensive set is available downstream in the simd-kernels crate, including a full
 /// set of univariate distributions.
 pub mod kernels {
+    pub mod aggregate;
     pub mod arithmetic;
     pub mod bitmask;
     #[cfg(feature = "broadcast")]
     pub mod broadcast;
+    pub mod comparison;
     #[cfg(feature = "views")]
     pub mod routing;
     pub mod string;
+    pub mod unary;
 }
 
 /// **Table**, **IntegerArray**, **FloatArray**, **Vec64** - *All the **Low-Level Control**, **Tables** and **Views***.
@@ -146,6 +154,7 @@ pub mod structs {
         pub mod categorical;
         #[cfg(feature = "datetime")]
         pub mod datetime;
+        pub mod fixed_size_binary;
         pub mod float;
         pub mod integer;
         pub mod string;
@@ -179,8 +188,11 @@ pub mod structs {
     pub mod cube;
     pub mod field;
     pub mod field_array;
+    pub mod group_by;
     #[cfg(feature = "matrix")]
     pub mod matrix;
+    #[cfg(feature = "scalar_type")]
+    pub mod row;
     pub mod shared_buffer;
     pub mod table;
 }
@@ -208,13 +220,17 @@ pub mod traits {
     pub mod selection;
     pub mod shape;
     pub mod type_unions;
+    pub mod vec64_ext;
     #[cfg(feature = "views")]
     pub mod view;
 }
 
 pub mod aliases;
 pub mod conversions;
+pub mod csv;
+pub mod ipc;
 pub mod macros;
+pub mod ndjson;
 pub mod utils;
 
 pub use aliases::{
@@ -224,17 +240,24 @@ pub use aliases::{
 
 #[cfg(feature = "datetime")]
 pub use aliases::DatetimeAVT;
+pub use csv::{CsvReadOptions, CsvWriteOptions};
+pub use enums::aggregate::{AggFn, Interpolation};
 pub use enums::array::Array;
+pub use enums::cast::CastOverflow;
 pub use enums::collections::numeric_array::NumericArray;
 #[cfg(feature = "datetime")]
 pub use enums::collections::temporal_array::TemporalArray;
 pub use enums::collections::text_array::TextArray;
+pub use enums::join::JoinType;
+pub use enums::nulls::DropHow;
 #[cfg(feature = "scalar_type")]
 pub use enums::scalar::Scalar;
+pub use enums::sort::{NullOrder, Side, SortOrder};
 #[cfg(feature = "datetime")]
-pub use enums::time_units::{IntervalUnit, TimeUnit};
+pub use enums::time_units::{DurationRounding, IntervalUnit, TimeUnit};
 #[cfg(feature = "value_type")]
 pub use enums::value::Value;
+pub use ndjson::{NdjsonReadOptions, NdjsonWriteOptions};
 
 #[cfg(feature = "arena")]
 pub use structs::arena::{AAMaker, Arena, ArenaRegion};
@@ -267,14 +290,18 @@ pub use structs::column::{Column, column};
 pub use structs::cube::Cube;
 pub use structs::field::Field;
 pub use structs::field_array::{FieldArray, field_array};
+pub use structs::group_by::GroupBy;
 #[cfg(feature = "matrix")]
-pub use structs::matrix::Matrix;
+pub use structs::matrix::{Axis, Matrix};
+#[cfg(feature = "scalar_type")]
+pub use structs::row::Row;
 pub use structs::shared_buffer::SharedBuffer;
-pub use structs::table::Table;
+pub use structs::table::{EqualsOptions, Table};
 pub use structs::variants::boolean::BooleanArray;
-pub use structs::variants::categorical::CategoricalArray;
+pub use structs::variants::categorical::{CategoricalArray, CategoricalOperand};
 #[cfg(feature = "datetime")]
 pub use structs::variants::datetime::DatetimeArray;
+pub use structs::variants::fixed_size_binary::FixedSizeBinaryArray;
 pub use structs::variants::float::FloatArray;
 pub use structs::variants::integer::IntegerArray;
 pub use structs::variants::string::StringArray;
@@ -288,7 +315,8 @@ pub use traits::consolidate::Consolidate;
 #[cfg(feature = "datetime_ops")]
 pub use traits::datetime_ops::DatetimeOps;
 pub use traits::masked_array::MaskedArray;
-pub use traits::print::Print;
+pub use traits::print::{DisplayWith, FormatWith, Print, PrintOptions};
 #[cfg(feature = "select")]
 pub use traits::selection::{ColumnSelection, RowSelection, Selection2D};
 pub use traits::type_unions::{Float, Integer, Numeric, Primitive};
+pub use traits::vec64_ext::Vec64Ext;
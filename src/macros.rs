@@ -594,6 +594,26 @@ macro_rules! impl_masked_array {
                 })
             }
 
+            /// Consuming parallel iterator – returns owned `T`
+            /// One must consult the null-mask if they care about nulls.
+            #[inline]
+            pub fn into_par_iter(self) -> impl rayon::prelude::IndexedParallelIterator<Item = T> {
+                self.data.into_par_iter()
+            }
+
+            /// Consuming nullable parallel iterator
+            /// `None` for null, `Some(T)` for valid values.
+            #[inline]
+            pub fn into_par_iter_opt(
+                self
+            ) -> impl rayon::prelude::IndexedParallelIterator<Item = Option<T>> {
+                use rayon::prelude::*;
+                let nmask = self.null_mask;
+                self.data.into_par_iter().enumerate().map(move |(idx, val)| {
+                    if nmask.as_ref().map(|m| !m.get(idx)).unwrap_or(false) { None } else { Some(val) }
+                })
+            }
+
             /// Parallel mutable iterator (zero-copy, gives `&mut T`)
             #[inline]
             pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
@@ -733,6 +753,7 @@ macro_rules! match_array {
                 NumericArray::Null              => Default::default(),
             },
             Array::BooleanArray(a)                  => a.$method($($args),*),
+            Array::FixedSizeBinaryArray(a)          => a.$method($($args),*),
             Array::TextArray(inner) => match inner {
                 TextArray::String32(a)              => a.$method($($args),*),
                 #[cfg(feature = "large_string")]
@@ -0,0 +1,813 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **NDJSON Module** - *Newline-Delimited JSON Encoding*
+//!
+//! Reads and writes tables as JSON objects, one per line. Use it through
+//! `Table::from_ndjson`, `Table::to_ndjson` and `Table::write_ndjson`.
+//!
+//! ## Decoding
+//! - Every non-blank line must hold a single JSON object. Blank lines are skipped.
+//! - Each key seen on any line becomes a column, in first-seen order. Keys missing
+//!   from a line, and explicit `null`s, are nulls.
+//! - Column types are inferred from the first `infer_rows` lines: `Int64` when all
+//!   values are integers, `Float64` when they are numbers, `Boolean` for `true`/`false`,
+//!   and `String` otherwise. Mixed columns fall back to `String`, holding the JSON text
+//!   of their numbers and booleans.
+//! - Nested objects are rejected, or flattened into `parent.child` keys when
+//!   `flatten_nested` is set. Arrays are always rejected.
+//!
+//! ## Encoding
+//! - Integers and finite floats are written as JSON numbers. `NaN` and infinities become `null`.
+//! - Strings and categoricals are written as escaped JSON strings.
+//! - Datetimes are RFC 3339 strings with the `datetime_ops` feature, or raw integers otherwise.
+//! - Fixed-size binary values are written as lowercase hex strings.
+//! - Nulls are written as `null`.
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::io::Read;
+
+use crate::enums::error::MinarrowError;
+use crate::ffi::arrow_dtype::ArrowType;
+use crate::structs::field_array::FieldArray;
+use crate::{
+    Array, Bitmask, BooleanArray, Field, Float, FloatArray, IntegerArray, NumericArray,
+    StringArray, Table, TextArray, Vec64, vec64,
+};
+#[cfg(feature = "datetime")]
+use crate::{TemporalArray, TimeUnit};
+
+/// Options for `Table::from_ndjson`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NdjsonReadOptions {
+    /// Number of lines sampled to infer each column's type.
+    pub infer_rows: usize,
+    /// Flatten nested objects into `parent.child` keys instead of rejecting them.
+    pub flatten_nested: bool,
+}
+
+impl Default for NdjsonReadOptions {
+    fn default() -> Self {
+        Self {
+            infer_rows: 1000,
+            flatten_nested: false,
+        }
+    }
+}
+
+/// Options for `Table::write_ndjson`.
+///
+/// The default writes the same output as `Table::to_ndjson`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NdjsonWriteOptions {
+    /// Leave null entries out of each object rather than writing `"key":null`.
+    /// On by default, so a row whose entries are all null is written as `{}`.
+    pub omit_nulls: bool,
+}
+
+impl Default for NdjsonWriteOptions {
+    fn default() -> Self {
+        Self { omit_nulls: true }
+    }
+}
+
+/// A decoded scalar JSON value. Objects are flattened or rejected before this point.
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl JsonValue {
+    fn kind(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "boolean",
+            JsonValue::Int(_) => "integer",
+            JsonValue::Float(_) => "float",
+            JsonValue::Str(_) => "string",
+        }
+    }
+
+    /// Text form used when the value lands in a `String` column.
+    fn into_text(self) -> String {
+        match self {
+            JsonValue::Null => String::new(),
+            JsonValue::Bool(b) => b.to_string(),
+            JsonValue::Int(v) => v.to_string(),
+            JsonValue::Float(v) => v.to_string(),
+            JsonValue::Str(s) => s,
+        }
+    }
+}
+
+/// A decoded line: its number and the `(column, value)` pairs it sets.
+struct Row {
+    line: usize,
+    values: Vec<(usize, JsonValue)>,
+}
+
+/// Column types the reader can infer.
+#[derive(Clone, Copy)]
+enum JsonType {
+    Int,
+    Float,
+    Bool,
+    Str,
+}
+
+/// Reads an NDJSON document from `reader` into a table.
+pub(crate) fn read_table(
+    mut reader: impl Read,
+    opts: &NdjsonReadOptions,
+) -> Result<Table, MinarrowError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let mut names: Vec<String> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut rows = Vec::new();
+    for (i, text) in input.split('\n').enumerate() {
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        if text.trim().is_empty() {
+            continue;
+        }
+        let mut parser = Parser {
+            bytes: text.as_bytes(),
+            pos: 0,
+            line: i + 1,
+            flatten: opts.flatten_nested,
+        };
+        let mut pairs = Vec::new();
+        parser.skip_ws();
+        parser.parse_object("", &mut pairs)?;
+        parser.skip_ws();
+        if parser.pos < parser.bytes.len() {
+            return Err(parser.error("unexpected characters after object"));
+        }
+        let values = pairs
+            .into_iter()
+            .map(|(key, value)| {
+                let col = *index.entry(key).or_insert_with_key(|k| {
+                    names.push(k.clone());
+                    names.len() - 1
+                });
+                (col, value)
+            })
+            .collect();
+        rows.push(Row {
+            line: i + 1,
+            values,
+        });
+    }
+
+    let mut columns: Vec<Vec<(usize, JsonValue)>> = vec![Vec::new(); names.len()];
+    for (r, row) in rows.iter_mut().enumerate() {
+        for (col, value) in row.values.drain(..) {
+            let cells = &mut columns[col];
+            // A repeated key on the same line keeps its last value.
+            if cells.last().is_some_and(|(prev, _)| *prev == r) {
+                cells.pop();
+            }
+            if value != JsonValue::Null {
+                cells.push((r, value));
+            }
+        }
+    }
+
+    let mut table = Table::new_empty();
+    for (name, cells) in names.into_iter().zip(columns) {
+        let (array, dtype) = build_column(&name, cells, &rows, opts.infer_rows)?;
+        let nullable = array.null_count() > 0;
+        table.add_col(FieldArray::new(
+            Field::new(name, dtype, nullable, None),
+            array,
+        ));
+    }
+    Ok(table)
+}
+
+/// Picks the column type from the values on the first `infer_rows` lines.
+fn infer_type(cells: &[(usize, JsonValue)], infer_rows: usize) -> JsonType {
+    let (mut int, mut float, mut boolean, mut string) = (false, false, false, false);
+    for (_, v) in cells.iter().take_while(|(r, _)| *r < infer_rows) {
+        match v {
+            JsonValue::Int(_) => int = true,
+            JsonValue::Float(_) => float = true,
+            JsonValue::Bool(_) => boolean = true,
+            _ => string = true,
+        }
+    }
+    match (int, float, boolean, string) {
+        (true, false, false, false) => JsonType::Int,
+        (_, true, false, false) => JsonType::Float,
+        (false, false, true, false) => JsonType::Bool,
+        _ => JsonType::Str,
+    }
+}
+
+/// Builds one column from its non-null `(row, value)` cells.
+fn build_column(
+    name: &str,
+    cells: Vec<(usize, JsonValue)>,
+    rows: &[Row],
+    infer_rows: usize,
+) -> Result<(Array, ArrowType), MinarrowError> {
+    let n = rows.len();
+    let mask = (cells.len() < n).then(|| {
+        let mut m = Bitmask::new_set_all(n, false);
+        for (r, _) in &cells {
+            m.set(*r, true);
+        }
+        m
+    });
+    let mismatch = |r: usize, v: &JsonValue, ty: &str| MinarrowError::ParseError {
+        line: rows[r].line,
+        message: format!("key '{}': expected {}, found {}", name, ty, v.kind()),
+    };
+    Ok(match infer_type(&cells, infer_rows) {
+        JsonType::Int => {
+            let mut data = vec64![0i64; n];
+            for (r, v) in cells {
+                match v {
+                    JsonValue::Int(x) => data[r] = x,
+                    other => return Err(mismatch(r, &other, "Int64")),
+                }
+            }
+            (
+                Array::from_int64(IntegerArray::new(data, mask)),
+                ArrowType::Int64,
+            )
+        }
+        JsonType::Float => {
+            let mut data = vec64![0f64; n];
+            for (r, v) in cells {
+                match v {
+                    JsonValue::Int(x) => data[r] = x as f64,
+                    JsonValue::Float(x) => data[r] = x,
+                    other => return Err(mismatch(r, &other, "Float64")),
+                }
+            }
+            (
+                Array::from_float64(FloatArray::new(data, mask)),
+                ArrowType::Float64,
+            )
+        }
+        JsonType::Bool => {
+            let mut data = Bitmask::new_set_all(n, false);
+            for (r, v) in cells {
+                match v {
+                    JsonValue::Bool(b) => data.set(r, b),
+                    other => return Err(mismatch(r, &other, "Boolean")),
+                }
+            }
+            (
+                Array::BooleanArray(BooleanArray::new(data, mask).into()),
+                ArrowType::Boolean,
+            )
+        }
+        JsonType::Str => {
+            let mut values = vec![String::new(); n];
+            for (r, v) in cells {
+                values[r] = v.into_text();
+            }
+            let values: Vec64<&str> = values.iter().map(String::as_str).collect();
+            (
+                Array::from_string32(StringArray::from_vec64(values, mask)),
+                ArrowType::String,
+            )
+        }
+    })
+}
+
+/// Recursive-descent parser over a single NDJSON line.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    flatten: bool,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> MinarrowError {
+        MinarrowError::ParseError {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.bytes.get(self.pos), Some(b' ' | b'\t' | b'\r' | b'\n')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), MinarrowError> {
+        if self.bytes.get(self.pos) == Some(&b) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!(
+                "expected '{}' at column {}",
+                b as char,
+                self.pos + 1
+            )))
+        }
+    }
+
+    /// Parses an object, pushing its scalar members onto `out` with keys
+    /// prefixed by `prefix`.
+    fn parse_object(
+        &mut self,
+        prefix: &str,
+        out: &mut Vec<(String, JsonValue)>,
+    ) -> Result<(), MinarrowError> {
+        if self.bytes.get(self.pos) != Some(&b'{') {
+            return Err(self.error("expected a JSON object"));
+        }
+        self.pos += 1;
+        self.skip_ws();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            self.skip_ws();
+            let key = format!("{}{}", prefix, self.parse_string()?);
+            self.skip_ws();
+            self.expect(b':')?;
+            self.skip_ws();
+            match self.bytes.get(self.pos) {
+                Some(b'{') if self.flatten => self.parse_object(&format!("{}.", key), out)?,
+                Some(b'{') => {
+                    return Err(self.error(format!(
+                        "key '{}' holds a nested object; enable flatten_nested to read it",
+                        key
+                    )));
+                }
+                Some(b'[') => {
+                    return Err(self.error(format!("key '{}' holds an array", key)));
+                }
+                _ => {
+                    let value = self.parse_scalar()?;
+                    out.push((key, value));
+                }
+            }
+            self.skip_ws();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return Err(self.error("expected ',' or '}' in object")),
+            }
+        }
+    }
+
+    fn parse_scalar(&mut self) -> Result<JsonValue, MinarrowError> {
+        let rest = &self.bytes[self.pos..];
+        for (lit, value) in [
+            (&b"null"[..], JsonValue::Null),
+            (&b"true"[..], JsonValue::Bool(true)),
+            (&b"false"[..], JsonValue::Bool(false)),
+        ] {
+            if rest.starts_with(lit) {
+                self.pos += lit.len();
+                return Ok(value);
+            }
+        }
+        match rest.first() {
+            Some(b'"') => Ok(JsonValue::Str(self.parse_string()?)),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            _ => Err(self.error(format!("invalid value at column {}", self.pos + 1))),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, MinarrowError> {
+        let start = self.pos;
+        while matches!(
+            self.bytes.get(self.pos),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        // The scanned range is ASCII, so it is valid UTF-8.
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        let integral = !text.contains(['.', 'e', 'E']);
+        match text.parse::<i64>() {
+            Ok(v) if integral => Ok(JsonValue::Int(v)),
+            _ => text
+                .parse::<f64>()
+                .map(JsonValue::Float)
+                .map_err(|_| self.error(format!("invalid number '{}'", text))),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, MinarrowError> {
+        self.expect(b'"')?;
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match self.bytes.get(self.pos) {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let unescaped = match self.bytes.get(self.pos) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("invalid escape in string")),
+                    };
+                    self.pos += 1;
+                    let mut tmp = [0u8; 4];
+                    buf.extend_from_slice(unescaped.encode_utf8(&mut tmp).as_bytes());
+                }
+                Some(&b) => {
+                    buf.push(b);
+                    self.pos += 1;
+                }
+            }
+        }
+        // Input came from a `str` and escapes are pushed as UTF-8, so this cannot fail.
+        Ok(String::from_utf8(buf).unwrap())
+    }
+
+    /// Decodes `uXXXX`, and a following low surrogate escape if needed, leaving
+    /// `pos` on the last hex digit.
+    fn parse_unicode_escape(&mut self) -> Result<char, MinarrowError> {
+        let hi = self.hex4(self.pos + 1)?;
+        self.pos += 4;
+        let code = if (0xD800..0xDC00).contains(&hi) {
+            if self.bytes.get(self.pos + 1..self.pos + 3) != Some(&b"\\u"[..]) {
+                return Err(self.error("unpaired surrogate in string"));
+            }
+            let lo = self.hex4(self.pos + 3)?;
+            if !(0xDC00..0xE000).contains(&lo) {
+                return Err(self.error("unpaired surrogate in string"));
+            }
+            self.pos += 6;
+            0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00)
+        } else {
+            hi
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex4(&self, at: usize) -> Result<u32, MinarrowError> {
+        self.bytes
+            .get(at..at + 4)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u32::from_str_radix(h, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))
+    }
+}
+
+/// Writes `table` as NDJSON to `writer`, one line at a time.
+pub(crate) fn write_table(
+    table: &Table,
+    mut writer: impl std::io::Write,
+    opts: &NdjsonWriteOptions,
+) -> Result<(), MinarrowError> {
+    let mut line = Vec64::new();
+    for row in 0..table.n_rows {
+        line.clear();
+        write_row(&mut line, &table.cols, row, opts.omit_nulls);
+        writer.write_all(&line)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// `fmt::Write` adapter that appends formatted output straight into a byte buffer.
+struct ByteSink<'a>(&'a mut Vec64<u8>);
+
+impl Write for ByteSink<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// Appends row `row` of `cols` as a single JSON object followed by `\n`.
+/// With `omit_nulls`, null entries are left out of the object.
+pub(crate) fn write_row(out: &mut Vec64<u8>, cols: &[FieldArray], row: usize, omit_nulls: bool) {
+    out.push(b'{');
+    let mut first = true;
+    for col in cols {
+        if omit_nulls && col.array.null_mask().is_some_and(|m| !m.get(row)) {
+            continue;
+        }
+        if !first {
+            out.push(b',');
+        }
+        first = false;
+        write_json_str(out, &col.field.name);
+        out.push(b':');
+        write_value(out, &col.array, row);
+    }
+    out.extend_from_slice(b"}\n");
+}
+
+/// Appends the JSON encoding of `array[idx]`.
+pub(crate) fn write_value(out: &mut Vec64<u8>, array: &Array, idx: usize) {
+    if array.null_mask().is_some_and(|m| !m.get(idx)) {
+        out.extend_from_slice(b"null");
+        return;
+    }
+    match array {
+        Array::NumericArray(inner) => match inner {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(a) => write_display(out, a.data[idx]),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(a) => write_display(out, a.data[idx]),
+            NumericArray::Int32(a) => write_display(out, a.data[idx]),
+            NumericArray::Int64(a) => write_display(out, a.data[idx]),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt8(a) => write_display(out, a.data[idx]),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::UInt16(a) => write_display(out, a.data[idx]),
+            NumericArray::UInt32(a) => write_display(out, a.data[idx]),
+            NumericArray::UInt64(a) => write_display(out, a.data[idx]),
+            NumericArray::Float32(a) => write_float(out, a.data[idx]),
+            NumericArray::Float64(a) => write_float(out, a.data[idx]),
+            NumericArray::Null => out.extend_from_slice(b"null"),
+        },
+        Array::TextArray(inner) => {
+            let s = match inner {
+                TextArray::String32(a) => a.get_str(idx),
+                #[cfg(feature = "large_string")]
+                TextArray::String64(a) => a.get_str(idx),
+                #[cfg(feature = "default_categorical_8")]
+                TextArray::Categorical8(a) => a.get_str(idx),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical16(a) => a.get_str(idx),
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                TextArray::Categorical32(a) => a.get_str(idx),
+                #[cfg(feature = "extended_categorical")]
+                TextArray::Categorical64(a) => a.get_str(idx),
+                TextArray::Null => None,
+            };
+            match s {
+                Some(s) => write_json_str(out, s),
+                None => out.extend_from_slice(b"null"),
+            }
+        }
+        #[cfg(feature = "datetime")]
+        Array::TemporalArray(inner) => match inner {
+            TemporalArray::Datetime32(a) => write_datetime(out, a.data[idx] as i64, a.time_unit),
+            TemporalArray::Datetime64(a) => write_datetime(out, a.data[idx], a.time_unit),
+            TemporalArray::Null => out.extend_from_slice(b"null"),
+        },
+        Array::BooleanArray(a) => {
+            let v: &[u8] = if a.data.get(idx) { b"true" } else { b"false" };
+            out.extend_from_slice(v);
+        }
+        Array::FixedSizeBinaryArray(a) => {
+            let bytes = &a.data[idx * a.width..(idx + 1) * a.width];
+            out.push(b'"');
+            for b in bytes {
+                write!(ByteSink(out), "{:02x}", b).unwrap();
+            }
+            out.push(b'"');
+        }
+        Array::Null => out.extend_from_slice(b"null"),
+    }
+}
+
+/// Writes `v` using its `Display` form. Writing into a byte buffer cannot fail.
+#[inline]
+fn write_display(out: &mut Vec64<u8>, v: impl fmt::Display) {
+    write!(ByteSink(out), "{}", v).unwrap();
+}
+
+/// Writes a finite float as a JSON number, and `NaN` or infinities as `null`.
+#[inline]
+fn write_float<T: Float + fmt::Display>(out: &mut Vec64<u8>, v: T) {
+    if v.is_finite() {
+        write_display(out, v);
+    } else {
+        out.extend_from_slice(b"null");
+    }
+}
+
+/// Writes a datetime as an RFC 3339 string, falling back to the raw
+/// integer when it falls outside the representable range.
+#[cfg(feature = "datetime")]
+fn write_datetime(out: &mut Vec64<u8>, v: i64, unit: TimeUnit) {
+    #[cfg(feature = "datetime_ops")]
+    {
+        use time::format_description::well_known::Rfc3339;
+        let formatted = crate::DatetimeArray::<i64>::i64_to_datetime(v, unit)
+            .and_then(|dt| dt.format(&Rfc3339).ok());
+        if let Some(s) = formatted {
+            write_json_str(out, &s);
+            return;
+        }
+    }
+    #[cfg(not(feature = "datetime_ops"))]
+    let _ = unit;
+    write_display(out, v);
+}
+
+/// Writes `s` as a quoted JSON string, escaping quotes, backslashes and control characters.
+pub(crate) fn write_json_str(out: &mut Vec64<u8>, s: &str) {
+    out.push(b'"');
+    let bytes = s.as_bytes();
+    let mut start = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        let escape: &[u8] = match b {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0x08 => b"\\b",
+            0x0C => b"\\f",
+            0x00..=0x1F => &[],
+            _ => continue,
+        };
+        out.extend_from_slice(&bytes[start..i]);
+        if escape.is_empty() {
+            write!(ByteSink(out), "\\u{:04x}", b).unwrap();
+        } else {
+            out.extend_from_slice(escape);
+        }
+        start = i + 1;
+    }
+    out.extend_from_slice(&bytes[start..]);
+    out.push(b'"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MaskedArray;
+
+    fn read(input: &str, opts: NdjsonReadOptions) -> Result<Table, MinarrowError> {
+        Table::from_ndjson(input.as_bytes(), opts)
+    }
+
+    #[test]
+    fn test_read_infers_types_and_missing_keys() {
+        let input = "{\"id\":1,\"x\":2.5,\"ok\":true,\"s\":\"a\\\"b\\u00e9\\ud83d\\ude00\"}\n\
+                     \r\n\
+                     {\"id\":2,\"x\":3,\"extra\":\"late\"}\r\n\
+                     {\"id\":null,\"ok\":false,\"s\":7}\n";
+        let t = read(input, NdjsonReadOptions::default()).unwrap();
+        assert_eq!(t.n_rows, 3);
+        assert_eq!(t.col_names(), vec!["id", "x", "ok", "s", "extra"]);
+        let dtypes: Vec<ArrowType> = t.cols.iter().map(|c| c.field.dtype.clone()).collect();
+        assert_eq!(
+            dtypes,
+            vec![
+                ArrowType::Int64,
+                ArrowType::Float64,
+                ArrowType::Boolean,
+                ArrowType::String,
+                ArrowType::String
+            ]
+        );
+
+        match &t.cols[0].array {
+            Array::NumericArray(NumericArray::Int64(a)) => {
+                assert_eq!(a.get(1), Some(2));
+                assert_eq!(a.get(2), None);
+            }
+            other => panic!("expected Int64, got {:?}", other),
+        }
+        match &t.cols[1].array {
+            Array::NumericArray(NumericArray::Float64(a)) => {
+                assert_eq!(a.get(0), Some(2.5));
+                assert_eq!(a.get(1), Some(3.0));
+                assert_eq!(a.get(2), None);
+            }
+            other => panic!("expected Float64, got {:?}", other),
+        }
+        match &t.cols[2].array {
+            Array::BooleanArray(a) => {
+                assert_eq!(a.get(0), Some(true));
+                assert_eq!(a.get(1), None);
+                assert_eq!(a.get(2), Some(false));
+            }
+            other => panic!("expected Boolean, got {:?}", other),
+        }
+        match &t.cols[3].array {
+            Array::TextArray(TextArray::String32(a)) => {
+                assert_eq!(a.get_str(0), Some("a\"b\u{e9}\u{1F600}"));
+                assert_eq!(a.get_str(1), None);
+                assert_eq!(a.get_str(2), Some("7"));
+            }
+            other => panic!("expected String32, got {:?}", other),
+        }
+        assert!(t.cols.iter().all(|c| c.field.nullable));
+    }
+
+    #[test]
+    fn test_read_nested_objects() {
+        let input = "{\"a\":{\"b\":1,\"c\":{\"d\":\"x\"}},\"e\":true}\n";
+        let err = read(input, NdjsonReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 1, .. }));
+
+        let opts = NdjsonReadOptions {
+            flatten_nested: true,
+            ..Default::default()
+        };
+        let t = read(input, opts.clone()).unwrap();
+        assert_eq!(t.col_names(), vec!["a.b", "a.c.d", "e"]);
+        assert!(!t.cols[0].field.nullable);
+
+        let err = read("{\"a\":[1,2]}", opts).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { .. }));
+    }
+
+    #[test]
+    fn test_read_errors_carry_line_numbers() {
+        let err = read("{\"a\":1}\n{\"a\":1,}\n", NdjsonReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 2, .. }));
+
+        let err = read("{\"a\":1}\n\n[1]\n", NdjsonReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 3, .. }));
+
+        let opts = NdjsonReadOptions {
+            infer_rows: 1,
+            ..Default::default()
+        };
+        let err = read("{\"a\":1}\n{\"a\":1.5}\n", opts).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 2, .. }));
+
+        let err = read("{\"a\":\"\\ud83d\"}", NdjsonReadOptions::default()).unwrap_err();
+        assert!(matches!(err, MinarrowError::ParseError { line: 1, .. }));
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_write_omits_nulls_and_round_trips() {
+        let mut score = FloatArray::<f64>::from_slice(&[1.5, 0.0]);
+        score.set_null(1);
+        let mut t = Table::new_empty();
+        t.add_col(crate::fa_i64!("id", 1, 2));
+        t.add_col(FieldArray::from_arr("score", Array::from_float64(score)));
+        t.add_col(crate::fa_cat32!("cat", "x", "y\n"));
+
+        let mut out = Vec::new();
+        t.write_ndjson(&mut out, NdjsonWriteOptions::default())
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(
+            text,
+            "{\"id\":1,\"score\":1.5,\"cat\":\"x\"}\n{\"id\":2,\"cat\":\"y\\n\"}\n"
+        );
+        assert_eq!(text.as_bytes(), t.to_ndjson().as_slice());
+
+        let mut out = Vec::new();
+        let opts = NdjsonWriteOptions { omit_nulls: false };
+        t.write_ndjson(&mut out, opts).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("\"score\":null"));
+
+        let back = read(&text, NdjsonReadOptions::default()).unwrap();
+        assert_eq!(back.col_names(), vec!["id", "score", "cat"]);
+        match &back.cols[1].array {
+            Array::NumericArray(NumericArray::Float64(a)) => {
+                assert_eq!(a.get(0), Some(1.5));
+                assert_eq!(a.get(1), None);
+            }
+            other => panic!("expected Float64, got {:?}", other),
+        }
+        match &back.cols[2].array {
+            Array::TextArray(TextArray::String32(a)) => assert_eq!(a.get_str(1), Some("y\n")),
+            other => panic!("expected String32, got {:?}", other),
+        }
+    }
+}
@@ -101,7 +101,7 @@ use crate::enums::time_units::TimeUnit;
 use crate::ffi::arrow_dtype::{ArrowType, CategoricalIndexType};
 use crate::structs::shared_buffer::SharedBuffer;
 use crate::utils::align64;
-use crate::{Bitmask, Buffer};
+use crate::{Bitmask, Buffer, FixedSizeBinaryArray};
 use vec64::Vec64;
 
 /// Bump allocator for bulk array construction.
@@ -435,6 +435,55 @@ impl Arena {
         }
     }
 
+    /// Write fixed-size binary arrays into a single contiguous byte region.
+    ///
+    /// Reserves `total_rows * width` bytes and copies each array's data
+    /// sequentially. When `has_nulls` is true, a mask region is reserved
+    /// and arrays without a mask contribute all-valid bits.
+    pub fn write_fixed_size_binary_slices(
+        &mut self,
+        slices: &[&FixedSizeBinaryArray],
+        width: usize,
+        total_rows: usize,
+        has_nulls: bool,
+    ) -> AAMaker {
+        let data_region = self.reserve_slice::<u8>(total_rows * width);
+        let mask_region = if has_nulls {
+            Some(self.reserve_slice::<u8>(total_rows.div_ceil(8)))
+        } else {
+            None
+        };
+
+        // Write data
+        {
+            let dest = self.region_as_mut_slice::<u8>(&data_region);
+            let mut pos = 0usize;
+            for src in slices {
+                let bytes = &src.data[..src.len * width];
+                dest[pos..pos + bytes.len()].copy_from_slice(bytes);
+                pos += bytes.len();
+            }
+        }
+
+        // Write mask
+        if let Some(mr) = mask_region.as_ref() {
+            let mut mask = Bitmask::default();
+            for src in slices {
+                match src.null_mask.as_ref() {
+                    Some(src_mask) => mask.extend_from_bitmask(src_mask),
+                    None => mask.resize(mask.len + src.len, true),
+                }
+            }
+            self.region_as_mut_slice::<u8>(mr)
+                .copy_from_slice(mask.bits.as_slice());
+        }
+
+        AAMaker::Primitive {
+            data: data_region,
+            mask: mask_region,
+        }
+    }
+
     /// Calculate total arena bytes needed for a set of regions.
     ///
     /// Each entry is `(len, element_size_in_bytes)`. Each region is
@@ -793,6 +842,17 @@ impl AAMaker {
                 )))
             }
 
+            // --- Fixed-size binary ---
+            (ArrowType::FixedSizeBinary(width), AAMaker::Primitive { data, mask }) => {
+                let m = mask.map(|r| r.to_bitmask(shared, n_rows));
+                Array::FixedSizeBinaryArray(Arc::new(FixedSizeBinaryArray {
+                    data: data.to_buffer::<u8>(shared),
+                    null_mask: m,
+                    width: *width as usize,
+                    len: n_rows,
+                }))
+            }
+
             (ArrowType::Null, _) => Array::Null,
             _ => unreachable!("Mismatched ArrowType and AAMaker variant"),
         }
@@ -892,6 +952,9 @@ pub(crate) fn consolidate_array_arena(chunks: &[&Array], dtype: &ArrowType) -> A
         Array::BooleanArray(_) => {
             total_bytes += align64(mask_bytes);
         }
+        Array::FixedSizeBinaryArray(a) => {
+            total_bytes += align64(n_rows * a.width);
+        }
         #[cfg(feature = "datetime")]
         Array::TemporalArray(temp) => {
             let elem = match temp {
@@ -1120,6 +1183,20 @@ pub(crate) fn consolidate_array_arena(chunks: &[&Array], dtype: &ArrowType) -> A
             arena.write_boolean_slices(&slices, n_rows, has_nulls)
         }
 
+        Array::FixedSizeBinaryArray(a) => {
+            let slices: Vec<_> = chunks
+                .iter()
+                .map(|c| {
+                    if let Array::FixedSizeBinaryArray(b) = c {
+                        b.as_ref()
+                    } else {
+                        unreachable!()
+                    }
+                })
+                .collect();
+            arena.write_fixed_size_binary_slices(&slices, a.width, n_rows, has_nulls)
+        }
+
         #[cfg(feature = "datetime")]
         Array::TemporalArray(temp) => {
             macro_rules! write_temporal {
@@ -1280,6 +1357,9 @@ pub(crate) fn consolidate_tables_arena(
             Array::BooleanArray(_) => {
                 total_bytes += align64(mask_bytes);
             }
+            Array::FixedSizeBinaryArray(a) => {
+                total_bytes += align64(n_rows * a.width);
+            }
             #[cfg(feature = "datetime")]
             Array::TemporalArray(temp) => {
                 let elem = match temp {
@@ -1536,6 +1616,20 @@ pub(crate) fn consolidate_tables_arena(
                 arena.write_boolean_slices(&slices, n_rows, has_nulls)
             }
 
+            Array::FixedSizeBinaryArray(first_fsb) => {
+                let slices: Vec<_> = tables
+                    .iter()
+                    .map(|t| {
+                        if let Array::FixedSizeBinaryArray(a) = &t.cols[col_idx].array {
+                            a.as_ref()
+                        } else {
+                            unreachable!()
+                        }
+                    })
+                    .collect();
+                arena.write_fixed_size_binary_slices(&slices, first_fsb.width, n_rows, has_nulls)
+            }
+
             #[cfg(feature = "datetime")]
             Array::TemporalArray(temp) => {
                 macro_rules! write_temporal {
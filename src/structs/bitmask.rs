@@ -31,6 +31,7 @@
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::ops::{BitAnd, BitOr, Deref, DerefMut, Index, Not};
 
+use crate::enums::error::MinarrowError;
 use crate::enums::shape_dim::ShapeDim;
 use crate::traits::concatenate::Concatenate;
 use crate::traits::shape::Shape;
@@ -148,15 +149,44 @@ impl Bitmask {
         self.as_ref()
     }
 
-    /// Creates a bitmask from an existing byte buffer
-    pub fn from_bytes(bytes: impl AsRef<[u8]>, len: usize) -> Self {
-        let mut mask = Bitmask::with_capacity(len);
+    /// Creates a bitmask from packed LSB-first bytes holding `len` logical bits.
+    ///
+    /// Only the first `ceil(len / 8)` bytes are copied, and any bits beyond
+    /// `len` in the final byte are zeroed so popcount-based null counts stay
+    /// accurate.
+    ///
+    /// # Errors
+    /// Returns `ShapeError` if `bytes` is shorter than `ceil(len / 8)`.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>, len: usize) -> Result<Self, MinarrowError> {
         let bytes = bytes.as_ref();
-        for i in 0..len {
-            let valid = (bytes[i >> 3] >> (i & 7)) & 1 != 0;
-            mask.set(i, valid);
+        let n_bytes = len.div_ceil(8);
+        if bytes.len() < n_bytes {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "Bitmask::from_bytes: {} bytes cannot hold {} bits (need {})",
+                    bytes.len(),
+                    len,
+                    n_bytes
+                ),
+            });
         }
-        mask
+        let mut buf = Vec64::with_capacity(n_bytes);
+        buf.extend_from_slice(&bytes[..n_bytes]);
+        let mut mask = Bitmask {
+            bits: buf.into(),
+            len,
+        };
+        mask.mask_trailing_bits();
+        Ok(mask)
+    }
+
+    /// Returns the packed bytes covering the logical bits, and the bit length.
+    ///
+    /// Round-trips with [`Bitmask::from_bytes`].
+    #[inline]
+    pub fn to_bytes(&self) -> (&[u8], usize) {
+        let n_bytes = self.len.div_ceil(8);
+        (&self.bits[..n_bytes], self.len)
     }
 
     /// Returns the logical length of the bitmask
@@ -289,6 +319,37 @@ impl Bitmask {
         }
     }
 
+    /// Set or clear the `len` bits starting at `start`, growing the mask if the
+    /// range ends past `len()`.
+    ///
+    /// Only the partial bytes at either end are masked bit-wise. The whole
+    /// bytes between them are filled in one bulk write, so large ranges cost
+    /// a `memset` rather than a per-bit loop.
+    pub fn set_range(&mut self, start: usize, len: usize, value: bool) {
+        if len == 0 {
+            return;
+        }
+        let end = start + len;
+        self.ensure_capacity(end);
+        let (first, last) = (start >> 3, (end - 1) >> 3);
+        let head = 0xFFu8 << (start & 7);
+        let tail = 0xFFu8 >> (7 - ((end - 1) & 7));
+        let apply = |byte: &mut u8, mask: u8| {
+            if value {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        };
+        if first == last {
+            apply(&mut self.bits[first], head & tail);
+            return;
+        }
+        apply(&mut self.bits[first], head);
+        self.bits[first + 1..last].fill(if value { 0xFF } else { 0 });
+        apply(&mut self.bits[last], tail);
+    }
+
     /// Set a chunk of bits from a u64 value at offset `start`, `n_bits` bits.
     #[inline]
     pub fn set_bits_chunk(&mut self, start: usize, value: u64, n_bits: usize) {
@@ -364,6 +425,22 @@ impl Bitmask {
         mask
     }
 
+    /// Construct a mask of `total_len` bits where only the `(start, len)` spans
+    /// in `valid_ranges` are set. Spans may overlap.
+    ///
+    /// Panics if a span runs past `total_len`.
+    pub fn from_ranges(total_len: usize, valid_ranges: &[(usize, usize)]) -> Self {
+        let mut mask = Self::new_set_all(total_len, false);
+        for &(start, len) in valid_ranges {
+            assert!(
+                start.checked_add(len).is_some_and(|end| end <= total_len),
+                "Bitmask::from_ranges: span ({start}, {len}) exceeds length {total_len}"
+            );
+            mask.set_range(start, len, true);
+        }
+        mask
+    }
+
     /// Returns true if there are any cleared bits (any nulls).
     #[inline]
     pub fn has_nulls(&self) -> bool {
@@ -389,13 +466,18 @@ impl Bitmask {
     }
 
     /// Count number of set (1) bits.
+    ///
+    /// Whole bytes are counted 64 bits at a time, with the partial final
+    /// byte masked so bits past `len` are ignored.
     #[inline]
     pub fn count_ones(&self) -> usize {
         let full_bytes = self.len / 8;
-        let mut count = self.bits[..full_bytes]
-            .iter()
-            .map(|&b| b.count_ones() as usize)
+        let words = self.bits[..full_bytes].chunks_exact(8);
+        let tail = words.remainder();
+        let mut count = words
+            .map(|w| u64::from_le_bytes(w.try_into().unwrap()).count_ones() as usize)
             .sum::<usize>();
+        count += tail.iter().map(|&b| b.count_ones() as usize).sum::<usize>();
         let rem = self.len & 7;
         if rem != 0 {
             let mask = (1u8 << rem) - 1;
@@ -418,9 +500,21 @@ impl Bitmask {
 
     /// Resizes mask to new_len. New bits set or cleared per `set`.
     pub fn resize(&mut self, new_len: usize, set: bool) {
+        let old_len = self.len;
+        let old_bytes = self.bits.len();
         let new_bytes = (new_len + 7) / 8;
         let fill = if set { 0xFF } else { 0 };
         self.bits.resize(new_bytes, fill);
+        if set && new_len > old_len {
+            // Bytes already present hold cleared bits past the old length.
+            let end = new_bytes.min(old_bytes);
+            let first = old_len / 8;
+            if first < end {
+                let bytes = self.bits.as_mut_slice();
+                bytes[first] |= 0xFF << (old_len % 8);
+                bytes[first + 1..end].fill(0xFF);
+            }
+        }
         self.len = new_len;
         self.mask_trailing_bits();
     }
@@ -506,15 +600,11 @@ impl Bitmask {
     }
 
     /// Appends all bits from another Bitmask.
+    ///
+    /// Copies whole bytes, shifting them into place when `self.len()` is not
+    /// a multiple of 8.
     pub fn extend_from_bitmask(&mut self, other: &Bitmask) {
-        let old_len = self.len();
-        self.resize(old_len + other.len(), true);
-        for i in 0..other.len() {
-            // Safety: falls within established lengths.
-            // Provided another thread isn't mutating this at the same
-            // time it's ok.
-            unsafe { self.set_unchecked(old_len + i, other.get_unchecked(i)) };
-        }
+        self.extend_from_slice(other.bits.as_slice(), other.len());
     }
 
     /// Appends bits `[offset..offset+len)` from another bitmask into self.
@@ -577,15 +667,22 @@ impl Bitmask {
             return;
         }
 
-        // General case: bit-level append
-        for i in 0..len {
-            let bit = (src[i >> 3] >> (i & 7)) & 1;
-            if bit != 0 {
-                let j = start + i;
-                dst[j >> 3] |= 1 << (j & 7);
-            } else {
-                let j = start + i;
-                dst[j >> 3] &= !(1 << (j & 7));
+        // Unaligned destination: each source byte straddles two destination
+        // bytes, landing `shift` bits up in the first and the rest in the next.
+        let shift = (start & 7) as u32;
+        let dst_byte = start >> 3;
+        let n_src_bytes = (len + 7) >> 3;
+        let tail = len & 7;
+        dst[dst_byte] &= (1u8 << shift) - 1;
+        for i in 0..n_src_bytes {
+            let mut b = src[i];
+            if tail != 0 && i == n_src_bytes - 1 {
+                b &= (1u8 << tail) - 1;
+            }
+            dst[dst_byte + i] |= b << shift;
+            // Bytes past `dst_byte` were zeroed by `resize`, so OR is enough.
+            if let Some(next) = dst.get_mut(dst_byte + i + 1) {
+                *next |= b >> (8 - shift);
             }
         }
         self.mask_trailing_bits();
@@ -939,6 +1036,15 @@ mod tests {
         assert!(m.len == 100);
     }
 
+    #[test]
+    fn test_resize_sets_bits_in_partial_byte() {
+        let mut m = Bitmask::new_set_all(3, false);
+        m.resize(12, true);
+        assert_eq!(m.count_ones(), 9);
+        assert!((0..3).all(|i| !m.get(i)));
+        assert!((3..12).all(|i| m.get(i)));
+    }
+
     #[test]
     fn test_count_and_all() {
         let mut m = Bitmask::new_set_all(16, true);
@@ -950,6 +1056,37 @@ mod tests {
         assert!(!m.all_unset());
     }
 
+    #[test]
+    fn test_bytes_round_trip() {
+        let m = Bitmask::from_bools(&[true, false, true, true, false, false, true, false, true]);
+        let (bytes, len) = m.to_bytes();
+        assert_eq!(len, 9);
+        assert_eq!(bytes.len(), 2);
+        let back = Bitmask::from_bytes(bytes, len).unwrap();
+        assert_eq!(back.len(), 9);
+        for i in 0..9 {
+            assert_eq!(back.get(i), m.get(i));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_zeroes_trailing_bits() {
+        // Extra trailing bytes are ignored and bits past `len` are cleared.
+        let m = Bitmask::from_bytes([0xFFu8, 0xFF, 0xFF], 10).unwrap();
+        assert_eq!(m.as_bytes(), &[0xFF, 0b0000_0011]);
+        assert_eq!(m.count_ones(), 10);
+        assert_eq!(m.null_count(), 0);
+    }
+
+    #[test]
+    fn test_from_bytes_too_short() {
+        assert!(matches!(
+            Bitmask::from_bytes([0xFFu8], 9),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+        assert!(Bitmask::from_bytes([0u8; 0], 0).is_ok());
+    }
+
     #[test]
     fn test_invert_union_and_intersect() {
         let mut a = Bitmask::new_set_all(8, false);
@@ -981,6 +1118,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_range_matches_per_bit() {
+        for start in 0..20 {
+            for len in 0..40 {
+                for &value in &[true, false] {
+                    let mut fast = Bitmask::new_set_all(64, !value);
+                    let mut slow = fast.clone();
+                    fast.set_range(start, len, value);
+                    for i in start..start + len {
+                        slow.set(i, value);
+                    }
+                    assert_eq!(fast, slow, "start={start} len={len} value={value}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_range_grows_and_from_ranges() {
+        let mut m = Bitmask::new_set_all(4, false);
+        m.set_range(2, 9, true);
+        assert_eq!(m.len(), 11);
+        assert_eq!(m.count_ones(), 9);
+        assert!(!m.get(1) && m.get(2) && m.get(10));
+
+        let m = Bitmask::from_ranges(200, &[(0, 3), (100, 70), (160, 20)]);
+        assert_eq!(m.len(), 200);
+        assert_eq!(m.count_ones(), 3 + 80);
+        assert!(m.get(2) && !m.get(3) && m.get(179) && !m.get(180));
+        assert_eq!(Bitmask::from_ranges(5, &[]).count_ones(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds length")]
+    fn test_from_ranges_out_of_bounds() {
+        Bitmask::from_ranges(8, &[(4, 5)]);
+    }
+
     #[test]
     fn test_slice_clone_and_view() {
         let mut m = Bitmask::new_set_all(10, false);
@@ -1049,6 +1224,21 @@ mod tests {
         assert_eq!(m3.len(), 3);
     }
 
+    #[test]
+    fn test_extend_from_slice_every_shift() {
+        // Exercise each destination bit offset against a per-bit reference.
+        let src_bools: Vec<bool> = (0..21).map(|i| i % 3 != 1).collect();
+        let src = Bitmask::from_bools(&src_bools);
+        for start in 0..16 {
+            let head: Vec<bool> = (0..start).map(|i| i % 2 == 0).collect();
+            let mut mask = Bitmask::from_bools(&head);
+            mask.extend_from_bitmask(&src);
+            assert_eq!(mask.len(), start + src_bools.len());
+            let expected: Vec<bool> = head.iter().chain(&src_bools).copied().collect();
+            assert_eq!(mask, Bitmask::from_bools(&expected), "start = {start}");
+        }
+    }
+
     #[test]
     fn test_union_opt_none_none() {
         assert!(Bitmask::union_opt(None, None).is_none());
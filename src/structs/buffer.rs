@@ -449,9 +449,15 @@ impl<T> Buffer<T> {
         self.make_owned_mut().clear();
     }
 
+    /// Reserves capacity for at least `addl` more elements.
+    ///
+    /// Growth goes through `Vec64`'s 64-byte aligned allocator, so the buffer stays
+    /// valid for SIMD kernels after any reallocation. A shared buffer is first copied
+    /// into owned aligned storage, whatever the alignment of its source.
     #[inline]
     pub fn reserve(&mut self, addl: usize) {
         self.make_owned_mut().reserve(addl);
+        self.debug_assert_aligned();
     }
 
     /// Returns the capacity in elements.
@@ -469,6 +475,19 @@ impl<T> Buffer<T> {
             }
         }
     }
+    /// Checks in debug builds that owned storage is still 64-byte aligned.
+    #[inline]
+    fn debug_assert_aligned(&self) {
+        if let Storage::Owned(vec) = &self.storage {
+            debug_assert!(
+                vec.capacity() == 0
+                    || mem::size_of::<T>() == 0
+                    || (vec.as_ptr() as usize).is_multiple_of(64),
+                "Buffer: owned storage lost its 64-byte alignment"
+            );
+        }
+    }
+
     /// Ensure owned and return &mut Vec64<T>.
     #[inline]
     fn make_owned_mut(&mut self) -> &mut Vec64<T> {
@@ -578,9 +597,14 @@ impl<T> Buffer<T> {
 }
 
 impl<T: Clone> Buffer<T> {
+    /// Resizes to `new_len` elements, filling any new slots with `value`.
+    ///
+    /// As with `reserve`, growth reallocates through the 64-byte aligned allocator,
+    /// and a shared buffer is copied into owned aligned storage first.
     #[inline]
     pub fn resize(&mut self, new_len: usize, value: T) {
         self.make_owned_mut().resize(new_len, value);
+        self.debug_assert_aligned();
     }
 
     #[inline]
@@ -804,6 +828,20 @@ impl<T: Send + Sync> Buffer<T> {
         use rayon::iter::IntoParallelRefMutIterator;
         self.make_owned_mut().par_iter_mut()
     }
+
+    /// Consuming parallel iterator yielding owned values.
+    ///
+    /// The buffer is moved behind an `Arc` rather than copied, and rayon
+    /// splits the index range into contiguous chunks over the aligned storage.
+    #[inline]
+    pub fn into_par_iter(self) -> impl rayon::iter::IndexedParallelIterator<Item = T>
+    where
+        T: Copy,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        let data = std::sync::Arc::new(self);
+        (0..data.len()).into_par_iter().map(move |i| data[i])
+    }
 }
 
 impl<T: Display> Display for Buffer<T> {
@@ -942,6 +980,30 @@ mod tests {
         assert!(buf.capacity() >= initial_cap + 100);
     }
 
+    #[test]
+    fn test_growth_stays_aligned() {
+        let mut buf = Buffer::from(vec64![1u8, 2, 3]);
+        for n in [1, 63, 1000, 100_000] {
+            buf.reserve(n);
+            assert_eq!(buf.as_ptr() as usize % 64, 0);
+        }
+        buf.resize(250_000, 7);
+        assert_eq!(buf.as_ptr() as usize % 64, 0);
+        assert_eq!(&buf[..4], &[1, 2, 3, 7]);
+    }
+
+    #[test]
+    fn test_growth_from_unaligned_shared_realigns() {
+        let owner = SharedBuffer::from_vec64(vec64![0u8, 1, 2, 3, 4, 5, 6, 7, 8]);
+        // Start one byte in, so the view is not 64-byte aligned
+        let mut buf: Buffer<u8> = Buffer::from_shared_column(owner, 1, 8);
+        assert!(buf.is_shared());
+        buf.resize(9, 9);
+        assert!(!buf.is_shared());
+        assert_eq!(buf.as_ptr() as usize % 64, 0);
+        assert_eq!(&buf[..], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
     #[test]
     fn test_capacity_shared() {
         let data = vec64![1u8, 2, 3, 4];
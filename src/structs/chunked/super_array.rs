@@ -28,6 +28,7 @@
 //! - Use `from_arrays()` when you don't need field metadata (e.g., Dam consolidation)
 //! - Use `from_arrays_with_field()` when field metadata is required
 
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::iter::FromIterator;
 use std::sync::Arc;
@@ -41,13 +42,16 @@ use crate::ffi::arrow_dtype::ArrowType;
 #[cfg(feature = "size")]
 use crate::traits::byte_size::ByteSize;
 use crate::traits::consolidate::Consolidate;
+use crate::traits::type_unions::Integer;
 use crate::traits::{concatenate::Concatenate, shape::Shape};
-use crate::{Array, Field, FieldArray};
+use crate::{Array, Bitmask, CategoricalArray, Field, FieldArray, TextArray, Vec64};
 
 /// Strategy for rechunking arrays and tables.
 ///
-/// Defines how to redistribute data across chunks/batches.
+/// Defines how to redistribute data across chunks/batches. New strategies may
+/// be added, so matches outside this crate need a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RechunkStrategy {
     /// Rechunk into uniform chunks of the specified element/row count.
     Count(usize),
@@ -56,6 +60,134 @@ pub enum RechunkStrategy {
     Memory(usize),
     /// Rechunk using a default size of 8192 elements/rows.
     Auto,
+    /// Rechunk so each chunk holds about the specified number of bytes, sizing
+    /// every chunk from its own rows.
+    ///
+    /// Unlike `Memory`, which derives one row count from the average row size,
+    /// this follows variable-width data, so string chunks stay evenly sized
+    /// however much their row widths vary. A row counts its value, its offset for
+    /// variable-width types and its validity bit when there is a null mask.
+    /// Chunks stop short of the target rather than exceed it, unless a single
+    /// row is larger on its own.
+    Bytes(usize),
+}
+
+/// Output chunk lengths for `total_len` rows split into chunks of `chunk_size`,
+/// with a shorter final chunk when it does not divide evenly.
+pub(crate) fn uniform_chunk_lengths(total_len: usize, chunk_size: usize) -> Vec<usize> {
+    let mut lengths = vec![chunk_size; total_len / chunk_size];
+    let rem = total_len % chunk_size;
+    if rem != 0 {
+        lengths.push(rem);
+    }
+    lengths
+}
+
+/// Groups consecutive rows into chunks of at most `target_bytes`, from each
+/// row's size in bits, for `RechunkStrategy::Bytes`.
+pub(crate) struct ByteChunker {
+    target_bits: usize,
+    chunk_bits: usize,
+    chunk_len: usize,
+    lengths: Vec<usize>,
+}
+
+impl ByteChunker {
+    /// # Errors
+    /// - `IndexError` if `target_bytes` is 0.
+    pub(crate) fn new(target_bytes: usize) -> Result<Self, MinarrowError> {
+        if target_bytes == 0 {
+            return Err(MinarrowError::IndexError(
+                "Bytes chunk size must be greater than 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            target_bits: target_bytes.saturating_mul(8),
+            chunk_bits: 0,
+            chunk_len: 0,
+            lengths: Vec::new(),
+        })
+    }
+
+    /// Adds the next row, closing the current chunk first if the row would
+    /// take it past the target.
+    pub(crate) fn push(&mut self, bits: usize) {
+        if self.chunk_len > 0 && self.chunk_bits + bits > self.target_bits {
+            self.lengths.push(self.chunk_len);
+            self.chunk_bits = 0;
+            self.chunk_len = 0;
+        }
+        self.chunk_bits += bits;
+        self.chunk_len += 1;
+    }
+
+    /// Output chunk lengths, including the final partial chunk.
+    pub(crate) fn finish(mut self) -> Vec<usize> {
+        if self.chunk_len > 0 {
+            self.lengths.push(self.chunk_len);
+        }
+        self.lengths
+    }
+}
+
+/// Adds the size in bits of each row of `array` to `bits`, which must hold one
+/// entry per row. See `RechunkStrategy::Bytes` for what a row counts.
+pub(crate) fn add_row_bits(array: &Array, bits: &mut [usize]) {
+    use crate::NumericArray;
+    debug_assert_eq!(array.len(), bits.len());
+    let fixed = |bits: &mut [usize], width: usize| bits.iter_mut().for_each(|b| *b += width);
+    match array {
+        Array::NumericArray(num) => match num {
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int8(_) | NumericArray::UInt8(_) => fixed(bits, 8),
+            #[cfg(feature = "extended_numeric_types")]
+            NumericArray::Int16(_) | NumericArray::UInt16(_) => fixed(bits, 16),
+            NumericArray::Int32(_) | NumericArray::UInt32(_) | NumericArray::Float32(_) => {
+                fixed(bits, 32)
+            }
+            NumericArray::Int64(_) | NumericArray::UInt64(_) | NumericArray::Float64(_) => {
+                fixed(bits, 64)
+            }
+            NumericArray::Null => {}
+        },
+        Array::BooleanArray(_) => fixed(bits, 1),
+        Array::FixedSizeBinaryArray(b) => fixed(bits, b.width * 8),
+        Array::TextArray(text) => match text {
+            TextArray::String32(s) => {
+                for (i, b) in bits.iter_mut().enumerate() {
+                    *b += (s.offsets[i + 1] - s.offsets[i]) as usize * 8 + 32;
+                }
+            }
+            #[cfg(feature = "large_string")]
+            TextArray::String64(s) => {
+                for (i, b) in bits.iter_mut().enumerate() {
+                    *b += (s.offsets[i + 1] - s.offsets[i]) as usize * 8 + 64;
+                }
+            }
+            #[cfg(feature = "default_categorical_8")]
+            TextArray::Categorical8(_) => fixed(bits, 8),
+            #[cfg(feature = "extended_categorical")]
+            TextArray::Categorical16(_) => fixed(bits, 16),
+            #[cfg(any(
+                not(feature = "default_categorical_8"),
+                feature = "extended_categorical"
+            ))]
+            TextArray::Categorical32(_) => fixed(bits, 32),
+            #[cfg(feature = "extended_categorical")]
+            TextArray::Categorical64(_) => fixed(bits, 64),
+            TextArray::Null => {}
+        },
+        #[cfg(feature = "datetime")]
+        Array::TemporalArray(temporal) => match temporal {
+            crate::TemporalArray::Datetime32(_) => fixed(bits, 32),
+            crate::TemporalArray::Datetime64(_) => fixed(bits, 64),
+            crate::TemporalArray::Null => {}
+        },
+        Array::Null => {}
+    }
+    if array.null_mask().is_some() {
+        fixed(bits, 1);
+    }
 }
 
 /// # SuperArray
@@ -652,12 +784,13 @@ impl SuperArray {
     /// - `Count(n)`: Creates chunks of `n` elements. The last chunk may be smaller.
     /// - `Auto`: Uses a default size of 8192 elements
     /// - `Memory(bytes)`: Targets a specific memory size per chunk
+    /// - `Bytes(bytes)`: Targets a byte size for each chunk from its own rows
     ///
     /// # Arguments
     /// * `strategy` - The rechunking strategy to use
     ///
     /// # Errors
-    /// - Returns `IndexError` if `Count(0)` is specified
+    /// - Returns `IndexError` if `Count(0)` or `Bytes(0)` is specified
     /// - Returns `IndexError` if memory-based calculation results in 0 chunk size
     ///
     /// # Example
@@ -670,23 +803,26 @@ impl SuperArray {
     ///
     /// // Target 64KB per chunk
     /// array.rechunk(RechunkStrategy::Memory(65536))?;
+    ///
+    /// // Target 1MiB per chunk, however wide each row is
+    /// array.rechunk(RechunkStrategy::Bytes(1 << 20))?;
     /// ```
     pub fn rechunk(&mut self, strategy: RechunkStrategy) -> Result<(), MinarrowError> {
         if self.chunks.is_empty() || self.len() == 0 {
             return Ok(());
         }
 
-        // Determine chunk size based on strategy
-        let chunk_size = match strategy {
+        // Determine output chunk lengths based on strategy
+        let lengths = match strategy {
             RechunkStrategy::Count(size) => {
                 if size == 0 {
                     return Err(MinarrowError::IndexError(
                         "Count chunk size must be greater than 0".to_string(),
                     ));
                 }
-                size
+                uniform_chunk_lengths(self.len(), size)
             }
-            RechunkStrategy::Auto => 8192,
+            RechunkStrategy::Auto => uniform_chunk_lengths(self.len(), 8192),
             #[cfg(feature = "size")]
             RechunkStrategy::Memory(bytes_per_chunk) => {
                 let total_bytes = self.est_bytes();
@@ -698,12 +834,28 @@ impl SuperArray {
                     ));
                 }
 
-                ((bytes_per_chunk * total_len) / total_bytes).max(1)
+                let chunk_size = ((bytes_per_chunk * total_len) / total_bytes).max(1);
+                uniform_chunk_lengths(total_len, chunk_size)
+            }
+            RechunkStrategy::Bytes(target_bytes) => {
+                let mut chunker = ByteChunker::new(target_bytes)?;
+                for chunk in &self.chunks {
+                    let mut bits = vec![0; chunk.len()];
+                    add_row_bits(chunk, &mut bits);
+                    bits.into_iter().for_each(|b| chunker.push(b));
+                }
+                chunker.finish()
             }
         };
+        self.rechunk_lengths(&lengths)
+    }
 
+    /// Redistributes the data into chunks of the given lengths, which must sum
+    /// to the array length.
+    fn rechunk_lengths(&mut self, lengths: &[usize]) -> Result<(), MinarrowError> {
+        debug_assert_eq!(lengths.iter().sum::<usize>(), self.len());
         // Fast path: single chunk already at target size
-        if self.chunks.len() == 1 && self.chunks[0].len() == chunk_size {
+        if self.chunks.len() == 1 && lengths.len() == 1 {
             return Ok(());
         }
 
@@ -725,6 +877,7 @@ impl SuperArray {
             let mut remaining = chunk;
 
             while remaining.len() > 0 {
+                let chunk_size = lengths[new_chunks.len()];
                 if let Some(ref mut acc) = accumulator {
                     let acc_len = acc.len();
                     let needed = chunk_size - acc_len;
@@ -887,6 +1040,246 @@ impl SuperArray {
     pub fn chunks(&self) -> &[Array] {
         &self.chunks
     }
+
+    /// Applies `f` to every chunk in order, returning the results as a new `SuperArray`.
+    ///
+    /// Each chunk is passed as a `FieldArray` carrying this array's field, or a
+    /// field named `data` when there is none. Outputs may change length or type,
+    /// but must all share one field. That field becomes the result's, unless this
+    /// array had no field, in which case the result has none either.
+    ///
+    /// # Errors
+    /// - Any error returned by `f`, from the first failing chunk.
+    /// - `IncompatibleTypeError` if the output chunks disagree on field.
+    pub fn map_chunks<F>(&self, f: F) -> Result<SuperArray, MinarrowError>
+    where
+        F: Fn(&FieldArray) -> Result<FieldArray, MinarrowError>,
+    {
+        if self.chunks.is_empty() {
+            return Ok(self.clone());
+        }
+        let field = self.chunk_field();
+        let out = (0..self.chunks.len())
+            .map(|i| f(&self.chunk_field_array(&field, i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.assemble_mapped_chunks(out)
+    }
+
+    /// Parallel version of [`SuperArray::map_chunks`], running `f` across chunks
+    /// with Rayon. Output chunk order matches the input order.
+    #[cfg(feature = "parallel_proc")]
+    pub fn par_map_chunks<F>(&self, f: F) -> Result<SuperArray, MinarrowError>
+    where
+        F: Fn(&FieldArray) -> Result<FieldArray, MinarrowError> + Sync + Send,
+    {
+        use rayon::prelude::*;
+        if self.chunks.is_empty() {
+            return Ok(self.clone());
+        }
+        let field = self.chunk_field();
+        let out = (0..self.chunks.len())
+            .into_par_iter()
+            .map(|i| f(&self.chunk_field_array(&field, i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.assemble_mapped_chunks(out)
+    }
+
+    /// The field handed to `map_chunks`, synthesised from the first chunk when absent.
+    fn chunk_field(&self) -> Arc<Field> {
+        self.field.clone().unwrap_or_else(|| {
+            Arc::new(Field::new(
+                "data",
+                self.chunks[0].arrow_type(),
+                self.chunks[0].is_nullable(),
+                None,
+            ))
+        })
+    }
+
+    /// Chunk `idx` paired with `field`, reusing the stored null count when present.
+    fn chunk_field_array(&self, field: &Arc<Field>, idx: usize) -> FieldArray {
+        let array = self.chunks[idx].clone();
+        let null_count = match &self.null_counts {
+            Some(counts) => counts[idx],
+            None => array.null_count(),
+        };
+        FieldArray {
+            field: field.clone(),
+            array,
+            null_count,
+        }
+    }
+
+    /// Reassembles the outputs of `map_chunks`, checking they share one field.
+    fn assemble_mapped_chunks(&self, out: Vec<FieldArray>) -> Result<SuperArray, MinarrowError> {
+        let field = out[0].field.clone();
+        for (idx, fa) in out.iter().enumerate().skip(1) {
+            if fa.field != field {
+                return Err(MinarrowError::IncompatibleTypeError {
+                    from: "FieldArray",
+                    to: "SuperArray",
+                    message: Some(format!(
+                        "map_chunks: chunk {} field does not match chunk 0",
+                        idx
+                    )),
+                });
+            }
+        }
+        let null_counts = out.iter().map(|fa| fa.null_count).collect();
+        Ok(SuperArray {
+            chunks: out.into_iter().map(|fa| fa.array).collect(),
+            field: self.field.as_ref().map(|_| field),
+            null_counts: Some(null_counts),
+        })
+    }
+
+    /// Rebuilds a categorical `SuperArray` so every chunk shares one dictionary.
+    ///
+    /// The global dictionary lists values in order of first appearance across
+    /// the chunks, and each chunk's indices are remapped onto it. If the combined
+    /// cardinality no longer fits the current index width, the output uses the
+    /// next wider categorical index type available in this build.
+    ///
+    /// # Errors
+    /// - `TypeError` if any chunk is not categorical
+    /// - `Overflow` if no available index type can hold the combined dictionary
+    pub fn unify_dictionaries(&self) -> Result<SuperArray, MinarrowError> {
+        let mut parts: Vec<(Vec<usize>, &[String], Option<Bitmask>)> =
+            Vec::with_capacity(self.chunks.len());
+        let mut width = 0;
+        for chunk in &self.chunks {
+            macro_rules! part {
+                ($a:expr, $bits:expr) => {{
+                    width = width.max($bits);
+                    let codes = $a.data.iter().map(|c| c.to_usize()).collect();
+                    (codes, $a.unique_values.as_slice(), $a.null_mask.clone())
+                }};
+            }
+            parts.push(match chunk {
+                #[cfg(feature = "default_categorical_8")]
+                Array::TextArray(TextArray::Categorical8(a)) => part!(a, 8),
+                #[cfg(feature = "extended_categorical")]
+                Array::TextArray(TextArray::Categorical16(a)) => part!(a, 16),
+                #[cfg(any(
+                    not(feature = "default_categorical_8"),
+                    feature = "extended_categorical"
+                ))]
+                Array::TextArray(TextArray::Categorical32(a)) => part!(a, 32),
+                #[cfg(feature = "extended_categorical")]
+                Array::TextArray(TextArray::Categorical64(a)) => part!(a, 64),
+                other => {
+                    return Err(MinarrowError::TypeError {
+                        from: "SuperArray",
+                        to: "Categorical",
+                        message: Some(format!(
+                            "unify_dictionaries requires categorical chunks, found {}",
+                            other.arrow_type()
+                        )),
+                    });
+                }
+            });
+        }
+
+        let mut dict: Vec64<String> = Vec64::new();
+        let mut lookup: HashMap<&str, usize> = HashMap::new();
+        let remaps: Vec<Vec<usize>> = parts
+            .iter()
+            .map(|(_, values, _)| {
+                values
+                    .iter()
+                    .map(|v| {
+                        *lookup.entry(v.as_str()).or_insert_with(|| {
+                            dict.push(v.clone());
+                            dict.len() - 1
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let width =
+            categorical_width_for(width, dict.len()).ok_or_else(|| MinarrowError::Overflow {
+                value: dict.len().to_string(),
+                target: "CategoricalIndexType",
+            })?;
+
+        let chunks: Vec<Array> = parts
+            .into_iter()
+            .zip(remaps)
+            .map(|((codes, _, mask), remap)| {
+                let codes = codes.into_iter().map(|c| remap[c]);
+                build_categorical(width, codes, dict.clone(), mask)
+            })
+            .collect();
+
+        let field = self.field.as_ref().map(|f| {
+            let mut f = (**f).clone();
+            if let Some(first) = chunks.first() {
+                f.dtype = first.arrow_type();
+            }
+            Arc::new(f)
+        });
+        Ok(SuperArray {
+            chunks,
+            field,
+            null_counts: self.null_counts.clone(),
+        })
+    }
+}
+
+/// Smallest categorical index width available in this build that is at least
+/// `min_width` bits and can address `cardinality` dictionary entries.
+fn categorical_width_for(min_width: u32, cardinality: usize) -> Option<u32> {
+    let widths = [
+        #[cfg(feature = "default_categorical_8")]
+        8,
+        #[cfg(feature = "extended_categorical")]
+        16,
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        32,
+        #[cfg(feature = "extended_categorical")]
+        64,
+    ];
+    widths
+        .into_iter()
+        .find(|&w| w >= min_width && (w >= 64 || cardinality <= 1usize << w))
+}
+
+/// Builds a categorical `Array` with the given index width from remapped codes.
+fn build_categorical(
+    width: u32,
+    codes: impl Iterator<Item = usize>,
+    dict: Vec64<String>,
+    mask: Option<Bitmask>,
+) -> Array {
+    macro_rules! build {
+        ($t:ty, $variant:ident) => {
+            Array::TextArray(TextArray::$variant(Arc::new(
+                CategoricalArray::<$t>::new_unchecked(
+                    codes.map(|c| c as $t).collect::<Vec64<$t>>(),
+                    dict,
+                    mask,
+                ),
+            )))
+        };
+    }
+    match width {
+        #[cfg(feature = "default_categorical_8")]
+        8 => build!(u8, Categorical8),
+        #[cfg(feature = "extended_categorical")]
+        16 => build!(u16, Categorical16),
+        #[cfg(any(
+            not(feature = "default_categorical_8"),
+            feature = "extended_categorical"
+        ))]
+        32 => build!(u32, Categorical32),
+        #[cfg(feature = "extended_categorical")]
+        64 => build!(u64, Categorical64),
+        _ => unreachable!("categorical_width_for only yields available widths"),
+    }
 }
 
 impl Default for SuperArray {
@@ -1151,6 +1544,7 @@ mod tests {
             dtype,
             nullable,
             metadata: Default::default(),
+            ordered: false,
         }
     }
 
@@ -1439,6 +1833,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rechunk_by_bytes_wide_strings() {
+        use crate::StringArray;
+        const MIB: usize = 1 << 20;
+
+        // Row widths vary from 1 to 2000 bytes, around 4MiB in total
+        let strings: Vec<String> = (0..4000)
+            .map(|i| "x".repeat((i * 7919) % 2000 + 1))
+            .collect();
+        let refs: Vec<&str> = strings.iter().map(|s| s.as_str()).collect();
+        let (left, right) = refs.split_at(1500);
+        let mut ca = SuperArray::from_arrays(vec![
+            Array::from_string32(StringArray::from_slice(left)),
+            Array::from_string32(StringArray::from_slice(right)),
+        ]);
+        let before = ca.clone().consolidate();
+
+        ca.rechunk(RechunkStrategy::Bytes(MIB)).unwrap();
+        assert!(ca.n_chunks() >= 4);
+        let sizes: Vec<usize> = ca
+            .chunks
+            .iter()
+            .map(|c| {
+                // Split chunks can share one data buffer, so measure by offsets
+                let s = c.str_ref().unwrap().str32_ref().unwrap();
+                let n = c.len();
+                let mask_bytes = c.null_mask().map_or(0, |_| n.div_ceil(8));
+                (s.offsets[n] - s.offsets[0]) as usize + 4 * n + mask_bytes
+            })
+            .collect();
+        for &size in &sizes[..sizes.len() - 1] {
+            assert!(size <= MIB && size > MIB - 2005, "chunk of {} bytes", size);
+        }
+        assert!(*sizes.last().unwrap() <= MIB);
+        assert_eq!(ca.consolidate(), before);
+    }
+
+    #[test]
+    fn test_rechunk_by_bytes_fixed_width() {
+        let values: Vec<i64> = (0..100).collect();
+        let mut ca = SuperArray::from_arrays(vec![Array::from_int64(
+            crate::IntegerArray::from_slice(&values),
+        )]);
+        ca.rechunk(RechunkStrategy::Bytes(80)).unwrap();
+        assert_eq!(ca.n_chunks(), 10);
+        assert!(ca.chunks.iter().all(|c| c.len() == 10));
+
+        // A single row larger than the target still gets a chunk
+        ca.rechunk(RechunkStrategy::Bytes(3)).unwrap();
+        assert_eq!(ca.n_chunks(), 100);
+        assert!(ca.rechunk(RechunkStrategy::Bytes(0)).is_err());
+    }
+
     #[test]
     fn test_rechunk_uniform_zero_error() {
         let mut ca = SuperArray::from_chunks(vec![fa_i32!("a", 1, 2, 3)]);
@@ -1471,4 +1918,103 @@ mod tests {
             panic!("Expected Int32");
         }
     }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_unify_dictionaries() {
+        use crate::{CategoricalArray, MaskedArray, TextArray};
+
+        let a = CategoricalArray::<u32>::from_values(["b", "a", "b"]);
+        let mut b = CategoricalArray::<u32>::from_values(["c", "a", "b"]);
+        b.push_null();
+        let sa = SuperArray::from_arrays_with_field(
+            vec![Array::from_categorical32(a), Array::from_categorical32(b)],
+            Field::new(
+                "cat",
+                ArrowType::Dictionary(crate::ffi::arrow_dtype::CategoricalIndexType::UInt32),
+                true,
+                None,
+            ),
+        );
+
+        let unified = sa.unify_dictionaries().unwrap();
+        assert_eq!(unified.n_chunks(), 2);
+        let chunk = |i: usize| match &unified.chunks[i] {
+            Array::TextArray(TextArray::Categorical32(c)) => c.clone(),
+            _ => panic!("expected Categorical32"),
+        };
+        let (c0, c1) = (chunk(0), chunk(1));
+        assert_eq!(c0.unique_values, c1.unique_values);
+        assert_eq!(c0.unique_values.as_slice(), &["b", "a", "c", ""]);
+        assert_eq!(c0.data.as_slice(), &[0, 1, 0]);
+        assert_eq!(&c1.data.as_slice()[..3], &[2, 1, 0]);
+        let values: Vec<Option<&str>> = (0..c1.len()).map(|i| c1.get_str(i)).collect();
+        assert_eq!(values, vec![Some("c"), Some("a"), Some("b"), None]);
+        assert_eq!(unified.field_ref().name, "cat");
+
+        let ints = SuperArray::from_arrays(vec![int_array(&[1])]);
+        assert!(matches!(
+            ints.unify_dictionaries(),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_map_chunks() {
+        let sa = SuperArray::from_arrays_with_field(
+            vec![int_array(&[1, 2, 3]), int_array(&[4, 5])],
+            field("x", ArrowType::Int32, false),
+        );
+
+        let reverse =
+            |fa: &FieldArray| Ok(FieldArray::new_arc(fa.field.clone(), fa.array.reverse()));
+        let reversed = sa.map_chunks(reverse).unwrap();
+        assert_eq!(
+            reversed.chunks,
+            vec![int_array(&[3, 2, 1]), int_array(&[5, 4])]
+        );
+        assert_eq!(reversed.null_counts, Some(vec![0, 0]));
+
+        // A type change is carried through to the result's field
+        let widened = sa
+            .map_chunks(|fa| {
+                let ints = fa.array.num_ref()?.clone().i64()?;
+                let f = Field::new(fa.field.name.clone(), ArrowType::Int64, false, None);
+                Ok(FieldArray::new(f, Array::from_int64(ints)))
+            })
+            .unwrap();
+        assert_eq!(widened.arrow_type(), ArrowType::Int64);
+        assert_eq!(widened.field_ref().name, "x");
+        assert_eq!(widened.len(), 5);
+
+        let bare = SuperArray::from_arrays(vec![int_array(&[1]), int_array(&[2])]);
+        let mapped = bare.map_chunks(|fa| Ok(fa.clone())).unwrap();
+        assert!(mapped.field.is_none());
+        assert_eq!(mapped.chunks, bare.chunks);
+
+        let renamed = sa.map_chunks(|fa| {
+            let name = format!("x{}", fa.len());
+            Ok(FieldArray::from_arr(name, fa.array.clone()))
+        });
+        assert!(matches!(
+            renamed,
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+        let failed = sa.map_chunks(|_| Err(MinarrowError::KernelError(None)));
+        assert!(matches!(failed, Err(MinarrowError::KernelError(None))));
+    }
+
+    #[cfg(feature = "parallel_proc")]
+    #[test]
+    fn test_par_map_chunks() {
+        let chunks = (0..8).map(|i| int_array(&[i, i + 1])).collect();
+        let sa = SuperArray::from_arrays_with_field(chunks, field("x", ArrowType::Int32, false));
+        let reverse =
+            |fa: &FieldArray| Ok(FieldArray::new_arc(fa.field.clone(), fa.array.reverse()));
+        let parallel = sa.par_map_chunks(reverse).unwrap();
+        assert_eq!(parallel, sa.map_chunks(reverse).unwrap());
+    }
 }
@@ -38,8 +38,13 @@ use std::fmt::{Display, Formatter};
 use std::iter::FromIterator;
 use std::sync::Arc;
 
+#[cfg(feature = "scalar_type")]
+use crate::Row;
 use crate::enums::{error::MinarrowError, shape_dim::ShapeDim};
-use crate::structs::chunked::super_array::RechunkStrategy;
+use crate::ffi::arrow_dtype::ArrowType;
+use crate::structs::chunked::super_array::{
+    ByteChunker, RechunkStrategy, SuperArray, add_row_bits, uniform_chunk_lengths,
+};
 use crate::structs::field::Field;
 use crate::structs::field_array::FieldArray;
 use crate::structs::table::Table;
@@ -362,6 +367,15 @@ impl SuperTable {
         self.batches.get(idx)
     }
 
+    /// Iterates over the logical rows of every batch in order, as `Table::row_iter`.
+    ///
+    /// Batch boundaries are crossed transparently. `Row::index` is the position
+    /// within the row's own batch.
+    #[cfg(feature = "scalar_type")]
+    pub fn row_iter(&self) -> impl Iterator<Item = Row<'_>> {
+        self.batches.iter().flat_map(|batch| batch.row_iter())
+    }
+
     /// Returns the schema-level metadata from the first batch, or an empty map
     /// if there are no batches.
     #[cfg(feature = "table_metadata")]
@@ -423,12 +437,13 @@ impl SuperTable {
     /// - `Count(n)`: Creates batches of `n` rows (last batch may be smaller)
     /// - `Auto`: Uses a default size of 8192 rows
     /// - `Memory(bytes)`: Targets a specific memory size per batch
+    /// - `Bytes(bytes)`: Targets a byte size for each batch from its own rows
     ///
     /// # Arguments
     /// * `strategy` - The rechunking strategy to use
     ///
     /// # Errors
-    /// - Returns `IndexError` if `Count(0)` is specified
+    /// - Returns `IndexError` if `Count(0)` or `Bytes(0)` is specified
     /// - Returns `IndexError` if memory-based calculation results in 0 chunk size
     ///
     /// # Example
@@ -441,23 +456,26 @@ impl SuperTable {
     ///
     /// // Target 64KB per batch
     /// table.rechunk(RechunkStrategy::Memory(65536))?;
+    ///
+    /// // Target 1MiB per batch, however wide each row is
+    /// table.rechunk(RechunkStrategy::Bytes(1 << 20))?;
     /// ```
     pub fn rechunk(&mut self, strategy: RechunkStrategy) -> Result<(), MinarrowError> {
         if self.batches.is_empty() || self.n_rows == 0 {
             return Ok(());
         }
 
-        // Determine chunk size based on strategy
-        let chunk_size = match strategy {
+        // Determine output batch lengths based on strategy
+        let lengths = match strategy {
             RechunkStrategy::Count(size) => {
                 if size == 0 {
                     return Err(MinarrowError::IndexError(
                         "Count chunk size must be greater than 0".to_string(),
                     ));
                 }
-                size
+                uniform_chunk_lengths(self.n_rows, size)
             }
-            RechunkStrategy::Auto => 8192,
+            RechunkStrategy::Auto => uniform_chunk_lengths(self.n_rows, 8192),
             #[cfg(feature = "size")]
             RechunkStrategy::Memory(bytes_per_chunk) => {
                 let total_bytes = self.est_bytes();
@@ -469,12 +487,24 @@ impl SuperTable {
                     ));
                 }
 
-                ((bytes_per_chunk * total_rows) / total_bytes).max(1)
+                let chunk_size = ((bytes_per_chunk * total_rows) / total_bytes).max(1);
+                uniform_chunk_lengths(total_rows, chunk_size)
+            }
+            RechunkStrategy::Bytes(target_bytes) => {
+                let mut chunker = ByteChunker::new(target_bytes)?;
+                for batch in &self.batches {
+                    let mut bits = vec![0; batch.n_rows];
+                    for col in &batch.cols {
+                        add_row_bits(&col.array, &mut bits);
+                    }
+                    bits.into_iter().for_each(|b| chunker.push(b));
+                }
+                chunker.finish()
             }
         };
 
         // Fast path: single batch already at target size
-        if self.batches.len() == 1 && self.batches[0].n_rows == chunk_size {
+        if self.batches.len() == 1 && lengths.len() == 1 {
             return Ok(());
         }
 
@@ -487,6 +517,7 @@ impl SuperTable {
             let mut remaining = batch;
 
             while remaining.n_rows > 0 {
+                let chunk_size = lengths[new_batches.len()];
                 if let Some(ref mut acc) = accumulator {
                     let acc_rows = acc.n_rows;
                     let needed = chunk_size - acc_rows;
@@ -630,6 +661,153 @@ impl SuperTable {
 
         Ok(())
     }
+
+    /// Applies `f` to every batch in order, returning the results as a new `SuperTable`.
+    ///
+    /// Each output batch may change the row count or columns, but all outputs
+    /// must share the same schema. The result keeps this table's name.
+    ///
+    /// # Errors
+    /// - Any error returned by `f`, from the first failing batch.
+    /// - `IncompatibleTypeError` if the output batches disagree on schema.
+    pub fn map_batches<F>(&self, f: F) -> Result<SuperTable, MinarrowError>
+    where
+        F: Fn(&Table) -> Result<Table, MinarrowError>,
+    {
+        let out = self
+            .batches
+            .iter()
+            .map(|b| f(b))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_mapped_batches(out, self.name.clone())
+    }
+
+    /// Parallel version of [`SuperTable::map_batches`], running `f` across batches
+    /// with Rayon. Output batch order matches the input order.
+    #[cfg(feature = "parallel_proc")]
+    pub fn par_map_batches<F>(&self, f: F) -> Result<SuperTable, MinarrowError>
+    where
+        F: Fn(&Table) -> Result<Table, MinarrowError> + Sync + Send,
+    {
+        use rayon::prelude::*;
+        let out = self
+            .batches
+            .par_iter()
+            .map(|b| f(b))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_mapped_batches(out, self.name.clone())
+    }
+
+    /// Consolidates all batches into a single `Table`, unifying categorical
+    /// dictionaries across batches first.
+    ///
+    /// `consolidate` appends raw dictionary codes, so it is only correct when every
+    /// batch already shares one dictionary. Here each categorical column is first
+    /// rebuilt via `SuperArray::unify_dictionaries`, which remaps the codes and widens
+    /// the index type when the combined dictionary needs it. Each categorical column
+    /// in the output carries one dictionary. An empty `SuperTable` yields an empty table.
+    ///
+    /// # Errors
+    /// - `Overflow` if a combined dictionary does not fit any available index type
+    pub fn to_table(&self) -> Result<Table, MinarrowError> {
+        if self.batches.is_empty() {
+            return Ok(Table::new(self.name.clone(), None));
+        }
+        let mut batches: Vec<Table> = self.batches.iter().map(|b| (**b).clone()).collect();
+        let mut schema = self.schema.clone();
+        for (col_idx, field) in self.schema.iter().enumerate() {
+            if !matches!(field.dtype, ArrowType::Dictionary(_)) {
+                continue;
+            }
+            let chunks = self
+                .batches
+                .iter()
+                .map(|b| b.cols[col_idx].array.clone())
+                .collect();
+            let unified =
+                SuperArray::from_arrays_with_field(chunks, field.clone()).unify_dictionaries()?;
+            let field = unified
+                .field_arc()
+                .cloned()
+                .unwrap_or_else(|| field.clone());
+            for (batch, array) in batches.iter_mut().zip(unified.into_chunks()) {
+                let col = &mut batch.cols[col_idx];
+                col.field = field.clone();
+                col.array = array;
+            }
+            schema[col_idx] = field;
+        }
+        let unified = SuperTable {
+            batches: batches.into_iter().map(Arc::new).collect(),
+            schema,
+            n_rows: self.n_rows,
+            name: self.name.clone(),
+        };
+        Ok(unified.consolidate())
+    }
+
+    /// Assembles mapped batches, checking each against the first batch's schema.
+    fn from_mapped_batches(batches: Vec<Table>, name: String) -> Result<Self, MinarrowError> {
+        let Some(first) = batches.first() else {
+            return Ok(Self::new(name));
+        };
+        let schema = first.schema().fields;
+        for (b_idx, batch) in batches.iter().enumerate().skip(1) {
+            let matches = batch.n_cols() == schema.len()
+                && batch.cols.iter().zip(&schema).all(|(fa, f)| fa.field == *f);
+            if !matches {
+                return Err(MinarrowError::IncompatibleTypeError {
+                    from: "Table",
+                    to: "SuperTable",
+                    message: Some(format!(
+                        "map_batches: batch {} schema does not match batch 0",
+                        b_idx
+                    )),
+                });
+            }
+        }
+        let n_rows = batches.iter().map(|b| b.n_rows).sum();
+        Ok(Self {
+            batches: batches.into_iter().map(Arc::new).collect(),
+            schema,
+            n_rows,
+            name,
+        })
+    }
+
+    /// Reads an Arrow IPC file, also known as Feather v2, with one batch per
+    /// record batch in the file.
+    ///
+    /// The schema comes from the file footer, so a file without record batches
+    /// still yields its columns. The super table and its batches are named after
+    /// the file stem. With the `mmap` feature the file is memory-mapped, and
+    /// columns share the mapping rather than copying it.
+    ///
+    /// # Errors
+    /// - `IoError` if the file cannot be read or is not a valid Arrow IPC file
+    /// - `NotImplemented` for field types without an IPC mapping
+    pub fn read_ipc_file(path: impl AsRef<std::path::Path>) -> Result<Self, MinarrowError> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let reader = crate::ipc::FileReader::open(path)?;
+        let batches = reader
+            .batches()
+            .map(|batch| {
+                let mut batch = batch?;
+                batch.name = name.clone();
+                Ok(Arc::new(batch))
+            })
+            .collect::<Result<Vec<_>, MinarrowError>>()?;
+        Ok(Self {
+            schema: reader.schema().fields.clone(),
+            n_rows: batches.iter().map(|b| b.n_rows).sum(),
+            batches,
+            name,
+        })
+    }
 }
 
 impl Default for SuperTable {
@@ -885,7 +1063,6 @@ impl From<SuperTableV> for SuperTable {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ffi::arrow_dtype::ArrowType;
     use crate::{fa_bool, fa_cat32, fa_f64, fa_i32, fa_i64, fa_str32};
     use crate::{Array, Field, FieldArray, MaskedArray, NumericArray, Table};
 
@@ -946,6 +1123,57 @@ mod tests {
         SuperTable::from_batches(vec![batch1, batch2].into(), None);
     }
 
+    #[test]
+    fn test_map_batches() {
+        let mut t = SuperTable::default();
+        t.push(Arc::new(table(vec![fa_i32!("x", 1, 2, 3)])));
+        t.push(Arc::new(table(vec![fa_i32!("x", 4, 5)])));
+
+        let mapped = t.map_batches(|b| Ok(b.slice_clone(0, 1))).unwrap();
+        assert_eq!(mapped.n_batches(), 2);
+        assert_eq!(mapped.n_rows(), 2);
+        assert_eq!(mapped.schema()[0].name, "x");
+        assert_eq!(mapped.name, t.name);
+
+        let err = t.map_batches(|_| Err(MinarrowError::NullError { message: None }));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_map_batches_schema_mismatch() {
+        let mut t = SuperTable::default();
+        t.push(Arc::new(table(vec![fa_i32!("x", 1)])));
+        t.push(Arc::new(table(vec![fa_i32!("x", 2, 3)])));
+
+        // Rename the column only in the second batch.
+        let res = t.map_batches(|b| {
+            let mut out = b.clone();
+            if b.n_rows == 2 {
+                out.rename_columns(&[("x", "y")])?;
+            }
+            Ok(out)
+        });
+        assert!(matches!(
+            res,
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+    }
+
+    #[cfg(feature = "parallel_proc")]
+    #[test]
+    fn test_par_map_batches() {
+        let mut t = SuperTable::default();
+        t.push(Arc::new(table(vec![fa_i32!("x", 1, 2, 3)])));
+        t.push(Arc::new(table(vec![fa_i32!("x", 4, 5)])));
+
+        let mapped = t.par_map_batches(|b| Ok(b.slice_clone(1, 1))).unwrap();
+        assert_eq!(mapped.n_rows(), 2);
+        let first = mapped.batches()[0].cols[0].array.num_ref().unwrap();
+        assert_eq!(first.i32_ref().unwrap().data.as_slice(), &[2]);
+        let second = mapped.batches()[1].cols[0].array.num_ref().unwrap();
+        assert_eq!(second.i32_ref().unwrap().data.as_slice(), &[5]);
+    }
+
     #[test]
     fn test_push_and_consolidate() {
         let mut t = SuperTable::default();
@@ -1248,6 +1476,23 @@ mod tests {
         assert_eq!(materialized.n_rows, 8);
     }
 
+    #[test]
+    fn test_rechunk_by_bytes() {
+        let batch1 = Arc::new(table(vec![
+            fa_i64!("a", 1, 2, 3),
+            fa_str32!("s", "a", "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb", "c"),
+        ]));
+        let batch2 = Arc::new(table(vec![fa_i64!("a", 4, 5), fa_str32!("s", "d", "e")]));
+        let mut st = SuperTable::from_batches(vec![batch1, batch2], None);
+
+        // Short rows take 8 + 4 + 1 bytes, and the long row 8 + 4 + 36
+        st.rechunk(RechunkStrategy::Bytes(30)).unwrap();
+        let rows: Vec<usize> = st.batches.iter().map(|b| b.n_rows).collect();
+        assert_eq!(rows, vec![1, 1, 2, 1]);
+        assert_eq!(st.n_rows(), 5);
+        assert!(st.rechunk(RechunkStrategy::Bytes(0)).is_err());
+    }
+
     #[test]
     fn test_rechunk_uniform_zero_error() {
         let batch1 = Arc::new(table(vec![fa_i32!("x", 1, 2, 3)]));
@@ -1583,6 +1828,29 @@ mod tests {
         }
     }
 
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_to_table_unifies_dictionaries() {
+        let b1 = Arc::new(table(vec![fa_cat32!("cat", "a", "b", "a")]));
+        let b2 = Arc::new(table(vec![fa_cat32!("cat", "c", "d", "c", "d")]));
+        let st = SuperTable::from_batches(vec![b1, b2], None);
+        let result = st.to_table().unwrap();
+
+        assert_eq!(result.n_rows, 7);
+        match &result.cols[0].array {
+            Array::TextArray(crate::TextArray::Categorical32(arr)) => {
+                assert_eq!(arr.unique_values.as_slice(), &["a", "b", "c", "d"]);
+                assert_eq!(arr.data.as_slice(), &[0, 1, 0, 2, 3, 2, 3]);
+                let values: Vec<_> = (0..arr.len()).map(|i| arr.get_str(i).unwrap()).collect();
+                assert_eq!(values, vec!["a", "b", "a", "c", "d", "c", "d"]);
+            }
+            _ => panic!("Expected Categorical32"),
+        }
+    }
+
     #[test]
     fn test_consolidate_arena_nullable_strings() {
         use crate::{Bitmask, StringArray};
@@ -1690,4 +1958,41 @@ mod tests {
             _ => panic!("Expected Datetime64"),
         }
     }
+
+    #[test]
+    fn test_ipc_file_round_trip() {
+        let t = table(vec![fa_i32!("a", 1, 2, 3), fa_str32!("s", "x", "y", "z")]);
+        let path = std::env::temp_dir().join(format!("minarrow_st_{}.arrow", std::process::id()));
+        t.write_ipc_file(&path).unwrap();
+        let st = SuperTable::read_ipc_file(&path).unwrap();
+
+        assert_eq!(st.n_batches(), 1);
+        assert_eq!(st.n_rows, 3);
+        assert_eq!(st.name, path.file_stem().unwrap().to_string_lossy());
+        assert_eq!(st.schema, t.schema().fields);
+        assert_eq!(st.batches[0].cols, t.cols);
+        drop(st);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_row_iter_crosses_batches() {
+        use crate::Scalar;
+
+        let b1 = Arc::new(table(vec![fa_i32!("a", 1, 2), fa_str32!("s", "x", "y")]));
+        let b2 = Arc::new(table(vec![fa_i32!("a", 3), fa_str32!("s", "z")]));
+        let st = SuperTable::from_batches(vec![b1, b2], None);
+
+        let rows: Vec<_> = st.row_iter().collect();
+        assert_eq!(rows.len(), st.n_rows());
+        let ids: Vec<_> = rows.iter().map(|r| r.get(0).unwrap()).collect();
+        assert_eq!(
+            ids,
+            vec![Scalar::Int32(1), Scalar::Int32(2), Scalar::Int32(3)]
+        );
+        assert_eq!(rows[2].index(), 0);
+        assert_eq!(rows[2].get_by_name("s"), Some(Scalar::String32("z".into())));
+        assert_eq!(SuperTable::default().row_iter().count(), 0);
+    }
 }
@@ -43,6 +43,8 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator};
 
 use super::field_array::FieldArray;
+#[cfg(feature = "scalar_type")]
+use crate::Scalar;
 #[cfg(feature = "views")]
 use crate::TableV;
 #[cfg(feature = "views")]
@@ -183,7 +185,7 @@ impl Cube {
     /// Gets the schemea from the first table as representative
     /// of the rest
     pub fn schema(&self) -> Vec<Arc<Field>> {
-        self.tables[0].schema()
+        self.tables[0].schema().fields
     }
 
     /// Returns the number of tables.
@@ -446,6 +448,65 @@ impl Cube {
         }
     }
 
+    /// Key of `table` along the third dimension: the values of the
+    /// `third_dim_index` columns at row 0, in index order.
+    ///
+    /// Each table is one layer, so its index columns hold that layer's key.
+    /// Returns `None` when no index is set, an index column is missing, or
+    /// the table is empty.
+    #[cfg(feature = "scalar_type")]
+    fn layer_key(&self, table: &Table) -> Option<Vec<Scalar>> {
+        self.third_dim_index
+            .as_ref()?
+            .iter()
+            .map(|col| {
+                let idx = table.col_name_index(col)?;
+                table.cols()[idx].array.get_scalar(0)
+            })
+            .collect()
+    }
+
+    /// Returns the table whose third-dimension key equals `key`, or `None`
+    /// if no layer matches.
+    ///
+    /// `key` holds one scalar per `third_dim_index` column, in index order,
+    /// so a single-column index takes a one-element slice. Keys compare with
+    /// `Scalar`'s `PartialEq`, so each scalar variant must match its index
+    /// column's type. The returned table shares its column buffers with the
+    /// cube.
+    #[cfg(feature = "scalar_type")]
+    pub fn slice_at(&self, key: &[Scalar]) -> Option<Table> {
+        self.tables
+            .iter()
+            .find(|t| self.layer_key(t).as_deref() == Some(key))
+            .map(|t| t.as_ref().clone())
+    }
+
+    /// Returns a sub-cube holding the layers whose third-dimension key is in
+    /// `keys`, in the cube's existing order.
+    ///
+    /// Each key holds one scalar per `third_dim_index` column, as for
+    /// [`slice_at`](Self::slice_at). Tables are shared with `self` rather
+    /// than copied. The name and `third_dim_index` carry over, and the
+    /// resolver is rebuilt.
+    #[cfg(feature = "scalar_type")]
+    pub fn filter_third_dim(&self, keys: &[Vec<Scalar>]) -> Cube {
+        let tables: Vec<Arc<Table>> = self
+            .tables
+            .iter()
+            .filter(|t| self.layer_key(t).is_some_and(|k| keys.contains(&k)))
+            .cloned()
+            .collect();
+        let mut cube = Cube {
+            tables,
+            name: self.name.clone(),
+            third_dim_index: self.third_dim_index.clone(),
+            resolver: HashMap::new(),
+        };
+        cube.rebuild_resolver();
+        cube
+    }
+
     /// Returns a new owned Cube containing rows `[offset, offset+len)` for all tables.
     #[cfg(feature = "views")]
     pub fn slice_clone(&self, offset: usize, len: usize) -> Self {
@@ -1013,6 +1074,79 @@ mod tests {
         assert_eq!(view[1].col("bools").col_ix(0).unwrap().len(), 2); // arrayview length is 2
     }
 
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_slice_at_and_filter_third_dim() {
+        let snapshot = |name: &str, day: i32| {
+            let mut t = build_test_table(name, &[day * 10, day * 10 + 1], &[true, false]);
+            let days = IntegerArray::from_slice(&[day, day]);
+            t.add_col(field_array("day", Array::from_int32(days)));
+            t
+        };
+        let cube = Cube::from_tables(
+            vec![snapshot("d1", 1), snapshot("d2", 2), snapshot("d3", 3)],
+            "days".into(),
+            Some(vec!["day".into()]),
+        );
+
+        let layer = cube.slice_at(&[Scalar::Int32(2)]).unwrap();
+        assert_eq!(layer.name, "d2");
+        assert_eq!(layer.cols()[0].array.get_scalar(0), Some(Scalar::Int32(20)));
+        assert!(cube.slice_at(&[Scalar::Int32(9)]).is_none());
+        // Keys are type-strict, matching Scalar equality.
+        assert!(cube.slice_at(&[Scalar::Int64(2)]).is_none());
+
+        let sub = cube.filter_third_dim(&[
+            vec![Scalar::Int32(3)],
+            vec![Scalar::Int32(1)],
+            vec![Scalar::Int32(7)],
+        ]);
+        assert_eq!(sub.table_names(), vec!["d1", "d3"]);
+        assert_eq!(sub.resolve("d3"), Some(1));
+        assert_eq!(sub.third_dim_index().unwrap(), &["day"]);
+        assert!(Arc::ptr_eq(&sub.tables[0], &cube.tables[0]));
+        assert!(cube.filter_third_dim(&[]).is_empty());
+
+        let unindexed = Cube::from_tables(vec![snapshot("d1", 1)], "raw".into(), None);
+        assert!(unindexed.slice_at(&[Scalar::Int32(1)]).is_none());
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_slice_at_compound_key() {
+        let snapshot = |name: &str, day: i32, hour: i32| {
+            let mut t = build_test_table(name, &[day, hour], &[true, false]);
+            let days = IntegerArray::from_slice(&[day, day]);
+            let hours = IntegerArray::from_slice(&[hour, hour]);
+            t.add_col(field_array("day", Array::from_int32(days)));
+            t.add_col(field_array("hour", Array::from_int32(hours)));
+            t
+        };
+        let cube = Cube::from_tables(
+            vec![
+                snapshot("d1h0", 1, 0),
+                snapshot("d1h1", 1, 1),
+                snapshot("d2h0", 2, 0),
+            ],
+            "hours".into(),
+            Some(vec!["day".into(), "hour".into()]),
+        );
+
+        // Both index columns take part in the key, not just the first.
+        let key = [Scalar::Int32(1), Scalar::Int32(1)];
+        assert_eq!(cube.slice_at(&key).unwrap().name, "d1h1");
+        let missing = [Scalar::Int32(2), Scalar::Int32(1)];
+        assert!(cube.slice_at(&missing).is_none());
+        // A key covering only part of the index matches nothing.
+        assert!(cube.slice_at(&[Scalar::Int32(1)]).is_none());
+
+        let sub = cube.filter_third_dim(&[
+            vec![Scalar::Int32(2), Scalar::Int32(0)],
+            vec![Scalar::Int32(1), Scalar::Int32(0)],
+        ]);
+        assert_eq!(sub.table_names(), vec!["d1h0", "d2h0"]);
+    }
+
     #[cfg(feature = "parallel_proc")]
     #[test]
     fn test_cube_par_iter_tables() {
@@ -32,6 +32,8 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "datetime")]
 use crate::TemporalArray;
+#[cfg(feature = "datetime")]
+use crate::enums::error::MinarrowError;
 use crate::ffi::arrow_dtype::{ArrowType, CategoricalIndexType};
 use crate::{Array, MaskedArray, NumericArray, TextArray};
 
@@ -71,6 +73,9 @@ pub struct Field {
     pub dtype: ArrowType,
     pub nullable: bool,
     pub metadata: BTreeMap<String, String>,
+    /// Whether dictionary codes carry a meaningful sort order.
+    /// Only applies to `ArrowType::Dictionary` fields.
+    pub ordered: bool,
 }
 
 impl Field {
@@ -93,6 +98,64 @@ impl Field {
             dtype,
             nullable,
             metadata: metadata.unwrap_or_default(),
+            ordered: false,
+        }
+    }
+
+    /// Sets the dictionary-ordered flag, carried over Arrow FFI as
+    /// `ARROW_FLAG_DICTIONARY_ORDERED`. Ignored for non-dictionary types.
+    pub fn with_ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    /// Adds a metadata entry, replacing any existing value for `key`.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the metadata value for `key`, if present.
+    pub fn get_metadata(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    /// True if this is a dictionary field whose codes carry a sort order.
+    ///
+    /// This is what Arrow FFI and IPC export read, so a stray `ordered` flag on
+    /// a non-dictionary field is never exported.
+    pub fn is_ordered_dictionary(&self) -> bool {
+        self.ordered && matches!(self.dtype, ArrowType::Dictionary(_))
+    }
+
+    /// Sets the dictionary-ordered flag in place. See [`Field::with_ordered`].
+    pub fn set_ordered_dictionary(&mut self, ordered: bool) {
+        self.ordered = ordered;
+    }
+
+    /// Sets the timezone of a `Timestamp` field, keeping its unit.
+    /// An empty string clears it.
+    ///
+    /// The timezone lives in the `ArrowType`, so it is exported as part of the
+    /// Arrow format string, e.g. `tsn:UTC`.
+    ///
+    /// # Errors
+    /// - `TypeError` if the field is not a `Timestamp`.
+    #[cfg(feature = "datetime")]
+    pub fn set_timezone(&mut self, tz: &str) -> Result<(), MinarrowError> {
+        match &mut self.dtype {
+            ArrowType::Timestamp(_, zone) => {
+                *zone = (!tz.is_empty()).then(|| tz.to_string());
+                Ok(())
+            }
+            other => Err(MinarrowError::TypeError {
+                from: "Field",
+                to: "Timestamp",
+                message: Some(format!(
+                    "cannot set a timezone on {} field \"{}\"",
+                    other, self.name
+                )),
+            }),
         }
     }
 
@@ -149,6 +212,9 @@ impl Field {
             Array::BooleanArray(a) => {
                 Field::new(name, ArrowType::Boolean, a.is_nullable(), Some(metadata))
             }
+            Array::FixedSizeBinaryArray(a) => {
+                Field::new(name, a.arrow_type(), a.is_nullable(), Some(metadata))
+            }
             Array::TextArray(inner) => match inner {
                 TextArray::String32(a) => {
                     Field::new(name, ArrowType::String, a.is_nullable(), Some(metadata))
@@ -221,6 +287,10 @@ impl Display for Field {
             if self.nullable { " (nullable)" } else { "" }
         )?;
 
+        if self.is_ordered_dictionary() {
+            write!(f, " (ordered)")?;
+        }
+
         if !self.metadata.is_empty() {
             write!(f, " [metadata: ")?;
             for (i, (k, v)) in self.metadata.iter().enumerate() {
@@ -268,4 +338,77 @@ mod tests {
         assert!(f2.name.starts_with("UnnamedField"));
         assert_ne!(f1.name, f2.name);
     }
+
+    #[test]
+    fn test_field_metadata_accessors() {
+        let field = Field::new("foo", ArrowType::Int64, true, None)
+            .with_metadata("unit", "ms")
+            .with_metadata("unit", "s");
+        assert_eq!(field.get_metadata("unit"), Some("s"));
+        assert_eq!(field.get_metadata("missing"), None);
+        assert_eq!(field.metadata.len(), 1);
+    }
+
+    #[test]
+    fn test_field_ordered_requires_dictionary() {
+        let mut field = Field::new("n", ArrowType::Int32, false, None);
+        field.set_ordered_dictionary(true);
+        assert!(field.ordered);
+        assert!(!field.is_ordered_dictionary());
+        assert!(!field.to_string().contains("(ordered)"));
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_field_set_timezone() {
+        use crate::enums::time_units::TimeUnit;
+
+        let mut field = Field::new(
+            "ts",
+            ArrowType::Timestamp(TimeUnit::Nanoseconds, None),
+            true,
+            None,
+        );
+        field.set_timezone("UTC").unwrap();
+        assert_eq!(
+            field.dtype,
+            ArrowType::Timestamp(TimeUnit::Nanoseconds, Some("UTC".to_string()))
+        );
+        assert_eq!(
+            crate::ffi::arrow_c_ffi::fmt_c(field.dtype.clone())
+                .to_str()
+                .unwrap(),
+            "tsn:UTC"
+        );
+        field.set_timezone("").unwrap();
+        assert_eq!(
+            field.dtype,
+            ArrowType::Timestamp(TimeUnit::Nanoseconds, None)
+        );
+
+        let mut date = Field::new("d", ArrowType::Date32, true, None);
+        assert!(matches!(
+            date.set_timezone("UTC"),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_field_with_ordered() {
+        let field = Field::new(
+            "cat",
+            ArrowType::Dictionary(CategoricalIndexType::UInt32),
+            true,
+            None,
+        );
+        assert!(!field.ordered);
+        let field = field.with_ordered(true);
+        assert!(field.ordered);
+        assert!(field.is_ordered_dictionary());
+        assert!(field.to_string().ends_with("(ordered)"));
+    }
 }
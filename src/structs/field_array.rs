@@ -171,6 +171,7 @@ impl FieldArray {
             dtype,
             nullable: nullable.unwrap_or_else(|| array.is_nullable()),
             metadata: metadata.unwrap_or_default(),
+            ordered: false,
         };
         FieldArray {
             field: field.into(),
@@ -233,6 +234,54 @@ impl FieldArray {
         self.tz("UTC")
     }
 
+    /// Casts the array to `target`, keeping the field's name, nullability and metadata.
+    ///
+    /// Non-dictionary targets go through [`Array::cast`]. For a `Dictionary` target
+    /// the values are first cast to `String` unless already text, then encoded
+    /// with that index type. The field's `ordered` flag carries over between
+    /// dictionary types and is otherwise cleared. The field also becomes nullable
+    /// if the cast introduced nulls, e.g. from text that does not parse.
+    ///
+    /// # Errors
+    /// - Any error from `Array::cast`.
+    /// - `Overflow` if a dictionary does not fit the target index type.
+    pub fn cast(&self, target: ArrowType) -> Result<FieldArray, MinarrowError> {
+        let array = match &target {
+            ArrowType::Dictionary(index) => {
+                let text = match &self.array {
+                    Array::TextArray(text) => text.clone(),
+                    other => other.cast(ArrowType::String)?.str(),
+                };
+                match index {
+                    #[cfg(feature = "default_categorical_8")]
+                    CategoricalIndexType::UInt8 => Array::from_categorical8(text.cat8()?),
+                    #[cfg(feature = "extended_categorical")]
+                    CategoricalIndexType::UInt16 => Array::from_categorical16(text.cat16()?),
+                    #[cfg(any(
+                        not(feature = "default_categorical_8"),
+                        feature = "extended_categorical"
+                    ))]
+                    CategoricalIndexType::UInt32 => Array::from_categorical32(text.cat32()?),
+                    #[cfg(feature = "extended_categorical")]
+                    CategoricalIndexType::UInt64 => Array::from_categorical64(text.cat64()?),
+                }
+            }
+            _ => self.array.cast(target.clone())?,
+        };
+        let null_count = array.null_count();
+        let was_dictionary = matches!(self.field.dtype, ArrowType::Dictionary(_));
+        let is_dictionary = matches!(target, ArrowType::Dictionary(_));
+        let mut field = (*self.field).clone();
+        field.ordered = field.ordered && was_dictionary && is_dictionary;
+        field.nullable = field.nullable || null_count > 0;
+        field.dtype = target;
+        Ok(FieldArray {
+            field: Arc::new(field),
+            array,
+            null_count,
+        })
+    }
+
     /// Returns a zero-copy view (`FieldArraySlice`) into the window `[offset, offset+len)`.
     ///
     /// The returned object holds references into the original `FieldArray`.
@@ -378,6 +427,7 @@ pub fn create_field_for_array(
             TemporalArray::Null => ArrowType::Null,
         },
         Array::BooleanArray(_) => ArrowType::Boolean,
+        Array::FixedSizeBinaryArray(a) => a.arrow_type(),
         Array::Null => ArrowType::Null,
     };
 
@@ -486,7 +536,9 @@ impl Concatenate for FieldArray {
     ///   - Same nullability
     ///
     /// # Returns
-    /// A new FieldArray with the concatenated array data
+    /// A new FieldArray with the concatenated array data. If a categorical
+    /// widens to a larger code type, the field's dtype follows it and the rest
+    /// of the field, including `ordered`, is kept.
     ///
     /// # Errors
     /// - `IncompatibleTypeError` if field metadata doesn't match
@@ -529,9 +581,17 @@ impl Concatenate for FieldArray {
         let concatenated_array = self.array.concat(other.array)?;
         let null_count = concatenated_array.null_count();
 
+        // A categorical whose merged dictionary outgrew its code type comes back
+        // wider. Retype the field, keeping its name, metadata and ordered flag.
+        let mut field = self.field;
+        let dtype = concatenated_array.arrow_type();
+        if matches!(field.dtype, ArrowType::Dictionary(_)) && field.dtype != dtype {
+            Arc::make_mut(&mut field).dtype = dtype;
+        }
+
         // Create result FieldArray with the same field metadata
         Ok(FieldArray {
-            field: self.field,
+            field,
             array: concatenated_array,
             null_count,
         })
@@ -1254,6 +1314,58 @@ mod tests {
         assert_eq!(field_array.array, array.into());
     }
 
+    #[test]
+    fn test_field_array_cast() {
+        let mut meta = BTreeMap::new();
+        meta.insert("unit".to_string(), "m".to_string());
+        let fa = FieldArray::new(
+            Field::new("dist", ArrowType::Int32, false, Some(meta.clone())),
+            Array::from_int32(IntegerArray::from_slice(&[1, 2, 3])),
+        );
+
+        let wide = fa.cast(ArrowType::Float64).unwrap();
+        assert_eq!(wide.field.name, "dist");
+        assert_eq!(wide.field.dtype, ArrowType::Float64);
+        assert_eq!(wide.array.arrow_type(), ArrowType::Float64);
+        assert_eq!(wide.field.metadata, meta);
+        assert!(!wide.field.nullable);
+
+        // Unparseable text becomes null, so the field turns nullable
+        let text = FieldArray::from_arr("n", crate::StringArray::<u32>::from_slice(&["1", "x"]));
+        let parsed = text.cast(ArrowType::Int64).unwrap();
+        assert!(parsed.field.nullable);
+        assert_eq!(parsed.null_count(), 1);
+
+        assert!(matches!(
+            fa.cast(ArrowType::Boolean),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+
+    #[cfg(any(
+        not(feature = "default_categorical_8"),
+        feature = "extended_categorical"
+    ))]
+    #[test]
+    fn test_field_array_cast_dictionary() {
+        let dict = ArrowType::Dictionary(CategoricalIndexType::UInt32);
+        let fa = FieldArray::new(
+            Field::new("code", ArrowType::Int32, true, None),
+            Array::from_int32(IntegerArray::from_slice(&[7, 3, 7])),
+        );
+        let cat = fa.cast(dict.clone()).unwrap();
+        assert_eq!(cat.field.dtype, dict);
+        assert_eq!(cat.array.arrow_type(), dict);
+        assert!(!cat.field.ordered);
+        let codes = cat.array.str_ref().unwrap().cat32_ref().unwrap();
+        assert_eq!(codes.unique_values.as_slice(), &["7", "3"]);
+        assert_eq!(codes.get_str(2), Some("7"));
+
+        let back = cat.cast(ArrowType::String).unwrap();
+        assert_eq!(back.field.dtype, ArrowType::String);
+        assert_eq!(back.array.arrow_type(), ArrowType::String);
+    }
+
     #[test]
     fn test_field_array_from_parts_infers_nullability() {
         let mut arr = IntegerArray::<i64>::default();
@@ -1495,6 +1607,34 @@ mod concat_tests {
             panic!("Expected IncompatibleTypeError");
         }
     }
+
+    #[cfg(feature = "extended_categorical")]
+    #[test]
+    fn test_field_array_concat_widened_categorical_keeps_ordering() {
+        use crate::CategoricalArray;
+
+        let names: Vec<String> = (0..70_000).map(|i| format!("v{}", i)).collect();
+        let ordered = |values: &[String]| {
+            let arr = CategoricalArray::<u16>::from_values(values.iter().map(String::as_str));
+            let dtype = ArrowType::Dictionary(CategoricalIndexType::UInt16);
+            let field = Field::new("level", dtype, false, None).with_ordered(true);
+            FieldArray::new(field, Array::from_categorical16(arr))
+        };
+        let out = ordered(&names[..40_000])
+            .concat(ordered(&names[40_000..]))
+            .unwrap();
+
+        assert!(matches!(
+            out.array,
+            Array::TextArray(TextArray::Categorical32(_))
+        ));
+        assert_eq!(
+            out.field.dtype,
+            ArrowType::Dictionary(CategoricalIndexType::UInt32)
+        );
+        assert!(out.field.is_ordered_dictionary());
+        assert_eq!(out.len(), 70_000);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,257 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **GroupBy Module** - *Grouped Table Aggregation*
+//!
+//! Provides `GroupBy`, returned by `Table::group_by`, which collapses a table
+//! to one row per distinct key and aggregates the remaining columns.
+
+use std::collections::HashMap;
+
+use crate::enums::aggregate::AggFn;
+use crate::enums::error::MinarrowError;
+use crate::structs::table::{KeyColumn, KeyValue, agg_columns};
+use crate::{FieldArray, Table};
+
+/// # GroupBy
+///
+/// Pending grouped aggregation over a borrowed `Table`, created by `Table::group_by`.
+///
+/// Key columns may be integer, string or categorical, and rows are grouped by
+/// hashing the tuple of their key values. Null keys form their own group.
+/// Call `agg` to produce the result.
+#[derive(Debug, Clone)]
+pub struct GroupBy<'a> {
+    table: &'a Table,
+    keys: Vec<String>,
+}
+
+impl<'a> GroupBy<'a> {
+    pub(crate) fn new(table: &'a Table, keys: &[&str]) -> Self {
+        Self {
+            table,
+            keys: keys.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// Aggregates each `(column, function)` pair per group.
+    ///
+    /// The result holds the key columns, one row per distinct key in order of
+    /// first appearance, followed by one column per aggregation named
+    /// `<column>_<function>`, e.g. `price_sum`, typed as described on [`AggFn`].
+    /// Value columns must be numeric.
+    /// Null values are skipped, so `Count` gives the number of non-null values and
+    /// the other functions are null for groups without any.
+    ///
+    /// # Errors
+    /// - `IndexError` if a key or value column does not exist.
+    /// - `TypeError` if a key column is not integer or text, or a value column is not numeric.
+    pub fn agg(&self, aggs: &[(&str, AggFn)]) -> Result<Table, MinarrowError> {
+        let table = self.table;
+        let key_idx = self
+            .keys
+            .iter()
+            .map(|k| col_index(table, k))
+            .collect::<Result<Vec<_>, _>>()?;
+        let key_cols = key_idx
+            .iter()
+            .map(|&c| KeyColumn::from_array(&table.cols[c].array))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Assign each row to a group, in order of first appearance.
+        let mut lookup: HashMap<Vec<Option<KeyValue<'_>>>, usize> = HashMap::new();
+        let mut head_rows = Vec::new();
+        let mut row_cells = Vec::with_capacity(table.n_rows);
+        for row in 0..table.n_rows {
+            let key: Vec<_> = key_cols.iter().map(|k| k.get(row)).collect();
+            let group = *lookup.entry(key).or_insert_with(|| {
+                head_rows.push(row);
+                head_rows.len() - 1
+            });
+            row_cells.push(Some(group));
+        }
+
+        let mut cols: Vec<FieldArray> = key_idx
+            .iter()
+            .map(|&c| {
+                let fa = &table.cols[c];
+                FieldArray::new_arc(fa.field.clone(), fa.array.take(&head_rows))
+            })
+            .collect();
+
+        let n_groups = head_rows.len();
+        for &(name, agg) in aggs {
+            let c = col_index(table, name)?;
+            let vals = table.cols[c].array.num_ref()?;
+            let out_name = format!("{}_{}", name, agg_suffix(agg));
+            cols.extend(agg_columns(&[&out_name], n_groups, agg, vals, &row_cells)?);
+        }
+
+        Ok(Table::build(cols, head_rows.len(), table.name.clone()))
+    }
+}
+
+fn col_index(table: &Table, name: &str) -> Result<usize, MinarrowError> {
+    table
+        .col_name_index(name)
+        .ok_or_else(|| MinarrowError::IndexError(format!("group_by: column '{}' not found", name)))
+}
+
+fn agg_suffix(agg: AggFn) -> &'static str {
+    match agg {
+        AggFn::Sum => "sum",
+        AggFn::Min => "min",
+        AggFn::Max => "max",
+        AggFn::Count => "count",
+        AggFn::Mean => "mean",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::masked_array::MaskedArray;
+    use crate::{Array, NumericArray, fa_f64, fa_i32, fa_str32};
+
+    fn f64_values(t: &Table, col: usize) -> Vec<Option<f64>> {
+        match &t.cols[col].array {
+            Array::NumericArray(NumericArray::Float64(arr)) => {
+                (0..arr.len()).map(|i| arr.get(i)).collect()
+            }
+            _ => panic!("wrong type"),
+        }
+    }
+
+    #[test]
+    fn test_group_by_agg() {
+        let t = Table::new_empty()
+            .with_column(fa_str32!("region", "n", "s", "n", "s", "e"))
+            .with_column(fa_i32!("year", 1, 1, 1, 2, 1))
+            .with_column(crate::fa_f64_opt!(
+                "sales",
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                None
+            ));
+
+        let out = t
+            .group_by(&["region"])
+            .agg(&[
+                ("sales", AggFn::Sum),
+                ("sales", AggFn::Mean),
+                ("sales", AggFn::Count),
+            ])
+            .unwrap();
+        assert_eq!(
+            out.col_names(),
+            vec!["region", "sales_sum", "sales_mean", "sales_count"]
+        );
+        assert_eq!(out.n_rows(), 3);
+        assert_eq!(f64_values(&out, 1), vec![Some(4.0), Some(6.0), None]);
+        assert_eq!(f64_values(&out, 2), vec![Some(2.0), Some(3.0), None]);
+        match &out.cols[3].array {
+            Array::NumericArray(NumericArray::UInt64(arr)) => {
+                assert_eq!(arr.data.as_slice(), &[2, 2, 0])
+            }
+            _ => panic!("wrong type"),
+        }
+
+        let out = t
+            .group_by(&["region", "year"])
+            .agg(&[("sales", AggFn::Max)])
+            .unwrap();
+        assert_eq!(out.n_rows(), 4);
+        assert_eq!(
+            f64_values(&out, 2),
+            vec![Some(3.0), Some(2.0), Some(4.0), None]
+        );
+    }
+
+    #[test]
+    fn test_group_by_agg_keeps_integer_types() {
+        let big = u64::MAX - 1;
+        let t = Table::new_empty()
+            .with_column(fa_i32!("k", 1, 1, 2))
+            .with_column(crate::fa_u64!("v", big, 1, big))
+            .with_column(fa_i32!("w", -3, 7, 2));
+
+        let out = t
+            .group_by(&["k"])
+            .agg(&[
+                ("v", AggFn::Sum),
+                ("v", AggFn::Max),
+                ("w", AggFn::Min),
+                ("w", AggFn::Mean),
+            ])
+            .unwrap();
+        let num = |c: usize| out.cols[c].array.num_ref().unwrap();
+        assert_eq!(num(1).u64_ref().unwrap().data.as_slice(), &[u64::MAX, big]);
+        assert_eq!(num(2).u64_ref().unwrap().data.as_slice(), &[big, big]);
+        assert_eq!(num(3).i32_ref().unwrap().data.as_slice(), &[-3, 2]);
+        assert_eq!(f64_values(&out, 4), vec![Some(2.0), Some(2.0)]);
+
+        let sums = t.group_by(&["k"]).agg(&[("w", AggFn::Sum)]).unwrap();
+        assert_eq!(sums.cols[1].field.dtype, crate::ArrowType::Int64);
+    }
+
+    #[test]
+    fn test_group_by_u64_keys() {
+        let big = u64::MAX - 1;
+        let t = Table::new_empty()
+            .with_column(crate::fa_u64!("k", big, 1, big))
+            .with_column(fa_i32!("w", -3, 7, 2));
+
+        let out = t.group_by(&["k"]).agg(&[("w", AggFn::Count)]).unwrap();
+        assert_eq!(out.n_rows(), 2);
+        let keys = out.cols[0].array.num_ref().unwrap();
+        assert_eq!(keys.u64_ref().unwrap().data.as_slice(), &[big, 1]);
+    }
+
+    #[test]
+    fn test_group_by_sum_overflow_is_null() {
+        let t = Table::new_empty()
+            .with_column(fa_i32!("k", 1, 1, 2, 2))
+            .with_column(crate::fa_i64!("v", i64::MAX, i64::MAX, i64::MAX, -1));
+
+        let out = t
+            .group_by(&["k"])
+            .agg(&[("v", AggFn::Sum), ("v", AggFn::Mean)])
+            .unwrap();
+        let sums = out.cols[1].array.num_ref().unwrap();
+        let sums = sums.i64_ref().unwrap();
+        assert_eq!(sums.get(0), None);
+        assert_eq!(sums.get(1), Some(i64::MAX - 1));
+        assert_eq!(
+            f64_values(&out, 2),
+            vec![Some(i64::MAX as f64), Some((i64::MAX - 1) as f64 / 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_group_by_errors() {
+        let t = Table::new_empty()
+            .with_column(fa_i32!("k", 1, 2))
+            .with_column(fa_f64!("v", 1.0, 2.0));
+        assert!(matches!(
+            t.group_by(&["missing"]).agg(&[("v", AggFn::Sum)]),
+            Err(MinarrowError::IndexError(_))
+        ));
+        assert!(matches!(
+            t.group_by(&["v"]).agg(&[("k", AggFn::Sum)]),
+            Err(MinarrowError::TypeError { .. })
+        ));
+    }
+}
@@ -18,6 +18,8 @@
 //! BLAS/LAPACK compatible with built-inconversions from `Table` data.
 
 use std::fmt;
+#[cfg(feature = "simd")]
+use std::simd::Simd;
 use std::sync::Arc;
 
 use crate::enums::error::MinarrowError;
@@ -94,6 +96,49 @@ const fn aligned_stride(n_rows: usize) -> usize {
     (n_rows + ALIGN_ELEMS - 1) & !(ALIGN_ELEMS - 1)
 }
 
+/// Direction of a `Matrix` reduction.
+///
+/// - `Row` reduces across each row, yielding one value per row (`n_rows` values).
+/// - `Col` reduces down each column, yielding one value per column (`n_cols` values).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+#[cfg(feature = "simd")]
+include!(concat!(env!("OUT_DIR"), "/simd_lanes.rs"));
+
+/// Tile edge in elements for the blocked `matmul` and `transpose` loops.
+/// A 64 x 64 tile of f64 is 32 KiB, which sits in a typical L1 data cache.
+const BLOCK: usize = 64;
+
+/// `dst[i] += src[i] * s` over equal-length slices.
+///
+/// Each lane is an independent multiply-add, so the SIMD and scalar paths
+/// produce identical results.
+#[inline(always)]
+fn axpy(dst: &mut [f64], src: &[f64], s: f64) {
+    debug_assert_eq!(dst.len(), src.len());
+    #[cfg(feature = "simd")]
+    {
+        let sv = Simd::<f64, W64>::splat(s);
+        let mut dst_chunks = dst.chunks_exact_mut(W64);
+        let mut src_chunks = src.chunks_exact(W64);
+        for (d, x) in (&mut dst_chunks).zip(&mut src_chunks) {
+            (Simd::from_slice(d) + Simd::from_slice(x) * sv).copy_to_slice(d);
+        }
+        let dst_tail = dst_chunks.into_remainder();
+        for (d, &x) in dst_tail.iter_mut().zip(src_chunks.remainder()) {
+            *d += x * s;
+        }
+    }
+    #[cfg(not(feature = "simd"))]
+    for (d, &x) in dst.iter_mut().zip(src) {
+        *d += x * s;
+    }
+}
+
 impl Matrix {
     /// Constructs a new dense Matrix with shape and optional name.
     /// Data buffer is zeroed. Columns are padded to 64-byte alignment.
@@ -306,19 +351,128 @@ impl Matrix {
 
     /// Transpose this matrix, returning a new Matrix with rows and columns swapped.
     /// The result has stride-aligned columns for SIMD access.
+    ///
+    /// Copies in square tiles so both the strided reads and the strided writes
+    /// stay within a cache-resident block.
     pub fn transpose(&self) -> Self {
         let mut dst = Matrix::new(self.n_cols, self.n_rows, self.name.clone());
         let src = self.data.as_slice();
         let dst_stride = dst.stride;
         let dst_slice = dst.data.as_mut_slice();
-        for j in 0..self.n_cols {
-            for i in 0..self.n_rows {
-                dst_slice[i * dst_stride + j] = src[j * self.stride + i];
+        for j0 in (0..self.n_cols).step_by(BLOCK) {
+            let j1 = (j0 + BLOCK).min(self.n_cols);
+            for i0 in (0..self.n_rows).step_by(BLOCK) {
+                let i1 = (i0 + BLOCK).min(self.n_rows);
+                for j in j0..j1 {
+                    for i in i0..i1 {
+                        dst_slice[i * dst_stride + j] = src[j * self.stride + i];
+                    }
+                }
             }
         }
         dst
     }
 
+    /// Matrix product `self * other`, returning a new `n_rows x other.n_cols` Matrix.
+    ///
+    /// Each output column is built as a sum of `self`'s columns scaled by the
+    /// matching column of `other`, so the inner loop runs over contiguous,
+    /// aligned column slices. Rows and the shared dimension are blocked so a
+    /// tile of `self` stays in cache while every column of `other` is applied.
+    ///
+    /// # Errors
+    /// - `ShapeError` if `self.n_cols != other.n_rows`
+    pub fn matmul(&self, other: &Matrix) -> Result<Matrix, MinarrowError> {
+        if self.n_cols != other.n_rows {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "matmul: inner dimensions differ ({} x {} * {} x {})",
+                    self.n_rows, self.n_cols, other.n_rows, other.n_cols
+                ),
+            });
+        }
+        let (m, k, n) = (self.n_rows, self.n_cols, other.n_cols);
+        let mut dst = Matrix::new(m, n, None);
+        let a = self.data.as_slice();
+        let b = other.data.as_slice();
+        let (lda, ldb, ldc) = (self.stride, other.stride, dst.stride);
+        let c = dst.data.as_mut_slice();
+        for i0 in (0..m).step_by(BLOCK) {
+            let i1 = (i0 + BLOCK).min(m);
+            for p0 in (0..k).step_by(BLOCK) {
+                let p1 = (p0 + BLOCK).min(k);
+                for j in 0..n {
+                    let c_col = &mut c[j * ldc + i0..j * ldc + i1];
+                    for p in p0..p1 {
+                        axpy(c_col, &a[p * lda + i0..p * lda + i1], b[j * ldb + p]);
+                    }
+                }
+            }
+        }
+        Ok(dst)
+    }
+
+    // ********************** Axis reductions **********************
+
+    /// Folds every value along `axis`, starting each output slot at `init`.
+    ///
+    /// Row reductions walk the columns in storage order and fold each one
+    /// into the running per-row results, so every pass is a contiguous scan.
+    fn fold_axis(&self, axis: Axis, init: f64, f: impl Fn(f64, f64) -> f64) -> Vec64<f64> {
+        match axis {
+            Axis::Row => {
+                let mut out = Vec64::with_capacity(self.n_rows);
+                out.resize(self.n_rows, init);
+                for j in 0..self.n_cols {
+                    for (acc, &v) in out.iter_mut().zip(self.col(j)) {
+                        *acc = f(*acc, v);
+                    }
+                }
+                out
+            }
+            Axis::Col => (0..self.n_cols)
+                .map(|j| self.col(j).iter().fold(init, |acc, &v| f(acc, v)))
+                .collect(),
+        }
+    }
+
+    /// Number of values folded into each slot of an `axis` reduction.
+    #[inline]
+    fn axis_len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Row => self.n_cols,
+            Axis::Col => self.n_rows,
+        }
+    }
+
+    /// Sums along `axis`. Matrix is dense, so every value counts; a `NaN`
+    /// propagates into its row or column sum.
+    pub fn sum_axis(&self, axis: Axis) -> FloatArray<f64> {
+        FloatArray::new(self.fold_axis(axis, 0.0, |a, v| a + v), None)
+    }
+
+    /// Arithmetic mean along `axis`. Reducing over zero values yields `NaN`.
+    pub fn mean_axis(&self, axis: Axis) -> FloatArray<f64> {
+        let n = self.axis_len(axis) as f64;
+        let mut sums = self.fold_axis(axis, 0.0, |a, v| a + v);
+        for v in sums.iter_mut() {
+            *v /= n;
+        }
+        FloatArray::new(sums, None)
+    }
+
+    /// Minimum along `axis`, ignoring `NaN` as the aggregate kernels do.
+    /// A row or column with no non-`NaN` values yields `NaN`.
+    pub fn min_axis(&self, axis: Axis) -> FloatArray<f64> {
+        FloatArray::new(self.fold_axis(axis, f64::NAN, f64::min), None)
+    }
+
+    /// Maximum along `axis`, ignoring `NaN` as the aggregate kernels do.
+    /// A row or column with no non-`NaN` values yields `NaN`.
+    pub fn max_axis(&self, axis: Axis) -> FloatArray<f64> {
+        FloatArray::new(self.fold_axis(axis, f64::NAN, f64::max), None)
+    }
+
     /// Extract rows by index into a new Matrix.
     /// Column stride alignment is maintained in the result.
     pub fn extract_rows(&self, indices: &[usize]) -> Self {
@@ -858,6 +1012,143 @@ impl IntoIterator for Matrix {
     }
 }
 
+#[cfg(test)]
+mod linalg_tests {
+    use super::*;
+
+    /// Row-major helper so expected values read the way they are written.
+    fn from_rows(rows: &[&[f64]]) -> Matrix {
+        let n_rows = rows.len();
+        let n_cols = rows[0].len();
+        let mut m = Matrix::new(n_rows, n_cols, None);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                m.set(i, j, v);
+            }
+        }
+        m
+    }
+
+    /// Unblocked reference product.
+    fn naive_matmul(a: &Matrix, b: &Matrix) -> Matrix {
+        let mut c = Matrix::new(a.n_rows, b.n_cols, None);
+        for i in 0..a.n_rows {
+            for j in 0..b.n_cols {
+                let mut acc = 0.0;
+                for p in 0..a.n_cols {
+                    acc += a.get(i, p) * b.get(p, j);
+                }
+                c.set(i, j, acc);
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn matmul_small() {
+        let a = from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]);
+        let b = from_rows(&[&[7.0, 8.0], &[9.0, 10.0], &[11.0, 12.0]]);
+        let c = a.matmul(&b).unwrap();
+        assert_eq!((c.n_rows, c.n_cols), (2, 2));
+        assert_eq!(c.row(0), vec![58.0, 64.0]);
+        assert_eq!(c.row(1), vec![139.0, 154.0]);
+    }
+
+    #[test]
+    fn matmul_matches_naive_across_blocks() {
+        // Dimensions straddle BLOCK and the SIMD width so every tail path runs.
+        let (m, k, n) = (BLOCK + 3, BLOCK + 5, 7);
+        let mut a = Matrix::new(m, k, None);
+        let mut b = Matrix::new(k, n, None);
+        for i in 0..m {
+            for p in 0..k {
+                a.set(i, p, ((i * 7 + p * 3) % 11) as f64 - 5.0);
+            }
+        }
+        for p in 0..k {
+            for j in 0..n {
+                b.set(p, j, ((p * 5 + j) % 13) as f64 * 0.5);
+            }
+        }
+        // Small integers and halves keep every partial sum exact.
+        assert_eq!(a.matmul(&b).unwrap(), naive_matmul(&a, &b));
+    }
+
+    #[test]
+    fn matmul_identity_and_empty_inner() {
+        let a = from_rows(&[&[1.5, -2.0], &[0.25, 4.0], &[3.0, 1.0]]);
+        let eye = from_rows(&[&[1.0, 0.0], &[0.0, 1.0]]);
+        assert_eq!(a.matmul(&eye).unwrap(), a);
+
+        let empty = Matrix::new(0, 3, None);
+        let c = Matrix::new(2, 0, None).matmul(&empty).unwrap();
+        assert_eq!((c.n_rows, c.n_cols), (2, 3));
+        assert!(c.columns().iter().all(|col| col.iter().all(|&v| v == 0.0)));
+    }
+
+    #[test]
+    fn matmul_rejects_inner_mismatch() {
+        let a = Matrix::new(2, 3, None);
+        let b = Matrix::new(2, 3, None);
+        let err = a.matmul(&b).expect_err("2x3 * 2x3 must reject");
+        assert!(matches!(err, MinarrowError::ShapeError { .. }));
+    }
+
+    #[test]
+    fn axis_reductions_have_one_value_per_row_or_col() {
+        let (r, c) = (5, 3);
+        let mut m = Matrix::new(r, c, None);
+        for i in 0..r {
+            for j in 0..c {
+                m.set(i, j, (i * c + j) as f64);
+            }
+        }
+        let rows = m.sum_axis(Axis::Row);
+        let cols = m.sum_axis(Axis::Col);
+        assert_eq!(rows.data.len(), r);
+        assert_eq!(cols.data.len(), c);
+        assert!(rows.null_mask.is_none() && cols.null_mask.is_none());
+        // Row i holds 3i, 3i + 1, 3i + 2.
+        assert_eq!(rows.data.as_slice(), &[3.0, 12.0, 21.0, 30.0, 39.0]);
+        // Column j holds j, j + 3, ..., j + 12.
+        assert_eq!(cols.data.as_slice(), &[30.0, 35.0, 40.0]);
+        assert_eq!(m.mean_axis(Axis::Col).data.as_slice(), &[6.0, 7.0, 8.0]);
+        let row_means = m.mean_axis(Axis::Row);
+        assert_eq!(row_means.data.as_slice(), &[1.0, 4.0, 7.0, 10.0, 13.0]);
+        assert_eq!(m.min_axis(Axis::Col).data.as_slice(), &[0.0, 1.0, 2.0]);
+        let row_maxes = m.max_axis(Axis::Row);
+        assert_eq!(row_maxes.data.as_slice(), &[2.0, 5.0, 8.0, 11.0, 14.0]);
+    }
+
+    #[test]
+    fn axis_min_max_skip_nan() {
+        let m = from_rows(&[&[f64::NAN, 2.0], &[-1.0, f64::NAN]]);
+        assert_eq!(m.min_axis(Axis::Row).data.as_slice(), &[2.0, -1.0]);
+        assert_eq!(m.max_axis(Axis::Col).data.as_slice(), &[-1.0, 2.0]);
+        assert!(m.sum_axis(Axis::Row).data.iter().all(|v| v.is_nan()));
+
+        let empty = Matrix::new(2, 0, None);
+        assert!(empty.min_axis(Axis::Row).data.iter().all(|v| v.is_nan()));
+        assert!(empty.mean_axis(Axis::Row).data.iter().all(|v| v.is_nan()));
+        assert!(empty.sum_axis(Axis::Col).data.is_empty());
+    }
+
+    #[test]
+    fn transpose_round_trips_across_blocks() {
+        let (r, c) = (BLOCK + 9, 3);
+        let mut m = Matrix::new(r, c, Some("m".into()));
+        for i in 0..r {
+            for j in 0..c {
+                m.set(i, j, (i * c + j) as f64);
+            }
+        }
+        let t = m.transpose();
+        assert_eq!((t.n_rows, t.n_cols), (c, r));
+        assert_eq!(t.get(2, BLOCK + 1), m.get(BLOCK + 1, 2));
+        assert_eq!(t.transpose(), m);
+    }
+}
+
 #[cfg(all(test, feature = "views"))]
 mod try_as_matrix_zc_tests {
     use super::*;
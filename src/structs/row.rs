@@ -0,0 +1,111 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **Row Module** - *Row-wise Access to Columnar Tables*
+//!
+//! Provides `Row`, yielded by `Table::row_iter` and `SuperTable::row_iter`,
+//! for streaming records out one at a time when throughput is not critical.
+
+use crate::{Scalar, Table};
+
+/// # Row
+///
+/// A single logical row of a `Table`, borrowing its columns.
+///
+/// Values are read on demand as `Scalar`s, with nulls as a `Scalar::Null` of the
+/// column's type. Prefer whole-column kernels for bulk work, since each access
+/// dispatches on the column type.
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    table: &'a Table,
+    idx: usize,
+}
+
+impl<'a> Row<'a> {
+    pub(crate) fn new(table: &'a Table, idx: usize) -> Self {
+        Self { table, idx }
+    }
+
+    /// Position of this row within the table, or batch, it was read from.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// Number of columns in the row.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.n_cols()
+    }
+
+    /// Whether the row has no columns.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Value of column `col`, or `None` if there is no such column or its type,
+    /// e.g. fixed-size binary, has no `Scalar` counterpart.
+    pub fn get(&self, col: usize) -> Option<Scalar> {
+        self.table.cols.get(col)?.array.get_scalar(self.idx)
+    }
+
+    /// Value of the column called `name`, as in `get`.
+    pub fn get_by_name(&self, name: &str) -> Option<Scalar> {
+        self.get(self.table.col_name_index(name)?)
+    }
+
+    /// Column names, in the same order as the values.
+    pub fn col_names(&self) -> Vec<&'a str> {
+        self.table.col_names()
+    }
+
+    /// Every value in column order, using `Scalar::Null` for values without
+    /// a `Scalar` counterpart.
+    pub fn to_vec(&self) -> Vec<Scalar> {
+        (0..self.len())
+            .map(|c| {
+                self.get(c)
+                    .unwrap_or_else(|| Scalar::Null(self.table.cols[c].field.dtype.clone()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Scalar, Table, fa_i32, fa_str32};
+
+    #[test]
+    fn test_row_access() {
+        let t = Table::new_empty()
+            .with_column(fa_i32!("id", 7, 8))
+            .with_column(fa_str32!("name", "a", "b"));
+        let rows: Vec<_> = t.row_iter().collect();
+        assert_eq!(rows.len(), 2);
+
+        let row = rows[1];
+        assert_eq!(row.index(), 1);
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.get(0), Some(Scalar::Int32(8)));
+        assert_eq!(row.get_by_name("name"), Some(Scalar::String32("b".into())));
+        assert_eq!(row.get(2), None);
+        assert_eq!(row.get_by_name("missing"), None);
+        assert_eq!(row.col_names(), vec!["id", "name"]);
+        assert_eq!(
+            row.to_vec(),
+            vec![Scalar::Int32(8), Scalar::String32("b".into())]
+        );
+    }
+}
@@ -37,6 +37,20 @@
 //! // Same physical memory, zero copy!
 //! ```
 //!
+//! ## Handing off over a Unix socket
+//! Instead of `reopen`, which goes through `/proc`, the fd itself can be sent:
+//! ```rust,ignore
+//! // Parent: fill the buffer, then freeze it so the receiver can trust it
+//! memfd.as_mut_slice().copy_from_slice(&bytes);
+//! memfd.seal()?;
+//! send_fd(&socket, memfd.as_raw_fd())?;  // SCM_RIGHTS, e.g. via `sendmsg`
+//!
+//! // Child: take ownership of the received fd and map the same pages
+//! let memfd = unsafe { MemfdBuffer::from_raw_fd(received_fd, len)? };
+//! assert!(memfd.is_sealed());
+//! let shared = SharedBuffer::from_memfd_owner(memfd);
+//! ```
+//!
 //! ## Alignment
 //! Buffers are guaranteed 64-byte aligned for SIMD operations.
 //! Extra space is allocated and an offset computed to achieve alignment.
@@ -63,6 +77,7 @@ use std::os::unix::io::{AsRawFd, RawFd};
 /// - When dropped, the mmap is unmapped and fd is closed
 /// - If other processes have mmap'd the fd, their mappings remain valid
 ///   (kernel keeps the memfd alive until all references are gone)
+/// - `seal()` makes the contents immutable for every holder of the fd
 pub struct MemfdBuffer {
     /// Raw pointer to the aligned data region
     ptr: *mut u8,
@@ -72,6 +87,8 @@ pub struct MemfdBuffer {
     mmap_len: usize,
     /// File descriptor for the memfd (for sharing with other processes)
     fd: RawFd,
+    /// Whether the mapping is writable, false once sealed or for read-only fds
+    writable: bool,
 }
 
 // SAFETY: The memfd memory is anonymous and can be safely shared between threads.
@@ -111,8 +128,8 @@ impl MemfdBuffer {
             io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid name: {}", e))
         })?;
 
-        // Create memfd
-        let fd = unsafe { libc::memfd_create(c_name.as_ptr(), 0) };
+        // Create memfd, allowing `seal()` later
+        let fd = unsafe { libc::memfd_create(c_name.as_ptr(), libc::MFD_ALLOW_SEALING) };
         if fd < 0 {
             return Err(io::Error::last_os_error());
         }
@@ -152,6 +169,7 @@ impl MemfdBuffer {
             len: size,
             mmap_len: total_size,
             fd,
+            writable: true,
         })
     }
 
@@ -182,65 +200,192 @@ impl MemfdBuffer {
 
         let file = OpenOptions::new().read(true).write(true).open(&path)?;
 
-        // Get the actual size of the memfd
-        let metadata = file.metadata()?;
-        let total_size = metadata.len() as usize;
+        // Duplicate the fd so we own it once `file` closes
+        let owned_fd = unsafe { libc::dup(file.as_raw_fd()) };
+        if owned_fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
 
-        if total_size < size {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "Memfd too small: expected at least {} bytes, got {}",
-                    size, total_size
+        // Safety: `owned_fd` is a fresh duplicate that nothing else refers to
+        unsafe { Self::from_raw_fd(owned_fd, size) }
+    }
+
+    /// Wrap a memfd received from another process, taking ownership of `fd`.
+    ///
+    /// Maps the whole memfd, exposing its first `len` bytes. This is the receiving
+    /// end of a handoff over a Unix socket (`SCM_RIGHTS`), or of an fd inherited
+    /// across `fork`. The mapping is read-only if the memfd is sealed against
+    /// writes, or if `fd` was opened read-only.
+    ///
+    /// # Arguments
+    /// * `fd` - File descriptor of the memfd in this process
+    /// * `len` - Expected size of the usable buffer region
+    ///
+    /// # Safety
+    /// `fd` must be an open file descriptor that is not used or closed elsewhere
+    /// afterwards. It is closed when the buffer drops, or immediately on error.
+    ///
+    /// # Platform
+    /// Linux only
+    #[cfg(target_os = "linux")]
+    pub unsafe fn from_raw_fd(fd: RawFd, len: usize) -> io::Result<Self> {
+        fn fail<T>(fd: RawFd, err: io::Error) -> io::Result<T> {
+            unsafe { libc::close(fd) };
+            Err(err)
+        }
+
+        if len == 0 {
+            return fail(
+                fd,
+                io::Error::new(io::ErrorKind::InvalidInput, "Size must be greater than 0"),
+            );
+        }
+
+        // Get the actual size of the memfd
+        let mut stat: libc::stat = unsafe { std::mem::zeroed() };
+        if unsafe { libc::fstat(fd, &mut stat) } < 0 {
+            return fail(fd, io::Error::last_os_error());
+        }
+        let total_size = stat.st_size as usize;
+        if total_size < len {
+            return fail(
+                fd,
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Memfd too small: expected at least {} bytes, got {}",
+                        len, total_size
+                    ),
                 ),
-            ));
+            );
         }
 
-        // mmap the file
-        let our_fd = file.as_raw_fd();
+        // Sealed memfds and read-only fds cannot back a shared writable mapping
+        let seals = unsafe { libc::fcntl(fd, libc::F_GET_SEALS) };
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        let writable = (seals < 0 || seals & libc::F_SEAL_WRITE == 0)
+            && flags >= 0
+            && flags & libc::O_ACCMODE == libc::O_RDWR;
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+
         let mmap_ptr = unsafe {
             libc::mmap(
                 std::ptr::null_mut(),
                 total_size,
-                libc::PROT_READ | libc::PROT_WRITE,
+                prot,
                 libc::MAP_SHARED,
-                our_fd,
+                fd,
                 0,
             )
         };
-
         if mmap_ptr == libc::MAP_FAILED {
-            return Err(io::Error::last_os_error());
+            return fail(fd, io::Error::last_os_error());
         }
 
-        // Keep the fd open (don't let File drop close it)
-        let owned_fd = unsafe {
-            // Duplicate the fd so we own it
-            let dup_fd = libc::dup(our_fd);
-            if dup_fd < 0 {
-                libc::munmap(mmap_ptr, total_size);
-                return Err(io::Error::last_os_error());
-            }
-            dup_fd
-        };
-
-        // Calculate alignment offset (must match what new() computed)
-        let base_addr = mmap_ptr as usize;
-        let alignment_offset = (64 - (base_addr % 64)) % 64;
-        let aligned_ptr = unsafe { (mmap_ptr as *mut u8).add(alignment_offset) };
-
+        // mmap returns page-aligned memory, so no alignment offset is needed
         Ok(Self {
-            ptr: aligned_ptr,
-            len: size,
+            ptr: mmap_ptr as *mut u8,
+            len,
             mmap_len: total_size,
-            fd: owned_fd,
+            fd,
+            writable,
         })
     }
 
+    /// Seal the memfd so its contents can no longer change.
+    ///
+    /// Adds `F_SEAL_SHRINK`, `F_SEAL_GROW`, `F_SEAL_WRITE` and `F_SEAL_SEAL`, which
+    /// bind every process holding the fd, so a receiver that sees `is_sealed()`
+    /// can treat the buffer as immutable. This buffer is remapped read-only, and
+    /// `as_mut_slice` panics from then on.
+    ///
+    /// # Errors
+    /// Fails with `EBUSY` if another writable mapping of the memfd exists, such as
+    /// an unsealed `reopen` or `from_raw_fd` in any process, and with `EPERM` for
+    /// memfds not created with sealing allowed. The buffer stays writable on failure.
+    ///
+    /// # Platform
+    /// Linux only
+    #[cfg(target_os = "linux")]
+    pub fn seal(&mut self) -> io::Result<()> {
+        const SEALS: libc::c_int =
+            libc::F_SEAL_SHRINK | libc::F_SEAL_GROW | libc::F_SEAL_WRITE | libc::F_SEAL_SEAL;
+
+        if !self.writable {
+            return if self.is_sealed() {
+                Ok(())
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "Cannot seal a read-only memfd mapping",
+                ))
+            };
+        }
+
+        // The kernel refuses F_SEAL_WRITE while a shared writable mapping exists,
+        // so drop ours first and map again with the resulting protection
+        unsafe { libc::munmap(self.mmap_base(), self.mmap_len) };
+        let seal_err = if unsafe { libc::fcntl(self.fd, libc::F_ADD_SEALS, SEALS) } < 0 {
+            Some(io::Error::last_os_error())
+        } else {
+            None
+        };
+        let prot = if seal_err.is_none() {
+            libc::PROT_READ
+        } else {
+            libc::PROT_READ | libc::PROT_WRITE
+        };
+        let mmap_ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                self.mmap_len,
+                prot,
+                libc::MAP_SHARED,
+                self.fd,
+                0,
+            )
+        };
+        if mmap_ptr == libc::MAP_FAILED {
+            // Leave an empty buffer that Drop will not unmap
+            let err = io::Error::last_os_error();
+            self.ptr = std::ptr::NonNull::dangling().as_ptr();
+            self.len = 0;
+            self.mmap_len = 0;
+            self.writable = false;
+            return Err(err);
+        }
+        self.ptr = mmap_ptr as *mut u8;
+        self.writable = seal_err.is_some();
+        match seal_err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Check whether the memfd is sealed against writes, by this or any other process.
+    #[inline]
+    pub fn is_sealed(&self) -> bool {
+        let seals = unsafe { libc::fcntl(self.fd, libc::F_GET_SEALS) };
+        seals >= 0 && seals & libc::F_SEAL_WRITE != 0
+    }
+
+    /// Start of the underlying mapping.
+    #[inline]
+    fn mmap_base(&self) -> *mut libc::c_void {
+        let alignment_offset = self.ptr as usize % 64;
+        unsafe { self.ptr.sub(alignment_offset) as *mut libc::c_void }
+    }
+
     /// Get the raw file descriptor for sharing with other processes.
     ///
     /// The fd can be passed to child processes which can then call
-    /// `MemfdBuffer::reopen()` to access the same memory.
+    /// `MemfdBuffer::reopen()`, or sent over a Unix socket to be wrapped with
+    /// `MemfdBuffer::from_raw_fd()`, to access the same memory. Also available
+    /// through `AsRawFd`.
     #[inline]
     pub fn fd(&self) -> RawFd {
         self.fd
@@ -265,12 +410,23 @@ impl MemfdBuffer {
     }
 
     /// Get the data as a mutable slice.
+    ///
+    /// # Panics
+    /// If the buffer has been sealed or was mapped read-only.
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        assert!(self.writable, "MemfdBuffer is sealed or mapped read-only");
         unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 }
 
+impl AsRawFd for MemfdBuffer {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
 impl AsRef<[u8]> for MemfdBuffer {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -287,14 +443,11 @@ impl AsMut<[u8]> for MemfdBuffer {
 
 impl Drop for MemfdBuffer {
     fn drop(&mut self) {
-        // Calculate the original mmap base pointer
-        let base_addr = self.ptr as usize;
-        let alignment_offset = base_addr % 64;
-        let mmap_ptr = unsafe { self.ptr.sub(alignment_offset) };
-
-        // Unmap the memory
-        unsafe {
-            libc::munmap(mmap_ptr as *mut libc::c_void, self.mmap_len);
+        // Unmap the memory, unless a failed remap in `seal` already lost it
+        if self.mmap_len > 0 {
+            unsafe {
+                libc::munmap(self.mmap_base(), self.mmap_len);
+            }
         }
 
         // Close the fd
@@ -522,4 +675,197 @@ mod tests {
         let shared = SharedBuffer::from_vec(vec![1, 2, 3, 4]);
         assert_eq!(shared.memfd_fd(), None);
     }
+
+    #[test]
+    fn test_from_raw_fd_shares_pages() {
+        let mut memfd = MemfdBuffer::new("raw_fd_test", 256).expect("Failed to create memfd");
+        let dup_fd = unsafe { libc::dup(memfd.as_raw_fd()) };
+        assert!(dup_fd >= 0);
+        let received = unsafe { MemfdBuffer::from_raw_fd(dup_fd, 256) }.expect("Failed to wrap fd");
+        assert_eq!(received.as_raw_fd(), dup_fd);
+
+        // Writes through the original are visible through the received mapping
+        memfd.as_mut_slice()[..5].copy_from_slice(b"hello");
+        let shared = SharedBuffer::from_memfd_owner(received);
+        assert_eq!(&shared[..5], b"hello");
+        assert_eq!(shared.as_ptr() as usize % 64, 0);
+    }
+
+    #[test]
+    fn test_from_raw_fd_rejects_short_memfd() {
+        let memfd = MemfdBuffer::new("short_test", 64).expect("Failed to create memfd");
+        let dup_fd = unsafe { libc::dup(memfd.as_raw_fd()) };
+        assert!(unsafe { MemfdBuffer::from_raw_fd(dup_fd, 1 << 20) }.is_err());
+    }
+
+    #[test]
+    fn test_seal() {
+        let mut memfd = MemfdBuffer::new("seal_test", 128).expect("Failed to create memfd");
+        memfd.as_mut_slice()[..4].copy_from_slice(&[1, 2, 3, 4]);
+        assert!(!memfd.is_sealed());
+        memfd.seal().expect("Failed to seal memfd");
+        assert!(memfd.is_sealed());
+        assert_eq!(&memfd.as_slice()[..4], &[1, 2, 3, 4]);
+        assert_eq!(memfd.as_slice().as_ptr() as usize % 64, 0);
+
+        // The kernel now rejects resizing and writes through the fd
+        assert!(unsafe { libc::ftruncate(memfd.as_raw_fd(), 0) } < 0);
+        assert!(unsafe { libc::write(memfd.as_raw_fd(), [9u8].as_ptr().cast(), 1) } < 0);
+        memfd.seal().expect("Sealing twice should succeed");
+    }
+
+    #[test]
+    #[should_panic(expected = "sealed")]
+    fn test_sealed_rejects_mut_slice() {
+        let mut memfd = MemfdBuffer::new("seal_mut_test", 64).expect("Failed to create memfd");
+        memfd.seal().expect("Failed to seal memfd");
+        memfd.as_mut_slice()[0] = 1;
+    }
+
+    #[test]
+    fn test_seal_fails_with_other_writable_mapping() {
+        let mut memfd = MemfdBuffer::new("seal_busy_test", 64).expect("Failed to create memfd");
+        let dup_fd = unsafe { libc::dup(memfd.as_raw_fd()) };
+        let other = unsafe { MemfdBuffer::from_raw_fd(dup_fd, 64) }.expect("Failed to wrap fd");
+        assert!(memfd.seal().is_err());
+        assert!(!memfd.is_sealed());
+        memfd.as_mut_slice()[0] = 7;
+        assert_eq!(other.as_slice()[0], 7);
+    }
+
+    #[test]
+    fn test_sealed_handoff_to_forked_child() {
+        let mut memfd = MemfdBuffer::new("fork_test", 4096).expect("Failed to create memfd");
+        for (i, b) in memfd.as_mut_slice().iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        memfd.seal().expect("Failed to seal memfd");
+        let fd = memfd.as_raw_fd();
+
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0, "fork failed");
+        if pid == 0 {
+            // Child: the inherited fd stands in for one received over a socket
+            let ok = match unsafe { MemfdBuffer::from_raw_fd(libc::dup(fd), 4096) } {
+                Ok(received) => {
+                    received.is_sealed()
+                        && received
+                            .as_slice()
+                            .iter()
+                            .enumerate()
+                            .all(|(i, &b)| b == i as u8)
+                }
+                Err(_) => false,
+            };
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) };
+        }
+
+        let mut status = 0;
+        assert_eq!(unsafe { libc::waitpid(pid, &mut status, 0) }, pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+
+    /// Sends `fd` over a Unix socket as `SCM_RIGHTS` ancillary data.
+    fn send_fd(sock: RawFd, fd: RawFd) -> io::Result<()> {
+        let mut byte = [0u8; 1];
+        let mut iov = libc::iovec {
+            iov_base: byte.as_mut_ptr().cast(),
+            iov_len: 1,
+        };
+        let mut control = [0u64; 4];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = unsafe { libc::CMSG_SPACE(size_of::<RawFd>() as u32) } as _;
+        unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(size_of::<RawFd>() as u32) as _;
+            std::ptr::write_unaligned(libc::CMSG_DATA(cmsg).cast::<RawFd>(), fd);
+        }
+        if unsafe { libc::sendmsg(sock, &msg, 0) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Receives an fd sent with `send_fd`, as a new fd owned by this process.
+    fn recv_fd(sock: RawFd) -> io::Result<RawFd> {
+        let mut byte = [0u8; 1];
+        let mut iov = libc::iovec {
+            iov_base: byte.as_mut_ptr().cast(),
+            iov_len: 1,
+        };
+        let mut control = [0u64; 4];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = std::mem::size_of_val(&control) as _;
+        if unsafe { libc::recvmsg(sock, &mut msg, 0) } <= 0 {
+            return Err(io::Error::last_os_error());
+        }
+        unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            if cmsg.is_null() || (*cmsg).cmsg_type != libc::SCM_RIGHTS {
+                return Err(io::Error::from(io::ErrorKind::InvalidData));
+            }
+            Ok(std::ptr::read_unaligned(
+                libc::CMSG_DATA(cmsg).cast::<RawFd>(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_scm_rights_handoff_between_processes() {
+        let mut socks = [0; 2];
+        let rc =
+            unsafe { libc::socketpair(libc::AF_UNIX, libc::SOCK_STREAM, 0, socks.as_mut_ptr()) };
+        assert_eq!(rc, 0, "socketpair failed");
+
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0, "fork failed");
+        if pid == 0 {
+            // Child: read the parent's bytes through the received fd, then reply in place
+            let code = match recv_fd(socks[1]) {
+                Ok(fd) => match unsafe { MemfdBuffer::from_raw_fd(fd, 4096) } {
+                    Ok(mut received) => {
+                        let ok = received
+                            .as_slice()
+                            .iter()
+                            .enumerate()
+                            .all(|(i, &b)| b == i as u8);
+                        received.as_mut_slice()[..5].copy_from_slice(b"reply");
+                        if ok { 0 } else { 1 }
+                    }
+                    Err(_) => 2,
+                },
+                Err(_) => 3,
+            };
+            unsafe { libc::_exit(code) };
+        }
+
+        // Parent: the memfd is created after the fork, so the socket is the child's only way in
+        let mut memfd = MemfdBuffer::new("scm_rights_test", 4096).expect("Failed to create memfd");
+        for (i, b) in memfd.as_mut_slice().iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        send_fd(socks[0], memfd.as_raw_fd()).expect("Failed to send fd");
+
+        let mut status = 0;
+        assert_eq!(unsafe { libc::waitpid(pid, &mut status, 0) }, pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+        unsafe {
+            libc::close(socks[0]);
+            libc::close(socks[1]);
+        }
+
+        // The child's write landed in the same pages
+        assert_eq!(&memfd.as_slice()[..5], b"reply");
+        assert_eq!(memfd.as_slice()[5], 5);
+    }
 }
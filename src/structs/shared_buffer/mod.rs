@@ -205,6 +205,39 @@ impl SharedBuffer {
         }
     }
 
+    /// Memory-maps the file at `path` read-only and wraps the mapping.
+    ///
+    /// The map is held through the `from_owner` mechanism, so it stays alive while
+    /// any clone or slice of the buffer does, and is unmapped when the last one drops.
+    /// Slicing and `Buffer::from_shared` views are zero-copy.
+    ///
+    /// ## Platform
+    /// Supported wherever `memmap2` is, which includes Linux, macOS and Windows.
+    /// An empty file gives an empty buffer without mapping.
+    ///
+    /// ## Alignment
+    /// Mappings start on a page boundary, so the buffer itself is 64-byte aligned.
+    /// Sub-slices are only 64-byte aligned if their file offset is a multiple of 64,
+    /// as `ipc::FileWriter` guarantees. `Buffer::from_shared` copies any that are not.
+    ///
+    /// ## Safety
+    /// The contents are undefined if the file is modified or truncated while mapped,
+    /// which this method cannot prevent.
+    ///
+    /// # Errors
+    /// Returns the underlying `io::Error` if the file cannot be opened or mapped.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap(path: &std::path::Path) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Ok(Self::new());
+        }
+        // Safety: the map is read-only, and mutation of the file while mapped is
+        // documented as the caller's responsibility.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self::from_owner(mmap))
+    }
+
     /// Returns the number of bytes in this buffer.
     #[inline]
     pub fn len(&self) -> usize {
@@ -296,6 +329,8 @@ impl SharedBuffer {
     ///   runtime references are tracked.
     /// - **Foreign owners** (e.g., `Arc<[u8]>`): Returns `true` only when there are
     ///   no other references to the underlying allocation.
+    /// - **Memory maps** (`from_mmap`): Returns `true` only when no clone or slice
+    ///   of the mapping remains.
     ///
     /// This method is primarily for determining whether zero-copy
     /// conversion to an owned type is possible without cloning the underlying data.
@@ -305,6 +340,15 @@ impl SharedBuffer {
     pub fn is_unique(&self) -> bool {
         unsafe { (self.vtable.is_unique)(&self.data) }
     }
+
+    /// Returns `true` if this buffer views static memory from `from_static`.
+    ///
+    /// Static memory is never freed, so it is never attributable to any one
+    /// holder even though `is_unique` reports `true` for it.
+    #[inline]
+    pub fn is_static(&self) -> bool {
+        ptr::eq(self.vtable, &STATIC_VT)
+    }
 }
 
 impl Clone for SharedBuffer {
@@ -511,4 +555,24 @@ mod tests {
         drop(sb2);
         assert!(sb.is_unique());
     }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_file_unique_and_zero_copy() {
+        let path = std::env::temp_dir().join(format!("minarrow_mmap_{}.bin", std::process::id()));
+        std::fs::write(&path, (0..=255u8).collect::<Vec<_>>()).unwrap();
+        let sb = SharedBuffer::from_mmap(&path).unwrap();
+        assert_eq!(sb.len(), 256);
+        assert_eq!(sb.as_ptr() as usize % 64, 0);
+        assert!(sb.is_unique());
+
+        let part = sb.slice(64..128);
+        assert!(!sb.is_unique());
+        assert_eq!(part.as_ptr(), sb[64..].as_ptr());
+        assert_eq!(part[0], 64);
+        drop(sb);
+        assert!(part.is_unique());
+        drop(part);
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -26,7 +26,9 @@
 //! Cast into *Polars* dataframe via `.to_polars()` or *Apache Arrow* RecordBatch via `.to_apache_arrow()`,
 //! zero-copy, via the `cast_polars` and `cast_arrow` features.
 
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::hash::Hash;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -41,27 +43,43 @@ use polars::prelude::Column;
 use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator};
 
 use super::field_array::FieldArray;
+use super::group_by::GroupBy;
 #[cfg(all(feature = "views", feature = "select"))]
 use crate::ArrayV;
-use crate::Field;
 #[cfg(feature = "chunked")]
 use crate::SuperTable;
-#[cfg(feature = "views")]
-use crate::{BitmaskV, NumericArrayV, TableV, TextArrayV};
+use crate::enums::aggregate::{AggFn, AggState, AggValue};
 use crate::enums::{error::MinarrowError, shape_dim::ShapeDim};
+use crate::ffi::arrow_dtype::ArrowType;
+use crate::ffi::schema::Schema;
 #[cfg(feature = "chunked")]
 use crate::traits::consolidate::Consolidate;
+use crate::traits::masked_array::MaskedArray;
 #[cfg(all(feature = "views", feature = "select"))]
 use crate::traits::selection::{ColumnSelection, DataSelector, FieldSelector, RowSelection};
 use crate::traits::{
     concatenate::Concatenate,
-    print::{MAX_PREVIEW, print_ellipsis_row, print_header_row, print_rule, value_to_string},
+    print::{FormatWith, PrintOptions, print_table, value_to_string, value_to_string_with},
     shape::Shape,
 };
+use crate::{
+    Array, Bitmask, DropHow, Field, IntegerArray, JoinType, NullOrder, NumericArray, SortOrder,
+    StringArray, Vec64, Vec64Ext,
+};
+#[cfg(feature = "views")]
+use crate::{BitmaskV, NumericArrayV, TableV, TextArrayV};
+#[cfg(feature = "scalar_type")]
+use crate::{FloatArray, Interpolation, Row, Scalar};
 
 // Global counter for unnamed table instances
 static UNNAMED_COUNTER: AtomicUsize = AtomicUsize::new(1);
 
+/// Maximum number of output columns, i.e. input rows, accepted by `Table::transpose`.
+pub const MAX_TRANSPOSE_COLUMNS: usize = 4096;
+
+/// Maximum number of distinct `columns` values, i.e. generated columns, accepted by `Table::pivot`.
+pub const MAX_PIVOT_COLUMNS: usize = 4096;
+
 /// # Table
 ///
 /// # Description
@@ -114,6 +132,35 @@ pub struct Table {
     pub metadata: std::collections::BTreeMap<String, String>,
 }
 
+/// Options for `Table::equals` and `Table::assert_equals`.
+///
+/// The defaults compare floats exactly, compare field metadata, and treat
+/// NaN as equal to NaN.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EqualsOptions {
+    /// Floats match when they differ by at most this much.
+    pub abs_tol: f64,
+    /// Floats match when they differ by at most this fraction of the larger
+    /// magnitude. Either tolerance is enough for a match.
+    pub rel_tol: f64,
+    /// Whether field metadata must match, along with the table's own
+    /// metadata under `table_metadata`.
+    pub check_metadata: bool,
+    /// Whether a NaN matches a NaN in the same cell.
+    pub nan_equal: bool,
+}
+
+impl Default for EqualsOptions {
+    fn default() -> Self {
+        Self {
+            abs_tol: 0.0,
+            rel_tol: 0.0,
+            check_metadata: true,
+            nan_equal: true,
+        }
+    }
+}
+
 impl Table {
     /// Internal constructor handling the conditional metadata field.
     /// All code paths that build a `Table` from parts should go through here
@@ -219,13 +266,20 @@ impl Table {
         self.cols.push(field_array);
     }
 
-    /// Builds a schema via the underlying field arrays
-    pub fn schema(&self) -> Vec<Arc<Field>> {
-        let mut vec = Vec::new();
-        for fa in &self.cols {
-            vec.push(fa.field.clone())
-        }
-        vec
+    /// Builds a schema via the underlying field arrays.
+    ///
+    /// The fields are shared with the columns rather than copied, and the
+    /// table's metadata is included when `table_metadata` is enabled.
+    /// See `Schema::matches` to validate other tables against it.
+    pub fn schema(&self) -> Schema {
+        let fields: Vec<Arc<Field>> = self.cols.iter().map(|fa| fa.field.clone()).collect();
+        let schema = Schema::from(fields);
+        #[cfg(feature = "table_metadata")]
+        let schema = Schema {
+            metadata: self.metadata.clone(),
+            ..schema
+        };
+        schema
     }
 
     /// Returns the number of columns.
@@ -251,34 +305,65 @@ impl Table {
 
     /// Rename columns in place. Each pair is (old_name, new_name).
     ///
-    /// Returns an error if any old name is not found.
+    /// All renames are applied together, so names can be swapped or chained
+    /// within one call. The whole mapping is validated before any column is
+    /// touched, and an error leaves the table unchanged.
     /// This is metadata-only - array data is not touched.
+    ///
+    /// # Errors
+    /// - `IndexError` if any old name is not found or is listed twice.
+    /// - `IndexError` if a new name matches a column that is not being renamed,
+    ///   or is the target of more than one rename.
     pub fn rename_columns(
         &mut self,
         mapping: &[(&str, &str)],
     ) -> Result<(), MinarrowError> {
-        for &(old, _) in mapping {
+        for (i, &(old, new)) in mapping.iter().enumerate() {
             if !self.cols.iter().any(|fa| fa.field.name == old) {
                 return Err(MinarrowError::IndexError(format!(
                     "rename_columns: column '{}' not found",
                     old
                 )));
             }
+            if mapping[..i].iter().any(|&(o, _)| o == old) {
+                return Err(MinarrowError::IndexError(format!(
+                    "rename_columns: column '{}' is renamed more than once",
+                    old
+                )));
+            }
+            if mapping[..i].iter().any(|&(_, n)| n == new) {
+                return Err(MinarrowError::IndexError(format!(
+                    "rename_columns: more than one column renamed to '{}'",
+                    new
+                )));
+            }
+            let collides = self.cols.iter().any(|fa| {
+                fa.field.name == new && !mapping.iter().any(|&(o, _)| o == fa.field.name)
+            });
+            if collides {
+                return Err(MinarrowError::IndexError(format!(
+                    "rename_columns: new name '{}' collides with an existing column",
+                    new
+                )));
+            }
         }
         for col in &mut self.cols {
             for &(old, new) in mapping {
                 if col.field.name == old {
                     let f = &col.field;
-                    col.field = Arc::new(Field::new(
-                        new,
-                        f.dtype.clone(),
-                        f.nullable,
-                        if f.metadata.is_empty() {
-                            None
-                        } else {
-                            Some(f.metadata.clone())
-                        },
-                    ));
+                    col.field = Arc::new(
+                        Field::new(
+                            new,
+                            f.dtype.clone(),
+                            f.nullable,
+                            if f.metadata.is_empty() {
+                                None
+                            } else {
+                                Some(f.metadata.clone())
+                            },
+                        )
+                        .with_ordered(f.ordered),
+                    );
                     break;
                 }
             }
@@ -286,6 +371,75 @@ impl Table {
         Ok(())
     }
 
+    /// Appends `field_array` and returns the table, for chained construction.
+    ///
+    /// The first column added to an empty table sets `n_rows`.
+    ///
+    /// # Panics
+    /// If the column length differs from `n_rows` on a non-empty table.
+    pub fn with_column(mut self, field_array: FieldArray) -> Table {
+        self.add_col(field_array);
+        self
+    }
+
+    /// Prepends a non-nullable `Int64` column called `name` counting
+    /// `start, start + 1, ...` down the rows, and returns the table.
+    ///
+    /// Useful before a sort or filter to keep each row's original position.
+    ///
+    /// # Errors
+    /// - `IndexError` if a column called `name` already exists.
+    /// - `Overflow` if the last index would not fit in an `i64`.
+    pub fn with_row_index(mut self, name: &str, start: i64) -> Result<Table, MinarrowError> {
+        if self.has_col(name) {
+            return Err(MinarrowError::IndexError(format!(
+                "with_row_index: column '{}' already exists",
+                name
+            )));
+        }
+        // Checking the last index up front keeps every `start + i` in range.
+        if let Some(last) = self.n_rows.checked_sub(1) {
+            i64::try_from(last)
+                .ok()
+                .and_then(|last| start.checked_add(last))
+                .ok_or_else(|| MinarrowError::Overflow {
+                    value: format!("{} + {}", start, last),
+                    target: "i64",
+                })?;
+        }
+        let data = Vec64::from_exact_iter((0..self.n_rows).map(|i| start + i as i64));
+        let index = FieldArray::new(
+            Field::new(name, ArrowType::Int64, false, None),
+            Array::from_int64(IntegerArray::new(data, None)),
+        );
+        self.cols.insert(0, index);
+        Ok(self)
+    }
+
+    /// Removes the column called `name` and returns the table.
+    ///
+    /// # Errors
+    /// - `IndexError` if no column has that name.
+    pub fn drop_column(mut self, name: &str) -> Result<Table, MinarrowError> {
+        if !self.remove_col(name) {
+            return Err(MinarrowError::IndexError(format!(
+                "drop_column: column '{}' not found",
+                name
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Renames the column `old` to `new` and returns the table.
+    /// Dtype, nullability and field metadata are kept.
+    ///
+    /// # Errors
+    /// - `IndexError` if `old` is not found or `new` collides with another column.
+    pub fn rename_column(mut self, old: &str, new: &str) -> Result<Table, MinarrowError> {
+        self.rename_columns(&[(old, new)])?;
+        Ok(self)
+    }
+
     /// Returns the index of a column by name.
     pub fn col_name_index(&self, name: &str) -> Option<usize> {
         self.cols.iter().position(|fa| fa.field.name == name)
@@ -318,6 +472,18 @@ impl Table {
         Ok(BitmaskV::new(ba.data.clone(), 0, ba.len))
     }
 
+    /// Returns the validity bitmap of the named column, with a set bit for each
+    /// non-null row. A column without a null mask yields an all-set bitmap.
+    ///
+    /// # Errors
+    /// - `IndexError` if the column does not exist
+    pub fn null_mask_of(&self, col: &str) -> Result<Bitmask, MinarrowError> {
+        let idx = self
+            .col_name_index(col)
+            .ok_or_else(|| MinarrowError::IndexError(format!("column '{}' not found", col)))?;
+        Ok(self.cols[idx].array.is_valid().data)
+    }
+
     /// Removes a column by name.
     pub fn remove_col(&mut self, name: &str) -> bool {
         if let Some(idx) = self.col_name_index(name) {
@@ -379,6 +545,15 @@ impl Table {
         self.cols.iter_mut()
     }
 
+    /// Iterates over the rows, each giving access to its values as `Scalar`s.
+    ///
+    /// Meant for streaming records to row-oriented sinks. Column kernels are
+    /// much faster for computation.
+    #[cfg(feature = "scalar_type")]
+    pub fn row_iter(&self) -> impl Iterator<Item = Row<'_>> {
+        (0..self.n_rows).map(move |i| Row::new(self, i))
+    }
+
     #[inline]
     pub fn set_name(&mut self, name: impl Into<String>) {
         self.name = name.into();
@@ -410,6 +585,982 @@ impl Table {
         table
     }
 
+    /// Sorts rows by `sort_keys` within each group of rows sharing the same
+    /// `group_keys` values.
+    ///
+    /// Groups keep the order in which they first appear, so a table that is
+    /// already grouped retains its segment layout and only the rows inside each
+    /// segment are reordered. The sort is stable: rows that compare equal on all
+    /// sort keys keep their original relative order. Nulls sort last in both
+    /// directions.
+    ///
+    /// With no `group_keys` the whole table is one group, and with no
+    /// `sort_keys` rows are only gathered into their groups.
+    ///
+    /// # Errors
+    /// - `IndexError` if any key refers to a column that does not exist.
+    pub fn sort_within_groups(
+        &self,
+        group_keys: &[usize],
+        sort_keys: &[(usize, SortOrder)],
+    ) -> Result<Table, MinarrowError> {
+        let n_cols = self.n_cols();
+        for &col in group_keys.iter().chain(sort_keys.iter().map(|(c, _)| c)) {
+            if col >= n_cols {
+                return Err(MinarrowError::IndexError(format!(
+                    "sort_within_groups: column index {} out of bounds for table with {} columns",
+                    col, n_cols
+                )));
+            }
+        }
+
+        let group_first = self.group_heads(group_keys);
+
+        let sort_keys: Vec<(usize, NullOrder, SortOrder)> = sort_keys
+            .iter()
+            .map(|&(c, order)| (c, NullOrder::Last, order))
+            .collect();
+        let mut perm: Vec<usize> = (0..self.n_rows).collect();
+        perm.sort_by(|&a, &b| {
+            group_first[a]
+                .cmp(&group_first[b])
+                .then_with(|| self.compare_rows(sort_keys.iter().copied(), a, b))
+        });
+
+        Ok(self.take_rows(&perm))
+    }
+
+    /// Sorts rows lexicographically by the named key columns.
+    ///
+    /// Each key gives its own null placement and direction; later keys only
+    /// break ties left by earlier ones. The sort is stable, so rows equal on
+    /// every key keep their input order, and the resulting permutation is
+    /// gathered across every column. Any column type can be a key, including
+    /// integer, float, string, categorical and datetime columns. Floats use
+    /// total ordering, so `NaN` sorts above all other values.
+    ///
+    /// # Errors
+    /// - `IndexError` if a key names a column that does not exist.
+    pub fn sort_by(&self, keys: &[(&str, NullOrder, SortOrder)]) -> Result<Table, MinarrowError> {
+        let keys = keys
+            .iter()
+            .map(|&(name, nulls, order)| {
+                let col = self.col_name_index(name).ok_or_else(|| {
+                    MinarrowError::IndexError(format!("sort_by: column '{}' not found", name))
+                })?;
+                Ok((col, nulls, order))
+            })
+            .collect::<Result<Vec<_>, MinarrowError>>()?;
+
+        let mut perm: Vec<usize> = (0..self.n_rows).collect();
+        perm.sort_by(|&a, &b| self.compare_rows(keys.iter().copied(), a, b));
+        Ok(self.take_rows(&perm))
+    }
+    /// Reshapes long data into wide form.
+    ///
+    /// Emits one row per distinct combination of the `index` columns, in order
+    /// of first appearance, and one column per distinct value of the `columns`
+    /// column, also in order of first appearance. Each cell aggregates the
+    /// `values` entries that share its (index, column) pair with `agg`, typed
+    /// as described on [`AggFn`]. The output starts with the
+    /// `index` columns, in the order given. An empty `index` collapses the
+    /// table into a single row.
+    ///
+    /// Missing cells are null. Rows whose `columns` value is null are skipped,
+    /// since they cannot name an output column.
+    ///
+    /// # Errors
+    /// - `IndexError` if any argument names a column that does not exist, or a
+    ///   generated column would share its name with an `index` column.
+    /// - `TypeError` if `columns` is not a string or categorical column, or
+    ///   `values` is not numeric.
+    /// - `ShapeError` if `columns` has more than `MAX_PIVOT_COLUMNS` distinct
+    ///   values.
+    pub fn pivot(
+        &self,
+        index: &[&str],
+        columns: &str,
+        values: &str,
+        agg: AggFn,
+    ) -> Result<Table, MinarrowError> {
+        let lookup = |name: &str| {
+            self.col_name_index(name).ok_or_else(|| {
+                MinarrowError::IndexError(format!("pivot: column '{}' not found", name))
+            })
+        };
+        let index = index
+            .iter()
+            .map(|name| lookup(name))
+            .collect::<Result<Vec<_>, MinarrowError>>()?;
+        let columns = lookup(columns)?;
+        let values = lookup(values)?;
+
+        let names = match &self.cols[columns].array {
+            Array::TextArray(text) => text.clone().str32()?,
+            _ => {
+                return Err(MinarrowError::TypeError {
+                    from: "Array",
+                    to: "column names",
+                    message: Some("pivot columns must be a string or categorical column".into()),
+                });
+            }
+        };
+        let vals = self.cols[values].array.num_ref()?;
+
+        // Output rows, one per distinct index value.
+        let heads = self.group_heads(&index);
+        let mut head_rows = Vec::new();
+        let mut row_group = vec![0usize; self.n_rows];
+        for row in 0..self.n_rows {
+            if heads[row] == row {
+                row_group[row] = head_rows.len();
+                head_rows.push(row);
+            } else {
+                row_group[row] = row_group[heads[row]];
+            }
+        }
+
+        // Output columns, one per distinct name.
+        let mut col_names: Vec<&str> = Vec::new();
+        let mut col_lookup: HashMap<&str, usize> = HashMap::new();
+        let mut row_cells = vec![None; self.n_rows];
+        for row in 0..self.n_rows {
+            let Some(name) = names.get_str(row) else {
+                continue;
+            };
+            let col = match col_lookup.get(name) {
+                Some(&col) => col,
+                None => {
+                    if col_names.len() == MAX_PIVOT_COLUMNS {
+                        return Err(MinarrowError::ShapeError {
+                            message: format!(
+                                "pivot: column '{}' has more than {} distinct values",
+                                self.cols[columns].field.name, MAX_PIVOT_COLUMNS
+                            ),
+                        });
+                    }
+                    if index.iter().any(|&i| self.cols[i].field.name == name) {
+                        return Err(MinarrowError::IndexError(format!(
+                            "pivot: generated column '{}' collides with an index column",
+                            name
+                        )));
+                    }
+                    col_names.push(name);
+                    col_lookup.insert(name, col_names.len() - 1);
+                    col_names.len() - 1
+                }
+            };
+            row_cells[row] = Some(col * head_rows.len() + row_group[row]);
+        }
+
+        let mut cols = Vec::with_capacity(col_names.len() + index.len());
+        for &i in &index {
+            let index_col = &self.cols[i];
+            cols.push(FieldArray::new_arc(
+                index_col.field.clone(),
+                index_col.array.take(&head_rows),
+            ));
+        }
+        let n_groups = head_rows.len();
+        cols.extend(agg_columns(&col_names, n_groups, agg, vals, &row_cells)?);
+
+        Ok(Table::build(cols, head_rows.len(), self.name.clone()))
+    }
+
+    /// Swaps rows and columns, keyed on the `label_col` column.
+    ///
+    /// Each input row becomes an output column named after its `label_col`
+    /// value, and each remaining input column becomes an output row. The first
+    /// output column is a string column named `label_col` holding the former
+    /// column names, in their original order.
+    ///
+    /// The body must be homogeneous: every column other than `label_col` has to
+    /// be numeric, since each output column mixes one value from every input
+    /// column. Mixed numeric types are promoted to a common type as in
+    /// [`Array::concat_promoting`]. Nulls are kept in place.
+    ///
+    /// Meant for small, wide tables. The output is capped at
+    /// `MAX_TRANSPOSE_COLUMNS` columns, i.e. that many input rows.
+    ///
+    /// # Errors
+    /// - `IndexError` if `label_col` does not exist, or two labels, or a label
+    ///   and `label_col` itself, render to the same name.
+    /// - `NullError` if a label is null.
+    /// - `TypeError` if a body column is not numeric.
+    /// - `ShapeError` if there are no body columns or too many rows.
+    /// - Any conversion error from the numeric promotion.
+    pub fn transpose(&self, label_col: &str) -> Result<Table, MinarrowError> {
+        let label_idx = self.col_name_index(label_col).ok_or_else(|| {
+            MinarrowError::IndexError(format!("transpose: column '{}' not found", label_col))
+        })?;
+        if self.n_rows > MAX_TRANSPOSE_COLUMNS {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "transpose: {} rows exceed the limit of {} output columns",
+                    self.n_rows, MAX_TRANSPOSE_COLUMNS
+                ),
+            });
+        }
+        let body: Vec<&FieldArray> = self
+            .cols
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != label_idx)
+            .map(|(_, c)| c)
+            .collect();
+        if body.is_empty() {
+            return Err(MinarrowError::ShapeError {
+                message: "transpose: table has no columns besides the label column".into(),
+            });
+        }
+
+        let mut target: Option<ArrowType> = None;
+        for col in &body {
+            let dtype = col.array.arrow_type();
+            let promoted = match (&target, col.array.is_numerical_array()) {
+                (_, false) => None,
+                (None, true) => Some(dtype.clone()),
+                (Some(t), true) => crate::enums::array::promote_numeric(t, &dtype),
+            };
+            target = Some(promoted.ok_or_else(|| MinarrowError::TypeError {
+                from: "Array",
+                to: "NumericArray",
+                message: Some(format!(
+                    "transpose: column '{}' is {:?}, but every column besides the label must be numeric",
+                    col.field.name, dtype
+                )),
+            })?);
+        }
+        let target = target.expect("body is non-empty");
+
+        let labels = &self.cols[label_idx].array;
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        seen.insert(label_col.to_string(), usize::MAX);
+        let mut names = Vec::with_capacity(self.n_rows);
+        for row in 0..self.n_rows {
+            if labels.null_mask().is_some_and(|m| !m.get(row)) {
+                return Err(MinarrowError::NullError {
+                    message: Some(format!("transpose: label at row {} is null", row)),
+                });
+            }
+            let name = value_to_string(labels, row);
+            if seen.insert(name.clone(), row).is_some() {
+                return Err(MinarrowError::IndexError(format!(
+                    "transpose: label '{}' at row {} is not a unique column name",
+                    name, row
+                )));
+            }
+            names.push(name);
+        }
+
+        // Stack the body column-major, then gather one value per input column.
+        let stacked = body
+            .iter()
+            .map(|c| {
+                c.array
+                    .to_common_numeric(target.clone())
+                    .map(Array::NumericArray)
+            })
+            .collect::<Result<Vec<_>, MinarrowError>>()?;
+        let stacked = Array::concat_many(&stacked)?;
+
+        let mut cols = Vec::with_capacity(self.n_rows + 1);
+        let former: Vec<&str> = body.iter().map(|c| c.field.name.as_str()).collect();
+        cols.push(FieldArray::from_arr(
+            label_col,
+            Array::from_string32(StringArray::<u32>::from_slice(&former)),
+        ));
+        for (row, name) in names.iter().enumerate() {
+            let indices: Vec<usize> = (0..body.len()).map(|c| c * self.n_rows + row).collect();
+            cols.push(FieldArray::from_arr(name, stacked.take(&indices)));
+        }
+        Ok(Table::build(cols, body.len(), self.name.clone()))
+    }
+
+    /// Summary statistics of every column, as a new table.
+    ///
+    /// The first column, `statistic`, labels the rows `count`, `null_count`,
+    /// `mean`, `std`, `min`, `25%`, `50%`, `75%` and `max`. If an input column
+    /// is already named `statistic`, the label column becomes `statistic_2`, or
+    /// `statistic_3` and so on if that is taken too. Each input column
+    /// follows as a nullable `Float64` column of the same name. `std` is the
+    /// sample standard deviation and the percentiles interpolate linearly, as in
+    /// [`NumericArray::quantiles`](crate::NumericArray::quantiles).
+    ///
+    /// Non-numeric columns only report `count` and `null_count`, leaving the
+    /// other rows null. `count` is the number of non-null values, so a `NaN`
+    /// counts and carries into `mean` and `std`, but is skipped by the rest.
+    #[cfg(feature = "scalar_type")]
+    pub fn describe(&self) -> Table {
+        const STATS: [&str; 9] = [
+            "count",
+            "null_count",
+            "mean",
+            "std",
+            "min",
+            "25%",
+            "50%",
+            "75%",
+            "max",
+        ];
+        // Keep the label column's name distinct from every input column.
+        let mut label = "statistic".to_string();
+        let mut n = 2;
+        while self.col_name_index(&label).is_some() {
+            label = format!("statistic_{}", n);
+            n += 1;
+        }
+        let mut cols = Vec::with_capacity(self.n_cols() + 1);
+        cols.push(FieldArray::from_arr(
+            label,
+            Array::from_string32(StringArray::<u32>::from_slice(&STATS)),
+        ));
+        for col in &self.cols {
+            let nulls = col.null_count;
+            let mut stats = vec![Some((self.n_rows - nulls) as f64), Some(nulls as f64)];
+            if let Array::NumericArray(num) = &col.array {
+                let mut row = vec![num.mean(), num.std(1), num.min()];
+                row.extend(num.quantiles(&[0.25, 0.5, 0.75], Interpolation::Linear));
+                row.push(num.max());
+                stats.extend(row.iter().map(Scalar::try_f64));
+            }
+            stats.resize(STATS.len(), None);
+
+            let mask = Bitmask::from_bools(&stats.iter().map(Option::is_some).collect::<Vec<_>>());
+            let data: Vec64<f64> = stats.iter().map(|v| v.unwrap_or_default()).collect();
+            let null_mask = if mask.all_set() { None } else { Some(mask) };
+            cols.push(FieldArray::new(
+                Field::new(&col.field.name, ArrowType::Float64, true, None),
+                Array::from_float64(FloatArray::new(data, null_mask)),
+            ));
+        }
+        Table::build(cols, STATS.len(), self.name.clone())
+    }
+
+    /// Starts a grouped aggregation keyed on the named columns.
+    ///
+    /// Key columns are resolved when `GroupBy::agg` runs, which reports any
+    /// unknown or unsupported key column.
+    pub fn group_by(&self, keys: &[&str]) -> GroupBy<'_> {
+        GroupBy::new(self, keys)
+    }
+
+    /// Splits the rows into one batch per distinct value of `col`, returned as
+    /// a `SuperTable` named after this table.
+    ///
+    /// Batches follow the order in which each key first appears, and each keeps
+    /// its rows in their original order along with every column, `col` included.
+    /// Keys compare as in `group_by`: integer columns by value, and string and
+    /// categorical columns by their strings. Null keys are bucketed together into
+    /// their own partition. An empty table yields a `SuperTable` without batches.
+    ///
+    /// # Errors
+    /// - `IndexError` if `col` does not exist.
+    /// - `TypeError` if `col` is not an integer, string or categorical column.
+    #[cfg(feature = "chunked")]
+    pub fn partition_by(&self, col: &str) -> Result<SuperTable, MinarrowError> {
+        let idx = self.col_name_index(col).ok_or_else(|| {
+            MinarrowError::IndexError(format!("Table::partition_by: column '{}' not found", col))
+        })?;
+        let keys = KeyColumn::from_array(&self.cols[idx].array)?;
+
+        let mut lookup: HashMap<Option<KeyValue<'_>>, usize> = HashMap::new();
+        let mut partitions: Vec<Vec<usize>> = Vec::new();
+        for row in 0..self.n_rows {
+            let part = *lookup.entry(keys.get(row)).or_insert_with(|| {
+                partitions.push(Vec::new());
+                partitions.len() - 1
+            });
+            partitions[part].push(row);
+        }
+
+        if partitions.is_empty() {
+            return Ok(SuperTable::new(self.name.clone()));
+        }
+        let batches = partitions
+            .iter()
+            .map(|rows| Arc::new(self.take_rows(rows)))
+            .collect();
+        Ok(SuperTable::from_batches(batches, Some(self.name.clone())))
+    }
+
+    /// Returns a new table without the rows that are null in the considered columns.
+    ///
+    /// With `DropHow::Any` a row is dropped if any considered column is null;
+    /// with `DropHow::All` only if every considered column is null. `subset`
+    /// restricts the considered columns, defaulting to all of them.
+    ///
+    /// The keep-mask is built by combining the column validity masks, so no
+    /// per-row checks are needed.
+    ///
+    /// # Errors
+    /// - `IndexError` if a `subset` index is out of bounds
+    pub fn drop_nulls(
+        &self,
+        how: DropHow,
+        subset: Option<&[usize]>,
+    ) -> Result<Table, MinarrowError> {
+        let n_cols = self.n_cols();
+        let all_cols: Vec<usize>;
+        let subset = match subset {
+            Some(cols) => cols,
+            None => {
+                all_cols = (0..n_cols).collect();
+                &all_cols
+            }
+        };
+        if let Some(&col) = subset.iter().find(|&&c| c >= n_cols) {
+            return Err(MinarrowError::IndexError(format!(
+                "drop_nulls: column index {} out of bounds for table with {} columns",
+                col, n_cols
+            )));
+        }
+        if subset.is_empty() {
+            return Ok(self.clone());
+        }
+
+        // A column without a null mask is fully valid, so it leaves `Any`
+        // unchanged and keeps every row under `All`.
+        let masks = subset.iter().map(|&c| self.cols[c].array.null_mask());
+        let keep = match how {
+            DropHow::Any => masks.flatten().fold(None, |acc: Option<Bitmask>, m| {
+                Some(acc.map_or_else(|| m.clone(), |a| a.intersect(m)))
+            }),
+            DropHow::All => masks
+                .map(|m| m.cloned())
+                .reduce(|a, b| Some(a?.union(&b?)))
+                .flatten(),
+        };
+
+        match keep {
+            Some(keep) if !keep.all_set() => {
+                let rows: Vec<usize> = keep.iter_set().collect();
+                Ok(self.take_rows(&rows))
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Splits the string column `col` on `sep` and emits one row per part,
+    /// repeating the other columns' values alongside it.
+    ///
+    /// A null or empty cell yields a single row holding null. Parts are kept
+    /// as-is, so `"a,,b"` yields an empty middle part. The exploded column is
+    /// returned as `String32` under its original name and metadata, with
+    /// categorical columns decoded first.
+    ///
+    /// # Errors
+    /// - `IndexError` if `col` does not exist
+    /// - `TypeError` if `col` is not a string or categorical column
+    /// - `KernelError` if `sep` is empty
+    pub fn explode(&self, col: &str, sep: &str) -> Result<Table, MinarrowError> {
+        let idx = self.col_name_index(col).ok_or_else(|| {
+            MinarrowError::IndexError(format!("Table::explode: column '{}' not found", col))
+        })?;
+        if sep.is_empty() {
+            return Err(MinarrowError::KernelError(Some(
+                "Table::explode: separator must not be empty".to_string(),
+            )));
+        }
+        let text = match &self.cols[idx].array {
+            Array::TextArray(text) => text.clone().str32()?,
+            _ => {
+                return Err(MinarrowError::TypeError {
+                    from: "Array",
+                    to: "StringArray",
+                    message: Some(format!(
+                        "Table::explode: column '{}' must be a string or categorical column",
+                        col
+                    )),
+                });
+            }
+        };
+
+        // Source row for every output row, alongside the exploded values.
+        let mut rows = Vec::with_capacity(self.n_rows);
+        let mut parts = StringArray::<u32>::with_capacity(self.n_rows, text.data.len(), false);
+        for row in 0..self.n_rows {
+            match text.get_str(row) {
+                Some(s) if !s.is_empty() => {
+                    for part in s.split(sep) {
+                        rows.push(row);
+                        parts.push_str(part);
+                    }
+                }
+                _ => {
+                    rows.push(row);
+                    parts.push_null();
+                }
+            }
+        }
+
+        let cols: Vec<FieldArray> = self
+            .cols
+            .iter()
+            .enumerate()
+            .map(|(i, fa)| {
+                if i != idx {
+                    return FieldArray::new_arc(fa.field.clone(), fa.array.take(&rows));
+                }
+                let array = Array::from_string32(std::mem::take(&mut parts));
+                let field = Field::new(
+                    fa.field.name.clone(),
+                    array.arrow_type(),
+                    fa.field.nullable || array.is_nullable(),
+                    Some(fa.field.metadata.clone()),
+                );
+                FieldArray::new(field, array)
+            })
+            .collect();
+        #[allow(unused_mut)]
+        let mut table = Table::build(cols, rows.len(), self.name.clone());
+        #[cfg(feature = "table_metadata")]
+        {
+            table.metadata = self.metadata.clone();
+        }
+        Ok(table)
+    }
+
+    /// Returns, for each row, the index of the first row sharing its `keys`
+    /// values. With no keys every row maps to `0`.
+    fn group_heads(&self, keys: &[usize]) -> Vec<usize> {
+        let mut heads = vec![0usize; self.n_rows];
+        if keys.is_empty() {
+            return heads;
+        }
+        // A stable sort on the keys puts the lowest row index at the head of every run.
+        let key_order: Vec<(usize, NullOrder, SortOrder)> = keys
+            .iter()
+            .map(|&c| (c, NullOrder::Last, SortOrder::Ascending))
+            .collect();
+        let mut by_key: Vec<usize> = (0..self.n_rows).collect();
+        by_key.sort_by(|&a, &b| self.compare_rows(key_order.iter().copied(), a, b));
+        let mut head = 0;
+        for (pos, &row) in by_key.iter().enumerate() {
+            let prev = pos.checked_sub(1).map(|p| by_key[p]);
+            if prev.is_none_or(|p| {
+                self.compare_rows(key_order.iter().copied(), p, row) != std::cmp::Ordering::Equal
+            }) {
+                head = row;
+            }
+            heads[row] = head;
+        }
+        heads
+    }
+
+    /// Compares rows `a` and `b` over `keys` in priority order, placing nulls
+    /// per key regardless of direction.
+    fn compare_rows(
+        &self,
+        keys: impl IntoIterator<Item = (usize, NullOrder, SortOrder)>,
+        a: usize,
+        b: usize,
+    ) -> std::cmp::Ordering {
+        for (col, nulls, order) in keys {
+            let array = &self.cols[col].array;
+            // `compare_at` ranks nulls last, and two nulls as equal.
+            let ord = array.compare_at(a, b);
+            let any_null = array.null_mask().is_some_and(|m| !m.get(a) || !m.get(b));
+            let ord = match (any_null, nulls) {
+                (false, _) => order.apply(ord),
+                (true, NullOrder::Last) => ord,
+                (true, NullOrder::First) => ord.reverse(),
+            };
+            if ord != std::cmp::Ordering::Equal {
+                return ord;
+            }
+        }
+        std::cmp::Ordering::Equal
+    }
+
+    /// Gathers the rows at `indices` into a new owned `Table`, in index order.
+    ///
+    /// Indices may repeat and appear in any order, so the output of a sort or
+    /// filter can be applied directly. Each column keeps its null mask, so an
+    /// index pointing at a null source row yields null.
+    ///
+    /// # Errors
+    /// - `IndexError` if any index is out of bounds
+    /// - `NullError` if `indices` itself contains nulls
+    pub fn take(&self, indices: &IntegerArray<u32>) -> Result<Table, MinarrowError> {
+        if indices.null_count() > 0 {
+            return Err(MinarrowError::NullError {
+                message: Some("Table::take: indices must not contain nulls".to_string()),
+            });
+        }
+        let rows: Vec<usize> = indices.data.iter().map(|&i| i as usize).collect();
+        if let Some(&bad) = rows.iter().find(|&&i| i >= self.n_rows) {
+            return Err(MinarrowError::IndexError(format!(
+                "Table::take: index {} out of bounds for table with {} rows",
+                bad, self.n_rows
+            )));
+        }
+        Ok(self.take_rows(&rows))
+    }
+
+    /// Returns the first `n` rows as an owned table, or every row if the table is shorter.
+    pub fn head(&self, n: usize) -> Table {
+        self.slice_clone(0, n.min(self.n_rows))
+    }
+
+    /// Returns the last `n` rows as an owned table, or every row if the table is shorter.
+    pub fn tail(&self, n: usize) -> Table {
+        let len = n.min(self.n_rows);
+        self.slice_clone(self.n_rows - len, len)
+    }
+
+    /// Returns `n` rows chosen uniformly at random without replacement.
+    ///
+    /// Rows are picked by reservoir sampling with a generator seeded from `seed`,
+    /// so the same seed always picks the same rows. The picked rows keep their
+    /// original order. If `n` is at least the row count, every row is returned.
+    pub fn sample(&self, n: usize, seed: u64) -> Table {
+        let n = n.min(self.n_rows);
+        let mut rows: Vec<usize> = (0..n).collect();
+        let mut state = seed;
+        for i in n..self.n_rows {
+            // Multiply-shift maps the 64-bit draw onto 0..=i without modulo bias
+            let j = ((splitmix64(&mut state) as u128 * (i as u128 + 1)) >> 64) as usize;
+            if j < n {
+                rows[j] = i;
+            }
+        }
+        rows.sort_unstable();
+        self.take_rows(&rows)
+    }
+
+    /// Returns a copy with the row order flipped, applying [`Array::reverse`]
+    /// to every column.
+    pub fn reverse(&self) -> Table {
+        let rows: Vec<usize> = (0..self.n_rows).rev().collect();
+        self.take_rows(&rows)
+    }
+
+    /// Returns a new table holding only the rows whose bit is set in `mask`,
+    /// applying the same mask to every column.
+    ///
+    /// # Errors
+    /// - `ShapeError` if `mask.len()` differs from the number of rows
+    pub fn filter(&self, mask: &Bitmask) -> Result<Table, MinarrowError> {
+        if mask.len() != self.n_rows {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "Table::filter: mask length {} does not match row count {}",
+                    mask.len(),
+                    self.n_rows
+                ),
+            });
+        }
+        let rows: Vec<usize> = mask.iter_set().collect();
+        Ok(self.take_rows(&rows))
+    }
+
+    /// Returns a new table holding the named columns in the requested order.
+    ///
+    /// Columns share their underlying buffers with `self`, so no data is copied.
+    /// Repeated names produce repeated columns.
+    ///
+    /// # Errors
+    /// - `IndexError` naming the first column that does not exist
+    pub fn select(&self, names: &[&str]) -> Result<Table, MinarrowError> {
+        let mut cols = Vec::with_capacity(names.len());
+        for name in names {
+            let idx = self.col_name_index(name).ok_or_else(|| {
+                MinarrowError::IndexError(format!("Table::select: column '{}' not found", name))
+            })?;
+            cols.push(self.cols[idx].clone());
+        }
+        #[allow(unused_mut)]
+        let mut table = Table::build(cols, self.n_rows, self.name.clone());
+        #[cfg(feature = "table_metadata")]
+        {
+            table.metadata = self.metadata.clone();
+        }
+        Ok(table)
+    }
+
+    /// Stacks `tables` row-wise into a single table.
+    ///
+    /// Every table must match the first table's schema under `Schema::matches`,
+    /// with the same column count and matching name, type and nullability per column. Each column is
+    /// built in one pass with `Array::concat_many`, and the result keeps the first
+    /// table's name.
+    /// An empty slice yields an empty table.
+    ///
+    /// # Errors
+    /// - `IncompatibleTypeError` identifying the table and column whose schema differs
+    pub fn vstack(tables: &[Table]) -> Result<Table, MinarrowError> {
+        let Some(first) = tables.first() else {
+            return Ok(Table::new_empty());
+        };
+        let schema = first.schema();
+        for (t_idx, table) in tables.iter().enumerate().skip(1) {
+            schema.matches(table).map_err(|e| match e {
+                MinarrowError::IncompatibleTypeError { message, .. } => {
+                    MinarrowError::IncompatibleTypeError {
+                        from: "Table",
+                        to: "Table",
+                        message: Some(format!(
+                            "Table::vstack: table {} differs: {}",
+                            t_idx,
+                            message.unwrap_or_default()
+                        )),
+                    }
+                }
+                other => other,
+            })?;
+        }
+
+        let mut cols = Vec::with_capacity(first.n_cols());
+        for (col_idx, col) in first.cols.iter().enumerate() {
+            let arrays: Vec<Array> = tables
+                .iter()
+                .map(|t| t.cols[col_idx].array.clone())
+                .collect();
+            let array = Array::concat_many(&arrays)?;
+            let null_count = array.null_count();
+            cols.push(FieldArray {
+                field: col.field.clone(),
+                array,
+                null_count,
+            });
+        }
+        let n_rows = tables.iter().map(|t| t.n_rows).sum();
+        #[allow(unused_mut)]
+        let mut table = Table::build(cols, n_rows, first.name.clone());
+        #[cfg(feature = "table_metadata")]
+        {
+            table.metadata = first.metadata.clone();
+        }
+        Ok(table)
+    }
+
+    /// Whether `other` holds the same data as this table under `opts`.
+    ///
+    /// See `assert_equals` for what is compared.
+    pub fn equals(&self, other: &Table, opts: EqualsOptions) -> bool {
+        self.assert_equals(other, opts).is_ok()
+    }
+
+    /// Checks that `other` holds the same data as this table under `opts`,
+    /// reporting the first difference found.
+    ///
+    /// Column and row counts must match, as must each column's name, type,
+    /// nullability and ordering flag. Cells are then compared column by column.
+    /// Nulls only match nulls, floats match within the tolerances in `opts`, and
+    /// categorical cells compare by their string values. Table names are ignored.
+    ///
+    /// # Errors
+    /// - `ShapeError` if the column or row counts differ.
+    /// - `IncompatibleTypeError` if a field or, under `check_metadata`, any
+    ///   metadata differs.
+    /// - `KernelError` naming the first differing `(row, col)` otherwise.
+    pub fn assert_equals(&self, other: &Table, opts: EqualsOptions) -> Result<(), MinarrowError> {
+        if self.n_cols() != other.n_cols() || self.n_rows != other.n_rows {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "Table::assert_equals: shape {}x{} vs {}x{}",
+                    self.n_rows,
+                    self.n_cols(),
+                    other.n_rows,
+                    other.n_cols()
+                ),
+            });
+        }
+        #[cfg(feature = "table_metadata")]
+        if opts.check_metadata && self.metadata != other.metadata {
+            return Err(MinarrowError::IncompatibleTypeError {
+                from: "Table",
+                to: "Table",
+                message: Some("Table::assert_equals: table metadata differs".into()),
+            });
+        }
+        for (col_idx, (a, b)) in self.cols.iter().zip(&other.cols).enumerate() {
+            let mismatch = if a.field.name != b.field.name {
+                Some(format!("name '{}' vs '{}'", a.field.name, b.field.name))
+            } else if a.field.dtype != b.field.dtype {
+                Some(format!("type {:?} vs {:?}", a.field.dtype, b.field.dtype))
+            } else if a.field.nullable != b.field.nullable {
+                Some(format!(
+                    "nullable {} vs {}",
+                    a.field.nullable, b.field.nullable
+                ))
+            } else if a.field.ordered != b.field.ordered {
+                Some(format!(
+                    "ordered {} vs {}",
+                    a.field.ordered, b.field.ordered
+                ))
+            } else if opts.check_metadata && a.field.metadata != b.field.metadata {
+                Some("metadata differs".to_string())
+            } else {
+                None
+            };
+            if let Some(detail) = mismatch {
+                return Err(MinarrowError::IncompatibleTypeError {
+                    from: "Table",
+                    to: "Table",
+                    message: Some(format!(
+                        "Table::assert_equals: column {} ('{}') differs: {}",
+                        col_idx, a.field.name, detail
+                    )),
+                });
+            }
+        }
+
+        for (col_idx, (a, b)) in self.cols.iter().zip(&other.cols).enumerate() {
+            // Equal arrays are equal under any options
+            if a.array == b.array {
+                continue;
+            }
+            let (a_mask, b_mask) = (a.array.null_mask(), b.array.null_mask());
+            for row in 0..self.n_rows {
+                let a_valid = a_mask.is_none_or(|m| m.get(row));
+                let b_valid = b_mask.is_none_or(|m| m.get(row));
+                let same = match (a_valid, b_valid) {
+                    (true, true) => cells_equal(&a.array, &b.array, row, &opts),
+                    (false, false) => true,
+                    _ => false,
+                };
+                if !same {
+                    return Err(MinarrowError::KernelError(Some(format!(
+                        "Table::assert_equals: row {}, column {} ('{}') differs: {} vs {}",
+                        row,
+                        col_idx,
+                        a.field.name,
+                        value_to_string_with(&a.array, row, 17),
+                        value_to_string_with(&b.array, row, 17)
+                    ))));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Joins `other` onto this table where `left_on` equals `right_on`.
+    ///
+    /// Output rows follow left row order, with matching right rows in their own
+    /// order. The result holds every left column followed by every right column.
+    /// When both key columns share a name the right key column is omitted, and any
+    /// other right column whose name is already taken gets a `_right` suffix, or
+    /// `_right_2`, `_right_3` and so on if that is taken too.
+    ///
+    /// Keys must both be integer columns, compared by value, or both be string or
+    /// categorical columns. Null keys never match. For `JoinType::Left`, right
+    /// columns are null on left rows without a match.
+    ///
+    /// # Errors
+    /// - `IndexError` if either key column does not exist.
+    /// - `TypeError` if the key columns are not both integer or both text.
+    pub fn join(
+        &self,
+        other: &Table,
+        left_on: &str,
+        right_on: &str,
+        how: JoinType,
+    ) -> Result<Table, MinarrowError> {
+        let key_col = |t: &Table, name: &str| {
+            t.col_name_index(name).ok_or_else(|| {
+                MinarrowError::IndexError(format!("join: column '{}' not found", name))
+            })
+        };
+        let l_key = key_col(self, left_on)?;
+        let r_key = key_col(other, right_on)?;
+
+        let left_keys = KeyColumn::from_array(&self.cols[l_key].array)?;
+        let right_keys = KeyColumn::from_array(&other.cols[r_key].array)?;
+        if !left_keys.same_kind(&right_keys) {
+            return Err(MinarrowError::TypeError {
+                from: "join key",
+                to: "join key",
+                message: Some(format!(
+                    "join: key columns '{}' and '{}' must both be integer or both be text",
+                    left_on, right_on
+                )),
+            });
+        }
+        let (left_rows, right_rows) = match_join_keys(
+            (0..self.n_rows).map(|i| left_keys.get(i)),
+            (0..other.n_rows).map(|j| right_keys.get(j)),
+            self.n_rows,
+            how,
+        );
+
+        let n_rows = left_rows.len();
+        let mut cols: Vec<FieldArray> = self
+            .cols
+            .iter()
+            .map(|fa| FieldArray::new_arc(fa.field.clone(), fa.array.take(&left_rows)))
+            .collect();
+
+        // Unmatched right rows gather row 0 as a placeholder and are then masked out.
+        let matched =
+            Bitmask::from_bools(&right_rows.iter().map(Option::is_some).collect::<Vec<_>>());
+        let all_matched = matched.all_set();
+        let gather: Vec<usize> = right_rows.iter().map(|r| r.unwrap_or(0)).collect();
+
+        for (c, fa) in other.cols.iter().enumerate() {
+            if c == r_key && left_on == right_on {
+                continue;
+            }
+            let mut field = (*fa.field).clone();
+            if cols.iter().any(|l| l.field.name == field.name) {
+                // Skip suffixed names that are already in use on either side.
+                let taken =
+                    |name: &str| cols.iter().chain(&other.cols).any(|f| f.field.name == name);
+                let mut name = format!("{}_right", field.name);
+                let mut n = 2;
+                while taken(&name) {
+                    name = format!("{}_right_{}", field.name, n);
+                    n += 1;
+                }
+                field.name = name;
+            }
+            let array = if all_matched {
+                fa.array.take(&gather)
+            } else {
+                field.nullable = true;
+                if other.n_rows == 0 {
+                    Array::null_array(&field.dtype, n_rows)
+                } else {
+                    let mut array = fa.array.take(&gather);
+                    let mask = match array.null_mask() {
+                        Some(m) => m.intersect(&matched),
+                        None => matched.clone(),
+                    };
+                    array.set_null_mask(mask);
+                    array
+                }
+            };
+            cols.push(FieldArray::new(field, array));
+        }
+
+        #[allow(unused_mut)]
+        let mut table = Table::build(cols, n_rows, self.name.clone());
+        #[cfg(feature = "table_metadata")]
+        {
+            table.metadata = self.metadata.clone();
+        }
+        Ok(table)
+    }
+
+    /// Gathers the rows at `indices` from every column into a new owned `Table`.
+    ///
+    /// Panics if any index is out of bounds.
+    pub(crate) fn take_rows(&self, indices: &[usize]) -> Table {
+        let cols: Vec<FieldArray> = self
+            .cols
+            .iter()
+            .map(|fa| FieldArray::new_arc(fa.field.clone(), fa.array.take(indices)))
+            .collect();
+        #[allow(unused_mut)]
+        let mut table = Table::build(cols, indices.len(), self.name.clone());
+        #[cfg(feature = "table_metadata")]
+        {
+            table.metadata = self.metadata.clone();
+        }
+        table
+    }
+
     /// Returns a zero-copy view over rows `[offset, offset+len)`.
     /// This view borrows from the parent table and does not copy data.
     #[cfg(feature = "views")]
@@ -661,6 +1812,108 @@ impl Table {
             Some(self.name),
         ))
     }
+
+    /// Reads a CSV document into a table, inferring a type for each column.
+    ///
+    /// Columns become `Int64`, `Float64`, `Boolean` or `String` arrays, and empty
+    /// unquoted fields become nulls. See the `csv` module and `CsvReadOptions` for
+    /// the parsing and inference rules.
+    ///
+    /// # Errors
+    /// - `IoError` if reading fails or the input is not valid UTF-8
+    /// - `ParseError` with the line number for malformed records, or values that
+    ///   do not parse as their column's inferred type
+    /// - `TypeError` if the configured delimiter is not usable
+    pub fn from_csv(
+        reader: impl std::io::Read,
+        opts: crate::CsvReadOptions,
+    ) -> Result<Table, MinarrowError> {
+        crate::csv::read_table(reader, &opts)
+    }
+
+    /// Writes the table as CSV, streaming one record per row into `writer`.
+    ///
+    /// A header of field names comes first unless disabled. Nulls are written as
+    /// `opts.null_token` and text is quoted per RFC 4180 where needed. See
+    /// `CsvWriteOptions` for the other settings.
+    ///
+    /// # Errors
+    /// - `IoError` if writing fails
+    /// - `TypeError` if the configured delimiter is not usable
+    pub fn to_csv(
+        &self,
+        writer: impl std::io::Write,
+        opts: crate::CsvWriteOptions,
+    ) -> Result<(), MinarrowError> {
+        crate::csv::write_table(self, writer, &opts)
+    }
+
+    /// Reads newline-delimited JSON from `reader`, one object per line, into a table.
+    ///
+    /// Every key seen becomes a column in first-seen order, with missing keys read
+    /// as nulls. Column types are inferred from the first `opts.infer_rows` lines.
+    /// Nested objects are rejected unless `opts.flatten_nested` is set, in which
+    /// case they become `parent.child` columns.
+    ///
+    /// # Errors
+    /// - `IoError` if reading fails or the input is not valid UTF-8
+    /// - `ParseError` with the line number for malformed JSON, nested objects or
+    ///   arrays, or values that do not match their column's inferred type
+    pub fn from_ndjson(
+        reader: impl std::io::Read,
+        opts: crate::NdjsonReadOptions,
+    ) -> Result<Table, MinarrowError> {
+        crate::ndjson::read_table(reader, &opts)
+    }
+
+    /// Serialises the table as newline-delimited JSON, one object per row.
+    ///
+    /// Output is written directly into a 64-byte aligned buffer. Keys are the
+    /// column names in column order. Null entries are left out of the object,
+    /// so a row with no non-null entries is written as `{}`. Non-finite floats
+    /// are emitted as `null`. With `datetime_ops`, datetime columns are written
+    /// as RFC 3339 strings.
+    pub fn to_ndjson(&self) -> Vec64<u8> {
+        let mut out = Vec64::new();
+        for row in 0..self.n_rows {
+            crate::ndjson::write_row(&mut out, &self.cols, row, true);
+        }
+        out
+    }
+
+    /// Writes the table as newline-delimited JSON, streaming one object per row
+    /// into `writer`.
+    ///
+    /// With default options the output is identical to `to_ndjson`. Clearing
+    /// `opts.omit_nulls` writes null entries as `"key":null` instead.
+    ///
+    /// # Errors
+    /// - `IoError` if writing fails
+    pub fn write_ndjson(
+        &self,
+        writer: impl std::io::Write,
+        opts: crate::NdjsonWriteOptions,
+    ) -> Result<(), MinarrowError> {
+        crate::ndjson::write_table(self, writer, &opts)
+    }
+
+    /// Writes the table to `path` in the Arrow IPC file format, also known as
+    /// Feather v2, as a single record batch.
+    ///
+    /// The schema is taken from the columns' fields, with the table's metadata
+    /// when `table_metadata` is enabled. Every buffer lands on a 64-byte boundary,
+    /// so the file can be memory-mapped back without copying.
+    ///
+    /// # Errors
+    /// - `IoError` if the file cannot be written
+    /// - `NotImplemented` for column types without an IPC mapping
+    pub fn write_ipc_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), MinarrowError> {
+        let schema = self.schema();
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        let mut writer = crate::ipc::FileWriter::new(file, schema)?;
+        writer.write(self)?;
+        writer.finish()
+    }
 }
 
 impl Table {
@@ -725,6 +1978,241 @@ impl Table {
     }
 }
 
+/// Key column values, normalised so that join and group-by keys compare directly.
+pub(crate) enum KeyColumn {
+    Int(IntegerArray<i64>),
+    /// `UInt64` keys, kept apart since they may not fit in `i64`.
+    UInt(Arc<IntegerArray<u64>>),
+    Str(StringArray<u32>),
+}
+
+/// A single non-null key value borrowed from a `KeyColumn`.
+///
+/// Integers in `i64` range are always `Int`, whatever their column type, so
+/// equal values match across signed and unsigned columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum KeyValue<'a> {
+    Int(i64),
+    UInt(u64),
+    Str(&'a str),
+}
+
+impl KeyColumn {
+    /// Reads integer columns as `i64`, `UInt64` columns as `u64`, and string or
+    /// categorical columns as strings.
+    ///
+    /// # Errors
+    /// - `TypeError` for any other column type.
+    pub(crate) fn from_array(array: &Array) -> Result<Self, MinarrowError> {
+        match array {
+            Array::NumericArray(NumericArray::UInt64(a)) => Ok(KeyColumn::UInt(a.clone())),
+            Array::NumericArray(num) if array.is_integer_array() => {
+                Ok(KeyColumn::Int(num.clone().i64()?))
+            }
+            Array::TextArray(text) => Ok(KeyColumn::Str(text.clone().str32()?)),
+            _ => Err(MinarrowError::TypeError {
+                from: "Array",
+                to: "key column",
+                message: Some("key columns must be integer, string or categorical".into()),
+            }),
+        }
+    }
+
+    /// Key at row `i`, or `None` when it is null.
+    #[inline]
+    pub(crate) fn get(&self, i: usize) -> Option<KeyValue<'_>> {
+        match self {
+            KeyColumn::Int(a) => a.get(i).map(KeyValue::Int),
+            KeyColumn::UInt(a) => a
+                .get(i)
+                .map(|v| i64::try_from(v).map_or(KeyValue::UInt(v), KeyValue::Int)),
+            KeyColumn::Str(a) => a.get_str(i).map(KeyValue::Str),
+        }
+    }
+
+    /// Whether both columns hold the same kind of key.
+    fn same_kind(&self, other: &KeyColumn) -> bool {
+        matches!(
+            (self, other),
+            (
+                KeyColumn::Int(_) | KeyColumn::UInt(_),
+                KeyColumn::Int(_) | KeyColumn::UInt(_)
+            ) | (KeyColumn::Str(_), KeyColumn::Str(_))
+        )
+    }
+}
+
+/// Whether the non-null cells at `idx` match under `opts`.
+///
+/// Both arrays are expected to share a type. Floats and datetimes compare by
+/// value, and everything else by its formatted value, so categorical cells
+/// match on their strings whatever their dictionaries.
+fn cells_equal(a: &Array, b: &Array, idx: usize, opts: &EqualsOptions) -> bool {
+    use crate::NumericArray;
+    match (a, b) {
+        (
+            Array::NumericArray(NumericArray::Float32(x)),
+            Array::NumericArray(NumericArray::Float32(y)),
+        ) => floats_equal(x.data[idx] as f64, y.data[idx] as f64, opts),
+        (
+            Array::NumericArray(NumericArray::Float64(x)),
+            Array::NumericArray(NumericArray::Float64(y)),
+        ) => floats_equal(x.data[idx], y.data[idx], opts),
+        #[cfg(feature = "datetime")]
+        (
+            Array::TemporalArray(crate::TemporalArray::Datetime32(x)),
+            Array::TemporalArray(crate::TemporalArray::Datetime32(y)),
+        ) => x.data[idx] == y.data[idx],
+        #[cfg(feature = "datetime")]
+        (
+            Array::TemporalArray(crate::TemporalArray::Datetime64(x)),
+            Array::TemporalArray(crate::TemporalArray::Datetime64(y)),
+        ) => x.data[idx] == y.data[idx],
+        _ => a.value_to_string(idx) == b.value_to_string(idx),
+    }
+}
+
+/// Float equality with tolerances, where infinities only match themselves.
+#[inline]
+fn floats_equal(x: f64, y: f64, opts: &EqualsOptions) -> bool {
+    if x.is_nan() || y.is_nan() {
+        return opts.nan_equal && x.is_nan() && y.is_nan();
+    }
+    if x == y {
+        return true;
+    }
+    // Differences between infinities are NaN and fail both checks
+    let diff = (x - y).abs();
+    diff <= opts.abs_tol || diff <= opts.rel_tol * x.abs().max(y.abs())
+}
+
+/// Advances a SplitMix64 generator, returning the next pseudo-random value.
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Aggregates the numeric `vals` column into `names.len()` output columns of
+/// `n_groups` cells each, for `Table::pivot` and `GroupBy::agg`.
+///
+/// `row_cells[row]` is the cell a row feeds, indexed `column * n_groups + group`,
+/// or `None` to skip the row. Cells that no row feeds are null, even under `Count`.
+pub(crate) fn agg_columns(
+    names: &[&str],
+    n_groups: usize,
+    agg: AggFn,
+    vals: &NumericArray,
+    row_cells: &[Option<usize>],
+) -> Result<Vec<FieldArray>, MinarrowError> {
+    macro_rules! agg {
+        ($a:expr) => {{
+            let mut cells = vec![None; names.len() * n_groups];
+            for (row, cell) in row_cells.iter().enumerate() {
+                if let Some(cell) = cell {
+                    let state: &mut AggState<_> = cells[*cell].get_or_insert_default();
+                    if let Some(v) = $a.get(row) {
+                        state.update(v);
+                    }
+                }
+            }
+            names
+                .iter()
+                .enumerate()
+                .map(|(c, name)| agg_column(name, agg, &cells[c * n_groups..(c + 1) * n_groups]))
+                .collect()
+        }};
+    }
+    Ok(match vals {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(a) => agg!(a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(a) => agg!(a),
+        NumericArray::Int32(a) => agg!(a),
+        NumericArray::Int64(a) => agg!(a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(a) => agg!(a),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(a) => agg!(a),
+        NumericArray::UInt32(a) => agg!(a),
+        NumericArray::UInt64(a) => agg!(a),
+        NumericArray::Float32(a) => agg!(a),
+        NumericArray::Float64(a) => agg!(a),
+        NumericArray::Null => return Err(MinarrowError::NullError { message: None }),
+    })
+}
+
+/// Builds one aggregated output column from its cells, typed as described on `AggFn`.
+fn agg_column<T: AggValue>(name: &str, agg: AggFn, cells: &[Option<AggState<T>>]) -> FieldArray {
+    fn build<V: AggValue>(values: impl ExactSizeIterator<Item = Option<V>>) -> Array {
+        let mut mask = Bitmask::new_set_all(values.len(), true);
+        let mut data = Vec64::with_capacity(values.len());
+        for (i, v) in values.enumerate() {
+            if v.is_none() {
+                mask.set(i, false);
+            }
+            data.push(v.unwrap_or_default());
+        }
+        let null_mask = if mask.all_set() { None } else { Some(mask) };
+        V::into_array(data, null_mask)
+    }
+    let array = match agg {
+        AggFn::Count => build(cells.iter().map(|c| c.as_ref().map(|s| s.count() as u64))),
+        AggFn::Sum => build(cells.iter().map(|c| c.as_ref().and_then(|s| s.sum()))),
+        AggFn::Min => build(cells.iter().map(|c| c.as_ref().and_then(|s| s.min()))),
+        AggFn::Max => build(cells.iter().map(|c| c.as_ref().and_then(|s| s.max()))),
+        AggFn::Mean => build(cells.iter().map(|c| c.as_ref().and_then(|s| s.mean()))),
+    };
+    FieldArray::new(Field::new(name, array.arrow_type(), true, None), array)
+}
+
+/// Pairs left and right row indices with equal, non-null keys.
+///
+/// Hashes the left keys, probes with the right keys, then emits pairs in left
+/// row order. Under `JoinType::Left` a left row without a match is paired with `None`.
+fn match_join_keys<K: Hash + Eq>(
+    left: impl Iterator<Item = Option<K>>,
+    right: impl Iterator<Item = Option<K>>,
+    n_left: usize,
+    how: JoinType,
+) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut lookup: HashMap<K, Vec<usize>> = HashMap::new();
+    for (i, key) in left.enumerate() {
+        if let Some(key) = key {
+            lookup.entry(key).or_default().push(i);
+        }
+    }
+
+    let mut matches: Vec<Vec<usize>> = vec![Vec::new(); n_left];
+    for (j, key) in right.enumerate() {
+        if let Some(rows) = key.and_then(|k| lookup.get(&k)) {
+            for &i in rows {
+                matches[i].push(j);
+            }
+        }
+    }
+
+    let mut left_rows = Vec::new();
+    let mut right_rows = Vec::new();
+    for (i, js) in matches.into_iter().enumerate() {
+        if js.is_empty() {
+            if how == JoinType::Left {
+                left_rows.push(i);
+                right_rows.push(None);
+            }
+            continue;
+        }
+        for j in js {
+            left_rows.push(i);
+            right_rows.push(Some(j));
+        }
+    }
+    (left_rows, right_rows)
+}
+
 impl<'a> IntoIterator for &'a Table {
     type Item = &'a FieldArray;
     type IntoIter = std::slice::Iter<'a, FieldArray>;
@@ -779,62 +2267,24 @@ impl Concatenate for Table {
             return Err(MinarrowError::IncompatibleTypeError {
                 from: "Table",
                 to: "Table",
-                message: Some(format!(
-                    "Cannot concatenate tables with different column counts: {} vs {}",
-                    self.n_cols(),
-                    other.n_cols()
-                )),
-            });
-        }
-
-        // If both tables are empty, return empty table
-        if self.n_cols() == 0 {
-            return Ok(Table::new(format!("{}+{}", self.name, other.name), None));
-        }
-
-        // Validate column schemas match and concatenate arrays
-        let mut result_cols = Vec::with_capacity(self.n_cols());
-
-        for (col_idx, (self_col, other_col)) in self
-            .cols
-            .into_iter()
-            .zip(other.cols.into_iter())
-            .enumerate()
-        {
-            // Check field compatibility
-            if self_col.field.name != other_col.field.name {
-                return Err(MinarrowError::IncompatibleTypeError {
-                    from: "Table",
-                    to: "Table",
-                    message: Some(format!(
-                        "Column {} name mismatch: '{}' vs '{}'",
-                        col_idx, self_col.field.name, other_col.field.name
-                    )),
-                });
-            }
+                message: Some(format!(
+                    "Cannot concatenate tables with different column counts: {} vs {}",
+                    self.n_cols(),
+                    other.n_cols()
+                )),
+            });
+        }
 
-            if self_col.field.dtype != other_col.field.dtype {
-                return Err(MinarrowError::IncompatibleTypeError {
-                    from: "Table",
-                    to: "Table",
-                    message: Some(format!(
-                        "Column '{}' type mismatch: {:?} vs {:?}",
-                        self_col.field.name, self_col.field.dtype, other_col.field.dtype
-                    )),
-                });
-            }
+        // If both tables are empty, return empty table
+        if self.n_cols() == 0 {
+            return Ok(Table::new(format!("{}+{}", self.name, other.name), None));
+        }
 
-            if self_col.field.nullable != other_col.field.nullable {
-                return Err(MinarrowError::IncompatibleTypeError {
-                    from: "Table",
-                    to: "Table",
-                    message: Some(format!(
-                        "Column '{}' nullable mismatch: {} vs {}",
-                        self_col.field.name, self_col.field.nullable, other_col.field.nullable
-                    )),
-                });
-            }
+        // Validate column schemas match, then concatenate arrays
+        self.schema().matches(&other)?;
+        let mut result_cols = Vec::with_capacity(self.n_cols());
 
+        for (self_col, other_col) in self.cols.into_iter().zip(other.cols) {
             // Concatenate arrays
             let concatenated_array = self_col.array.concat(other_col.array)?;
             let null_count = concatenated_array.null_count();
@@ -933,74 +2383,35 @@ fn consolidate_vec_concat(tables: Vec<Table>) -> Table {
 
 impl Display for Table {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, &PrintOptions::default())
+    }
+}
+
+impl FormatWith for Table {
+    fn fmt_with(&self, f: &mut Formatter<'_>, options: &PrintOptions) -> std::fmt::Result {
         if self.cols.is_empty() {
             return writeln!(f, "Table  \"{}\" [0 rows × 0 cols] – empty", self.name);
         }
 
-        // Gather column metadata & cell strings (with null handling)
-        let row_indices: Vec<usize> = if self.n_rows <= MAX_PREVIEW {
-            (0..self.n_rows).collect()
-        } else {
-            let mut idx = (0..10).collect::<Vec<_>>();
-            idx.extend((self.n_rows - 10)..self.n_rows);
-            idx
-        };
-
-        // column header strings and tracked widths
-        let mut headers: Vec<String> = Vec::with_capacity(self.cols.len());
-        let mut widths: Vec<usize> = Vec::with_capacity(self.cols.len());
-
-        for col in &self.cols {
-            let hdr = format!("{}:{:?}", col.field.name, col.field.dtype);
-            widths.push(hdr.len());
-            headers.push(hdr);
-        }
-
-        // matrix of cell strings
-        let mut rows: Vec<Vec<String>> = Vec::with_capacity(row_indices.len());
-
-        for &row_idx in &row_indices {
-            let mut row: Vec<String> = Vec::with_capacity(self.cols.len());
-
-            for (col_idx, col) in self.cols.iter().enumerate() {
-                let val = value_to_string(&col.array, row_idx);
-                widths[col_idx] = widths[col_idx].max(val.len());
-                row.push(val);
-            }
-            rows.push(row);
-        }
-
-        // row-index column (“idx”)
-        let idx_width = usize::max(
-            3, // “idx”
-            ((self.n_rows - 1) as f64).log10().floor() as usize + 1,
-        );
-
-        // Render header
-        writeln!(
-            f,
+        let headers: Vec<String> = self
+            .cols
+            .iter()
+            .map(|col| format!("{}:{:?}", col.field.name, col.field.dtype))
+            .collect();
+        let title = format!(
             "Table \"{}\" [{} rows × {} cols]",
             self.name,
             self.n_rows,
             self.cols.len()
-        )?;
-        print_rule(f, idx_width, &widths)?;
-        print_header_row(f, idx_width, &headers, &widths)?;
-        print_rule(f, idx_width, &widths)?;
-
-        // Render body
-        for (logical_row, cells) in rows.iter().enumerate() {
-            let physical_row = row_indices[logical_row];
-            write!(f, "| {idx:>w$} |", idx = physical_row, w = idx_width)?;
-            for (col_idx, cell) in cells.iter().enumerate() {
-                write!(f, " {val:^w$} |", val = cell, w = widths[col_idx])?;
-            }
-            writeln!(f)?;
-            if logical_row == 9 && self.n_rows > MAX_PREVIEW {
-                print_ellipsis_row(f, idx_width, &widths)?;
-            }
-        }
-        print_rule(f, idx_width, &widths)
+        );
+        print_table(
+            f,
+            &title,
+            &headers,
+            self.n_rows,
+            |col, row| value_to_string_with(&self.cols[col].array, row, options.float_precision),
+            options,
+        )
     }
 }
 
@@ -1104,7 +2515,7 @@ mod tests {
     use crate::{fa_bool, fa_i32, fa_i64, fa_u32};
     #[cfg(all(feature = "views", feature = "select"))]
     use crate::traits::selection::ColumnSelection;
-    use crate::{Array, BooleanArray, IntegerArray, NumericArray};
+    use crate::{Array, BooleanArray, FloatArray, IntegerArray, NumericArray};
 
     #[test]
     fn test_new_table() {
@@ -1221,6 +2632,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rename_columns() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("a", 1));
+        t.add_col(fa_i32!("b", 2));
+        t.add_col(fa_i32!("c", 3));
+
+        t.rename_columns(&[("a", "x"), ("c", "z")]).unwrap();
+        assert_eq!(t.col_names(), vec!["x", "b", "z"]);
+
+        // Swapping names within one call is allowed.
+        t.rename_columns(&[("x", "b"), ("b", "x")]).unwrap();
+        assert_eq!(t.col_names(), vec!["b", "x", "z"]);
+    }
+
+    #[test]
+    fn test_rename_columns_is_atomic() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("a", 1));
+        t.add_col(fa_i32!("b", 2));
+
+        // Missing old name: nothing is renamed.
+        assert!(t.rename_columns(&[("a", "x"), ("missing", "y")]).is_err());
+        assert_eq!(t.col_names(), vec!["a", "b"]);
+
+        // Collision with an unrenamed column.
+        assert!(t.rename_columns(&[("a", "b")]).is_err());
+        // Two columns renamed to the same name.
+        assert!(t.rename_columns(&[("a", "x"), ("b", "x")]).is_err());
+        // Same column renamed twice.
+        assert!(t.rename_columns(&[("a", "x"), ("a", "y")]).is_err());
+        assert_eq!(t.col_names(), vec!["a", "b"]);
+    }
+
     #[test]
     fn test_table_iter() {
         let mut t = Table::new_empty();
@@ -1729,6 +3174,967 @@ mod tests {
             }
         }
     }
+    fn i32_values(t: &Table, col: usize) -> Vec<i32> {
+        match &t.cols[col].array {
+            Array::NumericArray(NumericArray::Int32(arr)) => arr.data.as_slice().to_vec(),
+            _ => panic!("wrong type"),
+        }
+    }
+
+    #[test]
+    fn test_sort_within_groups() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("g", 1, 2, 1, 2, 1));
+        t.add_col(fa_i32!("v", 5, 3, 4, 1, 2));
+
+        let asc = t
+            .sort_within_groups(&[0], &[(1, SortOrder::Ascending)])
+            .unwrap();
+        assert_eq!(asc.n_rows(), 5);
+        assert_eq!(i32_values(&asc, 0), vec![1, 1, 1, 2, 2]);
+        assert_eq!(i32_values(&asc, 1), vec![2, 4, 5, 1, 3]);
+
+        let desc = t
+            .sort_within_groups(&[0], &[(1, SortOrder::Descending)])
+            .unwrap();
+        assert_eq!(i32_values(&desc, 0), vec![1, 1, 1, 2, 2]);
+        assert_eq!(i32_values(&desc, 1), vec![5, 4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_sort_within_groups_contiguous_and_stable() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("g", 7, 7, 7, 3, 3, 3));
+        t.add_col(fa_i32!("v", 2, 1, 2, 9, 8, 9));
+        t.add_col(fa_i32!("id", 0, 1, 2, 3, 4, 5));
+
+        let sorted = t
+            .sort_within_groups(&[0], &[(1, SortOrder::Ascending)])
+            .unwrap();
+        // Segment order is kept and ties keep their input order.
+        assert_eq!(i32_values(&sorted, 0), vec![7, 7, 7, 3, 3, 3]);
+        assert_eq!(i32_values(&sorted, 2), vec![1, 0, 2, 4, 3, 5]);
+    }
+
+    #[test]
+    fn test_sort_within_groups_nulls_last() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("g", 1, 1, 1));
+        t.add_col(crate::fa_i32_opt!("v", None::<i32>, Some(1), Some(2)));
+
+        let sorted = t
+            .sort_within_groups(&[0], &[(1, SortOrder::Descending)])
+            .unwrap();
+        let v = &sorted.cols[1].array;
+        assert_eq!(sorted.cols[1].null_count, 1);
+        assert!(v.null_mask().is_some_and(|m| !m.get(2)));
+        match v {
+            Array::NumericArray(NumericArray::Int32(arr)) => {
+                assert_eq!(arr.get(0), Some(2));
+                assert_eq!(arr.get(1), Some(1));
+            }
+            _ => panic!("wrong type"),
+        }
+    }
+
+    #[test]
+    fn test_sort_by_multiple_keys() {
+        let mut t = Table::new_empty();
+        t.add_col(crate::fa_str32!("k", "b", "a", "b", "a", "b"));
+        t.add_col(crate::fa_f64_opt!(
+            "v",
+            Some(1.5),
+            None,
+            Some(2.5),
+            Some(0.5),
+            None
+        ));
+        t.add_col(fa_i32!("id", 0, 1, 2, 3, 4));
+
+        let sorted = t
+            .sort_by(&[
+                ("k", NullOrder::Last, SortOrder::Ascending),
+                ("v", NullOrder::First, SortOrder::Descending),
+            ])
+            .unwrap();
+        assert_eq!(i32_values(&sorted, 2), vec![1, 3, 4, 2, 0]);
+        assert_eq!(sorted.cols[1].null_count, 2);
+
+        let sorted = t
+            .sort_by(&[("v", NullOrder::Last, SortOrder::Ascending)])
+            .unwrap();
+        // Stable: the two nulls keep their input order at the end.
+        assert_eq!(i32_values(&sorted, 2), vec![3, 0, 2, 1, 4]);
+
+        assert!(matches!(
+            t.sort_by(&[("missing", NullOrder::Last, SortOrder::Ascending)]),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_sort_by_datetime_key() {
+        use crate::{DatetimeArray, TimeUnit};
+
+        let mut t = Table::new_empty();
+        let ts = DatetimeArray::<i64>::from_slice(&[30, 10, 20], Some(TimeUnit::Seconds));
+        t.add_col(FieldArray::from_arr("ts", Array::from_datetime_i64(ts)));
+        t.add_col(fa_i32!("id", 0, 1, 2));
+
+        let sorted = t
+            .sort_by(&[("ts", NullOrder::Last, SortOrder::Descending)])
+            .unwrap();
+        assert_eq!(i32_values(&sorted, 1), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_within_groups_bad_column() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("g", 1, 2));
+        assert!(t.sort_within_groups(&[3], &[]).is_err());
+        assert!(
+            t.sort_within_groups(&[0], &[(1, SortOrder::Ascending)])
+                .is_err()
+        );
+    }
+    #[test]
+    fn test_pivot_sum() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 1, 2, 2, 1));
+        t.add_col(crate::fa_str32!("key", "a", "b", "a", "a", "a"));
+        t.add_col(fa_i32!("val", 1, 2, 3, 4, 5));
+
+        let wide = t.pivot(&["id"], "key", "val", AggFn::Sum).unwrap();
+        assert_eq!(wide.n_rows(), 2);
+        assert_eq!(wide.col_names(), vec!["id", "a", "b"]);
+        assert_eq!(i32_values(&wide, 0), vec![1, 2]);
+
+        let a = wide.cols[1].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!(a.data.as_slice(), &[6, 7]);
+        assert!(a.null_mask.is_none());
+
+        let b = wide.cols[2].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!(b.get(0), Some(2));
+        assert_eq!(b.get(1), None);
+        assert_eq!(wide.cols[2].null_count, 1);
+    }
+
+    #[test]
+    fn test_pivot_count_and_mean() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 1, 2, 2, 1));
+        t.add_col(crate::fa_str32!("key", "a", "b", "a", "a", "a"));
+        t.add_col(fa_i32!("val", 1, 2, 3, 4, 5));
+
+        let counts = t.pivot(&["id"], "key", "val", AggFn::Count).unwrap();
+        let a = counts.cols[1].array.num_ref().unwrap().u64_ref().unwrap();
+        assert_eq!(a.data.as_slice(), &[2, 2]);
+        let b = counts.cols[2].array.num_ref().unwrap().u64_ref().unwrap();
+        assert_eq!(b.get(0), Some(1));
+        assert_eq!(b.get(1), None);
+
+        let means = t.pivot(&["id"], "key", "val", AggFn::Mean).unwrap();
+        let a = means.cols[1].array.num_ref().unwrap().f64_ref().unwrap();
+        assert_eq!(a.data.as_slice(), &[3.0, 3.5]);
+
+        let maxes = t.pivot(&["id"], "key", "val", AggFn::Max).unwrap();
+        let a = maxes.cols[1].array.num_ref().unwrap().i32_ref().unwrap();
+        assert_eq!(a.data.as_slice(), &[5, 4]);
+    }
+
+    #[test]
+    fn test_pivot_errors() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2));
+        t.add_col(fa_i32!("key", 3, 4));
+        t.add_col(fa_i32!("val", 5, 6));
+
+        assert!(matches!(
+            t.pivot(&["id"], "key", "val", AggFn::Sum),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(matches!(
+            t.pivot(&["id"], "missing", "val", AggFn::Sum),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_pivot_multi_index_and_limits() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("year", 1, 1, 1, 2));
+        t.add_col(crate::fa_str32!("region", "n", "s", "n", "n"));
+        t.add_col(crate::fa_str32!("key", "a", "b", "b", "a"));
+        t.add_col(fa_i32!("val", 1, 2, 3, 4));
+
+        let wide = t
+            .pivot(&["year", "region"], "key", "val", AggFn::Sum)
+            .unwrap();
+        assert_eq!(wide.col_names(), vec!["year", "region", "a", "b"]);
+        assert_eq!(i32_values(&wide, 0), vec![1, 1, 2]);
+        let a = wide.cols[2].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!((a.get(0), a.get(1), a.get(2)), (Some(1), None, Some(4)));
+        let b = wide.cols[3].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!((b.get(0), b.get(1), b.get(2)), (Some(3), Some(2), None));
+
+        let total = t.pivot(&[], "key", "val", AggFn::Sum).unwrap();
+        assert_eq!(total.n_rows(), 1);
+        assert_eq!(total.col_names(), vec!["a", "b"]);
+
+        let mut clash = Table::new_empty();
+        clash.add_col(crate::fa_str32!("a", "x"));
+        clash.add_col(crate::fa_str32!("key", "a"));
+        clash.add_col(fa_i32!("val", 1));
+        assert!(matches!(
+            clash.pivot(&["a"], "key", "val", AggFn::Sum),
+            Err(MinarrowError::IndexError(_))
+        ));
+
+        let n = MAX_PIVOT_COLUMNS + 1;
+        let keys: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let mut wide_keys = Table::new_empty();
+        wide_keys.add_col(FieldArray::from_arr(
+            "key",
+            Array::from_string32(StringArray::from_slice(&keys)),
+        ));
+        wide_keys.add_col(FieldArray::from_arr(
+            "val",
+            Array::from_int32(IntegerArray::from_slice(&vec![0; n])),
+        ));
+        assert!(matches!(
+            wide_keys.pivot(&[], "key", "val", AggFn::Sum),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_ndjson() {
+        let mut score = IntegerArray::<i64>::from_slice(&[10, 0, -3]);
+        score.set_null(1);
+
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3));
+        t.add_col(crate::fa_str32!(
+            "name",
+            "plain",
+            "say \"hi\"",
+            "a\nb\\c\u{1}"
+        ));
+        t.add_col(FieldArray::new(
+            Field::new("score", ArrowType::Int64, true, None),
+            Array::from_int64(score),
+        ));
+        t.add_col(fa_bool!("ok", true, false, true));
+
+        let out = t.to_ndjson();
+        let text = std::str::from_utf8(&out).unwrap();
+        assert_eq!(
+            text,
+            "{\"id\":1,\"name\":\"plain\",\"score\":10,\"ok\":true}\n\
+             {\"id\":2,\"name\":\"say \\\"hi\\\"\",\"ok\":false}\n\
+             {\"id\":3,\"name\":\"a\\nb\\\\c\\u0001\",\"score\":-3,\"ok\":true}\n"
+        );
+        assert!(Table::new_empty().to_ndjson().is_empty());
+
+        let mut all_null = IntegerArray::<i64>::from_slice(&[0]);
+        all_null.set_null(0);
+        let mut t = Table::new_empty();
+        t.add_col(FieldArray::from_arr("score", Array::from_int64(all_null)));
+        assert_eq!(t.to_ndjson().as_slice(), b"{}\n");
+    }
+
+    #[test]
+    fn test_drop_nulls() {
+        let mut a = IntegerArray::<i32>::from_slice(&[1, 2, 3, 4]);
+        a.set_null(1);
+        a.set_null(3);
+        let mut b = IntegerArray::<i32>::from_slice(&[10, 20, 30, 40]);
+        b.set_null(2);
+        b.set_null(3);
+
+        let mut t = Table::new_empty();
+        t.add_col(FieldArray::new(
+            Field::new("a", ArrowType::Int32, true, None),
+            Array::from_int32(a),
+        ));
+        t.add_col(FieldArray::new(
+            Field::new("b", ArrowType::Int32, true, None),
+            Array::from_int32(b),
+        ));
+        t.add_col(fa_i32!("c", 5, 6, 7, 8));
+
+        let any = t.drop_nulls(DropHow::Any, None).unwrap();
+        assert_eq!(i32_values(&any, 2), vec![5]);
+
+        let all = t.drop_nulls(DropHow::All, Some(&[0, 1])).unwrap();
+        assert_eq!(i32_values(&all, 2), vec![5, 6, 7]);
+
+        let subset = t.drop_nulls(DropHow::Any, Some(&[1])).unwrap();
+        assert_eq!(i32_values(&subset, 2), vec![5, 6]);
+
+        // A fully valid column in the subset keeps every row under `All`.
+        let kept = t.drop_nulls(DropHow::All, None).unwrap();
+        assert_eq!(kept.n_rows(), 4);
+
+        assert!(matches!(
+            t.drop_nulls(DropHow::Any, Some(&[3])),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_explode() {
+        let mut tags = StringArray::<u32>::default();
+        tags.push_str("a,b");
+        tags.push_null();
+        tags.push_str("");
+        tags.push_str("c");
+        tags.push_str("d,,e");
+
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3, 4, 5));
+        t.add_col(FieldArray::from_arr("tags", tags));
+
+        let out = t.explode("tags", ",").unwrap();
+        assert_eq!(out.n_rows(), 8);
+        assert_eq!(i32_values(&out, 0), vec![1, 1, 2, 3, 4, 5, 5, 5]);
+        let parts = out.cols[1].array.str_ref().unwrap().str32_ref().unwrap();
+        let got: Vec<Option<&str>> = (0..parts.len()).map(|i| parts.get_str(i)).collect();
+        assert_eq!(
+            got,
+            vec![
+                Some("a"),
+                Some("b"),
+                None,
+                None,
+                Some("c"),
+                Some("d"),
+                Some(""),
+                Some("e")
+            ]
+        );
+        assert_eq!(out.cols[1].null_count, 2);
+        assert!(out.cols[1].field.nullable);
+
+        assert!(matches!(
+            t.explode("missing", ","),
+            Err(MinarrowError::IndexError(_))
+        ));
+        assert!(matches!(
+            t.explode("id", ","),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(t.explode("tags", "").is_err());
+    }
+
+    #[cfg(feature = "chunked")]
+    #[test]
+    fn test_partition_by() {
+        let mut region = StringArray::<u32>::from_slice(&["n", "s", "n", "", "s", "n"]);
+        region.set_null(3);
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3, 4, 5, 6));
+        t.add_col(FieldArray::new(
+            Field::new("region", ArrowType::String, true, None),
+            Array::from_string32(region),
+        ));
+
+        let parts = t.partition_by("region").unwrap();
+        assert_eq!(parts.n_batches(), 3);
+        assert_eq!(parts.n_rows(), 6);
+        assert_eq!(parts.name, t.name);
+        let ids: Vec<Vec<i32>> = parts.batches.iter().map(|b| i32_values(b, 0)).collect();
+        assert_eq!(ids, vec![vec![1, 3, 6], vec![2, 5], vec![4]]);
+        assert_eq!(parts.batches[2].cols[1].null_count, 1);
+
+        let by_id = t.partition_by("id").unwrap();
+        assert_eq!(by_id.n_batches(), 6);
+
+        let empty = t.slice_clone(0, 0).partition_by("id").unwrap();
+        assert_eq!(empty.n_batches(), 0);
+        assert!(matches!(
+            t.partition_by("missing"),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[cfg(feature = "chunked")]
+    #[test]
+    fn test_partition_by_u64_keys() {
+        let wide = Table::new_empty().with_column(crate::fa_u64!("k", u64::MAX, 1, u64::MAX));
+        let parts = wide.partition_by("k").unwrap();
+        assert_eq!(parts.n_batches(), 2);
+        assert_eq!(parts.batches[0].n_rows(), 2);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3));
+        t.add_col(crate::fa_str32!("name", "a", "bb", "ccc"));
+
+        let out = t.reverse();
+        assert_eq!(out.n_rows(), 3);
+        assert_eq!(i32_values(&out, 0), vec![3, 2, 1]);
+        let names = out.cols[1].array.str_ref().unwrap().str32_ref().unwrap();
+        assert_eq!(names.get_str(0), Some("ccc"));
+        assert_eq!(names.get_str(2), Some("a"));
+        assert_eq!(out.reverse().cols[1].array, t.cols[1].array);
+    }
+
+    #[test]
+    fn test_head_tail_sample() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9));
+
+        assert_eq!(i32_values(&t.head(3), 0), vec![0, 1, 2]);
+        assert_eq!(i32_values(&t.tail(3), 0), vec![7, 8, 9]);
+        assert_eq!(t.head(50).n_rows(), 10);
+        assert_eq!(t.tail(50).n_rows(), 10);
+        assert_eq!(t.tail(0).n_rows(), 0);
+
+        let s = t.sample(4, 42);
+        let ids = i32_values(&s, 0);
+        assert_eq!(ids.len(), 4);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(i32_values(&t.sample(4, 42), 0), ids);
+        assert_eq!(t.sample(20, 7).n_rows(), 10);
+        assert_eq!(t.sample(0, 7).n_rows(), 0);
+
+        // Every row should turn up across enough seeds
+        let mut seen = [false; 10];
+        for seed in 0..64 {
+            for id in i32_values(&t.sample(3, seed), 0) {
+                seen[id as usize] = true;
+            }
+        }
+        assert!(seen.iter().all(|&b| b));
+    }
+
+    #[test]
+    fn test_equals() {
+        let float_table = |values: &[f64], null: Option<usize>| {
+            let mut arr = FloatArray::<f64>::from_slice(values);
+            if let Some(i) = null {
+                arr.set_null(i);
+            }
+            let mut t = Table::new_empty();
+            t.add_col(fa_i32!("id", 1, 2, 3));
+            t.add_col(FieldArray::new(
+                Field::new("x", ArrowType::Float64, true, None),
+                Array::from_float64(arr),
+            ));
+            t
+        };
+        let opts = EqualsOptions::default();
+
+        let a = float_table(&[1.0, f64::NAN, 3.0], None);
+        assert!(a.equals(&a.clone(), opts.clone()));
+        let no_nan = EqualsOptions {
+            nan_equal: false,
+            ..opts.clone()
+        };
+        assert!(!a.equals(&a.clone(), no_nan));
+
+        // Values hidden behind nulls are not compared
+        let b = float_table(&[1.0, f64::NAN, 9.0], Some(2));
+        let c = float_table(&[1.0, f64::NAN, 7.0], Some(2));
+        assert!(b.equals(&c, opts.clone()));
+        let err = a.assert_equals(&b, opts.clone()).unwrap_err();
+        assert!(err.to_string().contains("row 2, column 1 ('x')"));
+
+        let near = float_table(&[1.0 + 1e-9, f64::NAN, 3.0], None);
+        assert!(!a.equals(&near, opts.clone()));
+        let abs = EqualsOptions {
+            abs_tol: 1e-6,
+            ..opts.clone()
+        };
+        assert!(a.equals(&near, abs));
+        let rel = EqualsOptions {
+            rel_tol: 1e-6,
+            ..opts.clone()
+        };
+        assert!(a.equals(&near, rel));
+
+        let mut tagged = a.clone();
+        std::sync::Arc::make_mut(&mut tagged.cols[1].field)
+            .metadata
+            .insert("unit".into(), "m".into());
+        assert!(matches!(
+            a.assert_equals(&tagged, opts.clone()),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+        let loose = EqualsOptions {
+            check_metadata: false,
+            ..opts.clone()
+        };
+        assert!(a.equals(&tagged, loose));
+
+        assert!(matches!(
+            a.assert_equals(&a.slice_clone(0, 2), opts),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_take() {
+        let mut score = IntegerArray::<i64>::from_slice(&[10, 20, 30]);
+        score.set_null(1);
+
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3));
+        t.add_col(crate::fa_str32!("name", "a", "b", "c"));
+        t.add_col(FieldArray::new(
+            Field::new("score", ArrowType::Int64, true, None),
+            Array::from_int64(score),
+        ));
+
+        let out = t.take(&IntegerArray::from_slice(&[2, 1, 1, 0])).unwrap();
+        assert_eq!(out.n_rows(), 4);
+        assert_eq!(i32_values(&out, 0), vec![3, 2, 2, 1]);
+        match &out.cols[1].array {
+            Array::TextArray(crate::TextArray::String32(names)) => {
+                assert_eq!(names.get_str(0), Some("c"));
+                assert_eq!(names.get_str(3), Some("a"));
+            }
+            _ => panic!("wrong type"),
+        }
+        assert_eq!(out.cols[2].null_count, 2);
+        assert!(!out.cols[2].array.null_mask().unwrap().get(1));
+
+        assert!(matches!(
+            t.take(&IntegerArray::from_slice(&[0, 3])),
+            Err(MinarrowError::IndexError(_))
+        ));
+        let mut with_null = IntegerArray::<u32>::from_slice(&[0, 1]);
+        with_null.set_null(0);
+        assert!(matches!(
+            t.take(&with_null),
+            Err(MinarrowError::NullError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3, 4));
+        t.add_col(fa_bool!("flag", true, false, true, false));
+
+        let out = t
+            .filter(&Bitmask::from_bools(&[true, true, false, true]))
+            .unwrap();
+        assert_eq!(out.n_rows(), 3);
+        assert_eq!(i32_values(&out, 0), vec![1, 2, 4]);
+        assert_eq!(out.cols[1].array.len(), 3);
+
+        assert!(matches!(
+            t.filter(&Bitmask::new_set_all(2, true)),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_select() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3));
+        t.add_col(fa_bool!("flag", true, false, true));
+        t.add_col(fa_i32!("score", 7, 8, 9));
+
+        let out = t.select(&["score", "id", "score"]).unwrap();
+        assert_eq!(out.col_names(), vec!["score", "id", "score"]);
+        assert_eq!(out.n_rows(), 3);
+        assert_eq!(i32_values(&out, 0), vec![7, 8, 9]);
+        assert_eq!(i32_values(&out, 1), vec![1, 2, 3]);
+        assert!(Arc::ptr_eq(&out.cols[1].field, &t.cols[0].field));
+
+        match t.select(&["id", "missing"]) {
+            Err(MinarrowError::IndexError(msg)) => assert!(msg.contains("missing")),
+            other => panic!("expected IndexError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_null_mask_of() {
+        let mut t = Table::new_empty();
+        t.add_col(fa_i32!("id", 1, 2, 3));
+        t.add_col(FieldArray::from_arr(
+            "opt",
+            Array::from_int32(IntegerArray::new(
+                crate::vec64![1, 0, 3],
+                Some(Bitmask::from_bools(&[true, false, true])),
+            )),
+        ));
+
+        assert!(t.null_mask_of("id").unwrap().all_set());
+        let mask = t.null_mask_of("opt").unwrap();
+        assert_eq!(mask.len(), 3);
+        assert!(mask.get(0) && !mask.get(1) && mask.get(2));
+        assert!(matches!(
+            t.null_mask_of("missing"),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_vstack() {
+        let mut a = Table::new("a".into(), None);
+        a.add_col(fa_i32!("id", 1, 2));
+        a.add_col(fa_bool!("flag", true, false));
+        let mut b = Table::new("b".into(), None);
+        b.add_col(fa_i32!("id", 3));
+        b.add_col(fa_bool!("flag", true));
+
+        let out = Table::vstack(&[a.clone(), b.clone(), a.clone()]).unwrap();
+        assert_eq!(out.name, "a");
+        assert_eq!(out.n_rows(), 5);
+        assert_eq!(out.col_names(), vec!["id", "flag"]);
+        assert_eq!(i32_values(&out, 0), vec![1, 2, 3, 1, 2]);
+
+        assert_eq!(Table::vstack(&[]).unwrap().n_rows(), 0);
+
+        let mut c = Table::new("c".into(), None);
+        c.add_col(fa_i32!("id", 4));
+        c.add_col(fa_i32!("flag", 0));
+        match Table::vstack(&[a.clone(), b, c]) {
+            Err(MinarrowError::IncompatibleTypeError { message, .. }) => {
+                let msg = message.unwrap();
+                assert!(msg.contains("'flag'") && msg.contains("table 2"));
+            }
+            other => panic!("expected IncompatibleTypeError, got {:?}", other),
+        }
+
+        let mut d = Table::new("d".into(), None);
+        d.add_col(fa_i32!("id", 4));
+        assert!(Table::vstack(&[a, d]).is_err());
+    }
+
+    #[test]
+    fn test_column_builders() {
+        let t = Table::new_empty()
+            .with_column(fa_i32!("id", 1, 2, 3))
+            .with_column(fa_bool!("flag", true, false, true));
+        assert_eq!(t.n_rows(), 3);
+        assert_eq!(t.col_names(), vec!["id", "flag"]);
+
+        let t = t.rename_column("flag", "active").unwrap();
+        assert_eq!(t.col_names(), vec!["id", "active"]);
+        assert_eq!(t.cols[1].field.dtype, ArrowType::Boolean);
+
+        let t = t.drop_column("id").unwrap();
+        assert_eq!(t.col_names(), vec!["active"]);
+        assert_eq!(t.n_rows(), 3);
+
+        assert!(matches!(
+            t.clone().drop_column("id"),
+            Err(MinarrowError::IndexError(_))
+        ));
+        assert!(matches!(
+            t.rename_column("nope", "x"),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Column length mismatch")]
+    fn test_with_column_length_mismatch() {
+        let _ = Table::new_empty()
+            .with_column(fa_i32!("a", 1, 2))
+            .with_column(fa_i32!("b", 1, 2, 3));
+    }
+
+    #[test]
+    fn test_with_row_index() {
+        let t = Table::new_empty().with_column(fa_i32!("v", 5, 6, 7));
+        let t = t.with_row_index("row", 10).unwrap();
+        assert_eq!(t.col_names(), vec!["row", "v"]);
+        assert!(!t.cols[0].field.nullable);
+        assert_eq!(t.cols[0].field.dtype, ArrowType::Int64);
+        let idx = t.cols[0].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!(idx.data.as_slice(), &[10, 11, 12]);
+
+        assert!(matches!(
+            t.clone().with_row_index("v", 0),
+            Err(MinarrowError::IndexError(_))
+        ));
+
+        // The last index may reach i64::MAX but not pass it.
+        let top = t.clone().with_row_index("top", i64::MAX - 3).unwrap();
+        let idx = top.cols[0].array.num_ref().unwrap().i64_ref().unwrap();
+        assert_eq!(idx.data.as_slice().last(), Some(&(i64::MAX - 1)));
+        assert!(matches!(
+            t.with_row_index("over", i64::MAX - 1),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        let empty = Table::new_empty().with_row_index("row", i64::MAX).unwrap();
+        assert_eq!(empty.n_rows(), 0);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t = Table::new_empty()
+            .with_column(crate::fa_str32!("metric", "min", "max"))
+            .with_column(fa_i32!("a", 1, 9))
+            .with_column(crate::fa_f64_opt!("b", Some(0.5), None));
+        let tt = t.transpose("metric").unwrap();
+        assert_eq!(tt.col_names(), vec!["metric", "min", "max"]);
+        assert_eq!(tt.n_rows(), 2);
+        assert_eq!(tt.cols[0].array.value_to_string(1), "b");
+        assert_eq!(tt.cols[1].field.dtype, ArrowType::Float64);
+        let max = tt.cols[2].array.num_ref().unwrap().f64_ref().unwrap();
+        assert_eq!(max.get(0), Some(9.0));
+        assert_eq!(max.get(1), None);
+        assert_eq!(tt.cols[2].null_count, 1);
+
+        assert!(matches!(
+            t.transpose("missing"),
+            Err(MinarrowError::IndexError(_))
+        ));
+        let text = t.clone().with_column(crate::fa_str32!("c", "x", "y"));
+        assert!(matches!(
+            text.transpose("metric"),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        let dup = Table::new_empty()
+            .with_column(crate::fa_str32!("k", "x", "x"))
+            .with_column(fa_i32!("a", 1, 2));
+        assert!(matches!(
+            dup.transpose("k"),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_join() {
+        let left = Table::new_empty()
+            .with_column(crate::fa_i32_opt!("id", Some(1), Some(2), None, Some(3)))
+            .with_column(fa_i32!("v", 10, 20, 30, 40));
+        let right = Table::new_empty()
+            .with_column(fa_i32!("id", 3, 1, 1))
+            .with_column(fa_i32!("v", 300, 100, 101));
+
+        let inner = left.join(&right, "id", "id", JoinType::Inner).unwrap();
+        assert_eq!(inner.col_names(), vec!["id", "v", "v_right"]);
+        assert_eq!(inner.n_rows(), 3);
+        assert_eq!(i32_values(&inner, 1), vec![10, 10, 40]);
+        assert_eq!(i32_values(&inner, 2), vec![100, 101, 300]);
+
+        let outer = left.join(&right, "id", "id", JoinType::Left).unwrap();
+        assert_eq!(outer.n_rows(), 5);
+        assert_eq!(i32_values(&outer, 1), vec![10, 10, 20, 30, 40]);
+        let mask = outer.cols[2].array.null_mask().unwrap();
+        let valid: Vec<bool> = (0..5).map(|i| mask.get(i)).collect();
+        assert_eq!(valid, vec![true, true, false, false, true]);
+        assert_eq!(outer.cols[2].null_count, 2);
+        assert!(outer.cols[2].field.nullable);
+    }
+
+    #[test]
+    fn test_join_u64_keys() {
+        let big = u64::MAX - 1;
+        let left = Table::new_empty()
+            .with_column(crate::fa_u64!("id", big, 7, u64::MAX))
+            .with_column(fa_i32!("x", 1, 2, 3));
+        let right = Table::new_empty()
+            .with_column(crate::fa_i64!("id", 7, -1))
+            .with_column(crate::fa_u64!("id_u", big, 0))
+            .with_column(fa_i32!("y", 10, 20));
+
+        let signed = left.join(&right, "id", "id", JoinType::Inner).unwrap();
+        assert_eq!(i32_values(&signed, 1), vec![2]);
+
+        let unsigned = left.join(&right, "id", "id_u", JoinType::Inner).unwrap();
+        assert_eq!(i32_values(&unsigned, 1), vec![1]);
+        assert_eq!(i32_values(&unsigned, 4), vec![10]);
+    }
+
+    #[test]
+    fn test_join_suffix_avoids_existing_names() {
+        let left = Table::new_empty()
+            .with_column(fa_i32!("id", 1))
+            .with_column(fa_i32!("v", 10))
+            .with_column(fa_i32!("v_right", 11));
+        let right = Table::new_empty()
+            .with_column(fa_i32!("id", 1))
+            .with_column(fa_i32!("v", 20))
+            .with_column(fa_i32!("v_right_2", 21));
+
+        let out = left.join(&right, "id", "id", JoinType::Inner).unwrap();
+        assert_eq!(
+            out.col_names(),
+            vec!["id", "v", "v_right", "v_right_3", "v_right_2"]
+        );
+        assert_eq!(i32_values(&out, 3), vec![20]);
+    }
+
+    #[test]
+    fn test_join_string_keys() {
+        let left = Table::new_empty()
+            .with_column(crate::fa_str32!("k", "a", "b"))
+            .with_column(fa_i32!("x", 1, 2));
+        let right = Table::new_empty()
+            .with_column(crate::fa_str32!("key", "b", "c"))
+            .with_column(fa_i32!("y", 7, 8));
+
+        let out = left.join(&right, "k", "key", JoinType::Inner).unwrap();
+        assert_eq!(out.col_names(), vec!["k", "x", "key", "y"]);
+        assert_eq!(i32_values(&out, 1), vec![2]);
+        assert_eq!(i32_values(&out, 3), vec![7]);
+
+        assert!(matches!(
+            left.join(&right, "x", "key", JoinType::Inner),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(matches!(
+            left.join(&right, "k", "missing", JoinType::Inner),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_print_with_truncates_rows_and_columns() {
+        let mut t = Table::new_empty();
+        for c in 0..6 {
+            let vals: Vec<i32> = (0..30).map(|r| r * 10 + c).collect();
+            t.add_col(field_array(
+                format!("c{c}"),
+                Array::from_int32(IntegerArray::from_slice(&vals)),
+            ));
+        }
+
+        let full = t.to_string();
+        assert!(full.contains("c2:Int32"));
+        assert!(!full.contains('…'));
+
+        let options = PrintOptions {
+            max_rows: 6,
+            min_rows: 4,
+            max_cols: 4,
+            ..Default::default()
+        };
+        let out = t.display_with(&options).to_string();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], format!("Table \"{}\" [30 rows × 6 cols]", t.name));
+        for hdr in ["c0:Int32", "c1:Int32", "c4:Int32", "c5:Int32"] {
+            assert!(lines[2].contains(hdr));
+        }
+        assert!(!lines[2].contains("c2:Int32") && !lines[2].contains("c3:Int32"));
+        // title, 3 rules, header, then 2 head rows, an ellipsis row and 2 tail rows
+        assert_eq!(lines.len(), 1 + 3 + 1 + 5);
+        assert!(lines[4].starts_with("|   0 |"));
+        assert!(lines[6].starts_with("|  …  |"));
+        assert!(lines[8].starts_with("|  29 |"));
+        assert!(lines[8].contains(" 295 ") && !lines[8].contains(" 292 "));
+
+        let width = lines[1].chars().count();
+        assert!(lines[1..].iter().all(|l| l.chars().count() == width));
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_describe() {
+        let t = Table::new_empty()
+            .with_column(crate::fa_i32_opt!(
+                "n",
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                None
+            ))
+            .with_column(crate::fa_str32!("s", "a", "b", "c", "d", "e"));
+        let d = t.describe();
+        assert_eq!(d.col_names(), vec!["statistic", "n", "s"]);
+        assert_eq!(d.n_rows(), 9);
+        assert_eq!(d.cols[0].array.value_to_string(5), "25%");
+
+        let n = d.cols[1].array.num_ref().unwrap().f64_ref().unwrap();
+        let sd = (5.0f64 / 3.0).sqrt();
+        let expected = [4.0, 1.0, 2.5, sd, 1.0, 1.75, 2.5, 3.25, 4.0];
+        for (i, v) in expected.iter().enumerate() {
+            assert!((n.get(i).unwrap() - v).abs() < 1e-12, "row {}", i);
+        }
+
+        let s = d.cols[2].array.num_ref().unwrap().f64_ref().unwrap();
+        assert_eq!(s.get(0), Some(5.0));
+        assert_eq!(s.get(1), Some(0.0));
+        assert!((2..9).all(|i| s.get(i).is_none()));
+
+        let clash = Table::new_empty()
+            .with_column(fa_i32!("statistic", 1, 2))
+            .with_column(fa_i32!("statistic_2", 3, 4));
+        let d = clash.describe();
+        let names = vec!["statistic_3", "statistic", "statistic_2"];
+        assert_eq!(d.col_names(), names);
+        assert_eq!(d.cols[0].array.value_to_string(0), "count");
+    }
+
+    #[test]
+    fn test_to_pretty_string() {
+        use crate::Print;
+        let t = Table::new_empty().with_column(fa_i32!("id", 1, 2, 3));
+        let out = t.to_pretty_string();
+        assert_eq!(out, t.to_string());
+        assert!(out.contains("id:Int32"));
+
+        let options = PrintOptions {
+            max_rows: 2,
+            min_rows: 2,
+            ..Default::default()
+        };
+        let short = t.to_pretty_string_with(options.clone());
+        assert_eq!(short, t.display_with(&options).to_string());
+        assert!(short.contains('…'));
+    }
+
+    #[test]
+    fn test_print_with_cell_width_and_precision() {
+        let t = Table::new_empty()
+            .with_column(crate::fa_str32!("description", "abcdefghij", "ab"))
+            .with_column(field_array(
+                "x",
+                Array::from_float64(crate::FloatArray::from_slice(&[1.23456, 2.0])),
+            ));
+
+        let options = PrintOptions {
+            max_cell_width: 5,
+            float_precision: 2,
+            ..Default::default()
+        };
+        let out = t.display_with(&options).to_string();
+        assert!(out.contains(" desc… "));
+        assert!(out.contains(" abcd… "));
+        assert!(out.contains(" ab "));
+        assert!(out.contains(" 1.23 "));
+        assert!(out.contains(" 2 "));
+        assert!(!out.contains("abcde"));
+
+        let lines: Vec<&str> = out.lines().skip(1).collect();
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+    }
+
+    #[test]
+    fn test_schema_shares_fields() {
+        let t = Table::new(
+            "t".into(),
+            Some(vec![fa_i32!("a", 1, 2), fa_bool!("b", true, false)]),
+        );
+        let schema = t.schema();
+        assert_eq!(schema.fields.len(), 2);
+        assert!(Arc::ptr_eq(&schema.fields[0], &t.cols[0].field));
+        assert!(Arc::ptr_eq(&schema.fields[1], &t.cols[1].field));
+        assert!(schema.matches(&t).is_ok());
+    }
+
+    #[test]
+    fn test_concat_rejects_schema_mismatch() {
+        let a = Table::new("a".into(), Some(vec![fa_i32!("x", 1, 2)]));
+        let b = Table::new("b".into(), Some(vec![fa_i64!("x", 3, 4)]));
+        let err = a.concat(b).unwrap_err();
+        assert!(err.to_string().contains("type mismatch"));
+    }
 }
 
 #[cfg(test)]
@@ -217,8 +217,10 @@ impl BooleanArray<()> {
     /// `len` is the logical number of bits (elements).
     pub fn from_bit_buffer(data: Vec64<u8>, len: usize, null_mask: Option<Vec64<u8>>) -> Self {
         Self {
-            data: Bitmask::from_bytes(data, len),
-            null_mask: null_mask.map(|nm| Bitmask::from_bytes(nm, len)),
+            data: Bitmask::from_bytes(data, len).expect("data buffer too short for len"),
+            null_mask: null_mask.map(|nm| {
+                Bitmask::from_bytes(nm, len).expect("null mask buffer too short for len")
+            }),
             len,
             _phantom: PhantomData,
         }
@@ -266,6 +268,49 @@ impl BooleanArray<()> {
         }
         out
     }
+
+    /// Number of `true` values, skipping nulls.
+    ///
+    /// Value bits are ANDed with the null mask and counted 64 at a time, with
+    /// the partial final byte masked so bits past `len` are ignored.
+    pub fn count_true(&self) -> u64 {
+        let Some(mask) = &self.null_mask else {
+            return self.data.count_ones() as u64;
+        };
+        let full_bytes = self.len / 8;
+        let word_bytes = full_bytes & !7;
+        let (data, valid) = (&self.data.bits[..full_bytes], &mask.bits[..full_bytes]);
+        let mut count = 0u64;
+        for (d, v) in data[..word_bytes]
+            .chunks_exact(8)
+            .zip(valid[..word_bytes].chunks_exact(8))
+        {
+            let d = u64::from_le_bytes(d.try_into().unwrap());
+            let v = u64::from_le_bytes(v.try_into().unwrap());
+            count += (d & v).count_ones() as u64;
+        }
+        for (d, v) in data[word_bytes..].iter().zip(&valid[word_bytes..]) {
+            count += (d & v).count_ones() as u64;
+        }
+        let rem = self.len & 7;
+        if rem != 0 {
+            let tail = self.data.bits[full_bytes] & mask.bits[full_bytes] & ((1u8 << rem) - 1);
+            count += tail.count_ones() as u64;
+        }
+        count
+    }
+
+    /// Whether every non-null value is `true`, or `None` if there are none.
+    pub fn all(&self) -> Option<bool> {
+        let valid = (self.len - self.null_count()) as u64;
+        (valid > 0).then(|| self.count_true() == valid)
+    }
+
+    /// Whether any non-null value is `true`, or `None` if there are none.
+    pub fn any(&self) -> Option<bool> {
+        let valid = self.len - self.null_count();
+        (valid > 0).then(|| self.count_true() > 0)
+    }
 }
 
 impl AsRef<[u8]> for BooleanArray<()> {
@@ -612,10 +657,8 @@ impl MaskedArray for BooleanArray<()> {
                 self_mask.resize(orig_len + other_len, true);
             }
             (None, Some(other_mask)) => {
-                let mut mask = Bitmask::new_set_all(orig_len + other_len, true);
-                for i in 0..other_len {
-                    mask.set(orig_len + i, other_mask.get(i));
-                }
+                let mut mask = Bitmask::new_set_all(orig_len, true);
+                mask.extend_from_bitmask(other_mask);
                 self.set_null_mask(Some(mask));
             }
             (None, None) => {
@@ -1006,8 +1049,8 @@ impl_arc_masked_array!(
 
 #[cfg(test)]
 mod tests {
-    use crate::BooleanArray;
     use crate::traits::{concatenate::Concatenate, masked_array::MaskedArray};
+    use crate::{Bitmask, BooleanArray};
 
     #[test]
     fn new_and_with_capacity() {
@@ -1252,6 +1295,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reductions() {
+        let empty = BooleanArray::default();
+        assert_eq!(empty.count_true(), 0);
+        assert_eq!((empty.all(), empty.any()), (None, None));
+
+        // 70 values crosses a full word and leaves a partial byte
+        let values: Vec<bool> = (0..70).map(|i| i % 3 != 0).collect();
+        let arr = BooleanArray::from_slice(&values);
+        assert_eq!(arr.count_true(), 46);
+        assert_eq!((arr.all(), arr.any()), (Some(false), Some(true)));
+
+        // Null out every false value, leaving only trues
+        let mask = Bitmask::from_bools(&values);
+        let masked = BooleanArray::new(arr.data.clone(), Some(mask));
+        assert_eq!(masked.count_true(), 46);
+        assert_eq!((masked.all(), masked.any()), (Some(true), Some(true)));
+
+        let all_null = BooleanArray::new(
+            Bitmask::from_bools(&[true, true]),
+            Some(Bitmask::new_set_all(2, false)),
+        );
+        assert_eq!(all_null.count_true(), 0);
+        assert_eq!((all_null.all(), all_null.any()), (None, None));
+
+        let falses = BooleanArray::from_slice(&[false, false]);
+        assert_eq!((falses.all(), falses.any()), (Some(false), Some(false)));
+    }
+
     #[test]
     fn test_boolean_array_concat() {
         let arr1 = BooleanArray::from_slice(&[true, false, true]);
@@ -1288,6 +1360,42 @@ mod tests {
         assert_eq!(result.get(4), None);
         assert_eq!(result.null_count(), 2);
     }
+
+    #[test]
+    fn test_boolean_array_concat_unaligned_join() {
+        // 5 + 3 puts the join mid-byte, so both the value bits and the null
+        // mask bits of `b` must be shifted up by 5.
+        let mut a = BooleanArray::with_capacity(5, true);
+        for v in [Some(true), None, Some(false), Some(true), None] {
+            match v {
+                Some(v) => a.push(v),
+                None => a.push_null(),
+            }
+        }
+        let mut b = BooleanArray::with_capacity(3, true);
+        b.push_null();
+        b.push(true);
+        b.push(false);
+
+        let (t, f) = (Some(true), Some(false));
+        let result = a.concat(b).unwrap();
+        assert_eq!(result.len(), 8);
+        let got: Vec<Option<bool>> = (0..8).map(|i| result.get(i)).collect();
+        assert_eq!(got, vec![t, None, f, t, None, None, t, f]);
+        assert_eq!(result.null_count(), 3);
+        assert_eq!(result.data.len(), 8);
+        assert_eq!(result.null_mask.as_ref().unwrap().len(), 8);
+
+        // Mask only on the right-hand side: the left half is all valid.
+        let lhs = BooleanArray::from_slice(&[true, true, false, false, true]);
+        let mut rhs = BooleanArray::with_capacity(3, true);
+        rhs.push(false);
+        rhs.push_null();
+        rhs.push(true);
+        let result = lhs.concat(rhs).unwrap();
+        let got: Vec<Option<bool>> = (0..8).map(|i| result.get(i)).collect();
+        assert_eq!(got, vec![t, t, f, f, t, f, None, t]);
+    }
 }
 
 /// ---------- parallel-path tests ---------------------------------------------
@@ -43,13 +43,14 @@ use rayon::iter::ParallelIterator;
 
 use crate::aliases::CategoricalAVT;
 use crate::enums::error::MinarrowError;
+use crate::enums::operators::ComparisonOperator;
 use crate::enums::shape_dim::ShapeDim;
 use crate::traits::concatenate::Concatenate;
 use crate::traits::shape::Shape;
 use crate::traits::type_unions::Integer;
 use crate::utils::validate_null_mask_len;
 use crate::{
-    Bitmask, Buffer, Length, MaskedArray, Offset, StringArray, impl_arc_masked_array,
+    Bitmask, BooleanArray, Buffer, Length, MaskedArray, Offset, StringArray, impl_arc_masked_array,
     impl_array_ref_deref,
 };
 use ::vec64::{Vec64, Vec64Alloc};
@@ -493,6 +494,288 @@ impl<T: Integer> CategoricalArray<T> {
             null_mask: self.null_mask.clone(),
         }
     }
+
+    /// Extends the dictionary with the values of `other` that it lacks, in
+    /// first-seen order, and returns the code in `self` for each code of `other`.
+    ///
+    /// Shared or equal dictionaries map onto themselves and are left unchanged.
+    ///
+    /// # Errors
+    /// - `Overflow` if the merged dictionary has more entries than `T` can index,
+    ///   in which case the dictionary is left unchanged.
+    pub fn merge_dictionary(&mut self, other: &Self) -> Result<Vec<T>, MinarrowError> {
+        let (ours, theirs) = (&self.unique_values, &other.unique_values);
+        if std::ptr::eq(ours.as_ptr(), theirs.as_ptr()) || ours == theirs {
+            return Ok((0..theirs.len()).map(T::from_usize).collect());
+        }
+        let mut lookup: HashMap<&str, usize> = ours
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.as_str(), i))
+            .collect();
+        let mut added: Vec<&str> = Vec::new();
+        let remap: Vec<usize> = theirs
+            .iter()
+            .map(|v| {
+                *lookup.entry(v.as_str()).or_insert_with(|| {
+                    added.push(v.as_str());
+                    ours.len() + added.len() - 1
+                })
+            })
+            .collect();
+        let total = ours.len() + added.len();
+        if !dictionary_fits::<T>(total) {
+            return Err(MinarrowError::Overflow {
+                value: total.to_string(),
+                target: std::any::type_name::<T>(),
+            });
+        }
+        for v in added {
+            self.unique_values.push(v.to_owned());
+        }
+        Ok(remap.into_iter().map(T::from_usize).collect())
+    }
+
+    /// Number of distinct values across this dictionary and `other`'s.
+    pub fn merged_cardinality(&self, other: &Self) -> usize {
+        let ours: std::collections::HashSet<&str> =
+            self.unique_values.iter().map(String::as_str).collect();
+        ours.len()
+            + other
+                .unique_values
+                .iter()
+                .filter(|v| !ours.contains(v.as_str()))
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+    }
+
+    /// Copies the array with its codes converted to `U`, keeping the dictionary
+    /// and null mask. Meant for widening, so `U` should be at least as wide as `T`.
+    pub fn widen<U: Integer>(&self) -> CategoricalArray<U> {
+        let codes: Vec64<U> = self
+            .data
+            .iter()
+            .map(|&c| U::from_usize(c.to_usize()))
+            .collect();
+        CategoricalArray::from_parts(codes, self.unique_values.clone(), self.null_mask.clone())
+    }
+}
+
+/// Whether a dictionary of `len` entries can be indexed by `T`.
+#[inline]
+pub(crate) fn dictionary_fits<T: Integer>(len: usize) -> bool {
+    len == 0 || T::try_from(len - 1).is_ok()
+}
+
+/// Maps a code of the other array through `remap`. Codes under null slots may
+/// be arbitrary, so out-of-range ones map to `0`.
+#[inline]
+fn remap_code<T: Integer>(remap: &[T], code: T) -> T {
+    remap
+        .get(code.to_usize())
+        .copied()
+        .unwrap_or_else(|| T::from_usize(0))
+}
+
+/// Right-hand side of a categorical comparison: a scalar string or another
+/// row-aligned categorical array.
+#[derive(Debug, Clone, Copy)]
+pub enum CategoricalOperand<'a, T: Integer> {
+    Str(&'a str),
+    Categorical(&'a CategoricalArray<T>),
+}
+
+impl<'a, T: Integer> From<&'a str> for CategoricalOperand<'a, T> {
+    fn from(s: &'a str) -> Self {
+        CategoricalOperand::Str(s)
+    }
+}
+
+impl<'a, T: Integer> From<&'a CategoricalArray<T>> for CategoricalOperand<'a, T> {
+    fn from(arr: &'a CategoricalArray<T>) -> Self {
+        CategoricalOperand::Categorical(arr)
+    }
+}
+
+impl<T: Integer> CategoricalArray<T> {
+    /// Compares each element against `rhs`, ranking categories by their position
+    /// in the dictionary rather than lexicographically.
+    ///
+    /// This makes ordered categories such as `Low`/`Medium`/`High` comparable
+    /// by rank, provided the dictionary lists them in that order. `ordered`
+    /// says whether it does, and is normally the array's
+    /// [`Field::is_ordered_dictionary`](crate::Field::is_ordered_dictionary),
+    /// which FFI, IPC and the pandas metadata set on import. Unordered
+    /// dictionaries only support `==` and `!=`, as in Arrow and pandas. Nulls
+    /// on either side produce nulls in the result.
+    ///
+    /// # Errors
+    /// - `NotImplemented` for operators other than `==`, `!=`, `<`, `<=`, `>`, `>=`
+    /// - `TypeError` if `op` is an ordering and `ordered` is false
+    /// - `IndexError` if a scalar `rhs` is not a category and `op` is an ordering
+    /// - `ShapeError` if a categorical `rhs` has a different length
+    /// - `IncompatibleTypeError` if a categorical `rhs` has a different dictionary
+    ///   and `op` is an ordering. Equality falls back to comparing the strings.
+    pub fn compare<'a>(
+        &self,
+        op: ComparisonOperator,
+        rhs: impl Into<CategoricalOperand<'a, T>>,
+        ordered: bool,
+    ) -> Result<BooleanArray<()>, MinarrowError>
+    where
+        T: 'a,
+    {
+        use std::cmp::Ordering;
+        let test: fn(Ordering) -> bool = match op {
+            ComparisonOperator::Equals => Ordering::is_eq,
+            ComparisonOperator::NotEquals => Ordering::is_ne,
+            ComparisonOperator::LessThan => Ordering::is_lt,
+            ComparisonOperator::LessThanOrEqualTo => Ordering::is_le,
+            ComparisonOperator::GreaterThan => Ordering::is_gt,
+            ComparisonOperator::GreaterThanOrEqualTo => Ordering::is_ge,
+            _ => {
+                return Err(MinarrowError::NotImplemented {
+                    feature: format!("CategoricalArray::compare with {:?}", op),
+                });
+            }
+        };
+        let ordering = !matches!(
+            op,
+            ComparisonOperator::Equals | ComparisonOperator::NotEquals
+        );
+        let len = self.data.len();
+        if ordering && !ordered {
+            return Err(MinarrowError::TypeError {
+                from: "CategoricalArray",
+                to: "ordered comparison",
+                message: Some(format!(
+                    "CategoricalArray::compare: {:?} requires an ordered dictionary",
+                    op
+                )),
+            });
+        }
+
+        let (bits, null_mask): (Vec<bool>, Option<Bitmask>) = match rhs.into() {
+            CategoricalOperand::Str(s) => {
+                let rank = self.unique_values.iter().position(|v| v == s);
+                let bits = match rank {
+                    Some(rank) => self
+                        .data
+                        .iter()
+                        .map(|&code| test(code.to_usize().cmp(&rank)))
+                        .collect(),
+                    None if ordering => {
+                        return Err(MinarrowError::IndexError(format!(
+                            "CategoricalArray::compare: '{}' is not a category",
+                            s
+                        )));
+                    }
+                    None => vec![op == ComparisonOperator::NotEquals; len],
+                };
+                (bits, self.null_mask.clone())
+            }
+            CategoricalOperand::Categorical(other) => {
+                if other.data.len() != len {
+                    return Err(MinarrowError::ShapeError {
+                        message: format!(
+                            "CategoricalArray::compare: length mismatch {} vs {}",
+                            len,
+                            other.data.len()
+                        ),
+                    });
+                }
+                let bits = if self.unique_values == other.unique_values {
+                    self.data
+                        .iter()
+                        .zip(other.data.iter())
+                        .map(|(&a, &b)| test(a.to_usize().cmp(&b.to_usize())))
+                        .collect()
+                } else if ordering {
+                    return Err(MinarrowError::IncompatibleTypeError {
+                        from: "CategoricalArray",
+                        to: "CategoricalArray",
+                        message: Some(
+                            "ordered comparison requires identical dictionaries".to_string(),
+                        ),
+                    });
+                } else {
+                    self.data
+                        .iter()
+                        .zip(other.data.iter())
+                        .map(|(&a, &b)| {
+                            let a = &self.unique_values[a.to_usize()];
+                            let b = &other.unique_values[b.to_usize()];
+                            test(a.cmp(b))
+                        })
+                        .collect()
+                };
+                let null_mask = match (&self.null_mask, &other.null_mask) {
+                    (Some(a), Some(b)) => Some(a.intersect(b)),
+                    (Some(m), None) | (None, Some(m)) => Some(m.clone()),
+                    (None, None) => None,
+                };
+                (bits, null_mask)
+            }
+        };
+
+        let mut data = Bitmask::from_bools(&bits);
+        if let Some(mask) = &null_mask {
+            data = data.intersect(mask);
+        }
+        Ok(BooleanArray::new(data, null_mask))
+    }
+
+    /// Elementwise `<` against `rhs` by dictionary rank. See [`Self::compare`].
+    #[inline]
+    pub fn lt<'a>(
+        &self,
+        rhs: impl Into<CategoricalOperand<'a, T>>,
+        ordered: bool,
+    ) -> Result<BooleanArray<()>, MinarrowError>
+    where
+        T: 'a,
+    {
+        self.compare(ComparisonOperator::LessThan, rhs, ordered)
+    }
+
+    /// Elementwise `<=` against `rhs` by dictionary rank. See [`Self::compare`].
+    #[inline]
+    pub fn le<'a>(
+        &self,
+        rhs: impl Into<CategoricalOperand<'a, T>>,
+        ordered: bool,
+    ) -> Result<BooleanArray<()>, MinarrowError>
+    where
+        T: 'a,
+    {
+        self.compare(ComparisonOperator::LessThanOrEqualTo, rhs, ordered)
+    }
+
+    /// Elementwise `>` against `rhs` by dictionary rank. See [`Self::compare`].
+    #[inline]
+    pub fn gt<'a>(
+        &self,
+        rhs: impl Into<CategoricalOperand<'a, T>>,
+        ordered: bool,
+    ) -> Result<BooleanArray<()>, MinarrowError>
+    where
+        T: 'a,
+    {
+        self.compare(ComparisonOperator::GreaterThan, rhs, ordered)
+    }
+
+    /// Elementwise `>=` against `rhs` by dictionary rank. See [`Self::compare`].
+    #[inline]
+    pub fn ge<'a>(
+        &self,
+        rhs: impl Into<CategoricalOperand<'a, T>>,
+        ordered: bool,
+    ) -> Result<BooleanArray<()>, MinarrowError>
+    where
+        T: 'a,
+    {
+        self.compare(ComparisonOperator::GreaterThanOrEqualTo, rhs, ordered)
+    }
 }
 
 impl<T: Integer> MaskedArray for CategoricalArray<T> {
@@ -809,12 +1092,22 @@ impl<T: Integer> MaskedArray for CategoricalArray<T> {
     }
 
     /// Appends all values (and null mask if present) from `other` to `self`.
+    ///
+    /// `other`'s dictionary is merged into this one and its codes remapped.
+    ///
+    /// # Panics
+    /// Panics if the merged dictionary overflows `T`. `TextArray::concat` widens
+    /// the code type instead.
     fn append_array(&mut self, other: &Self) {
         let orig_len = self.len();
         let other_len = other.len();
         if other_len == 0 { return; }
 
-        self.data_mut().extend_from_slice(other.data());
+        let remap = self
+            .merge_dictionary(other)
+            .unwrap_or_else(|e| panic!("append_array: {}", e));
+        let codes: Vec<T> = other.data.iter().map(|&c| remap_code(&remap, c)).collect();
+        self.data_mut().extend_from_slice(&codes);
 
         match (self.null_mask_mut(), other.null_mask()) {
             (Some(self_mask), Some(other_mask)) => {
@@ -841,7 +1134,12 @@ impl<T: Integer> MaskedArray for CategoricalArray<T> {
         }
         let orig_len = self.len();
 
-        self.data_mut().extend_from_slice(&other.data()[offset..offset + len]);
+        let remap = self.merge_dictionary(other)?;
+        let codes: Vec<T> = other.data()[offset..offset + len]
+            .iter()
+            .map(|&c| remap_code(&remap, c))
+            .collect();
+        self.data_mut().extend_from_slice(&codes);
 
         match (self.null_mask_mut(), other.null_mask()) {
             (Some(self_mask), Some(other_mask)) => {
@@ -880,19 +1178,7 @@ impl<T: Integer> MaskedArray for CategoricalArray<T> {
             return Ok(());
         }
 
-        // Build mapping from other's dictionary indices to self's dictionary indices
-        let mut index_map: Vec<T> = Vec::with_capacity(other.unique_values.len());
-        for other_value in &other.unique_values {
-            let self_idx = match self.unique_values.iter().position(|v| v == other_value) {
-                Some(idx) => T::from_usize(idx),
-                None => {
-                    let idx = self.unique_values.len();
-                    self.unique_values.push(other_value.clone());
-                    T::from_usize(idx)
-                }
-            };
-            index_map.push(self_idx);
-        }
+        let index_map = self.merge_dictionary(other)?;
 
         // Insert and remap other's data
         let new_len = orig_len + other_len;
@@ -910,8 +1196,8 @@ impl<T: Integer> MaskedArray for CategoricalArray<T> {
         for i in 0..other_len {
             unsafe {
                 let other_idx = *other.data.as_ref().get_unchecked(i);
-                let remapped_idx = *index_map.get_unchecked(other_idx.to_usize());
-                *self.data.as_mut().get_unchecked_mut(index + i) = remapped_idx;
+                *self.data.as_mut().get_unchecked_mut(index + i) =
+                    remap_code(&index_map, other_idx);
             }
         }
 
@@ -1204,6 +1490,11 @@ impl<T: Integer> Shape for CategoricalArray<T> {
 }
 
 impl<T: Integer> Concatenate for CategoricalArray<T> {
+    /// Appends `other`, merging its dictionary into this one and remapping its
+    /// codes. Nulls carry through.
+    ///
+    /// # Errors
+    /// - `Overflow` if the merged dictionary has more entries than `T` can index.
     fn concat(
         mut self,
         other: Self,
@@ -1215,26 +1506,10 @@ impl<T: Integer> Concatenate for CategoricalArray<T> {
             return Ok(self);
         }
 
-        // Build a mapping from other's dictionary indices to self's dictionary indices
-        let mut index_map = std::collections::HashMap::new();
-        for (other_idx, other_value) in other.unique_values.iter().enumerate() {
-            // Find or insert this value in self's dictionary
-            let result_idx =
-                if let Some(pos) = self.unique_values.iter().position(|v| v == other_value) {
-                    pos
-                } else {
-                    let new_idx = self.unique_values.len();
-                    self.unique_values.push(other_value.clone());
-                    new_idx
-                };
-            index_map.insert(other_idx, result_idx);
-        }
-
-        // Remap and extend data indices
+        // Merge the dictionaries, then remap and extend other's codes.
+        let remap = self.merge_dictionary(&other)?;
         for &other_code in other.data.iter() {
-            let other_idx = other_code.to_usize();
-            let result_idx = index_map[&other_idx];
-            self.data.push(T::from_usize(result_idx));
+            self.data.push(remap_code(&remap, other_code));
         }
 
         // Merge null masks
@@ -1677,6 +1952,56 @@ mod tests {
         assert!(result.unique_values.contains(&"cherry".to_string()));
     }
 
+    #[test]
+    fn test_categorical_append_remaps_dictionary() {
+        let mut arr1 = CategoricalArray::<u32>::from_values(["x", "y"]);
+        let mut arr2 = CategoricalArray::<u32>::from_values(["y", "z", "y"]);
+        arr2.push_null();
+
+        arr1.append_array(&arr2);
+        let values: Vec<_> = arr1.iter_str_opt().collect();
+        assert_eq!(
+            values,
+            vec![Some("x"), Some("y"), Some("y"), Some("z"), Some("y"), None]
+        );
+        assert_eq!(&arr1.unique_values[..3], &["x", "y", "z"]);
+
+        let mut arr3 = CategoricalArray::<u32>::from_values(["w"]);
+        arr3.append_range(&arr2, 1, 2).unwrap();
+        assert_eq!(arr3.iter_str().collect::<Vec<_>>(), vec!["w", "z", "y"]);
+    }
+
+    #[test]
+    fn test_categorical_concat_dictionary_overflow() {
+        let names: Vec<String> = (0..300).map(|i| format!("v{}", i)).collect();
+        let arr1 = CategoricalArray::<u8>::from_values(names[..200].iter().map(String::as_str));
+        let arr2 = CategoricalArray::<u8>::from_values(names[100..].iter().map(String::as_str));
+        assert_eq!(arr1.merged_cardinality(&arr2), 300);
+        assert!(matches!(
+            arr1.concat(arr2),
+            Err(MinarrowError::Overflow { .. })
+        ));
+    }
+
+    #[cfg(feature = "extended_categorical")]
+    #[test]
+    fn test_text_array_concat_widens_categorical() {
+        use crate::TextArray;
+        use std::sync::Arc;
+
+        let names: Vec<String> = (0..70_000).map(|i| format!("v{}", i)).collect();
+        let lhs = CategoricalArray::<u16>::from_values(names[..40_000].iter().map(String::as_str));
+        let rhs = CategoricalArray::<u16>::from_values(names[40_000..].iter().map(String::as_str));
+        let out = TextArray::Categorical16(Arc::new(lhs))
+            .concat(TextArray::Categorical16(Arc::new(rhs)))
+            .unwrap();
+        let TextArray::Categorical32(out) = out else {
+            panic!("expected Categorical32");
+        };
+        assert_eq!(out.len(), 70_000);
+        assert_eq!(out.get_str(69_999), Some("v69999"));
+    }
+
     #[test]
     fn test_categorical_array_concat_with_nulls() {
         let mut arr1 = CategoricalArray::<u32>::default();
@@ -1751,6 +2076,90 @@ mod tests {
         assert_eq!(result.get_str(7), Some("gamma"));
         assert_eq!(result.get_str(8), Some("alpha"));
     }
+
+    #[test]
+    fn compare_by_dictionary_rank() {
+        let dict: Vec64<String> = vec64!["Low".into(), "Medium".into(), "High".into()];
+        let arr = CategoricalArray::<u32>::from_parts(
+            vec64![1, 0, 2, 0, 2],
+            dict.clone(),
+            Some(bm(&[true, true, true, false, true])),
+        );
+
+        // "Medium" < "High" by rank even though it sorts after it lexicographically.
+        let lt = arr.lt("High", true).unwrap();
+        let got: Vec<Option<bool>> = (0..lt.len()).map(|i| lt.get(i)).collect();
+        assert_eq!(
+            got,
+            vec![Some(true), Some(true), Some(false), None, Some(false)]
+        );
+
+        let ge = arr.ge("Medium", true).unwrap();
+        let got: Vec<Option<bool>> = (0..ge.len()).map(|i| ge.get(i)).collect();
+        assert_eq!(
+            got,
+            vec![Some(true), Some(false), Some(true), None, Some(true)]
+        );
+
+        let other = CategoricalArray::<u32>::from_parts(vec64![0, 0, 1, 1, 1], dict, None);
+        let gt = arr.gt(&other, true).unwrap();
+        let got: Vec<Option<bool>> = (0..gt.len()).map(|i| gt.get(i)).collect();
+        assert_eq!(
+            got,
+            vec![Some(true), Some(false), Some(true), None, Some(true)]
+        );
+
+        let eq = arr
+            .compare(ComparisonOperator::Equals, "Unknown", true)
+            .unwrap();
+        assert_eq!(eq.get(0), Some(false));
+        assert_eq!(eq.get(3), None);
+        assert!(matches!(
+            arr.lt("Unknown", true),
+            Err(MinarrowError::IndexError(_))
+        ));
+    }
+
+    #[test]
+    fn compare_unordered_rejects_ordering() {
+        let arr = CategoricalArray::<u32>::from_values(["Low", "High", "Low"]);
+        assert!(matches!(
+            arr.lt("High", false),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        assert!(matches!(
+            arr.ge(&arr, false),
+            Err(MinarrowError::TypeError { .. })
+        ));
+        let eq = arr
+            .compare(ComparisonOperator::Equals, "Low", false)
+            .unwrap();
+        assert_eq!(eq.get(0), Some(true));
+        assert_eq!(eq.get(1), Some(false));
+    }
+
+    #[test]
+    fn compare_with_different_dictionaries() {
+        let a = CategoricalArray::<u8>::from_values(["x", "y"]);
+        let b = CategoricalArray::<u8>::from_values(["y", "y"]);
+        let eq = a.compare(ComparisonOperator::Equals, &b, false).unwrap();
+        assert_eq!(eq.get(0), Some(false));
+        assert_eq!(eq.get(1), Some(true));
+        assert!(matches!(
+            a.lt(&b, true),
+            Err(MinarrowError::IncompatibleTypeError { .. })
+        ));
+
+        let short = CategoricalArray::<u8>::from_values(["x"]);
+        assert!(matches!(
+            a.compare(ComparisonOperator::NotEquals, &short, false),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+        assert!(matches!(
+            a.compare(ComparisonOperator::IsNull, "x", false),
+            Err(MinarrowError::NotImplemented { .. })
+        ));
+    }
 }
 
 #[cfg(test)]
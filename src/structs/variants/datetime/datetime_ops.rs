@@ -87,7 +87,7 @@ impl<T: Integer + FromPrimitive> DatetimeArray<T> {
             TimeUnit::Nanoseconds => {
                 dt.unix_timestamp() * 1_000_000_000i64 + dt.nanosecond() as i64
             }
-            TimeUnit::Days => dt.unix_timestamp() / 86400i64,
+            TimeUnit::Days => dt.unix_timestamp().div_euclid(86400),
         }
     }
 
@@ -849,7 +849,7 @@ impl<T: Integer + FromPrimitive> DatetimeOps for DatetimeArray<T> {
                     TimeUnit::Nanoseconds => {
                         dt.unix_timestamp() * 1_000_000_000i64 + dt.nanosecond() as i64
                     }
-                    TimeUnit::Days => dt.unix_timestamp() / 86400i64,
+                    TimeUnit::Days => dt.unix_timestamp().div_euclid(86400),
                 };
 
                 if let Some(new_val_t) = T::from_i64(new_val) {
@@ -1346,6 +1346,39 @@ mod tests {
         assert_eq!(years.get(1), None);
     }
 
+    #[test]
+    fn test_component_extraction_days_unit() {
+        // Day counts since the epoch, including the epoch itself, the day before it,
+        // a leap day and the last day of a year.
+        let arr = DatetimeArray::<i64>::from_slice(&[0, -1, 11_016, 19_722], Some(TimeUnit::Days));
+
+        let years: Vec<_> = (0..4).map(|i| arr.year().get(i).unwrap()).collect();
+        let months: Vec<_> = (0..4).map(|i| arr.month().get(i).unwrap()).collect();
+        let days: Vec<_> = (0..4).map(|i| arr.day().get(i).unwrap()).collect();
+        assert_eq!(years, vec![1970, 1969, 2000, 2023]);
+        assert_eq!(months, vec![1, 12, 2, 12]);
+        assert_eq!(days, vec![1, 31, 29, 31]);
+        assert_eq!(arr.hour().get(0), Some(0));
+        // 1970-01-01 was a Thursday and 1969-12-31 a Wednesday (1=Sunday).
+        assert_eq!(arr.weekday().get(0), Some(5));
+        assert_eq!(arr.weekday().get(1), Some(4));
+    }
+
+    #[test]
+    fn test_cast_to_days_floors_pre_epoch() {
+        // 1969-12-31 12:00:00 UTC falls on day -1, not day 0.
+        let arr = DatetimeArray::<i64>::from_slice(&[-43_200, 43_200], Some(TimeUnit::Seconds));
+        let days = arr.cast_time_unit(TimeUnit::Days).unwrap();
+        assert_eq!(days.data.as_slice(), &[-1, 0]);
+        assert_eq!(
+            DatetimeArray::<i64>::datetime_to_i64(
+                DatetimeArray::<i64>::i64_to_datetime(-43_200, TimeUnit::Seconds).unwrap(),
+                TimeUnit::Days
+            ),
+            -1
+        );
+    }
+
     // Edge Case Tests
 
     #[test]
@@ -61,15 +61,17 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 #[cfg(feature = "datetime")]
 use crate::Buffer;
+use crate::enums::error::MinarrowError;
 use crate::enums::shape_dim::ShapeDim;
-use crate::enums::time_units::TimeUnit;
+use crate::enums::time_units::{DurationRounding, TimeUnit};
 use crate::traits::concatenate::Concatenate;
 use crate::traits::masked_array::MaskedArray;
 use crate::traits::shape::Shape;
 use crate::traits::type_unions::Integer;
 use crate::utils::validate_null_mask_len;
 use crate::{
-    Bitmask, Length, Offset, impl_arc_masked_array, impl_array_ref_deref, impl_masked_array,
+    Bitmask, IntegerArray, Length, Offset, impl_arc_masked_array, impl_array_ref_deref,
+    impl_masked_array,
 };
 use ::vec64::{Vec64, Vec64Alloc};
 
@@ -248,6 +250,161 @@ impl<T: Integer> DatetimeArray<T> {
     }
 }
 
+impl DatetimeArray<i64> {
+    /// Floors each value to the start of `unit`, e.g. `TimeUnit::Days` zeroes
+    /// the intra-day component of a seconds array.
+    ///
+    /// The result keeps this array's `time_unit` and null mask. Flooring is
+    /// towards negative infinity, so pre-epoch values move to the earlier
+    /// boundary. Truncating to a unit as fine or finer than the array's own
+    /// resolution returns an unchanged copy.
+    ///
+    /// Named `truncate_to` so it does not shadow the string-based
+    /// `DatetimeOps::truncate`, which also covers hour, minute, month and year.
+    pub fn truncate_to(&self, unit: TimeUnit) -> DatetimeArray<i64> {
+        let own = unit_nanos(self.time_unit);
+        let target = unit_nanos(unit);
+        if target <= own {
+            return self.clone();
+        }
+        let step = target / own;
+
+        let mut data = Vec64::with_capacity(self.data.len());
+        data.extend(self.data.iter().map(|&v| v - v.rem_euclid(step)));
+        DatetimeArray::from_vec64(data, self.null_mask.clone(), Some(self.time_unit))
+    }
+
+    /// Returns a copy with every timestamp moved by `amount` of `unit`.
+    ///
+    /// `amount` is first converted into this array's own `time_unit`, and
+    /// `rounding` decides what happens when that is not exact. Storage stays
+    /// UTC, and null slots keep their payload and stay null.
+    ///
+    /// Named `add_duration_units` so it does not shadow
+    /// `DatetimeOps::add_duration`, which takes a `time::Duration`.
+    ///
+    /// # Errors
+    /// - `LossyCast` if the conversion is inexact under `DurationRounding::Error`
+    /// - `Overflow` if the converted amount or a shifted value does not fit in `i64`
+    pub fn add_duration_units(
+        &self,
+        amount: i64,
+        unit: TimeUnit,
+        rounding: DurationRounding,
+    ) -> Result<DatetimeArray<i64>, MinarrowError> {
+        let step = convert_amount(amount, unit, self.time_unit, rounding)?;
+        let mut data = Vec64::with_capacity(self.data.len());
+        for (i, &v) in self.data.iter().enumerate() {
+            if self.is_null(i) {
+                data.push(v);
+                continue;
+            }
+            let shifted = v.checked_add(step).ok_or_else(|| MinarrowError::Overflow {
+                value: format!("{} + {}", v, step),
+                target: "i64",
+            })?;
+            data.push(shifted);
+        }
+        Ok(DatetimeArray::from_vec64(
+            data,
+            self.null_mask.clone(),
+            Some(self.time_unit),
+        ))
+    }
+
+    /// Returns `self - other` elementwise, in this array's `time_unit`.
+    ///
+    /// When the units differ, each value of `other` is first converted into
+    /// this array's unit, which must be exact. A null on either side yields
+    /// null. Named `diff_units` so it does not shadow `DatetimeOps::diff`,
+    /// which converts through `time` into any unit.
+    ///
+    /// # Errors
+    /// - `ShapeError` if the lengths differ
+    /// - `LossyCast` if a value of `other` is not a whole number of this array's unit
+    /// - `Overflow` if a converted value or a difference does not fit in `i64`
+    pub fn diff_units(
+        &self,
+        other: &DatetimeArray<i64>,
+    ) -> Result<IntegerArray<i64>, MinarrowError> {
+        if self.len() != other.len() {
+            return Err(MinarrowError::ShapeError {
+                message: format!(
+                    "DatetimeArray::diff_units: lengths {} and {} differ",
+                    self.len(),
+                    other.len()
+                ),
+            });
+        }
+        let null_mask = match (&self.null_mask, &other.null_mask) {
+            (Some(a), Some(b)) => Some(a.intersect(b)),
+            (Some(m), None) | (None, Some(m)) => Some(m.clone()),
+            (None, None) => None,
+        };
+        let mut data = Vec64::with_capacity(self.len());
+        for (i, (&a, &b)) in self.data.iter().zip(other.data.iter()).enumerate() {
+            if null_mask.as_ref().is_some_and(|m| !m.get(i)) {
+                data.push(0);
+                continue;
+            }
+            let b = if other.time_unit == self.time_unit {
+                b
+            } else {
+                convert_amount(b, other.time_unit, self.time_unit, DurationRounding::Error)?
+            };
+            let d = a.checked_sub(b).ok_or_else(|| MinarrowError::Overflow {
+                value: format!("{} - {}", a, b),
+                target: "i64",
+            })?;
+            data.push(d);
+        }
+        Ok(IntegerArray::new(data, null_mask))
+    }
+}
+
+/// Converts `amount` of `from` into a whole number of `to`, per `rounding`.
+fn convert_amount(
+    amount: i64,
+    from: TimeUnit,
+    to: TimeUnit,
+    rounding: DurationRounding,
+) -> Result<i64, MinarrowError> {
+    let nanos = amount as i128 * unit_nanos(from) as i128;
+    let per = unit_nanos(to) as i128;
+    let (q, r) = (nanos / per, nanos % per);
+    let whole = if r == 0 {
+        q
+    } else {
+        match rounding {
+            DurationRounding::Error => {
+                return Err(MinarrowError::LossyCast {
+                    value: format!("{} {}", amount, from),
+                    target: "whole units of the array's time unit",
+                });
+            }
+            // `r` carries the sign of `nanos`, so this rounds half away from zero.
+            DurationRounding::Nearest if 2 * r.abs() >= per => q + r.signum(),
+            DurationRounding::Nearest => q,
+        }
+    };
+    i64::try_from(whole).map_err(|_| MinarrowError::Overflow {
+        value: format!("{} {}", amount, from),
+        target: "i64",
+    })
+}
+
+/// Length of one `unit` in nanoseconds.
+#[inline]
+fn unit_nanos(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+        TimeUnit::Seconds => 1_000_000_000,
+        TimeUnit::Days => 86_400_000_000_000,
+    }
+}
+
 // Time crate datetime conversion
 #[cfg(feature = "datetime_ops")]
 impl DatetimeArray<i64> {
@@ -309,6 +466,64 @@ impl DatetimeArray<i64> {
         self.as_datetime(idx).map(|dt| dt.time())
     }
 
+    /// Returns a copy with each value shifted into the wall-clock time of `tz`.
+    ///
+    /// `tz` is resolved against the static tables in `tz.rs` (IANA identifier,
+    /// abbreviation or `±HH:MM` offset), using the standard offset only.
+    ///
+    /// **The result is no longer UTC.** Its integers hold local wall-clock time,
+    /// so it should not be re-tagged with [`Self::tz`], which would apply the
+    /// offset a second time on display. Null slots and the time unit are preserved.
+    ///
+    /// # Errors
+    /// Returns `TypeError` for an unknown timezone, and `Overflow` if a shifted
+    /// value no longer fits in `i64`.
+    pub fn to_local(
+        &self,
+        tz: &str,
+    ) -> Result<DatetimeArray<i64>, crate::enums::error::MinarrowError> {
+        use crate::enums::error::MinarrowError;
+
+        let offset_secs =
+            self::tz::lookup_offset_seconds(tz).ok_or_else(|| MinarrowError::TypeError {
+                from: "timezone",
+                to: "UTC offset",
+                message: Some(format!("unknown timezone '{}'", tz)),
+            })? as i64;
+
+        let shift = |v: i64| -> Option<i64> {
+            match self.time_unit {
+                TimeUnit::Seconds => v.checked_add(offset_secs),
+                TimeUnit::Milliseconds => v.checked_add(offset_secs * 1_000),
+                TimeUnit::Microseconds => v.checked_add(offset_secs * 1_000_000),
+                TimeUnit::Nanoseconds => v.checked_add(offset_secs * 1_000_000_000),
+                TimeUnit::Days => v
+                    .checked_mul(86_400)
+                    .and_then(|s| s.checked_add(offset_secs))
+                    .map(|s| s.div_euclid(86_400)),
+            }
+        };
+
+        let mut data = Vec64::with_capacity(self.data.len());
+        for (i, &v) in self.data.iter().enumerate() {
+            if self.is_null(i) {
+                data.push(v);
+                continue;
+            }
+            let shifted = shift(v).ok_or_else(|| MinarrowError::Overflow {
+                value: v.to_string(),
+                target: "i64",
+            })?;
+            data.push(shifted);
+        }
+
+        Ok(DatetimeArray::new(
+            data,
+            self.null_mask.clone(),
+            Some(self.time_unit),
+        ))
+    }
+
     /// Wraps this DatetimeArray in a FieldArray with timezone metadata.
     ///
     /// The underlying timestamp data (always UTC) remains unchanged. The timezone
@@ -668,6 +883,131 @@ mod tests {
         assert_eq!(time.second(), 20);
     }
 
+    #[cfg(feature = "datetime_ops")]
+    #[test]
+    fn test_to_local() {
+        let mut arr = DatetimeArray::<i64>::with_capacity(3, true, Some(TimeUnit::Seconds));
+        arr.push(1_700_000_000); // 2023-11-14 22:13:20 UTC
+        arr.push_null();
+        arr.push(0);
+
+        let local = arr.to_local("Australia/Sydney").unwrap();
+        assert_eq!(local.time_unit, TimeUnit::Seconds);
+        assert_eq!(local.value(0), Some(1_700_036_000));
+        assert_eq!(local.value(1), None);
+        assert_eq!(local.value(2), Some(36_000));
+        // 22:13 UTC is 08:13 the next day at +10:00
+        assert_eq!(local.as_time(0).unwrap().hour(), 8);
+        assert_eq!(local.as_date(0).unwrap().day(), 15);
+
+        let ms = DatetimeArray::<i64>::from_slice(&[0], Some(TimeUnit::Milliseconds));
+        assert_eq!(ms.to_local("-05:00").unwrap().value(0), Some(-18_000_000));
+
+        let days = DatetimeArray::<i64>::from_slice(&[0], Some(TimeUnit::Days));
+        assert_eq!(days.to_local("-05:00").unwrap().value(0), Some(-1));
+
+        assert!(arr.to_local("Not/AZone").is_err());
+    }
+
+    #[test]
+    fn test_truncate_to() {
+        let mut arr = DatetimeArray::<i64>::with_capacity(4, true, Some(TimeUnit::Seconds));
+        arr.push(90_061); // 1970-01-02 01:01:01
+        arr.push(-1); // 1969-12-31 23:59:59
+        arr.push_null();
+        arr.push(86_400);
+
+        let days = arr.truncate_to(TimeUnit::Days);
+        assert_eq!(days.time_unit, TimeUnit::Seconds);
+        assert_eq!(days.value(0), Some(86_400));
+        assert_eq!(days.value(1), Some(-86_400));
+        assert_eq!(days.value(2), None);
+        assert_eq!(days.value(3), Some(86_400));
+
+        // Finer or equal units are a no-op
+        assert_eq!(arr.truncate_to(TimeUnit::Milliseconds).data, arr.data);
+        assert_eq!(arr.truncate_to(TimeUnit::Seconds).data, arr.data);
+
+        let ns =
+            DatetimeArray::<i64>::from_slice(&[1_234_567_891, -1], Some(TimeUnit::Nanoseconds));
+        let ms = ns.truncate_to(TimeUnit::Milliseconds);
+        assert_eq!(ms.data.as_slice(), &[1_234_000_000, -1_000_000]);
+    }
+
+    #[test]
+    fn test_add_duration_units() {
+        let mut arr = DatetimeArray::<i64>::with_capacity(3, true, Some(TimeUnit::Milliseconds));
+        arr.push(1_000);
+        arr.push_null();
+        arr.push(-500);
+
+        let out = arr
+            .add_duration_units(2, TimeUnit::Seconds, DurationRounding::Error)
+            .unwrap();
+        assert_eq!(out.time_unit, TimeUnit::Milliseconds);
+        assert_eq!(out.value(0), Some(3_000));
+        assert_eq!(out.value(1), None);
+        assert_eq!(out.value(2), Some(1_500));
+
+        // 1500 µs is not a whole number of milliseconds
+        let lossy = arr.add_duration_units(1_500, TimeUnit::Microseconds, DurationRounding::Error);
+        assert!(matches!(lossy, Err(MinarrowError::LossyCast { .. })));
+        let near = arr
+            .add_duration_units(-1_500, TimeUnit::Microseconds, DurationRounding::Nearest)
+            .unwrap();
+        assert_eq!(near.value(0), Some(998));
+        let near = arr
+            .add_duration_units(1_400, TimeUnit::Microseconds, DurationRounding::Nearest)
+            .unwrap();
+        assert_eq!(near.value(0), Some(1_001));
+
+        let max = DatetimeArray::<i64>::from_slice(&[i64::MAX], Some(TimeUnit::Seconds));
+        let overflow = max.add_duration_units(1, TimeUnit::Seconds, DurationRounding::Error);
+        assert!(matches!(overflow, Err(MinarrowError::Overflow { .. })));
+    }
+
+    #[test]
+    fn test_diff_units() {
+        let mut a = DatetimeArray::<i64>::with_capacity(3, true, Some(TimeUnit::Seconds));
+        a.push(100);
+        a.push(50);
+        a.push_null();
+        let b = DatetimeArray::<i64>::from_slice(&[40, 80, 10], Some(TimeUnit::Seconds));
+
+        let d = a.diff_units(&b).unwrap();
+        assert_eq!(d.get(0), Some(60));
+        assert_eq!(d.get(1), Some(-30));
+        assert_eq!(d.get(2), None);
+
+        // `other` is converted into `self`'s unit; the null slot is never converted.
+        let ms =
+            DatetimeArray::<i64>::from_slice(&[40_000, 80_000, 3], Some(TimeUnit::Milliseconds));
+        let d = a.diff_units(&ms).unwrap();
+        assert_eq!(d.get(0), Some(60));
+        assert_eq!(d.get(1), Some(-30));
+        assert_eq!(d.get(2), None);
+        let d = ms.diff_units(&b).unwrap();
+        assert_eq!(d.get(0), Some(0));
+        assert_eq!(d.get(1), Some(0));
+        assert_eq!(d.get(2), Some(-9_997));
+
+        let lossy = DatetimeArray::<i64>::from_slice(&[1_500, 0, 0], Some(TimeUnit::Milliseconds));
+        assert!(matches!(
+            a.diff_units(&lossy),
+            Err(MinarrowError::LossyCast { .. })
+        ));
+        let huge = DatetimeArray::<i64>::from_slice(&[i64::MAX, 0, 0], Some(TimeUnit::Seconds));
+        assert!(matches!(
+            ms.diff_units(&huge),
+            Err(MinarrowError::Overflow { .. })
+        ));
+        let short = DatetimeArray::<i64>::from_slice(&[1], Some(TimeUnit::Seconds));
+        assert!(matches!(
+            a.diff_units(&short),
+            Err(MinarrowError::ShapeError { .. })
+        ));
+    }
+
     #[test]
     fn test_datetime_array_slice() {
         use crate::enums::time_units::TimeUnit;
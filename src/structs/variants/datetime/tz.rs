@@ -541,6 +541,47 @@ fn is_offset_string(s: &str) -> bool {
     }
 }
 
+/// Resolves a timezone string to its standard UTC offset in seconds
+///
+/// Accepts the same inputs as [`lookup_timezone`]. Daylight savings is not applied.
+///
+/// # Examples
+/// ```
+/// use minarrow::structs::variants::datetime::tz::lookup_offset_seconds;
+///
+/// # #[cfg(feature = "datetime_ops")]
+/// # {
+/// assert_eq!(lookup_offset_seconds("Australia/Sydney"), Some(36_000));
+/// assert_eq!(lookup_offset_seconds("-05:30"), Some(-19_800));
+/// assert_eq!(lookup_offset_seconds("Mars/Olympus"), None);
+/// # }
+/// ```
+#[cfg(feature = "datetime_ops")]
+pub fn lookup_offset_seconds(tz_str: &str) -> Option<i32> {
+    let offset = lookup_timezone(tz_str)?.trim();
+    if offset.eq_ignore_ascii_case("UTC") || offset.eq_ignore_ascii_case("Z") {
+        return Some(0);
+    }
+
+    let sign = match offset.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let rest = &offset[1..];
+    let (hh, mm) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => return None,
+    };
+    let hours: i32 = hh.parse().ok()?;
+    let minutes: i32 = mm.parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
 #[cfg(all(feature = "datetime", feature = "datetime_ops"))]
 #[cfg(test)]
 mod tests {
@@ -610,4 +651,15 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_lookup_offset_seconds() {
+        assert_eq!(lookup_offset_seconds("UTC"), Some(0));
+        assert_eq!(lookup_offset_seconds("Asia/Tokyo"), Some(9 * 3600));
+        assert_eq!(lookup_offset_seconds("America/New_York"), Some(-5 * 3600));
+        assert_eq!(lookup_offset_seconds("+0530"), Some(19_800));
+        assert_eq!(lookup_offset_seconds("-09:30"), Some(-34_200));
+        assert_eq!(lookup_offset_seconds("+25:00"), None);
+        assert_eq!(lookup_offset_seconds("Invalid/Timezone"), None);
+    }
 }
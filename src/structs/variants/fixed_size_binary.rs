@@ -0,0 +1,765 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # **FixedSizeBinaryArray Module** - *Mid-Level, Inner Typed Fixed-Width Binary Array*
+//!
+//! Arrow-compatible fixed-width binary array, for values such as hashes, UUIDs
+//! and packed keys where every element occupies the same number of bytes.
+//!
+//! ## Overview
+//! - Logical type: opaque byte strings of a fixed `width`.
+//! - Physical storage: one contiguous `Buffer<u8>` of `len * width` bytes.
+//!   The i-th value is at `data[i * width..(i + 1) * width]`.
+//! - Optional `null_mask` where `1 = valid`, `0 = null`. Null slots still
+//!   occupy `width` zeroed bytes, as per the Arrow layout.
+//! - Maps to Arrow's `FixedSizeBinary` (`w:<width>`) over the C Data Interface.
+//!
+//! ## Features
+//! - Builders: `new`, `from_slices`, `with_capacity`.
+//! - Access: `value`, `value_unchecked`, `iter_values`, `iter_values_opt`.
+//! - Mutation: `push_value`, `set_value`, `push_null`, `push_nulls`.
+//!
+//! ## Safety note
+//! As with `StringArray`, the `MaskedArray` methods that return `&'static [u8]`
+//! do so for trait compatibility only - the data borrows from `self`. Prefer the
+//! `value*` methods in this module for correct lifetime management.
+
+use std::fmt::{Display, Formatter};
+use std::mem::transmute;
+
+use crate::enums::error::MinarrowError;
+use crate::enums::shape_dim::ShapeDim;
+use crate::traits::concatenate::Concatenate;
+use crate::traits::masked_array::MaskedArray;
+use crate::traits::print::MAX_PREVIEW;
+use crate::traits::shape::Shape;
+use crate::utils::validate_null_mask_len;
+use crate::{Bitmask, Buffer, impl_arc_masked_array};
+use vec64::Vec64;
+
+/// # FixedSizeBinaryArray
+///
+/// Fixed-width binary array with a single contiguous value buffer.
+///
+/// ## Role
+/// - Many will prefer the higher level `Array` type, which dispatches to this when
+///   necessary.
+/// - Use for binary keys where the width is known up front, e.g. 16-byte UUIDs
+///   or 32-byte digests, rather than storing them as strings.
+///
+/// ## Fields
+/// - **Data**: concatenated values, `width` bytes each.
+/// - **Null mask**: optional bit-packed validity bitmap (1=valid, 0=null).
+/// - **Width**: the byte width of every element.
+/// - **Len**: number of logical elements. Stored explicitly so that
+///   zero-width arrays still carry a length.
+///
+/// ## Example
+/// ```rust
+/// use minarrow::{FixedSizeBinaryArray, MaskedArray, vec64};
+///
+/// let arr = FixedSizeBinaryArray::new(vec64![1u8, 2, 3, 4, 5, 6], 2, None);
+///
+/// assert_eq!(arr.len(), 3);
+/// assert_eq!(arr.width(), 2);
+/// assert_eq!(arr.value(1), Some(&[3u8, 4][..]));
+/// ```
+#[repr(C, align(64))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct FixedSizeBinaryArray {
+    /// Concatenated fixed-width values.
+    pub data: Buffer<u8>,
+
+    /// Optional null mask (bit-packed; 1=valid, 0=null).
+    pub null_mask: Option<Bitmask>,
+
+    /// Byte width of each element.
+    pub width: usize,
+
+    /// Number of elements.
+    pub len: usize,
+}
+
+impl FixedSizeBinaryArray {
+    /// Constructs a new `FixedSizeBinaryArray` from a flat byte buffer and element width.
+    ///
+    /// # Panics
+    /// - If `width > 0` and the buffer length is not a multiple of `width`.
+    /// - If `width == 0` and the buffer is non-empty.
+    /// - If the null mask length does not match the element count.
+    #[inline]
+    pub fn new(data: impl Into<Buffer<u8>>, width: usize, null_mask: Option<Bitmask>) -> Self {
+        let data: Buffer<u8> = data.into();
+        let len = if width == 0 {
+            assert!(
+                data.is_empty(),
+                "FixedSizeBinaryArray: zero width requires empty data"
+            );
+            null_mask.as_ref().map(|m| m.len()).unwrap_or(0)
+        } else {
+            assert_eq!(
+                data.len() % width,
+                0,
+                "FixedSizeBinaryArray: data length {} is not a multiple of width {}",
+                data.len(),
+                width
+            );
+            data.len() / width
+        };
+        validate_null_mask_len(len, &null_mask);
+        Self {
+            data,
+            null_mask,
+            width,
+            len,
+        }
+    }
+
+    /// Constructs an empty array with reserved capacity for `n` values of `width` bytes.
+    #[inline]
+    pub fn with_capacity(n: usize, width: usize, null_mask: bool) -> Self {
+        Self {
+            data: Vec64::with_capacity(n * width).into(),
+            null_mask: if null_mask {
+                Some(Bitmask::with_capacity(n))
+            } else {
+                None
+            },
+            width,
+            len: 0,
+        }
+    }
+
+    /// Constructs an array from a slice of byte slices, all of which must be `width` bytes long.
+    ///
+    /// # Panics
+    /// Panics if any value is not exactly `width` bytes.
+    #[inline]
+    pub fn from_slices<V: AsRef<[u8]>>(values: &[V], width: usize) -> Self {
+        let mut arr = Self::with_capacity(values.len(), width, false);
+        for v in values {
+            arr.push_value(v.as_ref());
+        }
+        arr
+    }
+
+    /// Returns the byte width of each element.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the value at `idx`, or `None` if null or out of bounds.
+    #[inline]
+    pub fn value(&self, idx: usize) -> Option<&[u8]> {
+        if idx >= self.len || self.is_null(idx) {
+            return None;
+        }
+        let start = idx * self.width;
+        Some(&self.data[start..start + self.width])
+    }
+
+    /// Returns the raw bytes at `idx` without bounds or null checks.
+    ///
+    /// # Safety
+    /// Caller must ensure `idx < self.len()`.
+    #[inline(always)]
+    pub unsafe fn value_unchecked(&self, idx: usize) -> &[u8] {
+        let start = idx * self.width;
+        unsafe {
+            self.data
+                .as_slice()
+                .get_unchecked(start..start + self.width)
+        }
+    }
+
+    /// Appends a value to the array, marking it valid in the null mask if present.
+    ///
+    /// # Panics
+    /// Panics if `value.len() != self.width()`.
+    #[inline]
+    pub fn push_value(&mut self, value: &[u8]) {
+        assert_eq!(
+            value.len(),
+            self.width,
+            "FixedSizeBinaryArray: value length {} does not match width {}",
+            value.len(),
+            self.width
+        );
+        self.data.extend_from_slice(value);
+        let idx = self.len;
+        self.len += 1;
+        if let Some(mask) = &mut self.null_mask {
+            mask.set(idx, true);
+        }
+    }
+
+    /// Overwrites the value at `idx`, marking it valid in the null mask if present.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds or `value.len() != self.width()`.
+    #[inline]
+    pub fn set_value(&mut self, idx: usize, value: &[u8]) {
+        assert!(idx < self.len, "index out of bounds");
+        assert_eq!(
+            value.len(),
+            self.width,
+            "FixedSizeBinaryArray: value length {} does not match width {}",
+            value.len(),
+            self.width
+        );
+        let start = idx * self.width;
+        self.data.as_mut_slice()[start..start + self.width].copy_from_slice(value);
+        if let Some(mask) = &mut self.null_mask {
+            mask.set(idx, true);
+        }
+    }
+
+    /// Iterates over all values as byte slices, ignoring the null mask.
+    #[inline]
+    pub fn iter_values(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        (0..self.len).map(move |i| unsafe { self.value_unchecked(i) })
+    }
+
+    /// Iterates over all values as `Option<&[u8]>`, with `None` for nulls.
+    #[inline]
+    pub fn iter_values_opt(&self) -> impl Iterator<Item = Option<&[u8]>> + '_ {
+        (0..self.len).map(move |i| {
+            if self.is_null(i) {
+                None
+            } else {
+                Some(unsafe { self.value_unchecked(i) })
+            }
+        })
+    }
+
+    /// Adopts `other`'s width when `self` is an empty, zero-width default,
+    /// and otherwise asserts the widths match.
+    #[inline]
+    fn align_width(&mut self, other: &Self) {
+        if self.len == 0 && self.width == 0 {
+            self.width = other.width;
+        }
+        assert_eq!(
+            self.width, other.width,
+            "FixedSizeBinaryArray: width mismatch ({} vs {})",
+            self.width, other.width
+        );
+    }
+}
+
+/// ⚠️ As with `StringArray`, the `MaskedArray` implementation here is primarily
+/// to support the type contract and null handling. Methods returning
+/// `&'static [u8]` borrow from `self` - prefer `value` / `iter_values`.
+impl MaskedArray for FixedSizeBinaryArray {
+    type T = u8;
+
+    type Container = Buffer<u8>;
+
+    type LogicalType = Vec<u8>;
+
+    type CopyType = &'static [u8];
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn data(&self) -> &Self::Container {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut Self::Container {
+        &mut self.data
+    }
+
+    /// Returns the bytes at `idx`, or `None` if null.
+    ///
+    /// # ⚠️ WARNING - prefer `value`
+    /// The returned slice borrows from `self` and is not truly static.
+    #[inline]
+    fn get(&self, idx: usize) -> Option<&'static [u8]> {
+        self.value(idx)
+            .map(|v| unsafe { transmute::<&[u8], &'static [u8]>(v) })
+    }
+
+    #[inline]
+    fn set(&mut self, idx: usize, value: Vec<u8>) {
+        self.set_value(idx, &value)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, idx: usize) -> Option<&'static [u8]> {
+        if let Some(mask) = &self.null_mask
+            && !mask.get(idx)
+        {
+            return None;
+        }
+        Some(unsafe { transmute::<&[u8], &'static [u8]>(self.value_unchecked(idx)) })
+    }
+
+    #[inline]
+    unsafe fn set_unchecked(&mut self, idx: usize, value: Vec<u8>) {
+        let start = idx * self.width;
+        self.data.as_mut_slice()[start..start + self.width].copy_from_slice(&value);
+        if let Some(mask) = &mut self.null_mask {
+            unsafe { mask.set_unchecked(idx, true) };
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> impl Iterator<Item = &'static [u8]> + '_ {
+        self.iter_values()
+            .map(|v| unsafe { transmute::<&[u8], &'static [u8]>(v) })
+    }
+
+    #[inline]
+    fn iter_opt(&self) -> impl Iterator<Item = Option<&'static [u8]>> + '_ {
+        self.iter_values_opt()
+            .map(|v| v.map(|v| unsafe { transmute::<&[u8], &'static [u8]>(v) }))
+    }
+
+    #[inline]
+    fn iter_range(&self, offset: usize, len: usize) -> impl Iterator<Item = &'static [u8]> + '_ {
+        (offset..offset + len)
+            .map(move |i| unsafe { transmute::<&[u8], &'static [u8]>(self.value_unchecked(i)) })
+    }
+
+    #[inline]
+    fn iter_opt_range(
+        &self,
+        offset: usize,
+        len: usize,
+    ) -> impl Iterator<Item = Option<&'static [u8]>> + '_ {
+        (offset..offset + len).map(move |i| {
+            if self.is_null(i) {
+                None
+            } else {
+                Some(unsafe { transmute::<&[u8], &'static [u8]>(self.value_unchecked(i)) })
+            }
+        })
+    }
+
+    /// ⚠️ Prefer `push_value` as it avoids an additional allocation.
+    #[inline]
+    fn push(&mut self, value: Vec<u8>) {
+        self.push_value(&value)
+    }
+
+    #[inline(always)]
+    unsafe fn push_unchecked(&mut self, value: Vec<u8>) {
+        self.push_value(&value)
+    }
+
+    /// Appends a zeroed, null slot.
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_nulls(1);
+    }
+
+    #[inline]
+    unsafe fn push_null_unchecked(&mut self) {
+        self.push_nulls(1);
+    }
+
+    /// Appends `n` zeroed, null slots.
+    #[inline]
+    fn push_nulls(&mut self, n: usize) {
+        let start = self.len;
+        let end = start + n;
+        self.data.resize(end * self.width, 0);
+        self.len = end;
+        match self.null_mask_mut() {
+            Some(mask) => mask.resize(end, false),
+            None => {
+                let mut m = Bitmask::new_set_all(end, true);
+                for i in start..end {
+                    m.set(i, false);
+                }
+                self.set_null_mask(Some(m));
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn push_nulls_unchecked(&mut self, n: usize) {
+        self.push_nulls(n);
+    }
+
+    fn slice_clone(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len, "slice out of bounds");
+        let start = offset * self.width;
+        let end = (offset + len) * self.width;
+        Self {
+            data: Vec64::from_slice(&self.data[start..end]).into(),
+            null_mask: self
+                .null_mask
+                .as_ref()
+                .map(|mask| mask.slice_clone(offset, len)),
+            width: self.width,
+            len,
+        }
+    }
+
+    /// Resizes to `n` elements, filling any new slots with `value`.
+    ///
+    /// The null mask is left untouched.
+    fn resize(&mut self, n: usize, value: Vec<u8>) {
+        if n > self.len {
+            assert_eq!(
+                value.len(),
+                self.width,
+                "FixedSizeBinaryArray: value length {} does not match width {}",
+                value.len(),
+                self.width
+            );
+            self.data.reserve((n - self.len) * self.width);
+            for _ in self.len..n {
+                self.data.extend_from_slice(&value);
+            }
+        } else {
+            self.data.truncate(n * self.width);
+        }
+        self.len = n;
+    }
+
+    fn null_mask(&self) -> Option<&Bitmask> {
+        self.null_mask.as_ref()
+    }
+
+    fn null_mask_mut(&mut self) -> Option<&mut Bitmask> {
+        self.null_mask.as_mut()
+    }
+
+    fn set_null_mask(&mut self, mask: Option<Bitmask>) {
+        self.null_mask = mask;
+    }
+
+    fn append_array(&mut self, other: &Self) {
+        let orig_len = self.len;
+        let other_len = other.len;
+        if other_len == 0 {
+            return;
+        }
+        self.align_width(other);
+
+        self.data.extend_from_slice(&other.data);
+        self.len += other_len;
+
+        match (self.null_mask_mut(), other.null_mask()) {
+            (Some(self_mask), Some(other_mask)) => {
+                self_mask.extend_from_bitmask(other_mask);
+            }
+            (Some(self_mask), None) => {
+                self_mask.resize(orig_len + other_len, true);
+            }
+            (None, Some(other_mask)) => {
+                let mut mask = Bitmask::new_set_all(orig_len, true);
+                mask.extend_from_bitmask(other_mask);
+                self.set_null_mask(Some(mask));
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn append_range(
+        &mut self,
+        other: &Self,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), MinarrowError> {
+        if len == 0 {
+            return Ok(());
+        }
+        if offset + len > other.len {
+            return Err(MinarrowError::IndexError(format!(
+                "append_range: offset {} + len {} exceeds source length {}",
+                offset, len, other.len
+            )));
+        }
+        self.align_width(other);
+        let orig_len = self.len;
+
+        let w = other.width;
+        self.data
+            .extend_from_slice(&other.data[offset * w..(offset + len) * w]);
+        self.len += len;
+
+        match (self.null_mask_mut(), other.null_mask()) {
+            (Some(self_mask), Some(other_mask)) => {
+                self_mask.extend_from_bitmask_range(other_mask, offset, len);
+            }
+            (Some(self_mask), None) => {
+                self_mask.resize(orig_len + len, true);
+            }
+            (None, Some(other_mask)) => {
+                let mut mask = Bitmask::new_set_all(orig_len, true);
+                mask.extend_from_bitmask_range(other_mask, offset, len);
+                self.set_null_mask(Some(mask));
+            }
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Inserts all values from `other` into `self` at the specified index.
+    ///
+    /// This is an **O(n)** operation.
+    fn insert_rows(&mut self, index: usize, other: &Self) -> Result<(), MinarrowError> {
+        let orig_len = self.len;
+        let other_len = other.len;
+
+        if index > orig_len {
+            return Err(MinarrowError::IndexError(format!(
+                "Index {} out of bounds for array of length {}",
+                index, orig_len
+            )));
+        }
+        if other_len == 0 {
+            return Ok(());
+        }
+        self.align_width(other);
+
+        let split = index * self.width;
+        let mut new_data = Vec64::with_capacity(self.data.len() + other.data.len());
+        new_data.extend_from_slice(&self.data.as_ref()[..split]);
+        new_data.extend_from_slice(&other.data);
+        new_data.extend_from_slice(&self.data.as_ref()[split..]);
+        self.data = new_data.into();
+        self.len = orig_len + other_len;
+
+        if self.null_mask.is_some() || other.null_mask.is_some() {
+            let mut new_mask = Bitmask::new_set_all(orig_len + other_len, true);
+            if let Some(self_mask) = &self.null_mask {
+                for i in 0..index {
+                    new_mask.set(i, self_mask.get(i));
+                }
+                for i in index..orig_len {
+                    new_mask.set(other_len + i, self_mask.get(i));
+                }
+            }
+            if let Some(other_mask) = &other.null_mask {
+                for i in 0..other_len {
+                    new_mask.set(index + i, other_mask.get(i));
+                }
+            }
+            self.null_mask = Some(new_mask);
+        }
+
+        Ok(())
+    }
+
+    /// Splits the array at the specified index, consuming self and returning two arrays.
+    fn split(mut self, index: usize) -> Result<(Self, Self), MinarrowError> {
+        let orig_len = self.len;
+        if index == 0 || index >= orig_len {
+            return Err(MinarrowError::IndexError(format!(
+                "Split index {} out of valid range (0, {})",
+                index, orig_len
+            )));
+        }
+
+        let after_data = self.data.split_off(index * self.width);
+        let after_mask = self.null_mask.as_mut().map(|mask| mask.split_off(index));
+        self.len = index;
+
+        let after = FixedSizeBinaryArray {
+            data: after_data,
+            null_mask: after_mask,
+            width: self.width,
+            len: orig_len - index,
+        };
+
+        Ok((self, after))
+    }
+
+    fn extend_from_iter_with_capacity<I>(&mut self, iter: I, additional_capacity: usize)
+    where
+        I: Iterator<Item = Self::LogicalType>,
+    {
+        self.data.reserve(additional_capacity * self.width);
+        for value in iter {
+            self.push_value(&value);
+        }
+    }
+
+    fn extend_from_slice(&mut self, slice: &[Self::LogicalType]) {
+        self.data.reserve(slice.len() * self.width);
+        for value in slice {
+            self.push_value(value);
+        }
+    }
+
+    /// Creates an array of `count` copies of `value`, taking the width from `value`.
+    fn fill(value: Self::LogicalType, count: usize) -> Self {
+        let mut array = Self::with_capacity(count, value.len(), false);
+        for _ in 0..count {
+            array.push_value(&value);
+        }
+        array
+    }
+}
+
+impl_arc_masked_array!(
+    Inner = FixedSizeBinaryArray,
+    T = u8,
+    Container = Buffer<u8>,
+    LogicalType = Vec<u8>,
+    CopyType = &'static [u8],
+    BufferT = u8,
+    Variant = FixedSizeBinaryArray
+);
+
+impl Shape for FixedSizeBinaryArray {
+    fn shape(&self) -> ShapeDim {
+        ShapeDim::Rank1(self.len())
+    }
+}
+
+impl Concatenate for FixedSizeBinaryArray {
+    fn concat(mut self, other: Self) -> core::result::Result<Self, MinarrowError> {
+        if self.len > 0 && other.len > 0 && self.width != other.width {
+            return Err(MinarrowError::IncompatibleTypeError {
+                from: "FixedSizeBinaryArray",
+                to: "FixedSizeBinaryArray",
+                message: Some(format!("width mismatch: {} vs {}", self.width, other.width)),
+            });
+        }
+        self.append_array(&other);
+        Ok(self)
+    }
+}
+
+impl Display for FixedSizeBinaryArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let len = self.len();
+        let nulls = self.null_count();
+
+        writeln!(
+            f,
+            "FixedSizeBinaryArray [{} values] (dtype: fixed_size_binary[{}], nulls: {})",
+            len, self.width, nulls
+        )?;
+
+        write!(f, "[")?;
+
+        for i in 0..usize::min(len, MAX_PREVIEW) {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match self.value(i) {
+                Some(bytes) => {
+                    write!(f, "0x")?;
+                    for b in bytes {
+                        write!(f, "{:02x}", b)?;
+                    }
+                }
+                None => write!(f, "null")?,
+            }
+        }
+
+        if len > MAX_PREVIEW {
+            write!(f, ", … ({} total)", len)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec64;
+
+    #[test]
+    fn test_new_and_value() {
+        let arr = FixedSizeBinaryArray::new(vec64![1u8, 2, 3, 4, 5, 6], 2, None);
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.width(), 2);
+        assert_eq!(arr.value(0), Some(&[1u8, 2][..]));
+        assert_eq!(arr.value(2), Some(&[5u8, 6][..]));
+        assert_eq!(arr.value(3), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a multiple of width")]
+    fn test_new_rejects_ragged_buffer() {
+        let _ = FixedSizeBinaryArray::new(vec64![1u8, 2, 3], 2, None);
+    }
+
+    #[test]
+    fn test_from_slices_and_nulls() {
+        let mut arr = FixedSizeBinaryArray::from_slices(&[b"abcd", b"efgh"], 4);
+        arr.push_null();
+        arr.push_value(b"ijkl");
+        assert_eq!(arr.len(), 4);
+        assert_eq!(arr.data.len(), 16);
+        assert_eq!(arr.null_count(), 1);
+        assert_eq!(arr.value(2), None);
+        assert_eq!(arr.value(3), Some(&b"ijkl"[..]));
+        let collected: Vec<Option<&[u8]>> = arr.iter_values_opt().collect();
+        assert_eq!(collected[1], Some(&b"efgh"[..]));
+        assert_eq!(collected[2], None);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match width")]
+    fn test_push_wrong_width_panics() {
+        let mut arr = FixedSizeBinaryArray::with_capacity(1, 4, false);
+        arr.push_value(b"abc");
+    }
+
+    #[test]
+    fn test_slice_clone_and_split() {
+        let mut arr = FixedSizeBinaryArray::from_slices(&[b"aa", b"bb", b"cc", b"dd"], 2);
+        arr.set_null(1);
+        let sliced = arr.slice_clone(1, 2);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.value(0), None);
+        assert_eq!(sliced.value(1), Some(&b"cc"[..]));
+
+        let (left, right) = arr.split(3).unwrap();
+        assert_eq!(left.len(), 3);
+        assert_eq!(right.len(), 1);
+        assert_eq!(right.value(0), Some(&b"dd"[..]));
+    }
+
+    #[test]
+    fn test_append_and_insert_rows() {
+        let mut a = FixedSizeBinaryArray::from_slices(&[b"aa", b"dd"], 2);
+        let b = FixedSizeBinaryArray::from_slices(&[b"bb", b"cc"], 2);
+        a.insert_rows(1, &b).unwrap();
+        let vals: Vec<&[u8]> = a.iter_values().collect();
+        assert_eq!(vals, vec![&b"aa"[..], &b"bb"[..], &b"cc"[..], &b"dd"[..]]);
+
+        let mut empty = FixedSizeBinaryArray::default();
+        empty.append_array(&a);
+        assert_eq!(empty.width(), 2);
+        assert_eq!(empty.len(), 4);
+    }
+
+    #[test]
+    fn test_concat_width_mismatch() {
+        let a = FixedSizeBinaryArray::from_slices(&[b"aa"], 2);
+        let b = FixedSizeBinaryArray::from_slices(&[b"bbb"], 3);
+        assert!(a.concat(b).is_err());
+    }
+
+    #[test]
+    fn test_display_hex() {
+        let arr = FixedSizeBinaryArray::from_slices(&[[0xdeu8, 0xad], [0xbe, 0xef]], 2);
+        let s = format!("{}", arr);
+        assert!(s.contains("0xdead"));
+        assert!(s.contains("0xbeef"));
+    }
+}
@@ -35,6 +35,7 @@
 //! - **NaN-based**: one also has the option to skip the mask entirely and use `NaN` as a sentinel for missing values
 //!   (similar to Pandas/NumPy conventions), but this is not recommended as it is not a supported pattern in this
 //! library suite and therefore may yield unexpected results.
+//! - A null and a stored `NaN` are independent: `fill_null` replaces the former and `fill_nan` the latter.
 //!
 //! ## Performance Benchmarks
 //! Benchmarks under `examples/hotloop_benchmark_std` compare construction + sum over 1000 elems,
@@ -56,15 +57,19 @@
 //! - For latency-critical workloads, use direct `FloatArray`; otherwise differences are negligible.
 //!
 use std::fmt::{Display, Formatter};
+use std::simd::{Simd, SimdElement};
 
 use crate::enums::shape_dim::ShapeDim;
+use crate::enums::sort::{NullOrder, Side, permute_null_mask, search_sorted, sort_permutation};
+use crate::kernels::aggregate;
 use crate::traits::concatenate::Concatenate;
 use crate::traits::print::{MAX_PREVIEW, format_float};
 use crate::traits::shape::Shape;
 use crate::traits::type_unions::Float;
 use crate::{
-    Bitmask, Buffer, Length, MaskedArray, Offset, impl_arc_masked_array, impl_array_ref_deref,
-    impl_from_vec_primitive, impl_masked_array, impl_numeric_array_constructors,
+    Bitmask, BooleanArray, Buffer, IntegerArray, Length, MaskedArray, Offset, Vec64Ext,
+    impl_arc_masked_array, impl_array_ref_deref, impl_from_vec_primitive, impl_masked_array,
+    impl_numeric_array_constructors,
 };
 use vec64::Vec64;
 
@@ -165,6 +170,263 @@ where
     }
 }
 
+impl<T: Float + SimdElement> FloatArray<T> {
+    /// Applies a custom elementwise function, using SIMD over full lanes of width `N`.
+    ///
+    /// `simd_f` runs over each full lane whose values are all valid. `scalar_f`
+    /// handles the trailing remainder and any lane containing nulls, where it
+    /// is only called for valid positions. Null positions keep their existing
+    /// value and the null mask is carried over unchanged.
+    ///
+    /// Both functions must compute the same result for a given value, as which
+    /// one sees a given element depends on its position and nearby nulls.
+    /// Without the `simd` feature, every valid element goes through `scalar_f`.
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// use std::simd::{Simd, StdFloat};
+    /// use minarrow::{FloatArray, MaskedArray};
+    ///
+    /// let arr = FloatArray::<f64>::from_slice(&[1.0, 4.0, 9.0, 16.0, 25.0]);
+    /// let out = arr.map_simd::<4, _, _>(|v: Simd<f64, 4>| v.sqrt(), |v| v.sqrt());
+    /// assert_eq!(out.data.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    pub fn map_simd<const N: usize, F, G>(&self, simd_f: F, scalar_f: G) -> FloatArray<T>
+    where
+        F: Fn(Simd<T, N>) -> Simd<T, N>,
+        G: Fn(T) -> T,
+    {
+        assert!(N > 0, "FloatArray::map_simd: lane count must be non-zero");
+        let data = self.data.as_slice();
+        let mask = self.null_mask.as_ref().filter(|m| m.has_cleared());
+        let is_valid = |i: usize| mask.is_none_or(|m| m.get(i));
+        let vectorise = cfg!(feature = "simd");
+
+        let mut out = Vec64::with_capacity(data.len());
+        let mut chunks = data.chunks_exact(N);
+        for (c, chunk) in chunks.by_ref().enumerate() {
+            let start = c * N;
+            if vectorise && (mask.is_none() || (start..start + N).all(is_valid)) {
+                out.extend_from_slice(&simd_f(Simd::from_slice(chunk)).to_array());
+            } else {
+                out.extend(
+                    chunk
+                        .iter()
+                        .enumerate()
+                        .map(|(j, &v)| if is_valid(start + j) { scalar_f(v) } else { v }),
+                );
+            }
+        }
+        let start = data.len() - chunks.remainder().len();
+        out.extend(
+            chunks
+                .remainder()
+                .iter()
+                .enumerate()
+                .map(|(j, &v)| if is_valid(start + j) { scalar_f(v) } else { v }),
+        );
+
+        FloatArray::new(out, self.null_mask.clone())
+    }
+}
+
+impl<T: Float> FloatArray<T> {
+    /// Builds `n` evenly spaced values from `start` to `end`, both included,
+    /// like numpy's `linspace`.
+    ///
+    /// `n == 1` yields just `start`, and `n == 0` an empty array. The last
+    /// value is exactly `end` rather than accumulated.
+    pub fn linspace(start: T, end: T, n: usize) -> Self {
+        let step = if n > 1 {
+            (end - start) / T::from(n - 1).expect("usize converts to float")
+        } else {
+            T::zero()
+        };
+        let data = Vec64::from_exact_iter((0..n).map(|i| {
+            if n > 1 && i == n - 1 {
+                end
+            } else {
+                start + step * T::from(i).expect("usize converts to float")
+            }
+        }));
+        FloatArray::new(data, None)
+    }
+
+    /// Returns the stable permutation that sorts this array ascending, with
+    /// nulls placed according to `nulls`. `NaN` values sort after all other
+    /// valid values.
+    ///
+    /// The indices can be reused to reorder sibling columns of a `Table`.
+    /// An all-null array yields the identity permutation.
+    pub fn sort_indices(&self, nulls: NullOrder) -> Vec64<u32> {
+        let data = self.data.as_slice();
+        sort_permutation(data.len(), self.null_mask.as_ref(), nulls, |a, b| {
+            let (a, b) = (data[a], data[b]);
+            a.partial_cmp(&b)
+                .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+        })
+    }
+
+    /// Returns a sorted copy of this array, with nulls placed according to `nulls`.
+    pub fn sort(&self, nulls: NullOrder) -> Self {
+        let perm = self.sort_indices(nulls);
+        let data: Vec64<T> = perm.iter().map(|&i| self.data[i as usize]).collect();
+        FloatArray::new(data, permute_null_mask(self.null_mask.as_ref(), &perm))
+    }
+
+    /// Returns a dense copy with every null replaced by `value` and the null mask cleared.
+    ///
+    /// A null is a cleared bit in the null mask, whatever payload sits underneath.
+    /// A stored `NaN` is a valid value, so it is left as-is here; use `fill_nan` for those.
+    pub fn fill_null(&self, value: T) -> Self {
+        let mask = self.null_mask.as_ref();
+        let data: Vec64<T> = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                if mask.is_none_or(|m| m.get(i)) {
+                    v
+                } else {
+                    value
+                }
+            })
+            .collect();
+        FloatArray::new(data, None)
+    }
+
+    /// Returns a copy with every stored `NaN` replaced by `value`.
+    ///
+    /// This looks only at payloads and ignores the null mask, which is carried over
+    /// unchanged, so a `NaN` under a null position is replaced but stays null.
+    pub fn fill_nan(&self, value: T) -> Self {
+        let data: Vec64<T> = self
+            .data
+            .iter()
+            .map(|&v| if v.is_nan() { value } else { v })
+            .collect();
+        FloatArray::new(data, self.null_mask.clone())
+    }
+
+    /// Returns a mask of which stored values are `NaN`.
+    ///
+    /// Null entries stay null, whatever payload sits underneath, so this finds
+    /// the `NaN`s that the null mask does not already account for.
+    #[inline]
+    pub fn is_nan(&self) -> BooleanArray<()> {
+        self.test_each(|v| v.is_nan())
+    }
+
+    /// Returns a mask of which stored values are neither `NaN` nor infinite.
+    ///
+    /// Null entries stay null.
+    #[inline]
+    pub fn is_finite(&self) -> BooleanArray<()> {
+        self.test_each(|v| v.is_finite())
+    }
+
+    /// Marks as null every position where `cond` is true.
+    ///
+    /// Null entries in `cond` leave the position untouched. Combined with
+    /// `is_nan`, `arr.set_null_where(&arr.is_nan())` turns `NaN`s into nulls.
+    ///
+    /// # Panics
+    /// Panics if `cond` and this array differ in length.
+    pub fn set_null_where(&mut self, cond: &BooleanArray<()>) {
+        assert_eq!(
+            cond.len,
+            self.len(),
+            "set_null_where: condition length {} does not match array length {}",
+            cond.len,
+            self.len()
+        );
+        let hits = match &cond.null_mask {
+            Some(valid) => cond.data.intersect(valid),
+            None => cond.data.clone(),
+        };
+        if hits.all_unset() {
+            return;
+        }
+        let keep = hits.invert();
+        self.null_mask = Some(match &self.null_mask {
+            Some(valid) => valid.intersect(&keep),
+            None => keep,
+        });
+    }
+
+    /// Evaluates `pred` on every non-null value, carrying the null mask over.
+    fn test_each(&self, pred: impl Fn(T) -> bool) -> BooleanArray<()> {
+        let len = self.len();
+        let mut out = Bitmask::new_set_all(len, false);
+        for (i, &v) in self.data.iter().enumerate() {
+            if !self.is_null(i) && pred(v) {
+                // Safety: `i < len`, the length of `out`.
+                unsafe { out.set_unchecked(i, true) };
+            }
+        }
+        BooleanArray::new(out, self.null_mask.clone())
+    }
+
+    /// Counts the non-null values in each half-open bucket `[edges[i], edges[i + 1])`.
+    ///
+    /// Values are compared as `f64`. The result holds `edges.len() - 1` counts.
+    /// Values outside the edges are dropped, unless `out_of_range` is set, in
+    /// which case an underflow count is prepended and an overflow count appended.
+    /// `NaN` values are never counted.
+    ///
+    /// # Panics
+    /// Panics if `edges` has fewer than two entries or is not strictly increasing.
+    pub fn histogram(&self, edges: &[f64], out_of_range: bool) -> IntegerArray<u64> {
+        let counts = aggregate::histogram(
+            self.data.as_slice(),
+            self.null_mask.as_ref(),
+            edges,
+            out_of_range,
+            |v| v.to_f64().unwrap_or(f64::NAN),
+        );
+        IntegerArray::new(counts, None)
+    }
+
+    /// Returns whether the values are in ascending order, with `NaN` values
+    /// after all others as `sort` places them.
+    ///
+    /// Null slots are compared by their underlying payload.
+    pub fn is_sorted(&self) -> bool {
+        self.data
+            .as_slice()
+            .windows(2)
+            .all(|w| !nan_last_less(w[1], w[0]))
+    }
+
+    /// Returns, for each of `values`, the index at which it would be inserted
+    /// to keep this array sorted ascending. `side` picks the first or last
+    /// such index when equal values are present.
+    ///
+    /// `NaN` orders after every other value, matching `sort`. The result is
+    /// meaningless if the array is not sorted, which debug builds check over the
+    /// non-null values. Null slots are searched by their underlying payload, so
+    /// drop or fill them first.
+    pub fn searchsorted(&self, values: &[T], side: Side) -> Vec64<usize> {
+        debug_assert!(
+            self.iter_opt()
+                .flatten()
+                .is_sorted_by(|&a, &b| !nan_last_less(b, a)),
+            "searchsorted: array is not sorted"
+        );
+        search_sorted(self.data.as_slice(), values, side, nan_last_less)
+    }
+}
+
+/// Strict ascending order with `NaN` after every other value.
+#[inline]
+fn nan_last_less<T: Float>(a: T, b: T) -> bool {
+    a < b || (!a.is_nan() && b.is_nan())
+}
+
 impl<T: Float> Shape for FloatArray<T> {
     fn shape(&self) -> ShapeDim {
         ShapeDim::Rank1(self.len())
@@ -307,7 +569,7 @@ mod tests {
     fn test_null_mask_replace() {
         let mut arr = FloatArray::<f64>::default();
         arr.push(1.0);
-        arr.set_null_mask(Some(Bitmask::from_bytes([0b0000_0001], 1)));
+        arr.set_null_mask(Some(Bitmask::from_bytes([0b0000_0001], 1).unwrap()));
         assert!(!arr.is_null(0));
     }
 
@@ -414,6 +676,140 @@ mod tests {
         assert_eq!(result.get(4), None);
         assert_eq!(result.null_count(), 2);
     }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_map_simd_lanes_remainder_and_nulls() {
+        let mut arr =
+            FloatArray::<f64>::from_slice(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        arr.set_null(5);
+
+        // Distinct functions make the routing observable: full valid lanes
+        // take the SIMD path, the lane with a null and the tail take the scalar path.
+        let out = arr.map_simd::<4, _, _>(|v| v + Simd::splat(100.0), |v| v + 1.0);
+        assert_eq!(out.len(), 10);
+        assert_eq!(&out.data.as_slice()[..4], &[100.0, 101.0, 102.0, 103.0]);
+        assert_eq!(out.get(4), Some(5.0));
+        assert_eq!(out.get(5), None);
+        assert_eq!(out.get(6), Some(7.0));
+        assert_eq!(out.get(7), Some(8.0));
+        assert_eq!(out.get(8), Some(9.0));
+        assert_eq!(out.get(9), Some(10.0));
+        assert_eq!(out.null_count(), 1);
+
+        let dense = FloatArray::<f32>::from_slice(&[1.0, 2.0, 3.0]);
+        let out = dense.map_simd::<8, _, _>(|v| v * Simd::splat(2.0), |v| v * 2.0);
+        assert_eq!(out.data.as_slice(), &[2.0, 4.0, 6.0]);
+        assert!(out.null_mask.is_none());
+    }
+
+    #[test]
+    fn test_sort_nan_and_nulls() {
+        let mut arr = FloatArray::<f64>::from_slice(&[2.5, f64::NAN, -1.0, 0.0, 2.5]);
+        arr.set_null(3);
+
+        assert_eq!(
+            arr.sort_indices(NullOrder::Last).as_slice(),
+            &[2, 0, 4, 1, 3]
+        );
+        let sorted = arr.sort(NullOrder::First);
+        assert_eq!(sorted.get(0), None);
+        assert_eq!(sorted.get(1), Some(-1.0));
+        assert_eq!(sorted.get(3), Some(2.5));
+        assert!(sorted.get(4).unwrap().is_nan());
+        assert_eq!(sorted.null_count(), 1);
+    }
+
+    #[test]
+    fn test_linspace() {
+        let grid = FloatArray::<f64>::linspace(0.0, 1.0, 5);
+        assert_eq!(grid.data.as_slice(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        let down = FloatArray::<f32>::linspace(1.0, -1.0, 3);
+        assert_eq!(down.data.as_slice(), &[1.0, 0.0, -1.0]);
+        let thirds = FloatArray::<f64>::linspace(0.0, 1.0, 4);
+        assert_eq!(thirds.data[3], 1.0);
+        assert_eq!(
+            FloatArray::<f64>::linspace(2.0, 9.0, 1).data.as_slice(),
+            &[2.0]
+        );
+        assert!(FloatArray::<f64>::linspace(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_fill_null_and_fill_nan() {
+        let mut arr = FloatArray::<f64>::from_slice(&[1.0, f64::NAN, 3.0, f64::NAN]);
+        arr.set_null(2);
+        arr.set_null(3);
+
+        let filled = arr.fill_null(0.0);
+        assert!(filled.null_mask.is_none());
+        assert_eq!(filled.data[0], 1.0);
+        assert!(filled.data[1].is_nan());
+        assert_eq!(filled.data[2], 0.0);
+        assert_eq!(filled.data[3], 0.0);
+
+        let no_nan = arr.fill_nan(-1.0);
+        assert_eq!(no_nan.data.as_slice(), &[1.0, -1.0, 3.0, -1.0]);
+        assert_eq!(no_nan.get(1), Some(-1.0));
+        assert_eq!(no_nan.get(3), None);
+        assert_eq!(no_nan.null_count(), 2);
+    }
+
+    #[test]
+    fn test_is_nan_is_finite_and_set_null_where() {
+        let mut arr = FloatArray::<f64>::from_slice(&[1.0, f64::NAN, f64::INFINITY, f64::NAN]);
+        arr.set_null(3);
+
+        let nan = arr.is_nan();
+        assert_eq!(nan.get(0), Some(false));
+        assert_eq!(nan.get(1), Some(true));
+        assert_eq!(nan.get(2), Some(false));
+        assert_eq!(nan.get(3), None);
+
+        let finite = arr.is_finite();
+        assert_eq!(finite.get(0), Some(true));
+        assert_eq!(finite.get(1), Some(false));
+        assert_eq!(finite.get(2), Some(false));
+        assert_eq!(finite.get(3), None);
+
+        arr.set_null_where(&nan);
+        assert_eq!(arr.get(0), Some(1.0));
+        assert_eq!(arr.get(1), None);
+        assert_eq!(arr.get(2), Some(f64::INFINITY));
+        assert_eq!(arr.null_count(), 2);
+
+        let mut dense = FloatArray::<f32>::from_slice(&[1.0, 2.0]);
+        dense.set_null_where(&BooleanArray::from_slice(&[false, false]));
+        assert!(dense.null_mask.is_none());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut arr = FloatArray::<f32>::from_slice(&[0.1, 0.25, f32::NAN, 0.5, 0.75, 1.0]);
+        arr.set_null(1);
+        let counts = arr.histogram(&[0.0, 0.5, 1.0], false);
+        assert_eq!(counts.data.as_slice(), &[1, 2]);
+        let counts = arr.histogram(&[0.0, 0.5, 1.0], true);
+        assert_eq!(counts.data.as_slice(), &[0, 1, 2, 1]);
+    }
+
+    #[test]
+    fn test_searchsorted() {
+        let arr = FloatArray::<f64>::from_slice(&[0.5, 1.0, 1.0, 2.5, f64::NAN]);
+        assert!(arr.is_sorted());
+        let queries = [0.0, 1.0, 2.0, 3.0, f64::NAN];
+        let left = arr.searchsorted(&queries, Side::Left);
+        assert_eq!(left.as_slice(), &[0, 1, 3, 4, 4]);
+        let right = arr.searchsorted(&queries, Side::Right);
+        assert_eq!(right.as_slice(), &[0, 3, 3, 4, 5]);
+
+        assert!(!FloatArray::<f64>::from_slice(&[f64::NAN, 1.0]).is_sorted());
+
+        // The debug sortedness check skips null slots and their payloads
+        let mut with_null = FloatArray::<f64>::from_slice(&[1.0, 9.0, 3.0]);
+        with_null.set_null(1);
+        assert_eq!(with_null.searchsorted(&[0.0], Side::Left).as_slice(), &[0]);
+    }
 }
 
 #[cfg(test)]
@@ -443,6 +839,23 @@ mod parallel_tests {
         assert_eq!(opt, vec![Some(1.0), None, None]);
     }
 
+    #[test]
+    fn test_floatarray_into_par_iter() {
+        let arr = FloatArray::<f32>::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        let vals: Vec<f32> = arr.into_par_iter().map(|v| v * 0.5).collect();
+        assert_eq!(vals, vec![0.5, 1.0, 1.5, 2.0]);
+    }
+
+    #[test]
+    fn test_floatarray_into_par_iter_opt() {
+        let mut arr = FloatArray::<f32>::from_slice(&[1.0, 2.0, 3.0]);
+        let mut mask = Bitmask::new_set_all(3, true);
+        mask.set(0, false);
+        arr.null_mask = Some(mask);
+        let opt: Vec<Option<f32>> = arr.into_par_iter_opt().collect();
+        assert_eq!(opt, vec![None, Some(2.0), Some(3.0)]);
+    }
+
     #[test]
     fn test_floatarray_par_iter_mut() {
         let mut arr = FloatArray::<f32>::from_slice(&[1.0, 2.0, 3.0]);
@@ -55,13 +55,16 @@
 use std::fmt::{Display, Formatter};
 
 use crate::enums::shape_dim::ShapeDim;
+use crate::enums::sort::{NullOrder, Side, permute_null_mask, search_sorted, sort_permutation};
+use crate::kernels::aggregate;
 use crate::traits::concatenate::Concatenate;
 use crate::traits::print::MAX_PREVIEW;
 use crate::traits::shape::Shape;
 use crate::traits::type_unions::Integer;
 use crate::{
-    Bitmask, Buffer, Length, MaskedArray, Offset, impl_arc_masked_array, impl_array_ref_deref,
-    impl_from_vec_primitive, impl_masked_array, impl_numeric_array_constructors,
+    Bitmask, Buffer, Length, MaskedArray, Offset, Vec64Ext, impl_arc_masked_array,
+    impl_array_ref_deref, impl_from_vec_primitive, impl_masked_array,
+    impl_numeric_array_constructors,
 };
 use vec64::Vec64;
 
@@ -160,6 +163,120 @@ where
     }
 }
 
+impl<T: Integer> IntegerArray<T> {
+    /// Builds the arithmetic sequence `start, start + step, ...` up to but
+    /// excluding `end`, like numpy's `arange`.
+    ///
+    /// A negative `step` counts down. The result is empty when `end` cannot be
+    /// reached from `start` in the direction of `step`.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub fn from_range(start: T, end: T, step: T) -> Self {
+        assert!(
+            step != T::zero(),
+            "IntegerArray::from_range: step must be non-zero"
+        );
+        // Every `Integer` fits in `i128`, so the span cannot overflow
+        let wide = |v: T| v.to_i128().expect("Integer fits in i128");
+        let (start, end, step) = (wide(start), wide(end), wide(step));
+        let span = end - start;
+        let len = if span == 0 || (span > 0) != (step > 0) {
+            0
+        } else {
+            ((span.abs() + step.abs() - 1) / step.abs()) as usize
+        };
+        let data = Vec64::from_exact_iter((0..len).map(|i| {
+            T::from(start + i as i128 * step).expect("from_range values stay within [start, end)")
+        }));
+        IntegerArray::new(data, None)
+    }
+
+    /// Returns the stable permutation that sorts this array ascending, with
+    /// nulls placed according to `nulls`.
+    ///
+    /// The indices can be reused to reorder sibling columns of a `Table`.
+    /// An all-null array yields the identity permutation.
+    pub fn sort_indices(&self, nulls: NullOrder) -> Vec64<u32> {
+        let data = self.data.as_slice();
+        if self.null_mask.as_ref().is_none_or(|m| !m.has_cleared()) {
+            // Dense path: a keyed stable sort skips the null partitioning.
+            let mut perm: Vec64<u32> = (0..data.len() as u32).collect();
+            perm.sort_by_key(|&i| data[i as usize]);
+            return perm;
+        }
+        sort_permutation(data.len(), self.null_mask.as_ref(), nulls, |a, b| {
+            data[a].cmp(&data[b])
+        })
+    }
+
+    /// Returns a sorted copy of this array, with nulls placed according to `nulls`.
+    pub fn sort(&self, nulls: NullOrder) -> Self {
+        let perm = self.sort_indices(nulls);
+        let data: Vec64<T> = perm.iter().map(|&i| self.data[i as usize]).collect();
+        IntegerArray::new(data, permute_null_mask(self.null_mask.as_ref(), &perm))
+    }
+
+    /// Returns a dense copy with every null replaced by `value` and the null mask cleared.
+    pub fn fill_null(&self, value: T) -> Self {
+        let mask = self.null_mask.as_ref();
+        let data: Vec64<T> = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                if mask.is_none_or(|m| m.get(i)) {
+                    v
+                } else {
+                    value
+                }
+            })
+            .collect();
+        IntegerArray::new(data, None)
+    }
+
+    /// Counts the non-null values in each half-open bucket `[edges[i], edges[i + 1])`.
+    ///
+    /// The result holds `edges.len() - 1` counts. Values outside the edges are
+    /// dropped, unless `out_of_range` is set, in which case an underflow count
+    /// is prepended and an overflow count appended.
+    ///
+    /// # Panics
+    /// Panics if `edges` has fewer than two entries or is not strictly increasing.
+    pub fn histogram(&self, edges: &[T], out_of_range: bool) -> IntegerArray<u64> {
+        let counts = aggregate::histogram(
+            self.data.as_slice(),
+            self.null_mask.as_ref(),
+            edges,
+            out_of_range,
+            |v| v,
+        );
+        IntegerArray::new(counts, None)
+    }
+
+    /// Returns whether the values are in ascending order.
+    ///
+    /// Null slots are compared by their underlying payload.
+    pub fn is_sorted(&self) -> bool {
+        self.data.as_slice().is_sorted()
+    }
+
+    /// Returns, for each of `values`, the index at which it would be inserted
+    /// to keep this array sorted ascending. `side` picks the first or last
+    /// such index when equal values are present.
+    ///
+    /// Runs a binary search per value. The result is meaningless if the array
+    /// is not sorted, which debug builds check over the non-null values. Null
+    /// slots are searched by their underlying payload, so drop or fill them first.
+    pub fn searchsorted(&self, values: &[T], side: Side) -> Vec64<usize> {
+        debug_assert!(
+            self.iter_opt().flatten().is_sorted(),
+            "searchsorted: array is not sorted"
+        );
+        search_sorted(self.data.as_slice(), values, side, |a, b| a < b)
+    }
+}
+
 impl<T: Integer> Shape for IntegerArray<T> {
     fn shape(&self) -> ShapeDim {
         ShapeDim::Rank1(self.len())
@@ -406,6 +523,110 @@ mod tests {
         assert!(arr.data.capacity() >= initial_capacity);
         assert_eq!(arr.len(), 5);
     }
+
+    #[test]
+    fn test_sort_and_sort_indices() {
+        let mut arr = IntegerArray::<i32>::from_slice(&[3, 1, 0, 2, 1]);
+        arr.set_null(2);
+
+        let perm = arr.sort_indices(NullOrder::Last);
+        assert_eq!(perm.as_slice(), &[1, 4, 3, 0, 2]);
+        let perm = arr.sort_indices(NullOrder::First);
+        assert_eq!(perm.as_slice(), &[2, 1, 4, 3, 0]);
+
+        let sorted = arr.sort(NullOrder::First);
+        let got: Vec<Option<i32>> = (0..sorted.len()).map(|i| sorted.get(i)).collect();
+        assert_eq!(got, vec![None, Some(1), Some(1), Some(2), Some(3)]);
+        assert_eq!(sorted.null_count(), 1);
+
+        let dense = IntegerArray::<u64>::from_slice(&[5, 2, 9]).sort(NullOrder::Last);
+        assert_eq!(dense.data.as_slice(), &[2, 5, 9]);
+        assert!(dense.null_mask.is_none());
+
+        let mut all_null = IntegerArray::<i64>::from_slice(&[7, 8, 9]);
+        all_null.null_mask = Some(Bitmask::new_set_all(3, false));
+        assert_eq!(
+            all_null.sort_indices(NullOrder::Last).as_slice(),
+            &[0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_iter_valid_and_indices_valid() {
+        let mut arr = IntegerArray::<i64>::from_slice(&(0..20).collect::<Vec<i64>>());
+        for i in [0, 3, 8, 9, 10, 11, 12, 13, 14, 15, 19] {
+            arr.set_null(i);
+        }
+        let idx: Vec<usize> = arr.indices_valid().collect();
+        assert_eq!(idx, vec![1, 2, 4, 5, 6, 7, 16, 17, 18]);
+        let vals: Vec<i64> = arr.iter_valid().collect();
+        assert_eq!(vals, vec![1, 2, 4, 5, 6, 7, 16, 17, 18]);
+
+        let dense = IntegerArray::<i32>::from_slice(&[4, 5, 6]);
+        assert_eq!(dense.iter_valid().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(dense.indices_valid().count(), 3);
+        assert_eq!(IntegerArray::<i32>::default().iter_valid().count(), 0);
+    }
+
+    #[test]
+    fn test_from_range() {
+        let up = IntegerArray::<i32>::from_range(0, 10, 3);
+        assert_eq!(up.data.as_slice(), &[0, 3, 6, 9]);
+        let down = IntegerArray::<i64>::from_range(5, -1, -2);
+        assert_eq!(down.data.as_slice(), &[5, 3, 1]);
+        assert!(IntegerArray::<i32>::from_range(0, 10, -1).is_empty());
+        assert!(IntegerArray::<u32>::from_range(4, 4, 1).is_empty());
+        // The span exceeds i8 but every value fits
+        let wide = IntegerArray::<i8>::from_range(-100, 100, 50);
+        assert_eq!(wide.data.as_slice(), &[-100, -50, 0, 50]);
+        assert!(wide.null_mask.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn test_from_range_zero_step() {
+        IntegerArray::<i32>::from_range(0, 5, 0);
+    }
+
+    #[test]
+    fn test_fill_null() {
+        let mut arr = IntegerArray::<i32>::from_slice(&[1, 2, 3]);
+        arr.set_null(1);
+        let filled = arr.fill_null(-9);
+        assert_eq!(filled.data.as_slice(), &[1, -9, 3]);
+        assert!(filled.null_mask.is_none());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut arr = IntegerArray::<i64>::from_slice(&[-1, 0, 2, 4, 5, 5, 7]);
+        arr.set_null(3);
+        let counts = arr.histogram(&[0, 2, 5], false);
+        assert_eq!(counts.data.as_slice(), &[1, 1]);
+        assert!(counts.null_mask.is_none());
+        let counts = arr.histogram(&[0, 2, 5], true);
+        assert_eq!(counts.data.as_slice(), &[1, 1, 1, 3]);
+    }
+
+    #[test]
+    fn test_searchsorted() {
+        let arr = IntegerArray::<i32>::from_slice(&[1, 3, 3, 3, 7]);
+        assert!(arr.is_sorted());
+        let queries = [0, 1, 3, 5, 7, 9];
+        let left = arr.searchsorted(&queries, Side::Left);
+        assert_eq!(left.as_slice(), &[0, 0, 1, 4, 4, 5]);
+        let right = arr.searchsorted(&queries, Side::Right);
+        assert_eq!(right.as_slice(), &[0, 1, 4, 4, 5, 5]);
+
+        assert!(!IntegerArray::<i32>::from_slice(&[2, 1]).is_sorted());
+        let empty = IntegerArray::<i32>::from_slice(&[]);
+        assert_eq!(empty.searchsorted(&[4], Side::Right).as_slice(), &[0]);
+
+        // The debug sortedness check skips null slots and their payloads
+        let mut with_null = IntegerArray::<i32>::from_slice(&[1, 9, 3]);
+        with_null.set_null(1);
+        assert_eq!(with_null.searchsorted(&[0], Side::Left).as_slice(), &[0]);
+    }
 }
 
 #[cfg(test)]
@@ -438,6 +659,25 @@ mod parallel_tests {
         assert_eq!(opt, vec![None, Some(2), None, None]);
     }
 
+    #[test]
+    fn test_integerarray_into_par_iter() {
+        let arr = IntegerArray::<i32>::from_slice(&[1, 2, 3, 4]);
+        let sum: i32 = arr.clone().into_par_iter().sum();
+        assert_eq!(sum, 10);
+        let vals: Vec<i32> = arr.into_par_iter().map(|v| v * 2).collect();
+        assert_eq!(vals, vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_integerarray_into_par_iter_opt() {
+        let mut arr = IntegerArray::<i32>::from_slice(&[1, 2, 3, 4]);
+        let mut mask = Bitmask::new_set_all(4, true);
+        mask.set(2, false);
+        arr.null_mask = Some(mask);
+        let opt: Vec<Option<i32>> = arr.into_par_iter_opt().collect();
+        assert_eq!(opt, vec![Some(1), Some(2), None, Some(4)]);
+    }
+
     #[test]
     fn test_integerarray_par_iter_mut() {
         let mut arr = IntegerArray::<i32>::from_slice(&[10, 20, 30, 40]);
@@ -59,7 +59,8 @@ use crate::traits::shape::Shape;
 use crate::traits::type_unions::Integer;
 use crate::utils::validate_null_mask_len;
 use crate::{
-    Bitmask, Buffer, CategoricalArray, Length, Offset, StringAVT, impl_arc_masked_array, vec64,
+    Bitmask, BooleanArray, Buffer, CategoricalArray, IntegerArray, Length, Offset, StringAVT,
+    impl_arc_masked_array, vec64,
 };
 use vec64::Vec64;
 
@@ -484,6 +485,100 @@ impl<T: Integer> StringArray<T> {
     pub fn slice_tuple(&self, offset: usize, len: usize) -> (&[u8], Offset, Length) {
         (&self.data.as_ref()[offset..offset + len], offset, len)
     }
+
+    /// Returns a mask of which strings contain `pat` as a literal substring.
+    ///
+    /// Null entries stay null. An empty pattern matches every non-null string.
+    #[inline]
+    pub fn contains(&self, pat: &str) -> BooleanArray<()> {
+        self.match_each(|s| s.contains(pat))
+    }
+
+    /// Returns a mask of which strings start with `pat`.
+    ///
+    /// Null entries stay null. An empty pattern matches every non-null string.
+    #[inline]
+    pub fn starts_with(&self, pat: &str) -> BooleanArray<()> {
+        self.match_each(|s| s.starts_with(pat))
+    }
+
+    /// Returns a mask of which strings end with `pat`.
+    ///
+    /// Null entries stay null. An empty pattern matches every non-null string.
+    #[inline]
+    pub fn ends_with(&self, pat: &str) -> BooleanArray<()> {
+        self.match_each(|s| s.ends_with(pat))
+    }
+
+    /// Returns the number of Unicode scalar values in each string.
+    ///
+    /// Counts code points, not bytes or grapheme clusters, so `"é"` spelt as
+    /// `e` plus a combining accent has length 2. Null entries stay null.
+    pub fn char_length(&self) -> IntegerArray<i32> {
+        let len = self.len();
+        let mut data = Vec64::with_capacity(len);
+        for i in 0..len {
+            let n = if self.is_null(i) {
+                0
+            } else {
+                // Safety: `i < len`, and the offsets describe valid UTF-8 ranges.
+                unsafe { self.get_str_unchecked(i) }.chars().count()
+            };
+            data.push(n as i32);
+        }
+        IntegerArray::new(data, self.null_mask.clone())
+    }
+
+    /// Splits each string at the first occurrence of `sep`.
+    ///
+    /// Returns the parts before and after the separator. A string without `sep`
+    /// goes whole into the left side, with a null on the right. Null entries
+    /// are null on both sides.
+    pub fn split_once(&self, sep: &str) -> (StringArray<T>, StringArray<T>) {
+        let len = self.len();
+        let bytes = self.data.len();
+        let mut left = StringArray::with_capacity(len, bytes, self.null_mask.is_some());
+        let mut right = StringArray::with_capacity(len, bytes, self.null_mask.is_some());
+        for i in 0..len {
+            if self.is_null(i) {
+                left.push_null();
+                right.push_null();
+                continue;
+            }
+            // Safety: `i < len`, and the offsets describe valid UTF-8 ranges.
+            let s = unsafe { self.get_str_unchecked(i) };
+            match s.split_once(sep) {
+                Some((l, r)) => {
+                    left.push_str(l);
+                    right.push_str(r);
+                }
+                None => {
+                    left.push_str(s);
+                    right.push_null();
+                }
+            }
+        }
+        (left, right)
+    }
+
+    /// Evaluates `pred` on every non-null string, carrying the null mask over.
+    ///
+    /// Shared by the literal predicates above, and the hook for pattern-based
+    /// matchers such as regex.
+    pub(crate) fn match_each(&self, pred: impl Fn(&str) -> bool) -> BooleanArray<()> {
+        let len = self.len();
+        let mut out = Bitmask::new_set_all(len, false);
+        for i in 0..len {
+            if self.is_null(i) {
+                continue;
+            }
+            // Safety: `i < len`, and the offsets describe valid UTF-8 ranges.
+            if pred(unsafe { self.get_str_unchecked(i) }) {
+                unsafe { out.set_unchecked(i, true) };
+            }
+        }
+        BooleanArray::new(out, self.null_mask.clone())
+    }
 }
 
 /// ⚠️ The string implementation of `MaskedArray` is primarily to support
@@ -1177,11 +1272,12 @@ impl<T: Integer> MaskedArray for StringArray<T> {
         // Split the data buffer
         let after_data = self.data.split_off(split_byte_offset);
 
-        // Split offsets
+        // Split offsets, keeping the boundary offset on both sides
         let mut after_offsets = self.offsets.split_off(index);
+        let base_offset = after_offsets[0];
+        self.offsets.push(base_offset);
 
         // Adjust after_offsets to start from 0
-        let base_offset = after_offsets[0];
         for off in &mut after_offsets {
             *off = T::from_usize(off.to_usize() - base_offset.to_usize());
         }
@@ -1950,6 +2046,93 @@ mod tests {
         assert_eq!(result.get_str(4), None);
         assert_eq!(result.null_count(), 2);
     }
+
+    #[test]
+    fn test_char_length_counts_code_points() {
+        let mut arr = StringArray::<u32>::default();
+        arr.push_str("abc");
+        arr.push_str("héllo");
+        arr.push_null();
+        // Two emoji, each one scalar value but four UTF-8 bytes
+        arr.push_str("🦀🚀");
+        // `e` followed by U+0301 COMBINING ACUTE ACCENT
+        arr.push_str("e\u{301}");
+        arr.push_str("");
+
+        let n = arr.char_length();
+        assert_eq!(n.data.as_slice(), &[3, 5, 0, 2, 2, 0]);
+        assert_eq!(n.get(2), None);
+        assert_eq!(n.null_count(), 1);
+    }
+
+    #[test]
+    fn test_split_keeps_rows() {
+        let mut arr = StringArray::<u32>::from_slice(&["a", "bb", "ccc", "dddd"]);
+        arr.set_null(1);
+        let (left, right) = arr.split(2).unwrap();
+        assert_eq!(left.len(), 2);
+        assert_eq!((left.get_str(0), left.get_str(1)), (Some("a"), None));
+        assert_eq!(right.len(), 2);
+        assert_eq!(right.get_str(0), Some("ccc"));
+        assert_eq!(right.get_str(1), Some("dddd"));
+    }
+
+    #[test]
+    fn test_split_once() {
+        let mut arr = StringArray::<u32>::default();
+        arr.push_str("key=value=more");
+        arr.push_str("novalue");
+        arr.push_null();
+        arr.push_str("🦀=é");
+        arr.push_str("=");
+
+        let (l, r) = arr.split_once("=");
+        assert_eq!(l.len(), 5);
+        assert_eq!(r.len(), 5);
+        assert_eq!(l.get_str(0), Some("key"));
+        assert_eq!(r.get_str(0), Some("value=more"));
+        assert_eq!(l.get_str(1), Some("novalue"));
+        assert_eq!(r.get_str(1), None);
+        assert_eq!(l.get_str(2), None);
+        assert_eq!(r.get_str(2), None);
+        assert_eq!(l.get_str(3), Some("🦀"));
+        assert_eq!(r.get_str(3), Some("é"));
+        assert_eq!(l.get_str(4), Some(""));
+        assert_eq!(r.get_str(4), Some(""));
+    }
+
+    #[test]
+    fn test_literal_predicates() {
+        let mut arr = StringArray::<u64>::with_capacity(5, 32, true);
+        arr.push_str("foobar");
+        arr.push_null();
+        arr.push_str("barfoo");
+        arr.push_str("");
+        arr.push_null();
+
+        let c = arr.contains("foo");
+        assert_eq!(c.len(), 5);
+        assert_eq!(c.get(0), Some(true));
+        assert_eq!(c.get(1), None);
+        assert_eq!(c.get(2), Some(true));
+        assert_eq!(c.get(3), Some(false));
+        assert_eq!(c.get(4), None);
+
+        let s = arr.starts_with("foo");
+        assert_eq!(s.get(0), Some(true));
+        assert_eq!(s.get(2), Some(false));
+
+        let e = arr.ends_with("foo");
+        assert_eq!(e.get(0), Some(false));
+        assert_eq!(e.get(2), Some(true));
+
+        // Empty patterns match every non-null entry, including empty strings
+        let empty = arr.contains("");
+        assert_eq!(empty.get(0), Some(true));
+        assert_eq!(empty.get(1), None);
+        assert_eq!(empty.get(3), Some(true));
+        assert_eq!(arr.starts_with("").null_count(), 2);
+    }
 }
 
 #[cfg(test)]
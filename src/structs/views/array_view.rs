@@ -51,7 +51,14 @@ use crate::traits::print::MAX_PREVIEW;
 #[cfg(feature = "select")]
 use crate::traits::selection::{DataSelector, RowSelection};
 use crate::traits::shape::Shape;
-use crate::{Array, BitmaskV, FieldArray, MaskedArray, TextArray};
+use crate::{Array, BitmaskV, FieldArray, MaskedArray, TextArray, Vec64, Vec64Ext};
+#[cfg(feature = "cast_arrow")]
+use crate::{
+    Field,
+    ffi::{arrow_c_ffi::export_view_to_c, schema::Schema},
+};
+#[cfg(feature = "cast_arrow")]
+use arrow::array::ArrayRef;
 
 /// # ArrayView
 ///
@@ -249,6 +256,40 @@ impl ArrayV {
         self.array.get_scalar(self.offset + i)
     }
 
+    /// Iterates the view window as `Option<Scalar>`, yielding `None` for nulls.
+    ///
+    /// Respects the view's offset and length and the parent's null mask.
+    /// `FixedSizeBinary` has no `Scalar` form, so its values also yield `None`.
+    #[cfg(feature = "scalar_type")]
+    pub fn iter(&self) -> impl Iterator<Item = Option<crate::Scalar>> + '_ {
+        (0..self.len).map(move |i| self.get_scalar(i).filter(|s| !s.is_null()))
+    }
+
+    /// Copies the view window of a numeric array into an aligned `Vec64<T>`.
+    ///
+    /// `T` must match the variant's element type exactly; use
+    /// [`to_typed_vec`](ArrayV::to_typed_vec) to cast between numeric types.
+    /// Null slots keep whatever value sits underneath them.
+    pub fn to_vec64<T: Copy + 'static>(&self) -> Result<Vec64<T>, MinarrowError> {
+        use crate::{FloatArray, IntegerArray};
+
+        let range = self.offset..self.offset + self.len;
+        if let Some(arr) = self.array.inner_check::<IntegerArray<T>>() {
+            return Ok(Vec64::from_slice_copy(&arr.data.as_slice()[range]));
+        }
+        if let Some(arr) = self.array.inner_check::<FloatArray<T>>() {
+            return Ok(Vec64::from_slice_copy(&arr.data.as_slice()[range]));
+        }
+        Err(MinarrowError::TypeError {
+            from: crate::enums::array::array_category_name(&self.array),
+            to: std::any::type_name::<T>(),
+            message: Some(format!(
+                "ArrayV::to_vec64: element type does not match {:?}",
+                self.array.arrow_type()
+            )),
+        })
+    }
+
     /// Returns a new window view into a sub-range of this view.
     #[inline]
     pub fn slice(&self, offset: usize, len: usize) -> Self {
@@ -273,6 +314,40 @@ impl ArrayV {
         self.array.slice_clone(self.offset, self.len)
     }
 
+    /// Derive a `Field` from the backing array (via `Field::from_array`)
+    /// and call `to_apache_arrow_with_field`.
+    ///
+    /// For Timestamp/Time/Duration/Interval, prefer passing
+    /// an explicit `Field` via `to_apache_arrow_with_field`.
+    #[cfg(feature = "cast_arrow")]
+    #[inline]
+    pub fn to_apache_arrow(&self, name: &str) -> ArrayRef {
+        let derived = Field::from_array(name, &self.array, None);
+        self.to_apache_arrow_with_field(&derived)
+    }
+
+    /// Export this view via Arrow C FFI using `field` as the logical type;
+    /// then import into arrow-rs as `ArrayRef`.
+    ///
+    /// The window is carried by the Arrow `offset` and `length` fields, so the
+    /// parent buffers are shared rather than copied, unlike `to_array`.
+    #[cfg(feature = "cast_arrow")]
+    pub fn to_apache_arrow_with_field(&self, field: &Field) -> ArrayRef {
+        let schema = Schema::from(vec![field.clone()]);
+        let (c_arr, c_schema) = export_view_to_c(self, schema);
+
+        let arr_ptr = c_arr as *mut arrow::array::ffi::FFI_ArrowArray;
+        let sch_ptr = c_schema as *mut arrow::array::ffi::FFI_ArrowSchema;
+        let ffi_arr = unsafe { arr_ptr.read() };
+        let ffi_sch = unsafe { sch_ptr.read() };
+
+        let array_data = unsafe {
+            arrow::array::ffi::from_ffi(ffi_arr, &ffi_sch).expect("arrow-rs FFI import failed")
+        };
+
+        arrow::array::make_array(array_data)
+    }
+
     /// Extract array data as `Vec64<T>`, casting numeric values if necessary.
     ///
     /// - If array type matches T exactly, copies the slice directly
@@ -350,14 +425,25 @@ impl ArrayV {
     #[cfg(feature = "select")]
     pub fn gather_indices(&self, indices: &[usize]) -> Array {
         use crate::{
-            BooleanArray, CategoricalArray, FloatArray, IntegerArray, NumericArray, StringArray,
-            TextArray,
+            BooleanArray, CategoricalArray, FixedSizeBinaryArray, FloatArray, IntegerArray,
+            NumericArray, StringArray, TextArray,
         };
         #[cfg(feature = "datetime")]
         use crate::{DatetimeArray, TemporalArray};
 
         match &self.array {
             Array::Null => Array::Null,
+            Array::FixedSizeBinaryArray(arr) => {
+                let mut new_arr =
+                    FixedSizeBinaryArray::with_capacity(indices.len(), arr.width, true);
+                for &idx in indices {
+                    match self.get::<FixedSizeBinaryArray>(idx) {
+                        Some(val) => new_arr.push_value(val),
+                        None => new_arr.push_null(),
+                    }
+                }
+                Array::from_fixed_size_binary(new_arr)
+            }
             Array::NumericArray(num_arr) => match num_arr {
                 NumericArray::Int32(_) => {
                     let mut new_arr = IntegerArray::<i32>::with_capacity(indices.len(), true);
@@ -913,6 +999,41 @@ mod tests {
     use super::*;
     use crate::{Array, Bitmask, IntegerArray, NumericArray, vec64};
 
+    #[test]
+    fn test_array_view_to_vec64_copies_window() {
+        let mut arr = IntegerArray::<i64>::default();
+        for v in [10, 20, 30, 40, 50] {
+            arr.push(v);
+        }
+        arr.set_null(2);
+        let view = ArrayV::new(Array::from_int64(arr), 1, 3);
+
+        let v = view.to_vec64::<i64>().unwrap();
+        assert_eq!(v.as_slice(), &[20, 30, 40]);
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+
+        let err = view.to_vec64::<f64>().unwrap_err();
+        assert!(matches!(err, MinarrowError::TypeError { .. }));
+    }
+
+    #[cfg(feature = "scalar_type")]
+    #[test]
+    fn test_array_view_iter_respects_window_and_nulls() {
+        use crate::Scalar;
+
+        let mut arr = IntegerArray::<i32>::default();
+        for v in [1, 2, 3, 4] {
+            arr.push(v);
+        }
+        arr.set_null(2);
+        let view = ArrayV::new(Array::from_int32(arr), 1, 2);
+
+        let got: Vec<Option<Scalar>> = view.iter().collect();
+        assert_eq!(got.len(), 2);
+        assert!(matches!(got[0], Some(Scalar::Int32(2))));
+        assert!(got[1].is_none());
+    }
+
     #[test]
     fn test_array_view_basic_indexing_and_slice() {
         let mut arr = IntegerArray::<i32>::default();
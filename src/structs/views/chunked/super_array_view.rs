@@ -57,6 +57,7 @@ use crate::{
     structs::bitmask::Bitmask,
     structs::variants::boolean::BooleanArray,
     structs::variants::categorical::CategoricalArray,
+    structs::variants::fixed_size_binary::FixedSizeBinaryArray,
     traits::type_unions::Integer,
     traits::{concatenate::Concatenate, consolidate::Consolidate, shape::Shape},
 };
@@ -276,6 +277,7 @@ fn consolidate_slices_extend(slices: Vec<ArrayV>) -> Array {
         Array::NumericArray(num_arr) => consolidate_numeric_slices(&slices, num_arr),
         Array::TextArray(text_arr) => consolidate_text_slices(&slices, text_arr),
         Array::BooleanArray(_) => consolidate_boolean_slices(&slices),
+        Array::FixedSizeBinaryArray(_) => consolidate_fixed_size_binary_slices(&slices),
         #[cfg(feature = "datetime")]
         Array::TemporalArray(temp_arr) => consolidate_temporal_slices(&slices, temp_arr),
         Array::Null => Array::Null,
@@ -545,6 +547,28 @@ fn consolidate_boolean_slices(slices: &[ArrayV]) -> Array {
     Array::BooleanArray(Arc::new(result))
 }
 
+/// Consolidates fixed-size binary slices by appending each window's byte range.
+fn consolidate_fixed_size_binary_slices(slices: &[ArrayV]) -> Array {
+    use crate::traits::masked_array::MaskedArray;
+
+    let total_len: usize = slices.iter().map(|s| s.len()).sum();
+    let width = match &slices[0].array {
+        Array::FixedSizeBinaryArray(arr) => arr.width,
+        _ => 0,
+    };
+    let mut result = FixedSizeBinaryArray::with_capacity(total_len, width, false);
+
+    for slice in slices {
+        if let Array::FixedSizeBinaryArray(arr) = &slice.array {
+            result
+                .append_range(arr, slice.offset, slice.len())
+                .expect("FixedSizeBinaryArray slice out of bounds during consolidation");
+        }
+    }
+
+    Array::FixedSizeBinaryArray(Arc::new(result))
+}
+
 /// Consolidates numeric array slices by directly extending from raw data buffers.
 fn consolidate_numeric_slices(slices: &[ArrayV], first_num: &NumericArray) -> Array {
     match first_num {
@@ -68,9 +68,7 @@ use crate::ArrayV;
 use crate::enums::error::MinarrowError;
 use crate::enums::shape_dim::ShapeDim;
 use crate::traits::concatenate::Concatenate;
-use crate::traits::print::{
-    MAX_PREVIEW, print_ellipsis_row, print_header_row, print_rule, value_to_string,
-};
+use crate::traits::print::{FormatWith, PrintOptions, print_table, value_to_string_with};
 #[cfg(feature = "select")]
 use crate::traits::selection::{ColumnSelection, DataSelector, FieldSelector, RowSelection};
 use crate::traits::shape::Shape;
@@ -346,6 +344,39 @@ impl TableV {
         self.fields.iter().position(|f| f.name == name)
     }
 
+    /// Returns a view holding only the named columns, in the requested order,
+    /// over the same row window.
+    ///
+    /// Names resolve against the active columns. The result keeps just those
+    /// columns' `Arc`s, so no data is copied and the parent buffers stay
+    /// shared. Repeated names produce repeated columns.
+    ///
+    /// # Errors
+    /// - `IndexError` naming the first column that is not active in this view
+    pub fn select(&self, names: &[&str]) -> Result<TableV, MinarrowError> {
+        let mut fields = Vec::with_capacity(names.len());
+        let mut cols = Vec::with_capacity(names.len());
+        for name in names {
+            let raw = self
+                .col_name_index(name)
+                .and_then(|idx| self.resolve_col_index(idx))
+                .ok_or_else(|| {
+                    MinarrowError::IndexError(format!("TableV::select: column '{name}' not found"))
+                })?;
+            fields.push(self.fields[raw].clone());
+            cols.push(self.cols[raw].clone());
+        }
+        Ok(TableV {
+            name: self.name.clone(),
+            fields,
+            cols,
+            offset: self.offset,
+            len: self.len,
+            #[cfg(feature = "select")]
+            active_col_selection: None,
+        })
+    }
+
     /// Returns the window of the column at the given active index.
     #[inline]
     pub fn col_window(&self, idx: usize) -> Option<ArrayV> {
@@ -423,14 +454,25 @@ impl TableV {
     #[cfg(feature = "select")]
     fn gather_rows_from_window(&self, window: &ArrayV, row_indices: &[usize]) -> Option<Array> {
         use crate::{
-            Array, BooleanArray, CategoricalArray, FloatArray, IntegerArray, MaskedArray,
-            NumericArray, StringArray, TextArray,
+            Array, BooleanArray, CategoricalArray, FixedSizeBinaryArray, FloatArray, IntegerArray,
+            MaskedArray, NumericArray, StringArray, TextArray,
         };
         #[cfg(feature = "datetime")]
         use crate::{DatetimeArray, TemporalArray};
 
         let result = match &window.array {
             Array::Null => return None,
+            Array::FixedSizeBinaryArray(arr) => {
+                let mut new_arr =
+                    FixedSizeBinaryArray::with_capacity(row_indices.len(), arr.width, true);
+                for &idx in row_indices {
+                    match window.get::<FixedSizeBinaryArray>(idx) {
+                        Some(val) => new_arr.push_value(val),
+                        None => new_arr.push_null(),
+                    }
+                }
+                Array::from_fixed_size_binary(new_arr)
+            }
             Array::NumericArray(num_arr) => match num_arr {
                 NumericArray::Int32(_) => {
                     let mut new_arr = IntegerArray::<i32>::with_capacity(row_indices.len(), true);
@@ -812,6 +854,12 @@ impl TableV {
 
 impl Display for TableV {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, &PrintOptions::default())
+    }
+}
+
+impl FormatWith for TableV {
+    fn fmt_with(&self, f: &mut Formatter<'_>, options: &PrintOptions) -> std::fmt::Result {
         let n_rows = self.n_rows();
         let n_cols = self.n_cols();
 
@@ -819,80 +867,31 @@ impl Display for TableV {
             return writeln!(f, "TableView \"{}\" [0 rows × 0 cols] – empty", self.name);
         }
 
-        // Determine which rows to display
-        let row_indices: Vec<usize> = if n_rows <= MAX_PREVIEW {
-            (0..n_rows).collect()
-        } else {
-            let mut idx = (0..10).collect::<Vec<_>>();
-            idx.extend((n_rows - 10)..n_rows);
-            idx
-        };
-
-        // Build column headers and track widths
-        let mut headers: Vec<String> = Vec::with_capacity(n_cols);
-        let mut widths: Vec<usize> = Vec::with_capacity(n_cols);
-
-        for col_idx in 0..n_cols {
-            if let Some(_col_view) = self.cols.get(col_idx) {
-                let hdr = if let Some(f) = self.fields.get(col_idx) {
-                    format!("{}:{:?}", f.name, f.dtype)
-                } else {
-                    "unknown".to_string()
-                };
-                widths.push(hdr.len());
-                headers.push(hdr);
-            }
-        }
-
-        // Build matrix of cell strings
-        let mut rows: Vec<Vec<String>> = Vec::with_capacity(row_indices.len());
-
-        for &row_idx in &row_indices {
-            let mut row: Vec<String> = Vec::with_capacity(n_cols);
-
-            for col_idx in 0..n_cols {
-                if let Some(col_view) = self.cols.get(col_idx) {
-                    let val = value_to_string(&col_view.array, row_idx);
-                    widths[col_idx] = widths[col_idx].max(val.len());
-                    row.push(val);
-                } else {
-                    row.push("·".to_string());
-                }
-            }
-            rows.push(row);
-        }
-
-        // Calculate idx column width
-        let max_idx = n_rows.saturating_sub(1);
-        let idx_width = usize::max(
-            3, // "idx"
-            (max_idx as f64).log10().floor() as usize + 1,
-        );
-
-        // Render header
-        writeln!(
-            f,
+        let cols = self.active_col_indices();
+        let headers: Vec<String> = cols
+            .iter()
+            .map(|&i| match self.fields.get(i) {
+                Some(field) => format!("{}:{:?}", field.name, field.dtype),
+                None => "unknown".to_string(),
+            })
+            .collect();
+        let title = format!(
             "TableView \"{}\" [{} rows × {} cols]",
             self.name, n_rows, n_cols
-        )?;
-        print_rule(f, idx_width, &widths)?;
-        print_header_row(f, idx_width, &headers, &widths)?;
-        print_rule(f, idx_width, &widths)?;
-
-        // Render body
-        for (i, cells) in rows.iter().enumerate() {
-            let row_idx = row_indices[i];
-
-            write!(f, "| {idx:>w$} |", idx = row_idx, w = idx_width)?;
-            for (col_idx, cell) in cells.iter().enumerate() {
-                write!(f, " {val:^w$} |", val = cell, w = widths[col_idx])?;
-            }
-            writeln!(f)?;
-            if i == 9 && n_rows > MAX_PREVIEW {
-                print_ellipsis_row(f, idx_width, &widths)?;
-            }
-        }
-        print_rule(f, idx_width, &widths)
+        );
+        print_table(
+            f,
+            &title,
+            &headers,
+            n_rows,
+            |col, row| match self.cols.get(cols[col]) {
+                Some(view) => {
+                    value_to_string_with(&view.array, view.offset + row, options.float_precision)
+                }
+                None => "·".to_string(),
+            },
+            options,
+        )
     }
 }
 
@@ -1097,6 +1096,27 @@ mod tests {
         assert_eq!(slice.end(), 4);
     }
 
+    #[test]
+    fn test_table_view_display_uses_window() {
+        let mut tbl = Table::new_empty();
+        tbl.add_col(fa_i32!("a", 1, 2, 3, 4, 5));
+        tbl.add_col(fa_i32!("b", 10, 20, 30, 40, 50));
+
+        let view = TableV::from_table(tbl, 3, 2);
+        let out = view.to_string();
+        let rows: Vec<&str> = out.lines().skip(4).take(2).collect();
+        assert!(rows[0].contains(" 4 ") && rows[0].contains(" 40 "));
+        assert!(rows[1].contains(" 5 ") && rows[1].contains(" 50 "));
+
+        let options = PrintOptions {
+            max_cols: 1,
+            ..Default::default()
+        };
+        let narrow = view.display_with(&options).to_string();
+        assert!(narrow.contains("a:Int32") && !narrow.contains("b:Int32"));
+        assert!(narrow.contains(" … "));
+    }
+
     #[cfg(feature = "select")]
     #[test]
     fn test_table_view_selection_trait() {
@@ -1112,6 +1132,31 @@ mod tests {
         assert_eq!(slice.col("nonexistent").n_cols(), 0);
     }
 
+    #[test]
+    fn test_table_view_select_projects_columns() {
+        let mut tbl = Table::new_empty();
+        tbl.add_col(fa_i32!("a", 1, 2, 3, 4, 5));
+        tbl.add_col(fa_i32!("b", 10, 20, 30, 40, 50));
+        tbl.add_col(fa_i32!("c", 100, 200, 300, 400, 500));
+        let view = TableV::from_table(tbl, 1, 3);
+
+        let projected = view.select(&["c", "a"]).unwrap();
+        assert_eq!(projected.col_names(), vec!["c", "a"]);
+        assert_eq!((projected.offset, projected.len), (1, 3));
+        assert_eq!(projected.cols[0], view.cols[2]);
+        // Same allocation, not just equal values.
+        let parent = view.cols[0].array.num_ref().unwrap().i32_ref().unwrap();
+        let child = projected.cols[1].array.num_ref().unwrap();
+        assert!(std::ptr::eq(parent, child.i32_ref().unwrap()));
+
+        let table = projected.to_table();
+        assert_eq!(table.n_rows, 3);
+        assert_eq!(table.col_names(), vec!["c", "a"]);
+
+        let err = view.select(&["a", "missing"]).unwrap_err();
+        assert!(matches!(err, MinarrowError::IndexError(ref m) if m.contains("missing")));
+    }
+
     #[test]
     fn test_table_slice_empty() {
         let tbl = Table::new_empty();
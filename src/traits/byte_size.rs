@@ -29,7 +29,22 @@
 //! let arr = IntegerArray::<i64>::from_slice(&[1, 2, 3, 4, 5]);
 //! let bytes = arr.est_bytes();
 //! // Returns data buffer size: 5 * 8 = 40 bytes (plus small overhead)
+//! assert_eq!(arr.shared_bytes(), 0);
+//! assert_eq!(arr.owned_bytes(), bytes);
 //! ```
+//!
+//! ## Owned vs shared
+//! `est_bytes` counts every buffer an object can read. `shared_bytes` is the
+//! part of that held in a `SharedBuffer` another object also references, such
+//! as many views over one mmap, and `owned_bytes` is the remainder. Summing
+//! `owned_bytes` across a pipeline's objects therefore counts each shared
+//! allocation zero times; track those allocations once at their source.
+//!
+//! Static buffers (`SharedBuffer::from_static`) are never freed, so they are
+//! always reported as shared and contribute zero owned bytes.
+//!
+//! Sharing is detected per buffer. Two tables holding clones of the same
+//! `Arc<Array>` each report its buffers as owned.
 
 use std::mem::size_of;
 
@@ -53,6 +68,21 @@ pub trait ByteSize {
     /// - Stack size of the struct itself (only heap allocations)
     /// - Arc pointer overhead (counted once per allocation, not per reference)
     fn est_bytes(&self) -> usize;
+
+    /// Returns the part of `est_bytes` held in shared allocations.
+    ///
+    /// A buffer counts as shared when it views a `SharedBuffer` with other
+    /// live references, or one backed by static memory. Defaults to `0` for
+    /// types that never hold shared buffers.
+    fn shared_bytes(&self) -> usize {
+        0
+    }
+
+    /// Returns the bytes attributable to this object alone,
+    /// `est_bytes() - shared_bytes()`.
+    fn owned_bytes(&self) -> usize {
+        self.est_bytes().saturating_sub(self.shared_bytes())
+    }
 }
 
 // Base Buffer Type Implementations
@@ -75,6 +105,15 @@ impl<T> ByteSize for Buffer<T> {
         // Capacity in elements * size per element
         self.capacity() * size_of::<T>()
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        match self.shared_parts() {
+            // A unique owner is freed with this buffer, so it counts as owned.
+            Some((owner, _, _)) if owner.is_static() || !owner.is_unique() => self.est_bytes(),
+            _ => 0,
+        }
+    }
 }
 
 /// ByteSize for Bitmask - bit-packed bitmask
@@ -84,11 +123,18 @@ impl ByteSize for Bitmask {
         // Bit-packed: (capacity + 7) / 8 bytes
         self.bits.est_bytes()
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        self.bits.shared_bytes()
+    }
 }
 
 // Concrete Array Type Implementations
 
-use crate::{BooleanArray, CategoricalArray, FloatArray, IntegerArray, StringArray};
+use crate::{
+    BooleanArray, CategoricalArray, FixedSizeBinaryArray, FloatArray, IntegerArray, StringArray,
+};
 
 /// ByteSize for IntegerArray<T>
 impl<T> ByteSize for IntegerArray<T> {
@@ -98,6 +144,12 @@ impl<T> ByteSize for IntegerArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
 }
 
 /// ByteSize for FloatArray<T>
@@ -108,6 +160,12 @@ impl<T> ByteSize for FloatArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
 }
 
 /// ByteSize for StringArray<T>
@@ -119,6 +177,12 @@ impl<T> ByteSize for StringArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + offsets_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + self.offsets.shared_bytes() + mask_bytes
+    }
 }
 
 /// ByteSize for CategoricalArray<T>
@@ -130,6 +194,13 @@ impl<T: crate::traits::type_unions::Integer> ByteSize for CategoricalArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + unique_values_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        // Dictionary values are owned `String`s.
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
 }
 
 /// ByteSize for BooleanArray<T>
@@ -140,6 +211,28 @@ impl<T> ByteSize for BooleanArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
+}
+
+/// ByteSize for FixedSizeBinaryArray
+impl ByteSize for FixedSizeBinaryArray {
+    #[inline]
+    fn est_bytes(&self) -> usize {
+        let data_bytes = self.data.est_bytes();
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
+        data_bytes + mask_bytes
+    }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
 }
 
 /// ByteSize for DatetimeArray<T> (when datetime feature is enabled)
@@ -154,52 +247,76 @@ impl<T> ByteSize for DatetimeArray<T> {
         let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.est_bytes());
         data_bytes + mask_bytes
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        let mask_bytes = self.null_mask.as_ref().map_or(0, |m| m.shared_bytes());
+        self.data.shared_bytes() + mask_bytes
+    }
 }
 
 // Mid-Level Enum Implementations
 
 use crate::{NumericArray, TextArray};
 
+/// The concrete array behind a `NumericArray`, or `None` for `Null`.
+fn numeric_inner(arr: &NumericArray) -> Option<&dyn ByteSize> {
+    match arr {
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int8(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::Int16(arr) => Some(arr.as_ref()),
+        NumericArray::Int32(arr) => Some(arr.as_ref()),
+        NumericArray::Int64(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt8(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "extended_numeric_types")]
+        NumericArray::UInt16(arr) => Some(arr.as_ref()),
+        NumericArray::UInt32(arr) => Some(arr.as_ref()),
+        NumericArray::UInt64(arr) => Some(arr.as_ref()),
+        NumericArray::Float32(arr) => Some(arr.as_ref()),
+        NumericArray::Float64(arr) => Some(arr.as_ref()),
+        NumericArray::Null => None,
+    }
+}
+
 /// ByteSize for NumericArray enum
 impl ByteSize for NumericArray {
     fn est_bytes(&self) -> usize {
-        match self {
-            #[cfg(feature = "extended_numeric_types")]
-            NumericArray::Int8(arr) => arr.est_bytes(),
-            #[cfg(feature = "extended_numeric_types")]
-            NumericArray::Int16(arr) => arr.est_bytes(),
-            NumericArray::Int32(arr) => arr.est_bytes(),
-            NumericArray::Int64(arr) => arr.est_bytes(),
-            #[cfg(feature = "extended_numeric_types")]
-            NumericArray::UInt8(arr) => arr.est_bytes(),
-            #[cfg(feature = "extended_numeric_types")]
-            NumericArray::UInt16(arr) => arr.est_bytes(),
-            NumericArray::UInt32(arr) => arr.est_bytes(),
-            NumericArray::UInt64(arr) => arr.est_bytes(),
-            NumericArray::Float32(arr) => arr.est_bytes(),
-            NumericArray::Float64(arr) => arr.est_bytes(),
-            NumericArray::Null => 0,
-        }
+        numeric_inner(self).map_or(0, |arr| arr.est_bytes())
+    }
+
+    fn shared_bytes(&self) -> usize {
+        numeric_inner(self).map_or(0, |arr| arr.shared_bytes())
+    }
+}
+
+/// The concrete array behind a `TextArray`, or `None` for `Null`.
+fn text_inner(arr: &TextArray) -> Option<&dyn ByteSize> {
+    match arr {
+        TextArray::String32(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "large_string")]
+        TextArray::String64(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "default_categorical_8")]
+        TextArray::Categorical8(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "extended_categorical")]
+        TextArray::Categorical16(arr) => Some(arr.as_ref()),
+        #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
+        TextArray::Categorical32(arr) => Some(arr.as_ref()),
+        #[cfg(feature = "extended_categorical")]
+        TextArray::Categorical64(arr) => Some(arr.as_ref()),
+        TextArray::Null => None,
     }
 }
 
 /// ByteSize for TextArray enum
 impl ByteSize for TextArray {
     fn est_bytes(&self) -> usize {
-        match self {
-            TextArray::String32(arr) => arr.est_bytes(),
-            #[cfg(feature = "large_string")]
-            TextArray::String64(arr) => arr.est_bytes(),
-            #[cfg(feature = "default_categorical_8")]
-            TextArray::Categorical8(arr) => arr.est_bytes(),
-            #[cfg(feature = "extended_categorical")]
-            TextArray::Categorical16(arr) => arr.est_bytes(),
-            #[cfg(any(not(feature = "default_categorical_8"), feature = "extended_categorical"))]
-            TextArray::Categorical32(arr) => arr.est_bytes(),
-            #[cfg(feature = "extended_categorical")]
-            TextArray::Categorical64(arr) => arr.est_bytes(),
-            TextArray::Null => 0,
-        }
+        text_inner(self).map_or(0, |arr| arr.est_bytes())
+    }
+
+    fn shared_bytes(&self) -> usize {
+        text_inner(self).map_or(0, |arr| arr.shared_bytes())
     }
 }
 
@@ -216,6 +333,14 @@ impl ByteSize for TemporalArray {
             TemporalArray::Null => 0,
         }
     }
+
+    fn shared_bytes(&self) -> usize {
+        match self {
+            TemporalArray::Datetime32(arr) => arr.shared_bytes(),
+            TemporalArray::Datetime64(arr) => arr.shared_bytes(),
+            TemporalArray::Null => 0,
+        }
+    }
 }
 
 // Top-Level Array Enum Implementation
@@ -231,6 +356,19 @@ impl ByteSize for Array {
             #[cfg(feature = "datetime")]
             Array::TemporalArray(arr) => arr.est_bytes(),
             Array::BooleanArray(arr) => arr.est_bytes(),
+            Array::FixedSizeBinaryArray(arr) => arr.est_bytes(),
+            Array::Null => 0,
+        }
+    }
+
+    fn shared_bytes(&self) -> usize {
+        match self {
+            Array::NumericArray(arr) => arr.shared_bytes(),
+            Array::TextArray(arr) => arr.shared_bytes(),
+            #[cfg(feature = "datetime")]
+            Array::TemporalArray(arr) => arr.shared_bytes(),
+            Array::BooleanArray(arr) => arr.shared_bytes(),
+            Array::FixedSizeBinaryArray(arr) => arr.shared_bytes(),
             Array::Null => 0,
         }
     }
@@ -256,6 +394,11 @@ impl ByteSize for FieldArray {
     fn est_bytes(&self) -> usize {
         self.field.est_bytes() + self.array.est_bytes()
     }
+
+    #[inline]
+    fn shared_bytes(&self) -> usize {
+        self.array.shared_bytes()
+    }
 }
 
 /// ByteSize for Table - sum of all column arrays
@@ -263,6 +406,10 @@ impl ByteSize for Table {
     fn est_bytes(&self) -> usize {
         self.cols.iter().map(|col| col.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.cols.iter().map(|col| col.shared_bytes()).sum()
+    }
 }
 
 // View Type Implementations
@@ -282,6 +429,12 @@ impl ByteSize for ArrayV {
             0
         }
     }
+
+    fn shared_bytes(&self) -> usize {
+        (self.array.shared_bytes() * self.len())
+            .checked_div(self.array.len())
+            .unwrap_or(0)
+    }
 }
 
 /// ByteSize for TableV - sum of column view estimates
@@ -290,6 +443,10 @@ impl ByteSize for TableV {
     fn est_bytes(&self) -> usize {
         self.cols.iter().map(|col| col.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.cols.iter().map(|col| col.shared_bytes()).sum()
+    }
 }
 
 #[cfg(all(feature = "chunked", feature = "views"))]
@@ -301,6 +458,10 @@ impl ByteSize for SuperArrayV {
     fn est_bytes(&self) -> usize {
         self.slices.iter().map(|slice| slice.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.slices.iter().map(|slice| slice.shared_bytes()).sum()
+    }
 }
 
 /// ByteSize for SuperTableV - sum of slice estimates
@@ -309,6 +470,10 @@ impl ByteSize for SuperTableV {
     fn est_bytes(&self) -> usize {
         self.slices.iter().map(|slice| slice.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.slices.iter().map(|slice| slice.shared_bytes()).sum()
+    }
 }
 
 /// ByteSize for Matrix (when matrix feature is enabled)
@@ -321,6 +486,10 @@ impl ByteSize for Matrix {
         // Matrix contains data buffer for n_rows * n_cols elements
         self.data.est_bytes()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.data.shared_bytes()
+    }
 }
 
 /// ByteSize for Cube (when cube feature is enabled)
@@ -333,6 +502,10 @@ impl ByteSize for Cube {
         // Cube contains multiple tables
         self.tables.iter().map(|tbl| tbl.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.tables.iter().map(|tbl| tbl.shared_bytes()).sum()
+    }
 }
 
 /// ByteSize for SuperArray (when chunked feature is enabled)
@@ -345,6 +518,10 @@ impl ByteSize for SuperArray {
         // Sum of all chunk arrays
         self.chunks().iter().map(|chunk| chunk.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.chunks().iter().map(|chunk| chunk.shared_bytes()).sum()
+    }
 }
 
 /// ByteSize for SuperTable (when chunked feature is enabled)
@@ -357,6 +534,10 @@ impl ByteSize for SuperTable {
         // Sum of all batch tables
         self.batches.iter().map(|batch| batch.est_bytes()).sum()
     }
+
+    fn shared_bytes(&self) -> usize {
+        self.batches.iter().map(|batch| batch.shared_bytes()).sum()
+    }
 }
 
 // Value Enum Implementation
@@ -447,4 +628,127 @@ impl ByteSize for Value {
             }
         }
     }
+
+    fn shared_bytes(&self) -> usize {
+        match self {
+            #[cfg(feature = "scalar_type")]
+            Value::Scalar(_) => 0,
+            Value::Array(arr) => arr.shared_bytes(),
+            #[cfg(feature = "views")]
+            Value::ArrayView(av) => av.shared_bytes(),
+            Value::Table(tbl) => tbl.shared_bytes(),
+            #[cfg(feature = "views")]
+            Value::TableView(tv) => tv.shared_bytes(),
+            #[cfg(feature = "chunked")]
+            Value::SuperArray(sa) => sa.shared_bytes(),
+            #[cfg(all(feature = "chunked", feature = "views"))]
+            Value::SuperArrayView(sav) => sav.shared_bytes(),
+            #[cfg(feature = "chunked")]
+            Value::SuperTable(st) => st.shared_bytes(),
+            #[cfg(all(feature = "chunked", feature = "views"))]
+            Value::SuperTableView(stv) => stv.shared_bytes(),
+            Value::FieldArray(fa) => fa.shared_bytes(),
+            #[cfg(feature = "matrix")]
+            Value::Matrix(m) => m.shared_bytes(),
+            #[cfg(feature = "cube")]
+            Value::Cube(c) => c.shared_bytes(),
+            Value::VecValue(vec) => vec.iter().map(|v| v.shared_bytes()).sum(),
+            Value::BoxValue(boxed) => boxed.shared_bytes(),
+            Value::ArcValue(arc) => arc.shared_bytes(),
+            Value::Tuple2(tuple) => tuple.0.shared_bytes() + tuple.1.shared_bytes(),
+            Value::Tuple3(tuple) => {
+                tuple.0.shared_bytes() + tuple.1.shared_bytes() + tuple.2.shared_bytes()
+            }
+            Value::Tuple4(tuple) => {
+                tuple.0.shared_bytes()
+                    + tuple.1.shared_bytes()
+                    + tuple.2.shared_bytes()
+                    + tuple.3.shared_bytes()
+            }
+            Value::Tuple5(tuple) => {
+                tuple.0.shared_bytes()
+                    + tuple.1.shared_bytes()
+                    + tuple.2.shared_bytes()
+                    + tuple.3.shared_bytes()
+                    + tuple.4.shared_bytes()
+            }
+            Value::Tuple6(tuple) => {
+                tuple.0.shared_bytes()
+                    + tuple.1.shared_bytes()
+                    + tuple.2.shared_bytes()
+                    + tuple.3.shared_bytes()
+                    + tuple.4.shared_bytes()
+                    + tuple.5.shared_bytes()
+            }
+            Value::Custom(_) => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SharedBuffer, Vec64Ext};
+
+    /// A `Buffer<i64>` viewing the whole of `owner`.
+    fn view(owner: &SharedBuffer, len: usize) -> Buffer<i64> {
+        Buffer::from_shared_column(owner.clone(), 0, len)
+    }
+
+    fn shared_i64s(values: &[i64]) -> SharedBuffer {
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        SharedBuffer::from_vec64(Vec64::from_slice_copy(&bytes))
+    }
+
+    #[test]
+    fn owned_buffers_have_no_shared_bytes() {
+        let arr = IntegerArray::<i64>::from_slice(&[1, 2, 3, 4]);
+        assert_eq!(arr.shared_bytes(), 0);
+        assert_eq!(arr.owned_bytes(), arr.est_bytes());
+    }
+
+    #[test]
+    fn buffer_is_shared_only_while_another_reference_lives() {
+        let owner = shared_i64s(&[1, 2, 3, 4]);
+        let a = view(&owner, 4);
+        let b = view(&owner, 4);
+        drop(owner);
+        assert_eq!(a.est_bytes(), 32);
+        assert_eq!(a.shared_bytes(), 32);
+        assert_eq!(a.owned_bytes(), 0);
+
+        // Once `b` goes, `a` is the sole owner and is freed with it.
+        drop(b);
+        assert_eq!(a.shared_bytes(), 0);
+        assert_eq!(a.owned_bytes(), 32);
+    }
+
+    #[test]
+    fn static_buffers_count_zero_owned() {
+        static BYTES: [u8; 16] = [0; 16];
+        let buf: Buffer<u8> = Buffer::from_shared_column(SharedBuffer::from_static(&BYTES), 0, 16);
+        assert_eq!(buf.est_bytes(), 16);
+        assert_eq!(buf.shared_bytes(), 16);
+        assert_eq!(buf.owned_bytes(), 0);
+    }
+
+    #[test]
+    fn table_splits_owned_and_shared_per_column() {
+        let owner = shared_i64s(&[1, 2, 3]);
+        let shared_col = IntegerArray::new(view(&owner, 3), None);
+        let owned_col = IntegerArray::<i64>::from_slice(&[4, 5, 6]);
+        let table = Table::new(
+            "t".into(),
+            Some(vec![
+                FieldArray::from_arr("shared", Array::from_int64(shared_col)),
+                FieldArray::from_arr("owned", Array::from_int64(owned_col.clone())),
+            ]),
+        );
+        assert_eq!(table.shared_bytes(), 24);
+        assert_eq!(table.owned_bytes(), table.est_bytes() - 24);
+        assert_eq!(
+            table.est_bytes(),
+            24 + owned_col.est_bytes() + "shared".len() + "owned".len()
+        );
+    }
 }
@@ -113,6 +113,44 @@ pub trait MaskedArray {
         len: usize,
     ) -> impl Iterator<Item = Option<Self::CopyType>> + '_;
 
+    /// Returns an iterator over the positions of the non-null values.
+    ///
+    /// Without a null mask this yields `0..len`. With one, the mask is walked a
+    /// byte at a time and only set bits are visited, so null runs are skipped cheaply.
+    fn indices_valid(&self) -> impl Iterator<Item = usize> + '_ {
+        let len = self.len();
+        let mask = self.null_mask().map(|m| m.as_bytes());
+        (0..len.div_ceil(8))
+            .flat_map(move |byte_i| {
+                let mut bits = mask.map_or(u8::MAX, |m| m[byte_i]);
+                let base = byte_i * 8;
+                std::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let idx = base + bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(idx)
+                })
+            })
+            .take_while(move |&i| i < len)
+    }
+
+    /// Returns an iterator over the non-null values only, without the `Option` wrapper.
+    ///
+    /// Useful for reductions, or for passing values to code that has no notion of nulls.
+    fn iter_valid(&self) -> impl Iterator<Item = Self::CopyType> + '_ {
+        let mut values = self.iter();
+        let mut next = 0;
+        self.indices_valid().map(move |i| {
+            let v = values
+                .nth(i - next)
+                .expect("valid index within array length");
+            next = i + 1;
+            v
+        })
+    }
+
     /// Appends a value to the array, updating masks if present.
     fn push(&mut self, value: Self::LogicalType);
 
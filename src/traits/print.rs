@@ -25,6 +25,9 @@ use crate::{DatetimeArray, Integer, TemporalArray};
 
 pub(crate) const MAX_PREVIEW: usize = 50;
 
+/// Decimal places kept by `format_float`.
+const DEFAULT_FLOAT_PRECISION: usize = 6;
+
 /// # Print
 ///
 /// Loaded print trait for pretty printing tables
@@ -40,13 +43,119 @@ pub trait Print {
     {
         println!("{}", self);
     }
+
+    /// Prints with explicit row, column, precision and cell width limits.
+    ///
+    /// ```rust
+    /// use minarrow::{Print, PrintOptions, Table, fa_i32};
+    ///
+    /// let mut table = Table::new_empty();
+    /// table.add_col(fa_i32!("id", 1, 2, 3));
+    /// table.print_with(PrintOptions {
+    ///     max_cols: 6,
+    ///     max_cell_width: 12,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    #[inline]
+    fn print_with(&self, options: PrintOptions)
+    where
+        Self: FormatWith,
+    {
+        println!("{}", self.display_with(&options));
+    }
+
+    /// Returns the text `print` writes as a `String` instead of printing it,
+    /// e.g. for logs or error messages.
+    #[inline]
+    fn to_pretty_string(&self) -> String
+    where
+        Self: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the text `print_with` writes for `options` as a `String`.
+    #[inline]
+    fn to_pretty_string_with(&self, options: PrintOptions) -> String
+    where
+        Self: FormatWith,
+    {
+        self.display_with(&options).to_string()
+    }
 }
 
 impl<T: Display> Print for T where T: Display {}
 
+/// Options for `Print::print_with`.
+///
+/// The defaults reproduce the `Display` output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintOptions {
+    /// Tables with more rows than this are truncated to `min_rows`.
+    pub max_rows: usize,
+    /// Rows shown once truncated, split between the head and the tail
+    /// with a `…` row between them. Capped at `max_rows`.
+    pub min_rows: usize,
+    /// Tables with more columns than this show only the first and last
+    /// columns, `max_cols` in total, with a `…` column between them.
+    pub max_cols: usize,
+    /// Maximum decimal places for floats. Trailing zeros are trimmed.
+    pub float_precision: usize,
+    /// Maximum characters per cell, headers included. Longer values are cut
+    /// short and end in `…`.
+    pub max_cell_width: usize,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: MAX_PREVIEW,
+            min_rows: 20,
+            max_cols: usize::MAX,
+            float_precision: DEFAULT_FLOAT_PRECISION,
+            max_cell_width: usize::MAX,
+        }
+    }
+}
+
+/// Formatting under `PrintOptions`, for types that support truncated previews.
+pub trait FormatWith {
+    /// Writes the preview to `f`, limited by `options`.
+    fn fmt_with(&self, f: &mut Formatter<'_>, options: &PrintOptions) -> fmt::Result;
+
+    /// Wraps `self` so that `Display` renders it with `options`,
+    /// e.g. for `to_string()` or `format!`.
+    #[inline]
+    fn display_with<'a>(&'a self, options: &'a PrintOptions) -> DisplayWith<'a, Self> {
+        DisplayWith {
+            value: self,
+            options,
+        }
+    }
+}
+
+/// `Display` adapter returned by `FormatWith::display_with`.
+pub struct DisplayWith<'a, T: ?Sized> {
+    value: &'a T,
+    options: &'a PrintOptions,
+}
+
+impl<T: FormatWith + ?Sized> Display for DisplayWith<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.value.fmt_with(f, self.options)
+    }
+}
+
 // Helper functions
 
 pub(crate) fn value_to_string(arr: &Array, idx: usize) -> String {
+    value_to_string_with(arr, idx, DEFAULT_FLOAT_PRECISION)
+}
+
+/// `value_to_string` with floats kept to at most `float_precision` decimals.
+pub(crate) fn value_to_string_with(arr: &Array, idx: usize, float_precision: usize) -> String {
     // Null checks (handles absent mask too)
     if let Some(mask) = arr.null_mask() {
         if !mask.get(idx) {
@@ -68,8 +177,8 @@ pub(crate) fn value_to_string(arr: &Array, idx: usize) -> String {
             NumericArray::UInt8(a) => a.data[idx].to_string(),
             #[cfg(feature = "extended_numeric_types")]
             NumericArray::UInt16(a) => a.data[idx].to_string(),
-            NumericArray::Float32(a) => format_float(a.data[idx] as f64),
-            NumericArray::Float64(a) => format_float(a.data[idx]),
+            NumericArray::Float32(a) => format_float_with(a.data[idx] as f64, float_precision),
+            NumericArray::Float64(a) => format_float_with(a.data[idx], float_precision),
             NumericArray::Null => "null".into(),
         },
         // ------------------------- boolean ------------------------------
@@ -77,6 +186,16 @@ pub(crate) fn value_to_string(arr: &Array, idx: usize) -> String {
             let bit = b.data.get(idx);
             bit.to_string()
         }
+        // ------------------------- fixed-size binary --------------------
+        Array::FixedSizeBinaryArray(b) => {
+            let bytes = &b.data[idx * b.width..(idx + 1) * b.width];
+            let mut s = String::with_capacity(2 + bytes.len() * 2);
+            s.push_str("0x");
+            for byte in bytes {
+                s.push_str(&format!("{:02x}", byte));
+            }
+            s
+        }
         // ------------------------- string / categorical -----------------
         Array::TextArray(inner) => match inner {
             TextArray::String32(s) => string_value(&s.offsets, &s.data, idx),
@@ -167,12 +286,117 @@ pub(crate) fn print_ellipsis_row(
     writeln!(f)
 }
 
+/// Renders a bordered table preview under `options`, shared by `Table` and `TableV`.
+///
+/// `cell(col, row)` returns the display string for a cell. Rows and columns
+/// beyond the limits are replaced by a single `…` row or column, and every
+/// header and cell is cut to `max_cell_width` before the widths are measured,
+/// so the columns stay aligned after truncation.
+pub(crate) fn print_table(
+    f: &mut Formatter<'_>,
+    title: &str,
+    headers: &[String],
+    n_rows: usize,
+    cell: impl Fn(usize, usize) -> String,
+    options: &PrintOptions,
+) -> fmt::Result {
+    let rows = preview_indices(
+        n_rows,
+        options.max_rows,
+        options.min_rows.min(options.max_rows),
+    );
+    let cols = preview_indices(headers.len(), options.max_cols, options.max_cols);
+
+    // Per displayed column: header, cells and width. `None` is the `…` column.
+    let mut shown_headers = Vec::with_capacity(cols.len());
+    let mut columns: Vec<Vec<String>> = Vec::with_capacity(cols.len());
+    let mut widths = Vec::with_capacity(cols.len());
+    for &col in &cols {
+        let (hdr, cells) = match col {
+            Some(c) => (
+                truncate_cell(headers[c].clone(), options.max_cell_width),
+                rows.iter()
+                    .map(|&r| {
+                        r.map_or_else(String::new, |r| {
+                            truncate_cell(cell(c, r), options.max_cell_width)
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            None => (
+                "…".to_string(),
+                rows.iter().map(|_| "…".to_string()).collect(),
+            ),
+        };
+        let width = cells
+            .iter()
+            .map(|v| v.chars().count())
+            .fold(hdr.chars().count(), usize::max);
+        shown_headers.push(hdr);
+        columns.push(cells);
+        widths.push(width);
+    }
+
+    // row-index column ("idx")
+    let idx_width = usize::max(
+        3, // "idx"
+        (n_rows.saturating_sub(1) as f64).log10().floor() as usize + 1,
+    );
+
+    writeln!(f, "{}", title)?;
+    print_rule(f, idx_width, &widths)?;
+    print_header_row(f, idx_width, &shown_headers, &widths)?;
+    print_rule(f, idx_width, &widths)?;
+    for (i, row) in rows.iter().enumerate() {
+        let Some(row) = row else {
+            print_ellipsis_row(f, idx_width, &widths)?;
+            continue;
+        };
+        write!(f, "| {idx:>w$} |", idx = row, w = idx_width)?;
+        for (cells, &w) in columns.iter().zip(&widths) {
+            write!(f, " {val:^w$} |", val = cells[i], w = w)?;
+        }
+        writeln!(f)?;
+    }
+    print_rule(f, idx_width, &widths)
+}
+
+/// Indices to preview out of `n`. When `n` exceeds `max`, keeps the first and
+/// last of `keep` in total, with `None` marking the gap between them.
+fn preview_indices(n: usize, max: usize, keep: usize) -> Vec<Option<usize>> {
+    if n <= max {
+        return (0..n).map(Some).collect();
+    }
+    let head = keep.div_ceil(2);
+    let tail = keep / 2;
+    let mut out: Vec<Option<usize>> = (0..head).map(Some).collect();
+    out.push(None);
+    out.extend((n - tail..n).map(Some));
+    out
+}
+
+/// Cuts `s` to at most `max` characters, ending in `…` when shortened.
+fn truncate_cell(s: String, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s;
+    }
+    let mut out: String = s.chars().take(max.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
 /// Formats floating point numbers:
 /// - Keeps up to 6 decimal digits
 /// - Trims trailing zeroes and unnecessary decimal point
 #[inline]
 pub(crate) fn format_float<T: Float + Display>(v: T) -> String {
-    let s = format!("{:.6}", v);
+    format_float_with(v, DEFAULT_FLOAT_PRECISION)
+}
+
+/// `format_float` keeping up to `precision` decimal digits.
+#[inline]
+pub(crate) fn format_float_with<T: Float + Display>(v: T, precision: usize) -> String {
+    let s = format!("{:.*}", precision, v);
     if s.contains('.') {
         s.trim_end_matches('0').trim_end_matches('.').to_string()
     } else {
@@ -0,0 +1,180 @@
+// Copyright 2025 Peter Garfield Bower
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Vec64Ext Trait Module
+//!
+//! Construction fast paths for `Vec64`, which lives in the `vec64` crate.
+//!
+//! `Vec64`'s own `FromIterator` and `Extend` impls are defined there, so they cannot
+//! be specialised here. These methods cover the hot construction cases instead:
+//! a single aligned allocation sized up front, then filled without reallocating.
+//!
+//! ## Example
+//! ```rust
+//! use minarrow::{Vec64, Vec64Ext};
+//!
+//! let a = Vec64::from_slice_copy(&[1i64, 2, 3]);
+//! let b = Vec64::from_exact_iter((0..3).map(|x| x as i64 + 1));
+//! assert_eq!(a, b);
+//! assert_eq!(a.as_ptr() as usize % 64, 0);
+//! ```
+
+use crate::Vec64;
+
+/// Single-allocation constructors for `Vec64`.
+pub trait Vec64Ext<T> {
+    /// Copies a slice of `Copy` values with one aligned allocation and a `memcpy`.
+    fn from_slice_copy(s: &[T]) -> Self
+    where
+        T: Copy;
+
+    /// Collects an exact-size iterator with one aligned allocation.
+    ///
+    /// Capacity is taken from the iterator's reported length, so an honest
+    /// `ExactSizeIterator` never reallocates. One that yields more is still
+    /// collected in full, growing as `Vec64` normally would.
+    fn from_exact_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator;
+
+    /// Extends with an exact-size iterator, reserving its full length first.
+    fn extend_exact<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator;
+}
+
+impl<T> Vec64Ext<T> for Vec64<T> {
+    #[inline]
+    fn from_slice_copy(s: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        let mut v = Vec64::with_capacity(s.len());
+        // Safety: `v` has capacity for `s.len()` values, the regions cannot
+        // overlap, and `T: Copy` means a bitwise copy is a valid clone.
+        unsafe {
+            std::ptr::copy_nonoverlapping(s.as_ptr(), v.as_mut_ptr(), s.len());
+            v.set_len(s.len());
+        }
+        v
+    }
+
+    #[inline]
+    fn from_exact_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let mut v = Vec64::with_capacity(iter.len());
+        v.extend(iter);
+        v
+    }
+
+    #[inline]
+    fn extend_exact<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.len());
+        self.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Yields `items` while reporting `hint` as its exact length.
+    struct MisreportedLen {
+        items: std::vec::IntoIter<i32>,
+        hint: usize,
+    }
+
+    impl Iterator for MisreportedLen {
+        type Item = i32;
+
+        fn next(&mut self) -> Option<i32> {
+            self.items.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.hint, Some(self.hint))
+        }
+    }
+
+    impl ExactSizeIterator for MisreportedLen {}
+
+    fn misreported(items: Vec<i32>, hint: usize) -> MisreportedLen {
+        MisreportedLen {
+            items: items.into_iter(),
+            hint,
+        }
+    }
+
+    #[test]
+    fn test_from_slice_copy() {
+        let v = Vec64::from_slice_copy(&[1u8, 2, 3]);
+        assert_eq!(v.as_slice(), &[1, 2, 3]);
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+
+        let empty = Vec64::<u8>::from_slice_copy(&[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_from_exact_iter_empty() {
+        let v = Vec64::<i32>::from_exact_iter(std::iter::empty());
+        assert!(v.is_empty());
+        assert_eq!(v.capacity(), 0);
+    }
+
+    #[test]
+    fn test_from_exact_iter_preallocates() {
+        let v = Vec64::from_exact_iter(0..100);
+        assert_eq!(v.len(), 100);
+        assert!(v.capacity() >= 100);
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+    }
+
+    #[test]
+    fn test_from_exact_iter_with_wrong_length_hint() {
+        // Under-reporting still collects everything, growing as needed
+        let short = Vec64::from_exact_iter(misreported(vec![1, 2, 3, 4, 5], 2));
+        assert_eq!(short.as_slice(), &[1, 2, 3, 4, 5]);
+
+        // Over-reporting only leaves spare capacity
+        let long = Vec64::from_exact_iter(misreported(vec![1, 2], 10));
+        assert_eq!(long.as_slice(), &[1, 2]);
+        assert!(long.capacity() >= 10);
+
+        let none = Vec64::from_exact_iter(misreported(vec![], 4));
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_extend_exact() {
+        let mut v = Vec64::from_slice_copy(&[1, 2]);
+        v.extend_exact(3..5);
+        assert_eq!(v.as_slice(), &[1, 2, 3, 4]);
+
+        v.extend_exact(misreported(vec![5, 6, 7], 1));
+        v.extend_exact(misreported(vec![], 3));
+        assert_eq!(v.as_slice(), &[1, 2, 3, 4, 5, 6, 7]);
+    }
+}
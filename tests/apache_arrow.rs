@@ -202,3 +202,131 @@ fn test_table_to_arrow_record_batch() {
     assert_eq!(b.value(0), "x");
     assert_eq!(b.value(1), "y");
 }
+
+// -------------------------------
+// ArrayV -> Arrow (zero-copy window)
+// -------------------------------
+#[cfg(feature = "views")]
+#[test]
+fn test_array_view_to_arrow_numeric_with_nulls() {
+    use minarrow::{ArrayV, MaskedArray};
+
+    let mut arr = minarrow::IntegerArray::<i32>::default();
+    for i in 0..20 {
+        if i % 3 == 0 {
+            arr.push_null();
+        } else {
+            arr.push(i);
+        }
+    }
+    let view = ArrayV::new(MArray::from_int32(arr), 5, 10);
+    let ar = view.to_apache_arrow("x");
+
+    assert_eq!(ar.data_type(), &ADataType::Int32);
+    assert_eq!(ar.len(), 10);
+    assert_eq!(ar.null_count(), view.null_count());
+
+    let col = ar.as_any().downcast_ref::<Int32Array>().unwrap();
+    for (j, i) in (5..15).enumerate() {
+        if i % 3 == 0 {
+            assert!(col.is_null(j));
+        } else {
+            assert_eq!(col.value(j), i);
+        }
+    }
+}
+
+#[cfg(feature = "views")]
+#[test]
+fn test_array_view_to_arrow_string_with_nulls() {
+    use minarrow::{ArrayV, MaskedArray};
+
+    let mut arr = minarrow::StringArray::<u32>::default();
+    arr.push_str("a");
+    arr.push_null();
+    arr.push_str("bb");
+    arr.push_str("ccc");
+    arr.push_null();
+    arr.push_str("d");
+    let view = ArrayV::new(MArray::from_string32(arr), 1, 4);
+    let ar = view.to_apache_arrow("s");
+
+    assert_eq!(ar.data_type(), &ADataType::Utf8);
+    let col = ar.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(col.len(), 4);
+    assert!(col.is_null(0));
+    assert_eq!(col.value(1), "bb");
+    assert_eq!(col.value(2), "ccc");
+    assert!(col.is_null(3));
+}
+
+// -------------------------------
+// Arrow -> Array -> Arrow (import)
+// -------------------------------
+#[test]
+fn test_array_from_arrow_round_trip() {
+    let ints: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+    let m = MArray::try_from_arrow(ints.as_ref()).unwrap();
+    assert_eq!(m.len(), 3);
+    assert_eq!(m.null_count(), 1);
+    let back = m.to_apache_arrow("x");
+    assert_eq!(back.to_data(), ints.to_data());
+    let m = MArray::try_from_arrow(ints.slice(1, 2).as_ref()).unwrap();
+    assert_eq!(m.to_apache_arrow("x").to_data(), ints.slice(1, 2).to_data());
+
+    let strs: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None, Some("ccc")]));
+    let m = MArray::try_from_arrow(strs.as_ref()).unwrap();
+    assert_eq!(m.arrow_type(), ArrowType::String);
+    assert_eq!(m.to_apache_arrow("s").to_data(), strs.to_data());
+
+    // Sliced inputs carry an offset, which the import compacts away
+    let sliced = strs.slice(1, 2);
+    let m = MArray::try_from_arrow(sliced.as_ref()).unwrap();
+    let col = m.to_apache_arrow("s");
+    let col = col.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(col.len(), 2);
+    assert!(col.is_null(0));
+    assert_eq!(col.value(1), "ccc");
+}
+
+#[cfg(any(
+    not(feature = "default_categorical_8"),
+    feature = "extended_categorical"
+))]
+#[test]
+fn test_array_from_arrow_dictionary() {
+    use arrow::array::DictionaryArray;
+    use arrow::datatypes::Int32Type;
+
+    let dict: DictionaryArray<Int32Type> = vec!["x", "y", "x", "z"].into_iter().collect();
+    let (m, field) = MArray::try_from_arrow_with_field(&dict).unwrap();
+    assert!(matches!(field.dtype, ArrowType::Dictionary(_)));
+    let cat = m.str_ref().unwrap().cat32_ref().unwrap();
+    assert_eq!(cat.get_str(0), Some("x"));
+    assert_eq!(cat.get_str(3), Some("z"));
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn test_array_from_arrow_timestamp_tz() {
+    let ts = TimestampNanosecondArray::from(vec![1_000, 2_000]).with_timezone("Europe/Paris");
+    let (m, field) = MArray::try_from_arrow_with_field(&ts).unwrap();
+    assert_eq!(
+        field.dtype,
+        ArrowType::Timestamp(TimeUnit::Nanoseconds, Some("Europe/Paris".to_string()))
+    );
+    let back = m.to_apache_arrow_with_field(&field);
+    assert_eq!(
+        back.data_type(),
+        &ADataType::Timestamp(ATimeUnit::Nanosecond, Some("Europe/Paris".into()))
+    );
+    assert_eq!(back.to_data(), ts.to_data());
+}
+
+#[test]
+fn test_array_from_arrow_unsupported() {
+    let nulls = arrow::array::NullArray::new(2);
+    assert!(MArray::try_from_arrow(&nulls).is_err());
+    let bin = arrow::array::BinaryArray::from(vec![b"ab".as_ref()]);
+    assert!(MArray::try_from_arrow(&bin).is_err());
+}
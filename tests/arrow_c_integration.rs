@@ -53,7 +53,7 @@ mod arrow_c_integration {
 
     fn schema_for(name: &str, ty: ArrowType, nullable: bool) -> Schema {
         Schema {
-            fields: vec![Field::new(name, ty, nullable, None)],
+            fields: vec![Arc::new(Field::new(name, ty, nullable, None))],
             ..Default::default()
         }
     }